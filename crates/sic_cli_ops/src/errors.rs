@@ -14,6 +14,9 @@ pub enum SicCliOpsError {
 
     #[error("Expected argument for image operation '{0}' (argument #{1})")]
     ExpectedArgumentForImageOperation(String, usize),
+
+    #[error("Unexpected argument '{1}' for image operation '{0}'")]
+    UnexpectedArgumentForImageOperation(String, String),
 }
 
 #[derive(Debug, Error)]
@@ -19,12 +19,11 @@ pub type TResult<T> = Result<T, SicCliOpsError>;
 
 /// Parses cli image operation definitions to image engine image operations.
 /// This parser however doesn't replace Clap, and specifically its validator.
-/// For example, `--flip-horizontal 0` will not be allowed
-/// by Clap's validator. The function below however does allow it, since we parse
-/// only the amount of arguments we expect to receive, in this case 0.
-/// Since we can rely on Clap, we left the added complexity out here.  
+/// We parse only the amount of arguments we expect to receive, and reject a value which directly
+/// follows them, e.g. the `0` of `--flip-horizontal 0`, or the `100` of
+/// `--resize width-only 4 100` (Clap allows up to 3 values for `--resize`).
 pub fn create_image_ops<I: IntoIterator<Item = String>>(iter: I) -> TResult<Vec<Instr>> {
-    let mut iter = iter.into_iter();
+    let mut iter = iter.into_iter().peekable();
 
     let size = iter.size_hint().1.unwrap_or(128);
 
@@ -36,6 +35,16 @@ pub fn create_image_ops<I: IntoIterator<Item = String>>(iter: I) -> TResult<Vec<
         {
             let operation = OperationId::try_from_name(&program_argument[2..])?;
             let inputs = take_n(&mut iter, operation)?;
+
+            // A value directly after the arguments of an operation can't belong to another
+            // argument, so it would be silently skipped otherwise
+            if let Some(surplus) = iter.next_if(|next| !next.starts_with('-')) {
+                return Err(SicCliOpsError::UnexpectedArgumentForImageOperation(
+                    operation.as_str().to_string(),
+                    surplus,
+                ));
+            }
+
             let inputs = inputs.iter().map(|v| v.as_str()).collect::<Vec<&str>>();
             ast.push(operation.create_instruction(inputs)?);
         }
@@ -50,10 +59,14 @@ fn take_n<I: Iterator<Item = String>>(
     operation: OperationId,
 ) -> TResult<Vec<String>> {
     let mut operation_arguments: Vec<String> = Vec::new();
+    let mut number_of_arguments = operation.takes_number_of_arguments();
 
-    for i in 0..operation.takes_number_of_arguments() {
+    let mut i = 0;
+    while i < number_of_arguments {
         if let Some(op_arg) = iter.next() {
-            operation_arguments.push(op_arg)
+            operation_arguments.push(op_arg);
+            number_of_arguments = operation.takes_number_of_arguments_given(&operation_arguments);
+            i += 1;
         } else {
             return Err(SicCliOpsError::ExpectedArgumentForImageOperation(
                 operation.as_str().to_string(),
@@ -72,10 +85,39 @@ mod tests {
     #[allow(clippy::vec_init_then_push)]
     mod individual_args {
         use super::*;
+        use sic_core::image::Rgba;
         use sic_image_engine::ImgOp;
         use sic_image_engine::engine::EnvItem;
+        use sic_image_engine::wrapper::bilateral::BilateralInputs;
+        use sic_image_engine::wrapper::channel::{
+            Channel, ChannelOrLuma, ChannelScope, MergeChannelsInputs,
+        };
+        use sic_image_engine::wrapper::chroma_key::ChromaKeyInputs;
+        use sic_image_engine::wrapper::clahe::ClaheInputs;
+        use sic_image_engine::wrapper::color_matrix::{ColorMatrixInputs, ColorMatrixPreset};
+        use sic_image_engine::wrapper::curves::{CurvePoints, CurvesChannel, CurvesInputs};
+        use sic_image_engine::wrapper::dither_algorithm::DitherAlgorithm;
+        use sic_image_engine::wrapper::equalize::EqualizeMode;
+        use sic_image_engine::wrapper::extend_canvas::ExtendCanvasInputs;
+        use sic_image_engine::wrapper::filter::{
+            Divisor, EdgeMode, FilterInputs, Kernel, KernelShape,
+        };
         use sic_image_engine::wrapper::filter_type::FilterTypeWrap;
+        use sic_image_engine::wrapper::frame_range::FrameRange;
+        use sic_image_engine::wrapper::gradient::{
+            ColorStop, GradientColorSpace, GradientInputs, GradientShape,
+        };
+        use sic_image_engine::wrapper::gravity::Gravity;
         use sic_image_engine::wrapper::image_path::ImageFromPath;
+        use sic_image_engine::wrapper::levels::LevelsInputs;
+        use sic_image_engine::wrapper::morphology::{MorphologyInputs, MorphologyNorm};
+        use sic_image_engine::wrapper::nl_means::NlMeansInputs;
+        use sic_image_engine::wrapper::overlay::{BlendMode, OverlayInputs, OverlayPlacement};
+        use sic_image_engine::wrapper::pad::PadInputs;
+        use sic_image_engine::wrapper::palette::{PaletteSource, QuantizeInputs, QuantizeMode};
+        use sic_image_engine::wrapper::resize::{ResizeInputs, ResizeValue};
+        use sic_image_engine::wrapper::rotate::{RotateCanvas, RotateInputs};
+        use sic_image_engine::wrapper::sprite_sheet::{SplitSpriteSheetInputs, SpriteSheetInputs};
         use sic_testing::setup_test_image;
 
         macro_rules! op {
@@ -112,52 +154,200 @@ mod tests {
 
         #[parameterized(
             ops = {
+                vec!["--bilateral", "3", "25", "3.5"],
                 vec!["--blur", "1.0"],
                 vec!["--brighten", "-1"],
+                vec!["--canny", "50", "100.5"],
+                vec!["--clahe", "8", "4", "2.5"],
+                vec!["--close", "1", "linf"],
+                vec!["--color-matrix", "sepia"],
+                vec!["--color-matrix", "1", "0", "0", "0", "0", "0", "1", "0", "0", "0", "0", "0", "1", "0", "0", "0", "0", "0", "-1", "1"],
                 vec!["--contrast", "1.0"],
                 vec!["--crop", "0", "1", "2", "3"],
+                vec!["--crop-to", "1", "2", "south-west"],
+                vec!["--curves", "luminance", "0:10,128:140,255:250"],
                 vec!["--diff", "▲"],
+                vec!["--dilate", "2", "l1"],
+                vec!["--equalize", "luma"],
+                vec!["--equalize", "channels"],
+                vec!["--erode", "1", "l2"],
+                vec!["--exposure", "-1.5"],
+                vec!["--extend-canvas", "10", "20", "north", "rgba(0, 0, 0, 255)"],
+                vec!["--filter", "3x1", "auto", "0.5", "wrap", "1", "-2", "1"],
+                vec!["--filter", "3x1-separable", "2", "0", "mirror", "1", "0", "1", "1"],
+                vec!["--filter", "3x1", "clamp", "1", "1", "1"],
+                vec!["--filter", "3x1", "3", "wrap", "1", "-2", "1"],
                 vec!["--filter3x3", "1.0", "1.0", "1.0", "-1.0", "-1.0", "-1.0", "0.0", "0.0", "0.0"],
                 vec!["--flip-horizontal"],
                 vec!["--flip-vertical"],
+                vec!["--gamma", "2.2"],
                 vec!["--grayscale"],
                 vec!["--hue-rotate", "-1"],
                 vec!["--invert"],
+                vec!["--laplacian"],
+                vec!["--levels", "10", "240", "1.2", "0", "255"],
+                vec!["--median", "2"],
+                vec!["--morph-gradient", "1", "L1"],
+                vec!["--nl-means", "10", "1", "5"],
+                vec!["--open", "3", "linf"],
+                vec!["--overlay", "logo.png", "10", "20"],
+                vec!["--overlay", "multiply", "0.5", "texture.png", "0", "0"],
+                vec!["--overlay", "width", "20%", "logo.png", "south-east", "10", "5"],
+                vec!["--overlay", "screen", "0.5", "logo.png", "tile", "20", "30", "-45"],
+                vec!["--pad", "1", "2", "3", "4", "rgba(255, 255, 255, 255)"],
+                vec!["--quantize", "dither", "palette.gpl"],
+                vec!["--quantize", "nearest", "rgba(0, 0, 0, 255) rgba(255, 255, 255, 255)"],
                 vec!["--resize", "1", "1"],
+                vec!["--resize", "50%", "1"],
+                vec!["--resize", "fit", "1", "10%"],
+                vec!["--resize", "cover", "1", "2"],
+                vec!["--resize", "width-only", "10"],
+                vec!["--resize", "height-only", "25%"],
                 vec!["--preserve-aspect-ratio", "true"],
                 vec!["--sampling-filter", "catmullrom"],
                 vec!["--sampling-filter", "gaussian"],
                 vec!["--sampling-filter", "lanczos3"],
                 vec!["--sampling-filter", "nearest"],
                 vec!["--sampling-filter", "triangle"],
+                vec!["--dither-algorithm", "atkinson"],
+                vec!["--dither-algorithm", "bayer8x8"],
+                vec!["--dither-serpentine", "true"],
+                vec!["--rotate", "-12.5", "expand", "rgba(0, 0, 0, 0)"],
                 vec!["--rotate90"],
                 vec!["--rotate180"],
                 vec!["--rotate270"],
+                vec!["--saturate", "1.5"],
+                vec!["--scharr"],
+                vec!["--sobel"],
+                vec!["--trim", "16"],
                 vec!["--unsharpen", "-1.0", "-1"],
+                vec!["--extract-alpha"],
+                vec!["--set-alpha", "mask.png"],
+                vec!["--scale-alpha", "0.5"],
+                vec!["--premultiply-alpha"],
+                vec!["--unpremultiply-alpha"],
+                vec!["--flatten", "rgba(255, 255, 255, 255)"],
+                vec!["--chroma-key", "rgba(0, 255, 0, 255)", "40", "10"],
+                vec!["--chroma-key", "despill", "rgba(0, 255, 0, 255)", "40.5", "0"],
+                vec!["--color-to-alpha", "rgba(255, 255, 255, 255)"],
+                vec!["--extract-channel", "green"],
+                vec!["--extract-channel", "luma"],
+                vec!["--swap-channels", "r", "a"],
+                vec!["--merge-channels", "ao.png", "keep", "metalness.png", "keep"],
+                vec!["--channel", "alpha"],
+                vec!["--channel", "all"],
+                vec!["--gradient", "linear", "-45", "rgba(255, 0, 0, 255) rgba(0, 0, 255, 255) 75%"],
+                vec!["--gradient", "radial", "rgba(255, 0, 0, 255) 0% rgba(0, 0, 255, 255)"],
+                vec!["--gradient-color-space", "linear"],
+                vec!["--set-frame-delay", "40"],
+                vec!["--scale-frame-delay", "0.5"],
+                vec!["--set-frame-delay-at", "2", "1000"],
+                vec!["--frames", "3..10"],
+                vec!["--drop-frames", "2"],
+                vec!["--reverse-frames"],
+                vec!["--boomerang"],
+                vec!["--duplicate-frame", "0", "3"],
+                vec!["--sprite-sheet", "4", "2", "rgba(0, 0, 0, 0)"],
+                vec!["--sprite-sheet", "atlas", "sheet.json", "4", "0", "rgba(0, 0, 0, 255)"],
+                vec!["--split-sprite-sheet", "32", "16", "1"],
             },
             expected = {
+                op![ImgOp::Bilateral(BilateralInputs::new(3, 25.0, 3.5))],
                 op![ImgOp::Blur(1.0)],
                 op![ImgOp::Brighten(-1)],
+                op![ImgOp::Canny((50.0, 100.5))],
+                op![ImgOp::Clahe(ClaheInputs::new(8, 4, 2.5))],
+                op![ImgOp::Close(MorphologyInputs::new(1, MorphologyNorm::LInf))],
+                op![ImgOp::ColorMatrix(ColorMatrixPreset::Sepia.matrix())],
+                op![ImgOp::ColorMatrix(ColorMatrixInputs::new([1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, -1.0, 1.0]))],
                 op![ImgOp::Contrast(1.0)],
                 op![ImgOp::Crop((0, 1, 2, 3))],
+                op![ImgOp::CropTo((1, 2, Gravity::SouthWest))],
+                op![ImgOp::Curves(CurvesInputs::new(CurvesChannel::Luminance, CurvePoints::new(vec![(0, 10), (128, 140), (255, 250)]).unwrap()))],
                 op![ImgOp::Diff(ImageFromPath::new(setup_test_image("aaa.png")))],
+                op![ImgOp::Dilate(MorphologyInputs::new(2, MorphologyNorm::L1))],
+                op![ImgOp::Equalize(EqualizeMode::Luma)],
+                op![ImgOp::Equalize(EqualizeMode::Channels)],
+                op![ImgOp::Erode(MorphologyInputs::new(1, MorphologyNorm::L2))],
+                op![ImgOp::Exposure(-1.5)],
+                op![ImgOp::ExtendCanvas(ExtendCanvasInputs::new(10, 20, Gravity::North, Rgba([0, 0, 0, 255])))],
+                op![ImgOp::Filter(FilterInputs::new(Kernel::new(KernelShape::Full { width: 3, height: 1 }, vec![1.0, -2.0, 1.0]).unwrap(), Divisor::Auto, 0.5, EdgeMode::Wrap))],
+                op![ImgOp::Filter(FilterInputs::new(Kernel::new(KernelShape::Separable { width: 3, height: 1 }, vec![1.0, 0.0, 1.0, 1.0]).unwrap(), Divisor::Value(2.0), 0.0, EdgeMode::Mirror))],
+                op![ImgOp::Filter(FilterInputs::new(Kernel::new(KernelShape::Full { width: 3, height: 1 }, vec![1.0, 1.0, 1.0]).unwrap(), Divisor::Auto, 0.0, EdgeMode::Clamp))],
+                op![ImgOp::Filter(FilterInputs::new(Kernel::new(KernelShape::Full { width: 3, height: 1 }, vec![1.0, -2.0, 1.0]).unwrap(), Divisor::Value(3.0), 0.0, EdgeMode::Wrap))],
                 op![ImgOp::Filter3x3([1.0, 1.0, 1.0, -1.0, -1.0, -1.0, 0.0, 0.0, 0.0])],
                 op![ImgOp::FlipHorizontal],
                 op![ImgOp::FlipVertical],
+                op![ImgOp::Gamma(2.2)],
                 op![ImgOp::Grayscale],
                 op![ImgOp::HueRotate(-1)],
                 op![ImgOp::Invert],
-                op![ImgOp::Resize((1, 1))],
+                op![ImgOp::Laplacian],
+                op![ImgOp::Levels(LevelsInputs::new((10, 240), 1.2, (0, 255)))],
+                op![ImgOp::Median(2)],
+                op![ImgOp::MorphGradient(MorphologyInputs::new(1, MorphologyNorm::L1))],
+                op![ImgOp::NlMeans(NlMeansInputs::new(10.0, 1, 5))],
+                op![ImgOp::Open(MorphologyInputs::new(3, MorphologyNorm::LInf))],
+                op![ImgOp::Overlay(OverlayInputs::new(ImageFromPath::new("logo.png".into()), (10, 20)))],
+                op![ImgOp::Overlay(OverlayInputs::new(ImageFromPath::new("texture.png".into()), (0, 0)).with_blending(BlendMode::Multiply, 0.5))],
+                op![ImgOp::Overlay(OverlayInputs::new(ImageFromPath::new("logo.png".into()), OverlayPlacement::Anchored { gravity: Gravity::SouthEast, margin: (10, 5) }).with_width(ResizeValue::Percentage(20.0)))],
+                op![ImgOp::Overlay(OverlayInputs::new(ImageFromPath::new("logo.png".into()), OverlayPlacement::Tiled { spacing: (20, 30), degrees: -45.0 }).with_blending(BlendMode::Screen, 0.5))],
+                op![ImgOp::Pad(PadInputs::new((1, 2, 3, 4), Rgba([255, 255, 255, 255])))],
+                op![ImgOp::Quantize(QuantizeInputs::new(QuantizeMode::Dither, PaletteSource::Path("palette.gpl".into())))],
+                op![ImgOp::Quantize(QuantizeInputs::new(QuantizeMode::Nearest, PaletteSource::Colors(vec![Rgba([0, 0, 0, 255]), Rgba([255, 255, 255, 255])])))],
+                op![ImgOp::Resize((1, 1).into())],
+                op![ImgOp::Resize(ResizeInputs::Dimensions(ResizeValue::Percentage(50.0), ResizeValue::Pixels(1)))],
+                op![ImgOp::Resize(ResizeInputs::Fit(ResizeValue::Pixels(1), ResizeValue::Percentage(10.0)))],
+                op![ImgOp::Resize(ResizeInputs::Cover(ResizeValue::Pixels(1), ResizeValue::Pixels(2)))],
+                op![ImgOp::Resize(ResizeInputs::WidthOnly(ResizeValue::Pixels(10)))],
+                op![ImgOp::Resize(ResizeInputs::HeightOnly(ResizeValue::Percentage(25.0)))],
                 modifier![EnvItem::PreserveAspectRatio(true)],
                 modifier![EnvItem::CustomSamplingFilter(FilterTypeWrap::try_from_str("catmullrom").unwrap())],
                 modifier![EnvItem::CustomSamplingFilter(FilterTypeWrap::try_from_str("gaussian").unwrap())],
                 modifier![EnvItem::CustomSamplingFilter(FilterTypeWrap::try_from_str("lanczos3").unwrap())],
                 modifier![EnvItem::CustomSamplingFilter(FilterTypeWrap::try_from_str("nearest").unwrap())],
                 modifier![EnvItem::CustomSamplingFilter(FilterTypeWrap::try_from_str("triangle").unwrap())],
+                modifier![EnvItem::DitherAlgorithm(DitherAlgorithm::Atkinson)],
+                modifier![EnvItem::DitherAlgorithm(DitherAlgorithm::Bayer8x8)],
+                modifier![EnvItem::DitherSerpentine(true)],
+                op![ImgOp::Rotate(RotateInputs::new(-12.5, RotateCanvas::Expand, Rgba([0, 0, 0, 0])))],
                 op![ImgOp::Rotate90],
                 op![ImgOp::Rotate180],
                 op![ImgOp::Rotate270],
+                op![ImgOp::Saturate(1.5)],
+                op![ImgOp::Scharr],
+                op![ImgOp::Sobel],
+                op![ImgOp::Trim(16)],
                 op![ImgOp::Unsharpen((-1.0, -1))],
+                op![ImgOp::ExtractAlpha],
+                op![ImgOp::SetAlpha(ImageFromPath::new("mask.png".into()))],
+                op![ImgOp::ScaleAlpha(0.5)],
+                op![ImgOp::PremultiplyAlpha],
+                op![ImgOp::UnpremultiplyAlpha],
+                op![ImgOp::Flatten(Rgba([255, 255, 255, 255]))],
+                op![ImgOp::ChromaKey(ChromaKeyInputs::new(Rgba([0, 255, 0, 255]), 40.0, 10.0))],
+                op![ImgOp::ChromaKey(ChromaKeyInputs::new(Rgba([0, 255, 0, 255]), 40.5, 0.0).with_despill(true))],
+                op![ImgOp::ColorToAlpha(Rgba([255, 255, 255, 255]))],
+                op![ImgOp::ExtractChannel(ChannelOrLuma::Channel(Channel::Green))],
+                op![ImgOp::ExtractChannel(ChannelOrLuma::Luma)],
+                op![ImgOp::SwapChannels((Channel::Red, Channel::Alpha))],
+                op![ImgOp::MergeChannels(MergeChannelsInputs::new([Some(ImageFromPath::new("ao.png".into())), None, Some(ImageFromPath::new("metalness.png".into())), None]))],
+                modifier![EnvItem::ChannelScope(ChannelScope::Only(Channel::Alpha))],
+                modifier![EnvItem::ChannelScope(ChannelScope::All)],
+                op![ImgOp::Gradient(GradientInputs::new(GradientShape::Linear { angle: -45.0 }, vec![ColorStop::new(Rgba([255, 0, 0, 255]), None), ColorStop::new(Rgba([0, 0, 255, 255]), Some(0.75))]))],
+                op![ImgOp::Gradient(GradientInputs::new(GradientShape::Radial, vec![ColorStop::new(Rgba([255, 0, 0, 255]), Some(0.0)), ColorStop::new(Rgba([0, 0, 255, 255]), None)]))],
+                modifier![EnvItem::GradientColorSpace(GradientColorSpace::Linear)],
+                op![ImgOp::SetFrameDelay(40)],
+                op![ImgOp::ScaleFrameDelay(0.5)],
+                op![ImgOp::SetFrameDelayAt((2, 1000))],
+                op![ImgOp::Frames(FrameRange::new(Some(3), Some(10), false))],
+                op![ImgOp::DropFrames(2)],
+                op![ImgOp::ReverseFrames],
+                op![ImgOp::Boomerang],
+                op![ImgOp::DuplicateFrame((0, 3))],
+                op![ImgOp::SpriteSheet(SpriteSheetInputs::new(4, 2, Rgba([0, 0, 0, 0])))],
+                op![ImgOp::SpriteSheet(SpriteSheetInputs::new(4, 0, Rgba([0, 0, 0, 255])).with_atlas("sheet.json".into()))],
+                op![ImgOp::SplitSpriteSheet(SplitSpriteSheetInputs::new((32, 16), 1))],
             },
         )]
         fn create_image_ops_t_sunny(ops: Vec<&str>, expected: Vec<Instr>) {
@@ -251,15 +441,82 @@ mod tests {
                 vec!["--blur", "A"],
                 vec!["--brighten", "-1.0"],
                 vec!["--contrast", ""],
+                vec!["--color-matrix", "sepa"],
+                vec!["--canny", "50"],
+                vec!["--canny", "50", "a"],
+                vec!["--color-matrix", "1", "0", "0", "0", "0"],
                 vec!["--crop", "--crop", "0", "1", "2", "3"],
                 vec!["--diff"],
                 vec!["--filter3x3", "[", "1.0", "1.0", "1.0", "-1.0", "-1.0", "-1.0", "0.0", "0.0", "0.0", "]"],
                 vec!["--hue-rotate", "-100.8"],
+                vec!["--filter", "3x1", "auto", "0", "clamp", "1", "1"],
+                vec!["--filter", "2x1", "auto", "0", "clamp", "1", "1"],
+                vec!["--filter", "3x1", "auto", "0", "repeat", "1", "1", "1"],
                 vec!["--resize", "1", "1", "--crop"],
+                vec!["--resize", "fit", "1"],
+                vec!["--resize", "0%", "1"],
+                vec!["--resize", "width-only", "4", "100"],
+                vec!["--resize", "height-only", "25%", "100", "--blur", "1"],
+                vec!["--rotate", "45", "shrink", "rgba(0, 0, 0, 0)"],
+                vec!["--rotate", "45", "crop"],
                 vec!["--preserve-aspect-ratio", "yes"],
                 vec!["--sampling-filter", "tri"],
                 vec!["--sampling-filter", ""],
+                vec!["--dither-algorithm", "bayer3x3"],
+                vec!["--dither-serpentine", "yes"],
+                vec!["--crop-to", "1", "2", "middle"],
+                vec!["--crop-to", "1", "2"],
+                vec!["--trim", "256"],
+                vec!["--pad", "1", "2", "3", "rgba(255, 255, 255, 255)"],
+                vec!["--overlay", "multiply", "texture.png", "0", "0"],
+                vec!["--overlay", "logo.png", "10"],
+                vec!["--overlay", "logo.png", "south-east", "10"],
+                vec!["--overlay", "width", "logo.png", "0", "0"],
+                vec!["--quantize", "closest", "palette.gpl"],
+                vec!["--quantize", "nearest"],
+                vec!["--quantize", "nearest", "rgba(0, 0, 0, 255) size(1)"],
+                vec!["--extend-canvas", "10", "20", "rgba(255, 255, 255, 255)"],
+                vec!["--saturate", "a"],
+                vec!["--gamma"],
+                vec!["--levels", "10", "240", "1.2", "0"],
+                vec!["--levels", "10", "240", "1.2", "0", "256"],
+                vec!["--curves", "alpha", "0:0,255:255"],
+                vec!["--curves", "rgb", "0:0"],
                 vec!["--unsharpen", "-1.0", "-1.0"],
+                vec!["--set-alpha"],
+                vec!["--scale-alpha", "a"],
+                vec!["--flatten", "255", "255", "255", "255"],
+                vec!["--flatten", "rgb(255, 255, 255)"],
+                vec!["--chroma-key", "rgba(0, 255, 0, 255)", "40"],
+                vec!["--chroma-key", "despill", "40", "10"],
+                vec!["--color-to-alpha", "white"],
+                vec!["--extract-channel", "rgb"],
+                vec!["--swap-channels", "r"],
+                vec!["--swap-channels", "r", "luma"],
+                vec!["--merge-channels", "r.png", "g.png", "b.png"],
+                vec!["--channel", "luma"],
+                vec!["--gradient", "linear", "rgba(255, 0, 0, 255) rgba(0, 0, 255, 255)"],
+                vec!["--gradient", "radial", "rgba(255, 0, 0, 255)"],
+                vec!["--gradient", "conic", "0", "rgba(255, 0, 0, 255) 50%"],
+                vec!["--gradient-color-space", "hsl"],
+                vec!["--set-frame-delay", "-40"],
+                vec!["--scale-frame-delay", "a"],
+                vec!["--set-frame-delay-at", "2"],
+                vec!["--frames", "3"],
+                vec!["--frames", "3..="],
+                vec!["--drop-frames", "a"],
+                vec!["--duplicate-frame", "1"],
+                vec!["--sprite-sheet", "4", "2"],
+                vec!["--sprite-sheet", "atlas", "4", "2", "rgba(0, 0, 0, 0)"],
+                vec!["--split-sprite-sheet", "32", "-16", "1"],
+                vec!["--erode", "1"],
+                vec!["--dilate", "256", "l1"],
+                vec!["--open", "1", "l3"],
+                vec!["--median", "-1"],
+                vec!["--bilateral", "3", "25"],
+                vec!["--nl-means", "10", "1.5", "5"],
+                vec!["--equalize", "rgb"],
+                vec!["--clahe", "8", "8"],
             }
         )]
         fn create_image_ops_t_expected_failure(ops: Vec<&str>) {
@@ -1,10 +1,33 @@
 use crate::TResult;
 use crate::errors::{InternalErrorSource, SicCliOpsError};
+use sic_core::image::Rgba;
 use sic_image_engine::ImgOp;
 use sic_image_engine::engine::{EnvItem, Instr};
+use sic_image_engine::wrapper::bilateral::BilateralInputs;
+use sic_image_engine::wrapper::channel::{
+    Channel, ChannelOrLuma, ChannelScope, MergeChannelsInputs,
+};
+use sic_image_engine::wrapper::chroma_key::ChromaKeyInputs;
+use sic_image_engine::wrapper::clahe::ClaheInputs;
+use sic_image_engine::wrapper::color_matrix::{ColorMatrixInputs, ColorMatrixPreset};
+use sic_image_engine::wrapper::curves::CurvesInputs;
+use sic_image_engine::wrapper::dither_algorithm::DitherAlgorithm;
+use sic_image_engine::wrapper::equalize::EqualizeMode;
+use sic_image_engine::wrapper::extend_canvas::ExtendCanvasInputs;
+use sic_image_engine::wrapper::filter::{EdgeMode, FilterInputs, KernelShape};
 use sic_image_engine::wrapper::filter_type::FilterTypeWrap;
+use sic_image_engine::wrapper::frame_range::FrameRange;
+use sic_image_engine::wrapper::gradient::{GradientColorSpace, GradientInputs};
+use sic_image_engine::wrapper::gravity::Gravity;
 use sic_image_engine::wrapper::image_path::ImageFromPath;
-use sic_image_engine::wrapper::overlay::OverlayInputs;
+use sic_image_engine::wrapper::levels::LevelsInputs;
+use sic_image_engine::wrapper::morphology::MorphologyInputs;
+use sic_image_engine::wrapper::nl_means::NlMeansInputs;
+use sic_image_engine::wrapper::overlay::{BlendMode, OverlayInputs};
+use sic_image_engine::wrapper::pad::PadInputs;
+use sic_image_engine::wrapper::palette::QuantizeInputs;
+use sic_image_engine::wrapper::resize::{ResizeInputs, ResizeMode};
+use sic_image_engine::wrapper::sprite_sheet::{SplitSpriteSheetInputs, SpriteSheetInputs};
 use sic_parser::errors::SicParserError;
 use sic_parser::value_parser::{Describable, ParseInputsFromIter};
 use std::fmt::Debug;
@@ -17,33 +40,86 @@ use std::str::FromStr;
 #[strum(serialize_all = "kebab_case")]
 pub enum OperationId {
     // image operations
+    Bilateral,
     Blur,
+    Boomerang,
     Brighten,
+    Canny,
+    ChromaKey,
+    Clahe,
+    Close,
+    ColorMatrix,
+    ColorToAlpha,
     Contrast,
     Crop,
+    CropTo,
+    Curves,
     Diff,
+    Dilate,
     Dither,
     DitherQuant,
     DrawText,
+    DropFrames,
+    DuplicateFrame,
+    Equalize,
+    Erode,
+    Exposure,
+    ExtendCanvas,
+    ExtractAlpha,
+    ExtractChannel,
+    Filter,
     Filter3x3,
+    Flatten,
     FlipHorizontal,
     FlipVertical,
+    Frames,
+    Gamma,
+    Gradient,
     Grayscale,
     HueRotate,
     HorizontalGradient,
     IndexColors,
     IndexColorsQuant,
     Invert,
+    Laplacian,
+    Levels,
+    Median,
+    MergeChannels,
+    MorphGradient,
+    NlMeans,
+    Open,
     Overlay,
+    Pad,
+    PremultiplyAlpha,
+    Quantize,
     Resize,
+    ReverseFrames,
+    Rotate,
     Rotate90,
     Rotate180,
     Rotate270,
+    Saturate,
+    ScaleAlpha,
+    ScaleFrameDelay,
+    Scharr,
+    SetAlpha,
+    SetFrameDelay,
+    SetFrameDelayAt,
+    Sobel,
+    SplitSpriteSheet,
+    SpriteSheet,
+    SwapChannels,
     Threshold,
+    Trim,
+    UnpremultiplyAlpha,
     Unsharpen,
     VerticalGradient,
 
     // modifiers
+    Channel,
+    DitherAlgorithm,
+    DitherSerpentine,
+    GradientColorSpace,
     PreserveAspectRatio,
     SamplingFilter,
 }
@@ -74,37 +150,162 @@ impl OperationId {
     pub fn takes_number_of_arguments(self) -> usize {
         match self {
             // image operations
+            OperationId::Bilateral => 3,
             OperationId::Blur => 1,
+            OperationId::Boomerang => 0,
             OperationId::Brighten => 1,
+            OperationId::Canny => 2,
+            OperationId::ChromaKey => 3,
+            OperationId::Clahe => 3,
+            OperationId::Close => 2,
+            OperationId::ColorMatrix => 20,
+            OperationId::ColorToAlpha => 1,
             OperationId::Contrast => 1,
             OperationId::Crop => 4,
+            OperationId::CropTo => 3,
+            OperationId::Curves => 2,
             OperationId::Diff => 1,
+            OperationId::Dilate => 2,
             OperationId::Dither => 0,
             OperationId::DitherQuant => 2,
             OperationId::DrawText => 5,
+            OperationId::DropFrames => 1,
+            OperationId::DuplicateFrame => 2,
+            OperationId::Equalize => 1,
+            OperationId::Erode => 2,
+            OperationId::Exposure => 1,
+            OperationId::ExtendCanvas => 4,
+            OperationId::ExtractAlpha => 0,
+            OperationId::ExtractChannel => 1,
+            OperationId::Filter => 2,
             OperationId::Filter3x3 => 9,
+            OperationId::Flatten => 1,
             OperationId::FlipHorizontal => 0,
             OperationId::FlipVertical => 0,
+            OperationId::Frames => 1,
+            OperationId::Gamma => 1,
+            OperationId::Gradient => 3,
             OperationId::Grayscale => 0,
             OperationId::HueRotate => 1,
             OperationId::HorizontalGradient => 2,
             OperationId::IndexColors => 0,
             OperationId::IndexColorsQuant => 2,
             OperationId::Invert => 0,
+            OperationId::Laplacian => 0,
+            OperationId::Levels => 5,
+            OperationId::Median => 1,
+            OperationId::MergeChannels => 4,
+            OperationId::MorphGradient => 2,
+            OperationId::NlMeans => 3,
+            OperationId::Open => 2,
             OperationId::Overlay => 3,
+            OperationId::Pad => 5,
+            OperationId::PremultiplyAlpha => 0,
+            OperationId::Quantize => 2,
             OperationId::Resize => 2,
+            OperationId::ReverseFrames => 0,
+            OperationId::Rotate => 3,
             OperationId::Rotate90 => 0,
             OperationId::Rotate180 => 0,
             OperationId::Rotate270 => 0,
+            OperationId::Saturate => 1,
+            OperationId::ScaleAlpha => 1,
+            OperationId::ScaleFrameDelay => 1,
+            OperationId::Scharr => 0,
+            OperationId::SetAlpha => 1,
+            OperationId::SetFrameDelay => 1,
+            OperationId::SetFrameDelayAt => 2,
+            OperationId::Sobel => 0,
+            OperationId::SplitSpriteSheet => 3,
+            OperationId::SpriteSheet => 3,
+            OperationId::SwapChannels => 2,
             OperationId::Threshold => 0,
+            OperationId::Trim => 1,
+            OperationId::UnpremultiplyAlpha => 0,
             OperationId::Unsharpen => 2,
             OperationId::VerticalGradient => 2,
 
             // image operation modifiers
+            OperationId::Channel => 1,
+            OperationId::DitherAlgorithm => 1,
+            OperationId::DitherSerpentine => 1,
+            OperationId::GradientColorSpace => 1,
             OperationId::PreserveAspectRatio => 1,
             OperationId::SamplingFilter => 1,
         }
     }
+
+    /// Provides the number of arguments an operation takes, given the arguments which have been
+    /// taken so far. Most operations take a fixed number of arguments, in which case this is equal
+    /// to [`OperationId::takes_number_of_arguments`]. Resize however takes an optional mode as
+    /// first argument, which determines how many values follow, e.g. `--resize 10 20`,
+    /// `--resize fit 10 20` and `--resize width-only 10`. Likewise, color-matrix takes either
+    /// a single preset name, or the 20 values of a matrix, and the kernel shape given as first
+    /// argument of filter determines the number of kernel values which follow, after an optional
+    /// divisor and bias and the edge mode. Overlay takes an optional blend mode and opacity, and an
+    /// optional width, before its path, followed by either a position, a gravity with margins, or
+    /// a tile spacing and rotation. Chroma-key takes an optional `despill` keyword before its key
+    /// color, and gradient takes an angle, unless its shape is `radial`.
+    ///
+    /// As more arguments are taken, the result may grow, but it never shrinks.
+    pub fn takes_number_of_arguments_given<S: AsRef<str>>(self, arguments: &[S]) -> usize {
+        let Some(first_argument) = arguments.first().map(AsRef::as_ref) else {
+            return self.takes_number_of_arguments();
+        };
+
+        match self {
+            OperationId::Resize => ResizeMode::from_str(first_argument)
+                .map(|mode| mode.takes_number_of_values() + 1)
+                .unwrap_or_else(|_| self.takes_number_of_arguments()),
+            OperationId::Filter => KernelShape::from_str(first_argument)
+                .map(|shape| filter_number_of_arguments(arguments) + shape.number_of_values())
+                .unwrap_or_else(|_| self.takes_number_of_arguments()),
+            OperationId::ColorMatrix if ColorMatrixPreset::from_str(first_argument).is_ok() => 1,
+            OperationId::Overlay => overlay_number_of_arguments(arguments),
+            OperationId::ChromaKey if first_argument.eq_ignore_ascii_case("despill") => 4,
+            OperationId::SpriteSheet if first_argument.eq_ignore_ascii_case("atlas") => 5,
+            OperationId::Gradient if first_argument.eq_ignore_ascii_case("radial") => 2,
+            _ => self.takes_number_of_arguments(),
+        }
+    }
+}
+
+fn filter_number_of_arguments<S: AsRef<str>>(arguments: &[S]) -> usize {
+    let is_edge_mode = |index: usize| {
+        arguments
+            .get(index)
+            .map(|arg| EdgeMode::from_str(arg.as_ref()).is_ok())
+    };
+
+    // shape and edge mode, with the divisor and bias in between, if given
+    match (is_edge_mode(1), is_edge_mode(2)) {
+        (None | Some(true), _) => 2,
+        (Some(false), None | Some(true)) => 3,
+        (Some(false), Some(false)) => 4,
+    }
+}
+
+fn overlay_number_of_arguments<S: AsRef<str>>(arguments: &[S]) -> usize {
+    let argument = |index: usize| arguments.get(index).map(AsRef::as_ref);
+
+    let mut count = 0;
+
+    if argument(count).is_some_and(|arg| BlendMode::from_str(arg).is_ok()) {
+        count += 2;
+    }
+
+    if argument(count).is_some_and(|arg| arg.eq_ignore_ascii_case("width")) {
+        count += 2;
+    }
+
+    // path
+    count += 1;
+
+    match argument(count) {
+        Some(arg) if arg.eq_ignore_ascii_case("tile") => count + 4,
+        Some(arg) if Gravity::from_str(arg).is_ok() => count + 3,
+        _ => count + 2,
+    }
 }
 
 macro_rules! parse_inputs_by_type {
@@ -129,10 +330,35 @@ impl OperationId {
     {
         let stmt = match self {
             // image operations
+            OperationId::Bilateral => Instr::Operation(ImgOp::Bilateral(parse_inputs_by_type!(
+                inputs,
+                BilateralInputs
+            )?)),
             OperationId::Blur => Instr::Operation(ImgOp::Blur(parse_inputs_by_type!(inputs, f32)?)),
+            OperationId::Boomerang => Instr::Operation(ImgOp::Boomerang),
             OperationId::Brighten => {
                 Instr::Operation(ImgOp::Brighten(parse_inputs_by_type!(inputs, i32)?))
             }
+            OperationId::Canny => {
+                Instr::Operation(ImgOp::Canny(parse_inputs_by_type!(inputs, (f32, f32))?))
+            }
+            OperationId::ChromaKey => Instr::Operation(ImgOp::ChromaKey(parse_inputs_by_type!(
+                inputs,
+                ChromaKeyInputs
+            )?)),
+            OperationId::Clahe => {
+                Instr::Operation(ImgOp::Clahe(parse_inputs_by_type!(inputs, ClaheInputs)?))
+            }
+            OperationId::Close => Instr::Operation(ImgOp::Close(parse_inputs_by_type!(
+                inputs,
+                MorphologyInputs
+            )?)),
+            OperationId::ColorMatrix => Instr::Operation(ImgOp::ColorMatrix(
+                parse_inputs_by_type!(inputs, ColorMatrixInputs)?,
+            )),
+            OperationId::ColorToAlpha => Instr::Operation(ImgOp::ColorToAlpha(
+                parse_inputs_by_type!(inputs, Rgba<u8>)?,
+            )),
             OperationId::Contrast => {
                 Instr::Operation(ImgOp::Contrast(parse_inputs_by_type!(inputs, f32)?))
             }
@@ -140,9 +366,20 @@ impl OperationId {
                 inputs,
                 (u32, u32, u32, u32)
             )?)),
+            OperationId::CropTo => Instr::Operation(ImgOp::CropTo(parse_inputs_by_type!(
+                inputs,
+                (u32, u32, Gravity)
+            )?)),
+            OperationId::Curves => {
+                Instr::Operation(ImgOp::Curves(parse_inputs_by_type!(inputs, CurvesInputs)?))
+            }
             OperationId::Diff => {
                 Instr::Operation(ImgOp::Diff(parse_inputs_by_type!(inputs, ImageFromPath)?))
             }
+            OperationId::Dilate => Instr::Operation(ImgOp::Dilate(parse_inputs_by_type!(
+                inputs,
+                MorphologyInputs
+            )?)),
             OperationId::Dither => Instr::Operation(ImgOp::Dither),
             OperationId::DitherQuant => Instr::Operation(ImgOp::DitherQuant(
                 parse_inputs_by_type!(inputs, (u32, u32))?,
@@ -154,11 +391,51 @@ impl OperationId {
                     DrawTextInner
                 )?))
             }
+            OperationId::DropFrames => {
+                Instr::Operation(ImgOp::DropFrames(parse_inputs_by_type!(inputs, u32)?))
+            }
+            OperationId::DuplicateFrame => Instr::Operation(ImgOp::DuplicateFrame(
+                parse_inputs_by_type!(inputs, (u32, u32))?,
+            )),
+            OperationId::Equalize => Instr::Operation(ImgOp::Equalize(parse_inputs_by_type!(
+                inputs,
+                EqualizeMode
+            )?)),
+            OperationId::Erode => Instr::Operation(ImgOp::Erode(parse_inputs_by_type!(
+                inputs,
+                MorphologyInputs
+            )?)),
+            OperationId::Exposure => {
+                Instr::Operation(ImgOp::Exposure(parse_inputs_by_type!(inputs, f32)?))
+            }
+            OperationId::ExtendCanvas => Instr::Operation(ImgOp::ExtendCanvas(
+                parse_inputs_by_type!(inputs, ExtendCanvasInputs)?,
+            )),
+            OperationId::ExtractAlpha => Instr::Operation(ImgOp::ExtractAlpha),
+            OperationId::ExtractChannel => Instr::Operation(ImgOp::ExtractChannel(
+                parse_inputs_by_type!(inputs, ChannelOrLuma)?,
+            )),
+            OperationId::Filter => {
+                Instr::Operation(ImgOp::Filter(parse_inputs_by_type!(inputs, FilterInputs)?))
+            }
             OperationId::Filter3x3 => {
                 Instr::Operation(ImgOp::Filter3x3(parse_inputs_by_type!(inputs, [f32; 9])?))
             }
+            OperationId::Flatten => {
+                Instr::Operation(ImgOp::Flatten(parse_inputs_by_type!(inputs, Rgba<u8>)?))
+            }
             OperationId::FlipHorizontal => Instr::Operation(ImgOp::FlipHorizontal),
             OperationId::FlipVertical => Instr::Operation(ImgOp::FlipVertical),
+            OperationId::Frames => {
+                Instr::Operation(ImgOp::Frames(parse_inputs_by_type!(inputs, FrameRange)?))
+            }
+            OperationId::Gamma => {
+                Instr::Operation(ImgOp::Gamma(parse_inputs_by_type!(inputs, f32)?))
+            }
+            OperationId::Gradient => Instr::Operation(ImgOp::Gradient(parse_inputs_by_type!(
+                inputs,
+                GradientInputs
+            )?)),
             OperationId::Grayscale => Instr::Operation(ImgOp::Grayscale),
             OperationId::HueRotate => {
                 Instr::Operation(ImgOp::HueRotate(parse_inputs_by_type!(inputs, i32)?))
@@ -175,17 +452,83 @@ impl OperationId {
                 parse_inputs_by_type!(inputs, (u32, u32))?,
             )),
             OperationId::Invert => Instr::Operation(ImgOp::Invert),
+            OperationId::Laplacian => Instr::Operation(ImgOp::Laplacian),
+            OperationId::Levels => {
+                Instr::Operation(ImgOp::Levels(parse_inputs_by_type!(inputs, LevelsInputs)?))
+            }
+            OperationId::Median => {
+                Instr::Operation(ImgOp::Median(parse_inputs_by_type!(inputs, u32)?))
+            }
+            OperationId::MergeChannels => Instr::Operation(ImgOp::MergeChannels(
+                parse_inputs_by_type!(inputs, MergeChannelsInputs)?,
+            )),
+            OperationId::MorphGradient => Instr::Operation(ImgOp::MorphGradient(
+                parse_inputs_by_type!(inputs, MorphologyInputs)?,
+            )),
+            OperationId::NlMeans => Instr::Operation(ImgOp::NlMeans(parse_inputs_by_type!(
+                inputs,
+                NlMeansInputs
+            )?)),
+            OperationId::Open => Instr::Operation(ImgOp::Open(parse_inputs_by_type!(
+                inputs,
+                MorphologyInputs
+            )?)),
             OperationId::Overlay => Instr::Operation(ImgOp::Overlay(parse_inputs_by_type!(
                 inputs,
                 OverlayInputs
             )?)),
+            OperationId::Pad => {
+                Instr::Operation(ImgOp::Pad(parse_inputs_by_type!(inputs, PadInputs)?))
+            }
+            OperationId::PremultiplyAlpha => Instr::Operation(ImgOp::PremultiplyAlpha),
+            OperationId::Quantize => Instr::Operation(ImgOp::Quantize(parse_inputs_by_type!(
+                inputs,
+                QuantizeInputs
+            )?)),
             OperationId::Resize => {
-                Instr::Operation(ImgOp::Resize(parse_inputs_by_type!(inputs, (u32, u32))?))
+                Instr::Operation(ImgOp::Resize(parse_inputs_by_type!(inputs, ResizeInputs)?))
+            }
+            OperationId::ReverseFrames => Instr::Operation(ImgOp::ReverseFrames),
+            OperationId::Rotate => {
+                use sic_image_engine::wrapper::rotate::RotateInputs;
+                Instr::Operation(ImgOp::Rotate(parse_inputs_by_type!(inputs, RotateInputs)?))
             }
             OperationId::Rotate90 => Instr::Operation(ImgOp::Rotate90),
             OperationId::Rotate180 => Instr::Operation(ImgOp::Rotate180),
             OperationId::Rotate270 => Instr::Operation(ImgOp::Rotate270),
+            OperationId::Saturate => {
+                Instr::Operation(ImgOp::Saturate(parse_inputs_by_type!(inputs, f32)?))
+            }
+            OperationId::ScaleAlpha => {
+                Instr::Operation(ImgOp::ScaleAlpha(parse_inputs_by_type!(inputs, f32)?))
+            }
+            OperationId::ScaleFrameDelay => {
+                Instr::Operation(ImgOp::ScaleFrameDelay(parse_inputs_by_type!(inputs, f32)?))
+            }
+            OperationId::Scharr => Instr::Operation(ImgOp::Scharr),
+            OperationId::SetAlpha => Instr::Operation(ImgOp::SetAlpha(parse_inputs_by_type!(
+                inputs,
+                ImageFromPath
+            )?)),
+            OperationId::SetFrameDelay => {
+                Instr::Operation(ImgOp::SetFrameDelay(parse_inputs_by_type!(inputs, u32)?))
+            }
+            OperationId::SetFrameDelayAt => Instr::Operation(ImgOp::SetFrameDelayAt(
+                parse_inputs_by_type!(inputs, (u32, u32))?,
+            )),
+            OperationId::Sobel => Instr::Operation(ImgOp::Sobel),
+            OperationId::SplitSpriteSheet => Instr::Operation(ImgOp::SplitSpriteSheet(
+                parse_inputs_by_type!(inputs, SplitSpriteSheetInputs)?,
+            )),
+            OperationId::SpriteSheet => Instr::Operation(ImgOp::SpriteSheet(
+                parse_inputs_by_type!(inputs, SpriteSheetInputs)?,
+            )),
+            OperationId::SwapChannels => Instr::Operation(ImgOp::SwapChannels(
+                parse_inputs_by_type!(inputs, (Channel, Channel))?,
+            )),
             OperationId::Threshold => Instr::Operation(ImgOp::Threshold),
+            OperationId::Trim => Instr::Operation(ImgOp::Trim(parse_inputs_by_type!(inputs, u8)?)),
+            OperationId::UnpremultiplyAlpha => Instr::Operation(ImgOp::UnpremultiplyAlpha),
             OperationId::Unsharpen => {
                 Instr::Operation(ImgOp::Unsharpen(parse_inputs_by_type!(inputs, (f32, i32))?))
             }
@@ -197,6 +540,19 @@ impl OperationId {
                 )?))
             }
             // image operation modifiers
+            OperationId::Channel => Instr::EnvAdd(EnvItem::ChannelScope(parse_inputs_by_type!(
+                inputs,
+                ChannelScope
+            )?)),
+            OperationId::DitherAlgorithm => Instr::EnvAdd(EnvItem::DitherAlgorithm(
+                parse_inputs_by_type!(inputs, DitherAlgorithm)?,
+            )),
+            OperationId::DitherSerpentine => Instr::EnvAdd(EnvItem::DitherSerpentine(
+                parse_inputs_by_type!(inputs, bool)?,
+            )),
+            OperationId::GradientColorSpace => Instr::EnvAdd(EnvItem::GradientColorSpace(
+                parse_inputs_by_type!(inputs, GradientColorSpace)?,
+            )),
             OperationId::PreserveAspectRatio => Instr::EnvAdd(EnvItem::PreserveAspectRatio(
                 parse_inputs_by_type!(inputs, bool)?,
             )),
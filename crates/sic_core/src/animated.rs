@@ -38,6 +38,12 @@ impl AnimatedImage {
         &mut self.frames
     }
 
+    /// Returns a mutable collection of image frames, which unlike [`AnimatedImage::frames_mut`]
+    /// allows frames to be added, removed or reordered
+    pub fn frames_vec_mut(&mut self) -> &mut Vec<image::Frame> {
+        &mut self.frames
+    }
+
     /// Collects and returns an owned collection of image frames
     pub fn collect_frames(&self) -> Vec<image::Frame> {
         self.frames.clone()
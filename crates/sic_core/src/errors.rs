@@ -8,6 +8,12 @@ pub enum SicCoreError {
     #[error("Invalid frame index: index (is {index}) should be < len (is {len}) ")]
     InvalidFrameIndex { index: usize, len: usize },
 
+    #[error("A palette should contain between 1 and 256 colors, but {0} colors were given")]
+    InvalidPaletteSize(usize),
+
+    #[error("Invalid palette index: index (is {index}) should be < palette len (is {len})")]
+    PaletteIndexOutOfRange { index: usize, len: usize },
+
     #[error("A static image was required, but an animated image was given")]
     RequiresStaticImage,
 }
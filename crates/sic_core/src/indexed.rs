@@ -0,0 +1,79 @@
+//! The definition of an image which consists of palette indices.
+
+use crate::errors::SicCoreError;
+use image::{DynamicImage, GrayImage, Rgba, RgbaImage};
+
+/// The largest number of colors a palette can hold, such that each index fits within a byte.
+pub const MAX_PALETTE_COLORS: usize = 256;
+
+/// An image of which each pixel is an index into a palette of colors.
+///
+/// Formats which support palette based images, like PNG and GIF, can store the indices and the
+/// palette as is, instead of the expanded colors.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IndexedImage {
+    indices: GrayImage,
+    palette: Vec<Rgba<u8>>,
+}
+
+impl IndexedImage {
+    /// Creates an indexed image from a buffer of palette indices and the palette itself.
+    ///
+    /// The palette must hold between 1 and 256 colors, and each index must refer to a color of
+    /// the palette.
+    pub fn try_new(indices: GrayImage, palette: Vec<Rgba<u8>>) -> Result<Self, SicCoreError> {
+        if palette.is_empty() || palette.len() > MAX_PALETTE_COLORS {
+            return Err(SicCoreError::InvalidPaletteSize(palette.len()));
+        }
+
+        if let Some(index) = indices
+            .as_raw()
+            .iter()
+            .find(|&&index| usize::from(index) >= palette.len())
+        {
+            return Err(SicCoreError::PaletteIndexOutOfRange {
+                index: usize::from(*index),
+                len: palette.len(),
+            });
+        }
+
+        Ok(Self { indices, palette })
+    }
+
+    /// Returns the palette index of each pixel
+    pub fn indices(&self) -> &GrayImage {
+        &self.indices
+    }
+
+    /// Returns the palette colors, in order of their index
+    pub fn palette(&self) -> &[Rgba<u8>] {
+        &self.palette
+    }
+
+    pub fn width(&self) -> u32 {
+        self.indices.width()
+    }
+
+    pub fn height(&self) -> u32 {
+        self.indices.height()
+    }
+
+    /// Whether any of the palette colors is not fully opaque
+    pub fn has_alpha(&self) -> bool {
+        self.palette.iter().any(|color| color[3] != u8::MAX)
+    }
+
+    /// Replaces each index by its palette color. The result is an `Rgb8` image if all palette
+    /// colors are fully opaque, and an `Rgba8` image otherwise.
+    pub fn to_dynamic_image(&self) -> DynamicImage {
+        let expanded = RgbaImage::from_fn(self.width(), self.height(), |x, y| {
+            self.palette[usize::from(self.indices[(x, y)][0])]
+        });
+
+        if self.has_alpha() {
+            DynamicImage::ImageRgba8(expanded)
+        } else {
+            DynamicImage::ImageRgb8(DynamicImage::ImageRgba8(expanded).into_rgb8())
+        }
+    }
+}
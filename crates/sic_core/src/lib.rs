@@ -13,18 +13,32 @@ use std::convert::TryFrom;
 
 mod animated;
 mod errors;
+mod indexed;
 
 pub use animated::AnimatedImage;
+pub use indexed::{IndexedImage, MAX_PALETTE_COLORS};
 
 pub use errors::SicCoreError;
 
 /// The fundamental image data structure in `imagineer`.
 /// An image can either be animated, in which case it consists of a collection of `image::Frame` frames,
 /// or static, in which case it's represented as an `image::DynamicImage`.
+/// A static image may also be indexed, in which case each pixel refers to a color of a palette.
 #[derive(Clone, Debug)]
 pub enum SicImage {
     Animated(AnimatedImage),
     Static(DynamicImage),
+    Indexed(IndexedImage),
+}
+
+impl SicImage {
+    /// Replaces an indexed image by a static image of its palette colors.
+    /// Animated and static images are left as is.
+    pub fn expand_indexed(&mut self) {
+        if let Self::Indexed(indexed) = self {
+            *self = Self::Static(indexed.to_dynamic_image());
+        }
+    }
 }
 
 // Should not be used outside of tests, as it doesn't support animated images
@@ -32,7 +46,7 @@ pub enum SicImage {
 impl AsRef<DynamicImage> for SicImage {
     fn as_ref(&self) -> &DynamicImage {
         match self {
-            Self::Animated(_) => unimplemented!(),
+            Self::Animated(_) | Self::Indexed(_) => unimplemented!(),
             Self::Static(image) => image,
         }
     }
@@ -50,7 +64,8 @@ impl TryFrom<SicImage> for DynamicImage {
     fn try_from(value: SicImage) -> Result<Self, Self::Error> {
         match value {
             SicImage::Static(image) => Ok(image),
-            _ => Err(SicCoreError::RequiresStaticImage),
+            SicImage::Indexed(image) => Ok(image.to_dynamic_image()),
+            SicImage::Animated(_) => Err(SicCoreError::RequiresStaticImage),
         }
     }
 }
@@ -1,11 +1,16 @@
 use std::collections::HashMap;
 use std::hash::Hash;
 
+use sic_core::image::DynamicImage;
 use sic_core::image::imageops::FilterType;
 
 use crate::errors::SicImageEngineError;
 use crate::operations::ImageOperation;
+use crate::operations::sprite_sheet::SpriteSheetAtlas;
+use crate::wrapper::channel::{Channel, ChannelScope};
+use crate::wrapper::dither_algorithm::DitherAlgorithm;
 use crate::wrapper::filter_type::FilterTypeWrap;
+use crate::wrapper::gradient::GradientColorSpace;
 use crate::{ImgOp, operations};
 use sic_core::SicImage;
 
@@ -16,11 +21,22 @@ trait EnvironmentKey {
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, EnumDiscriminants)]
 #[strum_discriminants(name(ItemName), derive(Display, Hash))]
 pub enum EnvItem {
+    ChannelScope(ChannelScope),
     CustomSamplingFilter(FilterTypeWrap),
+    DitherAlgorithm(DitherAlgorithm),
+    DitherSerpentine(bool),
+    GradientColorSpace(GradientColorSpace),
     PreserveAspectRatio(bool),
 }
 
 impl EnvItem {
+    pub fn channel_scope(self) -> Option<ChannelScope> {
+        match self {
+            EnvItem::ChannelScope(k) => Some(k),
+            _ => None,
+        }
+    }
+
     pub fn resize_sampling_filter(self) -> Option<FilterTypeWrap> {
         match self {
             EnvItem::CustomSamplingFilter(k) => Some(k),
@@ -28,6 +44,27 @@ impl EnvItem {
         }
     }
 
+    pub fn dither_algorithm(self) -> Option<DitherAlgorithm> {
+        match self {
+            EnvItem::DitherAlgorithm(k) => Some(k),
+            _ => None,
+        }
+    }
+
+    pub fn dither_serpentine(self) -> Option<bool> {
+        match self {
+            EnvItem::DitherSerpentine(k) => Some(k),
+            _ => None,
+        }
+    }
+
+    pub fn gradient_color_space(self) -> Option<GradientColorSpace> {
+        match self {
+            EnvItem::GradientColorSpace(k) => Some(k),
+            _ => None,
+        }
+    }
+
     pub fn preserve_aspect_ratio(self) -> Option<bool> {
         match self {
             EnvItem::PreserveAspectRatio(k) => Some(k),
@@ -39,7 +76,11 @@ impl EnvItem {
 impl EnvironmentKey for EnvItem {
     fn key(&self) -> ItemName {
         match self {
+            EnvItem::ChannelScope(_) => ItemName::ChannelScope,
             EnvItem::CustomSamplingFilter(_) => ItemName::CustomSamplingFilter,
+            EnvItem::DitherAlgorithm(_) => ItemName::DitherAlgorithm,
+            EnvItem::DitherSerpentine(_) => ItemName::DitherSerpentine,
+            EnvItem::GradientColorSpace(_) => ItemName::GradientColorSpace,
             EnvItem::PreserveAspectRatio(_) => ItemName::PreserveAspectRatio,
         }
     }
@@ -77,6 +118,7 @@ pub enum Instr {
 pub struct ImageEngine {
     environment: Box<Env>,
     image: Box<SicImage>,
+    atlases: Vec<SpriteSheetAtlas>,
 }
 
 impl ImageEngine {
@@ -84,10 +126,21 @@ impl ImageEngine {
         Self {
             environment: Box::from(Env::default()),
             image: Box::from(image),
+            atlases: Vec::new(),
         }
     }
 
-    pub fn ignite(mut self, instructions: &[Instr]) -> Result<SicImage, SicImageEngineError> {
+    pub fn ignite(self, instructions: &[Instr]) -> Result<SicImage, SicImageEngineError> {
+        self.ignite_with_atlases(instructions)
+            .map(|(image, _)| image)
+    }
+
+    /// Like [`ImageEngine::ignite`], but also returns the atlases of the sprite sheets created
+    /// by `sprite-sheet` operations, which are left to the caller to write.
+    pub fn ignite_with_atlases(
+        mut self,
+        instructions: &[Instr],
+    ) -> Result<(SicImage, Vec<SpriteSheetAtlas>), SicImageEngineError> {
         for instruction in instructions {
             match self.process_instruction(instruction) {
                 Ok(_) => continue,
@@ -95,49 +148,158 @@ impl ImageEngine {
             }
         }
 
-        Ok(*self.image)
+        Ok((*self.image, self.atlases))
     }
 
     fn process_instruction(&mut self, instruction: &Instr) -> Result<(), SicImageEngineError> {
         match instruction {
-            Instr::Operation(op) => self.process_operation(op),
+            Instr::Operation(op) => match channel_scope(&self.environment) {
+                ChannelScope::All => self.process_operation(op),
+                ChannelScope::Only(channel) => self.process_operation_on_channel(op, channel),
+            },
             Instr::EnvAdd(item) => self.insert_env(*item),
             Instr::EnvRemove(key) => self.remove_env(*key),
         }
     }
 
+    /// Applies an operation to a single channel of the image: the channel is split off as a
+    /// grayscale image, the operation is applied to it, and the result is merged back into the
+    /// channel.
+    fn process_operation_on_channel(
+        &mut self,
+        operation: &ImgOp,
+        channel: Channel,
+    ) -> Result<(), SicImageEngineError> {
+        let gray = operations::channels::split_channel(&self.image, channel);
+        let image = std::mem::replace(self.image.as_mut(), gray);
+
+        let result = self.process_operation(operation);
+        let gray = std::mem::replace(self.image.as_mut(), image);
+        result?;
+
+        operations::channels::merge_channel(&mut self.image, gray, channel)
+    }
+
     fn process_operation(&mut self, operation: &ImgOp) -> Result<(), SicImageEngineError> {
         match operation {
+            ImgOp::Bilateral(inputs) => {
+                operations::bilateral::Bilateral::new(inputs).apply_operation(&mut self.image)
+            }
             ImgOp::Blur(sigma) => {
                 operations::blur::Blur::new(*sigma).apply_operation(&mut self.image)
             }
+            ImgOp::Boomerang => {
+                operations::boomerang::Boomerang::new().apply_operation(&mut self.image)
+            }
             ImgOp::Brighten(amount) => {
                 operations::brighten::Brighten::new(*amount).apply_operation(&mut self.image)
             }
+            ImgOp::Canny((low, high)) => {
+                operations::canny::Canny::new(*low, *high).apply_operation(&mut self.image)
+            }
+            ImgOp::ChromaKey(inputs) => {
+                operations::chroma_key::ChromaKey::new(inputs).apply_operation(&mut self.image)
+            }
+            ImgOp::Clahe(inputs) => {
+                operations::clahe::Clahe::new(inputs).apply_operation(&mut self.image)
+            }
+            ImgOp::Close(inputs) => {
+                operations::close::Close::new(inputs).apply_operation(&mut self.image)
+            }
+            ImgOp::ColorMatrix(inputs) => {
+                operations::color_matrix::ColorMatrix::new(inputs).apply_operation(&mut self.image)
+            }
+            ImgOp::ColorToAlpha(color) => operations::color_to_alpha::ColorToAlpha::new(*color)
+                .apply_operation(&mut self.image),
             ImgOp::Contrast(f) => {
                 operations::contrast::Contrast::new(*f).apply_operation(&mut self.image)
             }
             ImgOp::Crop((lx, ly, rx, ry)) => {
                 operations::crop::Crop::new((*lx, *ly), (*rx, *ry)).apply_operation(&mut self.image)
             }
+            ImgOp::CropTo((width, height, gravity)) => {
+                operations::crop_to::CropTo::new(*width, *height, *gravity)
+                    .apply_operation(&mut self.image)
+            }
+            ImgOp::Curves(inputs) => {
+                operations::curves::Curves::new(inputs).apply_operation(&mut self.image)
+            }
             ImgOp::Diff(path) => operations::diff::Diff::new(path).apply_operation(&mut self.image),
-            ImgOp::Dither => operations::dither::Dither::new().apply_operation(&mut self.image),
-            ImgOp::DitherQuant((colors, sample_factor)) => {
-                operations::dither_quant::DitherQuant::new(*colors, *sample_factor)
+            ImgOp::Dilate(inputs) => {
+                operations::dilate::Dilate::new(inputs).apply_operation(&mut self.image)
+            }
+            ImgOp::Dither => {
+                let algorithm = dither_algorithm_or_default(&self.environment);
+                let serpentine = should_dither_serpentine(&self.environment);
+                operations::dither::Dither::new(algorithm, serpentine)
                     .apply_operation(&mut self.image)
             }
+            ImgOp::DitherQuant((colors, sample_factor)) => {
+                let algorithm = dither_algorithm_or_default(&self.environment);
+                let serpentine = should_dither_serpentine(&self.environment);
+                operations::dither_quant::DitherQuant::new(
+                    *colors,
+                    *sample_factor,
+                    algorithm,
+                    serpentine,
+                )
+                .apply_operation(&mut self.image)
+            }
             ImgOp::DrawText(inner) => {
                 operations::draw_text::DrawText::new(inner).apply_operation(&mut self.image)
             }
+            ImgOp::DropFrames(interval) => {
+                operations::drop_frames::DropFrames::new(*interval).apply_operation(&mut self.image)
+            }
+            ImgOp::DuplicateFrame((index, copies)) => {
+                operations::duplicate_frame::DuplicateFrame::new(*index, *copies)
+                    .apply_operation(&mut self.image)
+            }
+            ImgOp::Equalize(mode) => {
+                operations::equalize::Equalize::new(*mode).apply_operation(&mut self.image)
+            }
+            ImgOp::Erode(inputs) => {
+                operations::erode::Erode::new(inputs).apply_operation(&mut self.image)
+            }
+            ImgOp::ExtendCanvas(inputs) => operations::extend_canvas::ExtendCanvas::new(inputs)
+                .apply_operation(&mut self.image),
+            ImgOp::Exposure(stops) => {
+                operations::exposure::Exposure::new(*stops).apply_operation(&mut self.image)
+            }
+            ImgOp::ExtractAlpha => {
+                operations::extract_alpha::ExtractAlpha::new().apply_operation(&mut self.image)
+            }
+            ImgOp::ExtractChannel(channel) => {
+                operations::extract_channel::ExtractChannel::new(*channel)
+                    .apply_operation(&mut self.image)
+            }
+            ImgOp::Filter(inputs) => {
+                operations::filter::Filter::new(inputs).apply_operation(&mut self.image)
+            }
             ImgOp::Filter3x3(kernel) => {
                 operations::filter3x3::Filter3x3::new(kernel).apply_operation(&mut self.image)
             }
+            ImgOp::Flatten(background) => {
+                operations::flatten::Flatten::new(*background).apply_operation(&mut self.image)
+            }
             ImgOp::FlipHorizontal => {
                 operations::flip_horizontal::FlipHorizontal::new().apply_operation(&mut self.image)
             }
             ImgOp::FlipVertical => {
                 operations::flip_vertical::FlipVertical::new().apply_operation(&mut self.image)
             }
+            ImgOp::Frames(range) => {
+                operations::frames::Frames::new(*range).apply_operation(&mut self.image)
+            }
+            ImgOp::Gamma(gamma) => {
+                operations::gamma::Gamma::new(*gamma).apply_operation(&mut self.image)
+            }
+            ImgOp::Gradient(inputs) => {
+                let stops = inputs.resolved_stops()?;
+                let color_space = gradient_color_space_or_default(&self.environment);
+                operations::gradient::Gradient::new(inputs.shape(), &stops, color_space)
+                    .apply_operation(&mut self.image)
+            }
             ImgOp::Grayscale => {
                 operations::grayscale::Grayscale::new().apply_operation(&mut self.image)
             }
@@ -156,15 +318,59 @@ impl ImageEngine {
                     .apply_operation(&mut self.image)
             }
             ImgOp::Invert => operations::invert::Invert::new().apply_operation(&mut self.image),
+            ImgOp::Laplacian => {
+                operations::laplacian::Laplacian::new().apply_operation(&mut self.image)
+            }
+            ImgOp::Levels(inputs) => {
+                operations::levels::Levels::new(inputs).apply_operation(&mut self.image)
+            }
+            ImgOp::Median(radius) => {
+                operations::median::Median::new(*radius).apply_operation(&mut self.image)
+            }
+            ImgOp::MergeChannels(inputs) => {
+                let images = inputs.load()?;
+                operations::merge_channels::MergeChannels::new(&images)
+                    .apply_operation(&mut self.image)
+            }
+            ImgOp::MorphGradient(inputs) => operations::morph_gradient::MorphGradient::new(inputs)
+                .apply_operation(&mut self.image),
+            ImgOp::NlMeans(inputs) => {
+                operations::nl_means::NlMeans::new(inputs).apply_operation(&mut self.image)
+            }
+            ImgOp::Open(inputs) => {
+                operations::open::Open::new(inputs).apply_operation(&mut self.image)
+            }
             ImgOp::Overlay(inputs) => {
-                operations::overlay::Overlay::new(inputs).apply_operation(&mut self.image)
+                let sampling_filter = resize_filter_or_default(&self.environment);
+                operations::overlay::Overlay::new(inputs, sampling_filter)
+                    .apply_operation(&mut self.image)
+            }
+            ImgOp::Pad(inputs) => {
+                operations::pad::Pad::new(inputs).apply_operation(&mut self.image)
             }
-            ImgOp::Resize((x, y)) => {
+            ImgOp::PremultiplyAlpha => operations::premultiply_alpha::PremultiplyAlpha::new()
+                .apply_operation(&mut self.image),
+            ImgOp::Quantize(inputs) => {
+                let palette = inputs.palette().load()?;
+                let algorithm = dither_algorithm_or_default(&self.environment);
+                let serpentine = should_dither_serpentine(&self.environment);
+                operations::quantize::Quantize::new(&palette, inputs.mode(), algorithm, serpentine)
+                    .apply_operation(&mut self.image)
+            }
+            ImgOp::Resize(inputs) => {
                 let aspect_ratio = should_preserve_aspect_ratio(&self.environment);
                 let sampling_filter = resize_filter_or_default(&self.environment);
-                let op = operations::resize::Resize::new(*x, *y, aspect_ratio, sampling_filter);
+                let op = operations::resize::Resize::new(*inputs, aspect_ratio, sampling_filter);
                 op.apply_operation(&mut self.image)
             }
+            ImgOp::ReverseFrames => {
+                operations::reverse_frames::ReverseFrames::new().apply_operation(&mut self.image)
+            }
+            ImgOp::Rotate(inputs) => {
+                let sampling_filter = resize_filter_or_default(&self.environment);
+                operations::rotate::Rotate::new(inputs, sampling_filter)
+                    .apply_operation(&mut self.image)
+            }
             ImgOp::Rotate90 => {
                 operations::rotate90::Rotate90::new().apply_operation(&mut self.image)
             }
@@ -174,9 +380,50 @@ impl ImageEngine {
             ImgOp::Rotate270 => {
                 operations::rotate270::Rotate270::new().apply_operation(&mut self.image)
             }
+            ImgOp::Saturate(factor) => {
+                operations::saturate::Saturate::new(*factor).apply_operation(&mut self.image)
+            }
+            ImgOp::ScaleAlpha(factor) => {
+                operations::scale_alpha::ScaleAlpha::new(*factor).apply_operation(&mut self.image)
+            }
+            ImgOp::ScaleFrameDelay(factor) => {
+                operations::scale_frame_delay::ScaleFrameDelay::new(*factor)
+                    .apply_operation(&mut self.image)
+            }
+            ImgOp::Scharr => operations::scharr::Scharr::new().apply_operation(&mut self.image),
+            ImgOp::SetAlpha(path) => {
+                let mask = DynamicImage::try_from(path.open_image()?)?;
+                operations::set_alpha::SetAlpha::new(&mask).apply_operation(&mut self.image)
+            }
+            ImgOp::SetFrameDelay(delay) => operations::set_frame_delay::SetFrameDelay::new(*delay)
+                .apply_operation(&mut self.image),
+            ImgOp::SetFrameDelayAt((index, delay)) => {
+                operations::set_frame_delay_at::SetFrameDelayAt::new(*index, *delay)
+                    .apply_operation(&mut self.image)
+            }
+            ImgOp::Sobel => operations::sobel::Sobel::new().apply_operation(&mut self.image),
+            ImgOp::SplitSpriteSheet(inputs) => {
+                operations::split_sprite_sheet::SplitSpriteSheet::new(*inputs)
+                    .apply_operation(&mut self.image)
+            }
+            ImgOp::SpriteSheet(inputs) => {
+                let atlas =
+                    operations::sprite_sheet::SpriteSheet::new(inputs).pack(&mut self.image)?;
+                self.atlases.extend(atlas);
+                Ok(())
+            }
+            ImgOp::SwapChannels(channels) => {
+                operations::swap_channels::SwapChannels::new(*channels)
+                    .apply_operation(&mut self.image)
+            }
             ImgOp::Threshold => {
                 operations::threshold::Threshold::new().apply_operation(&mut self.image)
             }
+            ImgOp::Trim(tolerance) => {
+                operations::trim::Trim::new(*tolerance).apply_operation(&mut self.image)
+            }
+            ImgOp::UnpremultiplyAlpha => operations::unpremultiply_alpha::UnpremultiplyAlpha::new()
+                .apply_operation(&mut self.image),
             ImgOp::Unsharpen((sigma, threshold)) => {
                 operations::unsharpen::Unsharpen::new(*sigma, *threshold)
                     .apply_operation(&mut self.image)
@@ -208,6 +455,12 @@ impl ImageEngine {
     }
 }
 
+fn channel_scope(env: &Env) -> ChannelScope {
+    env.get(ItemName::ChannelScope)
+        .and_then(|item| item.channel_scope())
+        .unwrap_or_default()
+}
+
 fn resize_filter_or_default(env: &Env) -> FilterType {
     env.get(ItemName::CustomSamplingFilter)
         .and_then(|item| item.resize_sampling_filter())
@@ -215,6 +468,24 @@ fn resize_filter_or_default(env: &Env) -> FilterType {
         .unwrap_or_else(|| FilterTypeWrap::default().into())
 }
 
+fn dither_algorithm_or_default(env: &Env) -> DitherAlgorithm {
+    env.get(ItemName::DitherAlgorithm)
+        .and_then(|item| item.dither_algorithm())
+        .unwrap_or_default()
+}
+
+fn should_dither_serpentine(env: &Env) -> bool {
+    env.get(ItemName::DitherSerpentine)
+        .and_then(|item| item.dither_serpentine())
+        .unwrap_or_default()
+}
+
+fn gradient_color_space_or_default(env: &Env) -> GradientColorSpace {
+    env.get(ItemName::GradientColorSpace)
+        .and_then(|item| item.gradient_color_space())
+        .unwrap_or_default()
+}
+
 fn should_preserve_aspect_ratio(env: &Env) -> bool {
     env.get(ItemName::PreserveAspectRatio)
         .and_then(|item| item.preserve_aspect_ratio())
@@ -234,6 +505,10 @@ mod compatibility {
 
     impl RawPixels for SicImage {
         fn raw_pixels(&self) -> Vec<u8> {
+            if let SicImage::Indexed(indexed) = self {
+                return indexed.indices().to_vec();
+            }
+
             match self.as_ref() {
                 sic_core::image::DynamicImage::ImageLuma8(buffer) => buffer.to_vec(),
                 sic_core::image::DynamicImage::ImageLumaA8(buffer) => buffer.to_vec(),
@@ -326,15 +601,15 @@ mod tests {
     use crate::operations::diff::{DIFF_PX_DIFF, DIFF_PX_NO_OVERLAP, DIFF_PX_SAME};
     use crate::wrapper::gradient_input::GradientInput;
     use crate::wrapper::image_path::ImageFromPath;
-    use sic_core::image::Rgba;
     use sic_core::image::imageops::FilterType;
+    use sic_core::image::{DynamicImage, GrayImage, Luma, Rgba};
     use sic_testing::*;
     use std::path::PathBuf;
 
     // output images during tests to verify the results visually
     fn output_test_image_for_manual_inspection(img: &SicImage, path: &str) {
         if cfg!(feature = "output-test-images") {
-            let _ = img.as_ref().save(path);
+            let _ = sic_core::image::DynamicImage::try_from(img.clone()).map(|img| img.save(path));
         }
     }
 
@@ -343,6 +618,27 @@ mod tests {
         sic_testing::open_test_image(sic_testing::in_!(DEFAULT_TEST_IMAGE_PATH))
     }
 
+    fn apply(
+        image: impl Into<SicImage>,
+        operation: ImgOp,
+    ) -> Result<SicImage, SicImageEngineError> {
+        ImageEngine::new(image.into()).ignite(&[Instr::Operation(operation)])
+    }
+
+    // An animated image of `count` 2x2 frames, where the red channel of each frame holds its
+    // index, and the delay of each frame in milliseconds is given by `delay`.
+    fn animated_image(count: u32, delay: impl Fn(u32) -> u32) -> SicImage {
+        use sic_core::AnimatedImage;
+        use sic_core::image::{Delay, Frame, RgbaImage};
+
+        let frames = (0..count).map(|i| {
+            let buffer = RgbaImage::from_pixel(2, 2, Rgba([i as u8, 0, 0, 255]));
+            Frame::from_parts(buffer, 0, 0, Delay::from_numer_denom_ms(delay(i), 1))
+        });
+
+        SicImage::Animated(AnimatedImage::from_frames(frames))
+    }
+
     #[test]
     fn diff_check_out_pixels() {
         const LEFT: &str = "2x3_wrabaa.png";
@@ -435,12 +731,12 @@ mod tests {
         let engine2 = engine.clone();
         let cmp_left = engine.ignite(&[
             Instr::EnvAdd(EnvItem::PreserveAspectRatio(true)),
-            Instr::Operation(ImgOp::Resize((100, 100))),
+            Instr::Operation(ImgOp::Resize((100, 100).into())),
         ]);
 
         assert!(cmp_left.is_ok());
 
-        let cmp_right = engine2.ignite(&[Instr::Operation(ImgOp::Resize((100, 100)))]);
+        let cmp_right = engine2.ignite(&[Instr::Operation(ImgOp::Resize((100, 100).into()))]);
 
         assert!(cmp_left.is_ok());
 
@@ -472,12 +768,12 @@ mod tests {
         let engine2 = engine.clone();
         let cmp_left = engine.ignite(&[
             Instr::EnvAdd(EnvItem::PreserveAspectRatio(false)),
-            Instr::Operation(ImgOp::Resize((100, 100))),
+            Instr::Operation(ImgOp::Resize((100, 100).into())),
         ]);
 
         assert!(cmp_left.is_ok());
 
-        let cmp_right = engine2.ignite(&[Instr::Operation(ImgOp::Resize((100, 100)))]);
+        let cmp_right = engine2.ignite(&[Instr::Operation(ImgOp::Resize((100, 100).into()))]);
 
         assert!(cmp_left.is_ok());
 
@@ -509,12 +805,12 @@ mod tests {
             Instr::EnvAdd(EnvItem::CustomSamplingFilter(FilterTypeWrap::new(
                 FilterType::Nearest,
             ))),
-            Instr::Operation(ImgOp::Resize((100, 100))),
+            Instr::Operation(ImgOp::Resize((100, 100).into())),
         ]);
 
         assert!(cmp_left.is_ok());
 
-        let cmp_right = engine2.ignite(&[Instr::Operation(ImgOp::Resize((100, 100)))]);
+        let cmp_right = engine2.ignite(&[Instr::Operation(ImgOp::Resize((100, 100).into()))]);
 
         assert!(cmp_left.is_ok());
 
@@ -546,12 +842,12 @@ mod tests {
                 FilterType::Nearest,
             ))),
             Instr::EnvRemove(ItemName::CustomSamplingFilter),
-            Instr::Operation(ImgOp::Resize((100, 100))),
+            Instr::Operation(ImgOp::Resize((100, 100).into())),
         ]);
 
         assert!(cmp_left.is_ok());
 
-        let cmp_right = engine2.ignite(&[Instr::Operation(ImgOp::Resize((100, 100)))]);
+        let cmp_right = engine2.ignite(&[Instr::Operation(ImgOp::Resize((100, 100).into()))]);
 
         assert!(cmp_left.is_ok());
 
@@ -870,218 +1166,2777 @@ mod tests {
         assert!(done.is_err());
     }
 
-    #[test]
-    fn test_filter3x3() {
-        let img = setup_default_test_image();
-        let cmp = setup_default_test_image();
+    mod crop_to {
+        use super::*;
+        use crate::wrapper::gravity::Gravity;
+        use sic_core::AnimatedImage;
+        use sic_core::image::{DynamicImage, Frame, Rgba, RgbaImage};
+
+        // 3x3 image, where each pixel has a unique red channel value: 10 * (y * 3 + x)
+        fn numbered_image() -> DynamicImage {
+            DynamicImage::ImageRgba8(RgbaImage::from_fn(3, 3, |x, y| {
+                Rgba([(10 * (y * 3 + x)) as u8, 0, 0, 255])
+            }))
+        }
 
-        let operation = ImgOp::Filter3x3([1.0, 0.5, 0.0, 1.0, 0.5, 0.0, 1.0, 0.5, 0.0]);
+        fn crop_to(width: u32, height: u32, gravity: Gravity) -> SicImage {
+            let img = SicImage::from(numbered_image());
+            let operation = ImgOp::CropTo((width, height, gravity));
 
-        let operator = ImageEngine::new(img);
-        let done = operator.ignite(&[Instr::Operation(operation)]);
+            ImageEngine::new(img)
+                .ignite(&[Instr::Operation(operation)])
+                .unwrap()
+        }
 
-        assert!(done.is_ok());
+        #[test]
+        fn crop_to_center() {
+            let result_img = crop_to(1, 1, Gravity::Center);
 
-        let result_img = done.unwrap();
+            assert_eq!(result_img.dimensions(), (1, 1));
+            assert_eq!(result_img.get_pixel(0, 0), Rgba([40, 0, 0, 255]));
+        }
 
-        assert_ne!(cmp.raw_pixels(), result_img.raw_pixels());
+        #[test]
+        fn crop_to_south_east() {
+            let result_img = crop_to(2, 1, Gravity::SouthEast);
 
-        output_test_image_for_manual_inspection(&result_img, out_!("test_filter3x3.png"))
-    }
+            assert_eq!(result_img.dimensions(), (2, 1));
+            assert_eq!(result_img.get_pixel(0, 0), Rgba([70, 0, 0, 255]));
+            assert_eq!(result_img.get_pixel(1, 0), Rgba([80, 0, 0, 255]));
+        }
 
-    #[test]
-    fn test_flip_h() {
-        let img = setup_default_test_image();
-        let operation = ImgOp::FlipHorizontal;
+        #[test]
+        fn crop_to_north() {
+            let result_img = crop_to(1, 2, Gravity::North);
 
-        let (xa, ya) = img.dimensions();
-        let operator = ImageEngine::new(img);
-        let done = operator.ignite(&[Instr::Operation(operation)]);
+            assert_eq!(result_img.dimensions(), (1, 2));
+            assert_eq!(result_img.get_pixel(0, 0), Rgba([10, 0, 0, 255]));
+        }
 
-        assert!(done.is_ok());
+        #[test]
+        fn crop_to_clamps_to_bounds() {
+            let result_img = crop_to(100, 2, Gravity::SouthWest);
 
-        let img_result = done.unwrap();
-        let (xb, yb) = img_result.dimensions();
+            assert_eq!(result_img.dimensions(), (3, 2));
+            assert_eq!(result_img.get_pixel(0, 0), Rgba([30, 0, 0, 255]));
+        }
 
-        assert_eq!(xa, xb);
-        assert_eq!(ya, yb);
+        #[test]
+        fn crop_to_default_image() {
+            let img = setup_default_test_image();
+            let operation = ImgOp::CropTo((300, 200, Gravity::Center));
 
-        output_test_image_for_manual_inspection(&img_result, out_!("test_fliph.png"));
+            let result_img = ImageEngine::new(img)
+                .ignite(&[Instr::Operation(operation)])
+                .unwrap();
+
+            assert_eq!(result_img.dimensions(), (217, 200));
+
+            output_test_image_for_manual_inspection(&result_img, out_!("test_crop_to_center.png"));
+        }
+
+        #[test]
+        fn crop_to_animated_frames() {
+            let frames = (0..3).map(|_| Frame::new(RgbaImage::new(4, 4)));
+            let img = SicImage::Animated(AnimatedImage::from_frames(frames));
+
+            let operation = ImgOp::CropTo((2, 8, Gravity::East));
+            let done = ImageEngine::new(img).ignite(&[Instr::Operation(operation)]);
+
+            match done.unwrap() {
+                SicImage::Animated(animated) => {
+                    assert_eq!(animated.frames().len(), 3);
+                    assert!(
+                        animated
+                            .frames()
+                            .iter()
+                            .all(|frame| frame.buffer().dimensions() == (2, 4))
+                    );
+                }
+                SicImage::Static(_) | SicImage::Indexed(_) => panic!("expected an animated image"),
+            }
+        }
     }
 
-    #[test]
-    fn test_flip_v() {
-        let img = setup_default_test_image();
-        let operation = ImgOp::FlipVertical;
+    mod pad {
+        use super::*;
+        use crate::wrapper::pad::PadInputs;
+        use sic_core::AnimatedImage;
+        use sic_core::image::{DynamicImage, Frame, Rgba, RgbaImage};
 
-        let (xa, ya) = img.dimensions();
-        let operator = ImageEngine::new(img);
-        let done = operator.ignite(&[Instr::Operation(operation)]);
+        const FILL: Rgba<u8> = Rgba([255, 0, 255, 128]);
+        const CONTENT: Rgba<u8> = Rgba([10, 20, 30, 255]);
 
-        assert!(done.is_ok());
+        #[test]
+        fn pad_each_side() {
+            let img = SicImage::from(DynamicImage::ImageRgba8(RgbaImage::from_pixel(
+                2, 2, CONTENT,
+            )));
+            let operation = ImgOp::Pad(PadInputs::new((1, 2, 3, 4), FILL));
+
+            let result_img = ImageEngine::new(img)
+                .ignite(&[Instr::Operation(operation)])
+                .unwrap();
+
+            assert_eq!(result_img.dimensions(), (8, 6));
+            assert_eq!(result_img.get_pixel(0, 0), FILL);
+            assert_eq!(result_img.get_pixel(3, 1), FILL);
+            assert_eq!(result_img.get_pixel(4, 0), FILL);
+            assert_eq!(result_img.get_pixel(4, 1), CONTENT);
+            assert_eq!(result_img.get_pixel(5, 2), CONTENT);
+            assert_eq!(result_img.get_pixel(6, 2), FILL);
+            assert_eq!(result_img.get_pixel(5, 3), FILL);
+        }
 
-        let img_result = done.unwrap();
-        let (xb, yb) = img_result.dimensions();
+        #[test]
+        fn pad_zero_is_unchanged() {
+            let img = setup_default_test_image();
+            let cmp = setup_default_test_image();
+            let operation = ImgOp::Pad(PadInputs::new((0, 0, 0, 0), FILL));
 
-        assert_eq!(xa, xb);
-        assert_eq!(ya, yb);
+            let result_img = ImageEngine::new(img)
+                .ignite(&[Instr::Operation(operation)])
+                .unwrap();
 
-        output_test_image_for_manual_inspection(&img_result, out_!("test_flipv.png"));
-    }
+            assert_eq!(result_img.dimensions(), cmp.dimensions());
+            assert_eq!(result_img.get_pixel(10, 10), cmp.get_pixel(10, 10));
+        }
 
-    #[test]
-    fn test_gray_scale() {
-        use sic_core::image::Pixel;
+        #[test]
+        fn pad_overflow() {
+            let img = SicImage::from(DynamicImage::ImageRgba8(RgbaImage::new(2, 2)));
+            let operation = ImgOp::Pad(PadInputs::new((0, u32::MAX, 0, 0), FILL));
 
-        let img = open_test_image(in_!("rainbow_8x6.bmp"));
-        let operation = ImgOp::Grayscale;
+            let done = ImageEngine::new(img).ignite(&[Instr::Operation(operation)]);
 
-        let operator = ImageEngine::new(img);
-        let done = operator.ignite(&[Instr::Operation(operation)]);
+            assert!(done.is_err());
+        }
 
-        assert!(done.is_ok());
+        #[test]
+        fn pad_default_image() {
+            let img = setup_default_test_image();
+            let operation = ImgOp::Pad(PadInputs::new((10, 20, 30, 40), FILL));
 
-        let img_result = done.unwrap();
+            let result_img = ImageEngine::new(img)
+                .ignite(&[Instr::Operation(operation)])
+                .unwrap();
 
-        // The color type isn't actually changed to luma, so instead of checking color type,
-        // here pixels are checked to have equal (r, g, b) components.
-        for i in 0..8 {
-            for j in 0..6 {
-                let pixel = img_result.get_pixel(i, j);
-                let channels_result = pixel.channels();
-                let r_component = channels_result[0];
-                let g_component = channels_result[1];
-                let b_component = channels_result[2];
+            assert_eq!(result_img.dimensions(), (217 + 60, 447 + 40));
 
-                assert_eq!(r_component, g_component);
-                assert_eq!(g_component, b_component);
-            }
+            output_test_image_for_manual_inspection(&result_img, out_!("test_pad.png"));
         }
 
-        output_test_image_for_manual_inspection(&img_result, out_!("test_gray_scale.png"));
+        #[test]
+        fn pad_animated_frames() {
+            let frames = (0..3).map(|_| Frame::new(RgbaImage::new(4, 4)));
+            let img = SicImage::Animated(AnimatedImage::from_frames(frames));
+
+            let operation = ImgOp::Pad(PadInputs::new((1, 1, 1, 1), FILL));
+            let done = ImageEngine::new(img).ignite(&[Instr::Operation(operation)]);
+
+            match done.unwrap() {
+                SicImage::Animated(animated) => {
+                    assert_eq!(animated.frames().len(), 3);
+                    assert!(
+                        animated
+                            .frames()
+                            .iter()
+                            .all(|frame| frame.buffer().dimensions() == (6, 6))
+                    );
+                }
+                SicImage::Static(_) | SicImage::Indexed(_) => panic!("expected an animated image"),
+            }
+        }
     }
 
-    #[test]
-    fn test_hue_rotate_neg() {
-        let img = setup_default_test_image();
-        let cmp = setup_default_test_image();
+    mod extend_canvas {
+        use super::*;
+        use crate::wrapper::extend_canvas::ExtendCanvasInputs;
+        use crate::wrapper::gravity::Gravity;
+        use sic_core::image::{DynamicImage, Rgba, RgbaImage};
+
+        const FILL: Rgba<u8> = Rgba([0, 0, 0, 255]);
+        const CONTENT: Rgba<u8> = Rgba([10, 20, 30, 255]);
+
+        fn extend_canvas(width: u32, height: u32, gravity: Gravity) -> SicImage {
+            let img = SicImage::from(DynamicImage::ImageRgba8(RgbaImage::from_pixel(
+                2, 2, CONTENT,
+            )));
+            let operation =
+                ImgOp::ExtendCanvas(ExtendCanvasInputs::new(width, height, gravity, FILL));
+
+            ImageEngine::new(img)
+                .ignite(&[Instr::Operation(operation)])
+                .unwrap()
+        }
 
-        let operation = ImgOp::HueRotate(-100);
+        #[test]
+        fn extend_canvas_center() {
+            let result_img = extend_canvas(4, 6, Gravity::Center);
+
+            assert_eq!(result_img.dimensions(), (4, 6));
+            assert_eq!(result_img.get_pixel(0, 0), FILL);
+            assert_eq!(result_img.get_pixel(1, 2), CONTENT);
+            assert_eq!(result_img.get_pixel(2, 3), CONTENT);
+            assert_eq!(result_img.get_pixel(1, 1), FILL);
+            assert_eq!(result_img.get_pixel(3, 3), FILL);
+        }
 
-        let operator = ImageEngine::new(img);
-        let done = operator.ignite(&[Instr::Operation(operation)]);
+        #[test]
+        fn extend_canvas_south_east() {
+            let result_img = extend_canvas(3, 3, Gravity::SouthEast);
 
-        assert!(done.is_ok());
+            assert_eq!(result_img.get_pixel(0, 0), FILL);
+            assert_eq!(result_img.get_pixel(1, 1), CONTENT);
+            assert_eq!(result_img.get_pixel(2, 2), CONTENT);
+            assert_eq!(result_img.get_pixel(0, 2), FILL);
+        }
 
-        let result_img = done.unwrap();
+        #[test]
+        fn extend_canvas_smaller_than_image_cuts_off() {
+            let result_img = extend_canvas(1, 3, Gravity::North);
 
-        assert_ne!(cmp.raw_pixels(), result_img.raw_pixels());
+            assert_eq!(result_img.dimensions(), (1, 3));
+            assert_eq!(result_img.get_pixel(0, 0), CONTENT);
+            assert_eq!(result_img.get_pixel(0, 1), CONTENT);
+            assert_eq!(result_img.get_pixel(0, 2), FILL);
+        }
 
-        output_test_image_for_manual_inspection(&result_img, out_!("test_hue_rot_neg_100.png"));
-    }
+        #[test]
+        fn extend_canvas_letterbox_default_image() {
+            // 217x447px => 16:9 letterbox
+            let img = setup_default_test_image();
+            let operation =
+                ImgOp::ExtendCanvas(ExtendCanvasInputs::new(795, 447, Gravity::Center, FILL));
 
-    #[test]
-    fn test_hue_rotate_pos() {
-        let img = setup_default_test_image();
-        let cmp = setup_default_test_image();
+            let result_img = ImageEngine::new(img)
+                .ignite(&[Instr::Operation(operation)])
+                .unwrap();
 
-        let operation = ImgOp::HueRotate(100);
+            assert_eq!(result_img.dimensions(), (795, 447));
+            assert_eq!(result_img.get_pixel(0, 0), FILL);
 
-        let operator = ImageEngine::new(img);
-        let done = operator.ignite(&[Instr::Operation(operation)]);
+            output_test_image_for_manual_inspection(
+                &result_img,
+                out_!("test_extend_canvas_letterbox.png"),
+            );
+        }
+    }
 
-        assert!(done.is_ok());
+    mod color_adjustments {
+        use super::*;
+        use crate::wrapper::curves::{CurvePoints, CurvesChannel, CurvesInputs};
+        use crate::wrapper::levels::LevelsInputs;
+        use sic_core::AnimatedImage;
+        use sic_core::image::{DynamicImage, Frame, GrayImage, Luma, Rgb, Rgba, RgbaImage};
 
-        let result_img = done.unwrap();
+        fn single_pixel(pixel: Rgba<u8>) -> DynamicImage {
+            DynamicImage::ImageRgba8(RgbaImage::from_pixel(1, 1, pixel))
+        }
 
-        assert_ne!(cmp.raw_pixels(), result_img.raw_pixels());
+        #[test]
+        fn saturate_zero_is_gray() {
+            let img = single_pixel(Rgba([200, 100, 50, 128]));
+            let result_img = apply(img, ImgOp::Saturate(0.0)).unwrap();
 
-        output_test_image_for_manual_inspection(&result_img, out_!("test_hue_rot_pos_100.png"));
-    }
+            let Rgba([r, g, b, a]) = result_img.get_pixel(0, 0);
+            assert_eq!((r, a), (g, 128));
+            assert_eq!(r, b);
+        }
 
-    #[test]
-    fn test_hue_rotate_zero() {
-        let img = setup_default_test_image();
-        let cmp = setup_default_test_image();
+        #[test]
+        fn saturate_one_is_unchanged() {
+            let img = setup_default_test_image();
+            let cmp = setup_default_test_image();
 
-        let operation = ImgOp::HueRotate(0);
+            let result_img = ImageEngine::new(img)
+                .ignite(&[Instr::Operation(ImgOp::Saturate(1.0))])
+                .unwrap();
 
-        let operator = ImageEngine::new(img);
-        let done = operator.ignite(&[Instr::Operation(operation)]);
+            assert_eq!(result_img.raw_pixels(), cmp.raw_pixels());
+        }
 
-        assert!(done.is_ok());
+        #[test]
+        fn saturate_increases_distance_to_gray() {
+            let img = setup_default_test_image();
+            let result_img = ImageEngine::new(img)
+                .ignite(&[Instr::Operation(ImgOp::Saturate(1.8))])
+                .unwrap();
 
-        let result_img = done.unwrap();
+            output_test_image_for_manual_inspection(&result_img, out_!("test_saturate.png"));
 
-        assert_eq!(cmp.raw_pixels(), result_img.raw_pixels());
+            let img = single_pixel(Rgba([150, 100, 100, 255]));
+            let Rgba([r, g, b, _]) = apply(img, ImgOp::Saturate(2.0)).unwrap().get_pixel(0, 0);
+            assert!(r > 150);
+            assert!(g < 100 && b < 100);
+        }
+
+        #[test]
+        fn gamma() {
+            let img = DynamicImage::ImageLuma8(GrayImage::from_pixel(1, 1, Luma([64])));
+
+            let result_img = apply(img, ImgOp::Gamma(2.0)).unwrap();
+
+            // (64 / 255) ^ (1 / 2) * 255 ~= 127.75
+            assert_eq!(result_img.get_pixel(0, 0), Rgba([128, 128, 128, 255]));
+        }
+
+        #[test]
+        fn gamma_not_positive() {
+            let img = single_pixel(Rgba([0, 0, 0, 0]));
+
+            assert!(apply(img.clone(), ImgOp::Gamma(0.0)).is_err());
+            assert!(apply(img, ImgOp::Gamma(-1.0)).is_err());
+        }
+
+        #[test]
+        fn exposure() {
+            let img = single_pixel(Rgba([100, 0, 255, 255]));
+            let brighter = apply(img.clone(), ImgOp::Exposure(1.0)).unwrap();
+            let darker = apply(img, ImgOp::Exposure(-1.0)).unwrap();
+
+            let Rgba([r, g, b, _]) = brighter.get_pixel(0, 0);
+            // linear(100) ~= 0.1274, * 2 ~= 0.2549, srgb(0.2549) ~= 0.5419 ~= 138
+            assert_eq!((r, g, b), (138, 0, 255));
+
+            let Rgba([r, g, b, _]) = darker.get_pixel(0, 0);
+            assert_eq!((r, g), (71, 0));
+            assert!(b < 255);
+        }
+
+        #[test]
+        fn exposure_preserves_16_bit() {
+            let img = DynamicImage::ImageRgb16(sic_core::image::ImageBuffer::from_pixel(
+                1,
+                1,
+                Rgb([1000u16, 2000, 3000]),
+            ));
+
+            let result_img = apply(img, ImgOp::Exposure(0.5)).unwrap();
+
+            match result_img {
+                SicImage::Static(DynamicImage::ImageRgb16(buffer)) => {
+                    let Rgb([r, g, b]) = *buffer.get_pixel(0, 0);
+                    assert!(r > 1000 && g > 2000 && b > 3000);
+                }
+                _ => panic!("expected a 16 bit rgb image"),
+            }
+        }
+
+        #[test]
+        fn levels() {
+            let img = DynamicImage::ImageRgba8(RgbaImage::from_fn(3, 1, |x, _| match x {
+                0 => Rgba([10, 10, 10, 255]),
+                1 => Rgba([130, 130, 130, 255]),
+                _ => Rgba([250, 250, 250, 255]),
+            }));
+
+            let inputs = LevelsInputs::new((20, 240), 1.0, (0, 255));
+            let result_img = apply(img, ImgOp::Levels(inputs)).unwrap();
+
+            assert_eq!(result_img.get_pixel(0, 0), Rgba([0, 0, 0, 255]));
+            assert_eq!(result_img.get_pixel(1, 0), Rgba([128, 128, 128, 255]));
+            assert_eq!(result_img.get_pixel(2, 0), Rgba([255, 255, 255, 255]));
+        }
+
+        #[test]
+        fn levels_output_range_inverts() {
+            let img = single_pixel(Rgba([0, 255, 51, 255]));
+            let inputs = LevelsInputs::new((0, 255), 1.0, (255, 0));
+
+            let result_img = apply(img, ImgOp::Levels(inputs)).unwrap();
+
+            assert_eq!(result_img.get_pixel(0, 0), Rgba([255, 0, 204, 255]));
+        }
+
+        #[test]
+        fn levels_invalid() {
+            let img = single_pixel(Rgba([0, 0, 0, 0]));
+
+            let inputs = LevelsInputs::new((100, 100), 1.0, (0, 255));
+            assert!(apply(img.clone(), ImgOp::Levels(inputs)).is_err());
+
+            let inputs = LevelsInputs::new((0, 255), 0.0, (0, 255));
+            assert!(apply(img, ImgOp::Levels(inputs)).is_err());
+        }
+
+        #[test]
+        fn curves_per_channel() {
+            let img = single_pixel(Rgba([100, 100, 100, 255]));
+            let points = CurvePoints::new(vec![(0, 255), (255, 0)]).unwrap();
+
+            let inputs = CurvesInputs::new(CurvesChannel::Green, points.clone());
+            let result_img = apply(img.clone(), ImgOp::Curves(inputs)).unwrap();
+            assert_eq!(result_img.get_pixel(0, 0), Rgba([100, 155, 100, 255]));
+
+            let inputs = CurvesInputs::new(CurvesChannel::Rgb, points);
+            let result_img = apply(img, ImgOp::Curves(inputs)).unwrap();
+            assert_eq!(result_img.get_pixel(0, 0), Rgba([155, 155, 155, 255]));
+        }
+
+        #[test]
+        fn curves_luminance_preserves_channel_differences() {
+            let img = single_pixel(Rgba([120, 100, 80, 255]));
+            let points = CurvePoints::new(vec![(0, 20), (255, 255)]).unwrap();
+
+            let inputs = CurvesInputs::new(CurvesChannel::Luminance, points);
+            let Rgba([r, g, b, _]) = apply(img, ImgOp::Curves(inputs)).unwrap().get_pixel(0, 0);
+
+            assert!(g > 100);
+            assert_eq!(i32::from(r) - i32::from(g), 20);
+            assert_eq!(i32::from(g) - i32::from(b), 20);
+        }
+
+        #[test]
+        fn curves_default_image() {
+            let img = setup_default_test_image();
+            let points = "0:0,64:40,192:220,255:255".parse::<CurvePoints>().unwrap();
+            let operation = ImgOp::Curves(CurvesInputs::new(CurvesChannel::Rgb, points));
+
+            let result_img = ImageEngine::new(img)
+                .ignite(&[Instr::Operation(operation)])
+                .unwrap();
+
+            output_test_image_for_manual_inspection(&result_img, out_!("test_curves.png"));
+        }
+
+        #[test]
+        fn color_adjustments_animated_frames() {
+            let frames = (0..2).map(|_| Frame::new(RgbaImage::from_pixel(2, 2, Rgba([64; 4]))));
+            let img = SicImage::Animated(AnimatedImage::from_frames(frames));
+
+            let done = ImageEngine::new(img).ignite(&[Instr::Operation(ImgOp::Gamma(2.0))]);
+
+            match done.unwrap() {
+                SicImage::Animated(animated) => {
+                    assert!(animated.frames().iter().all(|frame| {
+                        *frame.buffer().get_pixel(0, 0) == Rgba([128, 128, 128, 64])
+                    }));
+                }
+                SicImage::Static(_) | SicImage::Indexed(_) => panic!("expected an animated image"),
+            }
+        }
+    }
+
+    mod histogram_equalization {
+        use super::*;
+        use crate::wrapper::clahe::ClaheInputs;
+        use crate::wrapper::equalize::EqualizeMode;
+        use sic_core::image::{
+            ColorType, DynamicImage, GrayImage, ImageBuffer, Luma, Rgb, RgbImage,
+        };
+        use std::collections::HashSet;
+
+        // A 16x16 gray image with values in the range 100 up to (and including) 155.
+        fn low_contrast() -> DynamicImage {
+            DynamicImage::ImageLuma8(GrayImage::from_fn(16, 16, |x, y| {
+                Luma([100 + ((y * 16 + x) % 56) as u8])
+            }))
+        }
+
+        fn min_max(buffer: &GrayImage) -> (u8, u8) {
+            let values = buffer.pixels().map(|p| p[0]);
+            (values.clone().min().unwrap(), values.max().unwrap())
+        }
+
+        #[test]
+        fn equalize_luma_stretches_to_full_range() {
+            let result_img = apply(low_contrast(), ImgOp::Equalize(EqualizeMode::Luma)).unwrap();
+
+            assert_eq!(result_img.as_ref().color(), ColorType::L8);
+            assert_eq!(min_max(&result_img.as_ref().to_luma8()), (0, 255));
+        }
+
+        #[test]
+        fn equalize_channels() {
+            let img = DynamicImage::ImageRgb8(RgbImage::from_fn(10, 10, |x, y| {
+                Rgb([100 + (y * 10 + x) as u8 / 2, 10, 200])
+            }));
+
+            let result_img = apply(img, ImgOp::Equalize(EqualizeMode::Channels)).unwrap();
+            let buffer = result_img.as_ref().to_rgb8();
+
+            let reds: Vec<u8> = buffer.pixels().map(|p| p[0]).collect();
+            assert_eq!(*reds.iter().min().unwrap(), 0);
+            assert_eq!(*reds.iter().max().unwrap(), 255);
+
+            // a single value is left unchanged
+            assert!(buffer.pixels().all(|p| p[1] == 10 && p[2] == 200));
+        }
+
+        #[test]
+        fn equalize_16_bit_preserves_precision() {
+            let img = DynamicImage::ImageLuma16(ImageBuffer::from_fn(40, 40, |x, y| {
+                Luma([20_000 + (y * 40 + x) as u16])
+            }));
+
+            let result_img = apply(img, ImgOp::Equalize(EqualizeMode::Luma)).unwrap();
+
+            let DynamicImage::ImageLuma16(buffer) = result_img.as_ref() else {
+                panic!("Expected a 16-bit gray image");
+            };
+            let values: HashSet<u16> = buffer.pixels().map(|p| p[0]).collect();
+
+            assert_eq!(values.len(), 1600);
+            assert!(values.contains(&0));
+            assert!(values.contains(&u16::MAX));
+        }
+
+        #[test]
+        fn clahe_enhances_local_contrast() {
+            // The left half is dark, and the right half is bright, both with low contrast
+            let img = DynamicImage::ImageLuma8(GrayImage::from_fn(32, 32, |x, y| {
+                let base = if x < 16 { 20 } else { 200 };
+                Luma([base + ((x + y) % 8) as u8])
+            }));
+
+            let inputs = ClaheInputs::new(2, 2, 4.0);
+            let result_img = apply(img, ImgOp::Clahe(inputs)).unwrap();
+            let buffer = result_img.as_ref().to_luma8();
+
+            let range = |x0: u32, x1: u32| {
+                let values = (0..32).flat_map(|y| (x0..x1).map(move |x| (x, y)));
+                let values = values.map(|(x, y)| buffer[(x, y)][0]).collect::<Vec<_>>();
+                values.iter().max().unwrap() - values.iter().min().unwrap()
+            };
+
+            assert!(range(2, 6) > 7, "{}", range(2, 6));
+            assert!(range(26, 30) > 7, "{}", range(26, 30));
+        }
+
+        #[test]
+        fn clahe_16_bit() {
+            let img = DynamicImage::ImageLuma16(ImageBuffer::from_fn(20, 20, |x, y| {
+                Luma([30_000 + (x * y) as u16])
+            }));
+
+            let inputs = ClaheInputs::new(4, 4, 2.0);
+            let result_img = apply(img, ImgOp::Clahe(inputs)).unwrap();
+
+            assert_eq!(result_img.as_ref().color(), ColorType::L16);
+        }
+
+        #[test]
+        fn clahe_more_tiles_than_pixels() {
+            let inputs = ClaheInputs::new(100, 100, 2.0);
+            assert!(apply(low_contrast(), ImgOp::Clahe(inputs)).is_ok());
+        }
+
+        #[test]
+        fn clahe_invalid_inputs() {
+            let inputs = ClaheInputs::new(0, 2, 2.0);
+            assert!(apply(low_contrast(), ImgOp::Clahe(inputs)).is_err());
+
+            let inputs = ClaheInputs::new(2, 2, 0.0);
+            assert!(apply(low_contrast(), ImgOp::Clahe(inputs)).is_err());
+        }
+
+        #[test]
+        fn equalized_for_manual_inspection() {
+            for (operation, name) in [
+                (
+                    ImgOp::Equalize(EqualizeMode::Luma),
+                    out_!("equalize_luma.png"),
+                ),
+                (
+                    ImgOp::Equalize(EqualizeMode::Channels),
+                    out_!("equalize_channels.png"),
+                ),
+                (
+                    ImgOp::Clahe(ClaheInputs::new(8, 8, 3.0)),
+                    out_!("equalize_clahe.png"),
+                ),
+            ] {
+                let result_img = ImageEngine::new(setup_default_test_image())
+                    .ignite(&[Instr::Operation(operation)])
+                    .unwrap();
+
+                assert_eq!(result_img.dimensions(), (217, 447));
+                output_test_image_for_manual_inspection(&result_img, name);
+            }
+        }
+    }
+
+    mod color_matrix {
+        use super::*;
+        use crate::wrapper::color_matrix::{ColorMatrixInputs, ColorMatrixPreset};
+        use sic_core::image::{DynamicImage, Rgb, RgbImage, Rgba, RgbaImage};
+
+        #[test]
+        fn identity_is_unchanged() {
+            let img = setup_default_test_image();
+            let cmp = setup_default_test_image();
+
+            #[rustfmt::skip]
+            let identity = ColorMatrixInputs::new([
+                1.0, 0.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 1.0, 0.0,
+            ]);
+
+            let result_img = ImageEngine::new(img)
+                .ignite(&[Instr::Operation(ImgOp::ColorMatrix(identity))])
+                .unwrap();
+
+            assert_eq!(result_img.raw_pixels(), cmp.raw_pixels());
+        }
+
+        #[test]
+        fn swap_rb() {
+            let img = DynamicImage::ImageRgba8(RgbaImage::from_pixel(1, 1, Rgba([10, 20, 30, 40])));
+            let result_img =
+                apply(img, ImgOp::ColorMatrix(ColorMatrixPreset::SwapRb.into())).unwrap();
+
+            assert_eq!(result_img.get_pixel(0, 0), Rgba([30, 20, 10, 40]));
+        }
+
+        #[test]
+        fn sepia() {
+            let img = setup_default_test_image();
+            let result_img = ImageEngine::new(img)
+                .ignite(&[Instr::Operation(ImgOp::ColorMatrix(
+                    ColorMatrixPreset::Sepia.into(),
+                ))])
+                .unwrap();
+
+            let Rgba([r, g, b, _]) = result_img.get_pixel(10, 10);
+            assert!(r >= g && g >= b);
+
+            output_test_image_for_manual_inspection(&result_img, out_!("color_matrix_sepia.png"));
+        }
+
+        #[test]
+        fn offset_is_normalized() {
+            let mut matrix = [0.0; 20];
+            matrix[4] = 0.5;
+            matrix[18] = 1.0;
+
+            let img = DynamicImage::ImageRgba8(RgbaImage::from_pixel(1, 1, Rgba([0, 0, 0, 255])));
+            let result_img =
+                apply(img, ImgOp::ColorMatrix(ColorMatrixInputs::new(matrix))).unwrap();
+
+            assert_eq!(result_img.get_pixel(0, 0), Rgba([128, 0, 0, 255]));
+        }
+
+        #[test]
+        fn luminance_to_alpha_adds_alpha_channel() {
+            let img = DynamicImage::ImageRgb8(RgbImage::from_pixel(1, 1, Rgb([255, 255, 255])));
+            let result_img = apply(
+                img,
+                ImgOp::ColorMatrix(ColorMatrixPreset::LuminanceToAlpha.into()),
+            )
+            .unwrap();
+
+            assert_eq!(result_img.get_pixel(0, 0), Rgba([0, 0, 0, 255]));
+
+            let img = DynamicImage::ImageRgb8(RgbImage::from_pixel(1, 1, Rgb([0, 0, 0])));
+            let result_img = apply(
+                img,
+                ImgOp::ColorMatrix(ColorMatrixPreset::LuminanceToAlpha.into()),
+            )
+            .unwrap();
+
+            assert_eq!(result_img.get_pixel(0, 0), Rgba([0, 0, 0, 0]));
+        }
+
+        #[test]
+        fn preserves_color_type() {
+            let img = DynamicImage::new_rgb16(2, 2);
+            let result_img =
+                apply(img, ImgOp::ColorMatrix(ColorMatrixPreset::Sepia.into())).unwrap();
+
+            let SicImage::Static(image) = result_img else {
+                panic!("expected a static image");
+            };
+
+            assert!(matches!(image, DynamicImage::ImageRgb16(_)));
+        }
+    }
+
+    mod denoise {
+        use super::*;
+        use crate::wrapper::bilateral::BilateralInputs;
+        use crate::wrapper::nl_means::NlMeansInputs;
+        use sic_core::image::{DynamicImage, GrayImage, Luma, Rgba, RgbaImage};
+
+        // A 10x10 image, of which the left half is dark and the right half is bright, with a
+        // checkerboard of +/- 10 noise.
+        fn noisy_edge() -> DynamicImage {
+            DynamicImage::ImageLuma8(GrayImage::from_fn(10, 10, |x, y| {
+                let base = if x < 5 { 50 } else { 200 };
+                let noise = if (x + y) % 2 == 0 { 10 } else { -10 };
+
+                Luma([(base + noise) as u8])
+            }))
+        }
+
+        // Asserts the noise is reduced, while the edge is kept
+        fn assert_denoised(result_img: &SicImage) {
+            let buffer = result_img.as_ref().to_luma8();
+
+            for y in 1..9 {
+                for x in 1..4 {
+                    let Luma([value]) = buffer[(x, y)];
+                    assert!((45..=55).contains(&value), "({}, {}): {}", x, y, value);
+                }
+
+                for x in 6..9 {
+                    let Luma([value]) = buffer[(x, y)];
+                    assert!((195..=205).contains(&value), "({}, {}): {}", x, y, value);
+                }
+            }
+        }
+
+        #[test]
+        fn median_removes_salt_and_pepper_noise() {
+            // The left half is black and the right half is white, with a single white pixel in
+            // the left half, and a single black pixel in the right half.
+            let img = DynamicImage::ImageLuma8(GrayImage::from_fn(10, 5, |x, y| match (x, y) {
+                (2, 2) => Luma([255]),
+                (7, 2) => Luma([0]),
+                (x, _) if x < 5 => Luma([0]),
+                _ => Luma([255]),
+            }));
+
+            let result_img = apply(img, ImgOp::Median(1)).unwrap();
+            let buffer = result_img.as_ref().to_luma8();
+
+            for (x, _, Luma([value])) in buffer.enumerate_pixels() {
+                assert_eq!(*value, if x < 5 { 0 } else { 255 });
+            }
+        }
+
+        #[test]
+        fn bilateral() {
+            let inputs = BilateralInputs::new(2, 30.0, 2.0);
+            let result_img = apply(noisy_edge(), ImgOp::Bilateral(inputs)).unwrap();
+
+            assert_denoised(&result_img);
+        }
+
+        #[test]
+        fn bilateral_keeps_alpha() {
+            let img = DynamicImage::ImageRgba8(RgbaImage::from_fn(4, 4, |x, _| {
+                Rgba([100, 100, 100, (x * 50) as u8])
+            }));
+
+            let inputs = BilateralInputs::new(1, 10.0, 1.0);
+            let result_img = apply(img, ImgOp::Bilateral(inputs)).unwrap();
+            let buffer = result_img.as_ref().to_rgba8();
+
+            assert_eq!(buffer[(0, 0)], Rgba([100, 100, 100, 0]));
+            assert_eq!(buffer[(3, 3)], Rgba([100, 100, 100, 150]));
+        }
+
+        #[test]
+        fn bilateral_invalid_sigma() {
+            let inputs = BilateralInputs::new(1, 0.0, 1.0);
+            assert!(apply(noisy_edge(), ImgOp::Bilateral(inputs)).is_err());
+
+            let inputs = BilateralInputs::new(1, 10.0, -1.0);
+            assert!(apply(noisy_edge(), ImgOp::Bilateral(inputs)).is_err());
+        }
+
+        #[test]
+        fn nl_means() {
+            let inputs = NlMeansInputs::new(30.0, 1, 3);
+            let result_img = apply(noisy_edge(), ImgOp::NlMeans(inputs)).unwrap();
+
+            assert_denoised(&result_img);
+        }
+
+        #[test]
+        fn nl_means_invalid_strength() {
+            let inputs = NlMeansInputs::new(0.0, 1, 3);
+            assert!(apply(noisy_edge(), ImgOp::NlMeans(inputs)).is_err());
+        }
+
+        #[test]
+        fn nl_means_tiny_strength_is_unchanged() {
+            let inputs = NlMeansInputs::new(1e-30, 1, 3);
+            let result_img = apply(noisy_edge(), ImgOp::NlMeans(inputs)).unwrap();
+
+            assert_eq!(
+                result_img.raw_pixels(),
+                SicImage::from(noisy_edge()).raw_pixels()
+            );
+        }
+
+        #[test]
+        fn denoised_for_manual_inspection() {
+            for (operation, name) in [
+                (ImgOp::Median(2), out_!("denoise_median.png")),
+                (
+                    ImgOp::Bilateral(BilateralInputs::new(3, 25.0, 3.0)),
+                    out_!("denoise_bilateral.png"),
+                ),
+                (
+                    ImgOp::NlMeans(NlMeansInputs::new(10.0, 1, 2)),
+                    out_!("denoise_nl_means.png"),
+                ),
+            ] {
+                let result_img = ImageEngine::new(setup_default_test_image())
+                    .ignite(&[Instr::Operation(operation)])
+                    .unwrap();
+
+                assert_eq!(result_img.dimensions(), (217, 447));
+                output_test_image_for_manual_inspection(&result_img, name);
+            }
+        }
+    }
+
+    mod dithering {
+        use super::*;
+        use crate::wrapper::dither_algorithm::DitherAlgorithm;
+        use sic_core::image::{DynamicImage, GrayImage, Luma};
+
+        const ALGORITHMS: [DitherAlgorithm; 8] = [
+            DitherAlgorithm::FloydSteinberg,
+            DitherAlgorithm::Atkinson,
+            DitherAlgorithm::JarvisJudiceNinke,
+            DitherAlgorithm::Stucki,
+            DitherAlgorithm::Sierra,
+            DitherAlgorithm::Bayer2x2,
+            DitherAlgorithm::Bayer4x4,
+            DitherAlgorithm::Bayer8x8,
+        ];
+
+        fn dither(img: DynamicImage, env: &[EnvItem]) -> GrayImage {
+            let mut instructions: Vec<Instr> = env.iter().copied().map(Instr::EnvAdd).collect();
+            instructions.push(Instr::Operation(ImgOp::Dither));
+
+            let result_img = ImageEngine::new(SicImage::from(img))
+                .ignite(&instructions)
+                .unwrap();
+
+            result_img.as_ref().to_luma8()
+        }
+
+        // A 64x16 horizontal gradient from black to (almost) white.
+        fn gradient() -> DynamicImage {
+            DynamicImage::ImageLuma8(GrayImage::from_fn(64, 16, |x, _| Luma([(x * 4) as u8])))
+        }
+
+        fn mean(buffer: &GrayImage) -> f32 {
+            let sum: f32 = buffer.pixels().map(|p| f32::from(p[0])).sum();
+            sum / (buffer.width() * buffer.height()) as f32
+        }
+
+        #[test]
+        fn algorithms_are_bilevel_and_preserve_brightness() {
+            let expected = mean(&gradient().to_luma8());
+
+            for algorithm in ALGORITHMS {
+                let buffer = dither(gradient(), &[EnvItem::DitherAlgorithm(algorithm)]);
+
+                assert!(buffer.pixels().all(|p| p[0] == 0 || p[0] == 255));
+                assert!(
+                    (mean(&buffer) - expected).abs() < 16.0,
+                    "{:?}: mean {} differs from {}",
+                    algorithm,
+                    mean(&buffer),
+                    expected
+                );
+            }
+        }
+
+        #[test]
+        fn default_is_floyd_steinberg() {
+            let default = dither(gradient(), &[]);
+            let floyd_steinberg = dither(
+                gradient(),
+                &[EnvItem::DitherAlgorithm(DitherAlgorithm::FloydSteinberg)],
+            );
+
+            assert_eq!(default, floyd_steinberg);
+        }
+
+        #[test]
+        fn bayer2x2_of_half_gray_is_checkerboard() {
+            let img = DynamicImage::ImageLuma8(GrayImage::from_pixel(4, 4, Luma([128])));
+
+            let buffer = dither(img, &[EnvItem::DitherAlgorithm(DitherAlgorithm::Bayer2x2)]);
+
+            for (x, y, pixel) in buffer.enumerate_pixels() {
+                let expected = if (x + y) % 2 == 1 { 255 } else { 0 };
+                assert_eq!(pixel[0], expected, "at ({}, {})", x, y);
+            }
+        }
+
+        #[test]
+        fn serpentine_changes_scan_order() {
+            for algorithm in [DitherAlgorithm::FloydSteinberg, DitherAlgorithm::Stucki] {
+                let algorithm = EnvItem::DitherAlgorithm(algorithm);
+
+                let raster = dither(gradient(), &[algorithm]);
+                let serpentine = dither(gradient(), &[algorithm, EnvItem::DitherSerpentine(true)]);
+
+                assert_ne!(raster, serpentine);
+            }
+        }
+
+        #[test]
+        fn serpentine_has_no_effect_on_ordered_dithering() {
+            let algorithm = EnvItem::DitherAlgorithm(DitherAlgorithm::Bayer4x4);
+
+            let raster = dither(gradient(), &[algorithm]);
+            let serpentine = dither(gradient(), &[algorithm, EnvItem::DitherSerpentine(true)]);
+
+            assert_eq!(raster, serpentine);
+        }
+
+        #[test]
+        fn dither_quant_with_algorithm() {
+            let img = setup_default_test_image();
+            let cmp = setup_default_test_image();
+
+            let instructions = [
+                Instr::EnvAdd(EnvItem::DitherAlgorithm(DitherAlgorithm::Bayer4x4)),
+                Instr::Operation(ImgOp::DitherQuant((64, 10))),
+            ];
+
+            let result_img = ImageEngine::new(img).ignite(&instructions).unwrap();
+
+            assert_ne!(cmp.raw_pixels(), result_img.raw_pixels());
+
+            output_test_image_for_manual_inspection(
+                &result_img,
+                out_!("test_dither_quant_bayer4x4.png"),
+            );
+        }
+
+        #[test]
+        fn manual_inspection() {
+            for (algorithm, name) in [
+                (DitherAlgorithm::Atkinson, "atkinson"),
+                (DitherAlgorithm::JarvisJudiceNinke, "jarvis_judice_ninke"),
+                (DitherAlgorithm::Bayer8x8, "bayer8x8"),
+            ] {
+                let instructions = [
+                    Instr::EnvAdd(EnvItem::DitherAlgorithm(algorithm)),
+                    Instr::EnvAdd(EnvItem::DitherSerpentine(true)),
+                    Instr::Operation(ImgOp::Dither),
+                ];
+
+                let result_img = ImageEngine::new(setup_default_test_image())
+                    .ignite(&instructions)
+                    .unwrap();
+
+                let name = format!("test_dither_{}.png", name);
+                output_test_image_for_manual_inspection(&result_img, out_!(&name));
+            }
+        }
+    }
+
+    mod quantize {
+        use super::*;
+        use crate::wrapper::dither_algorithm::DitherAlgorithm;
+        use crate::wrapper::palette::{PaletteSource, QuantizeInputs, QuantizeMode};
+        use sic_core::AnimatedImage;
+        use sic_core::image::{ColorType, DynamicImage, Frame, Rgb, RgbImage, Rgba};
+        use std::path::PathBuf;
+
+        const PALETTE: [Rgba<u8>; 4] = [
+            Rgba([0, 0, 0, 255]),
+            Rgba([255, 255, 255, 255]),
+            Rgba([255, 0, 0, 255]),
+            Rgba([0, 0, 255, 255]),
+        ];
+
+        fn quantize(mode: QuantizeMode, palette: PaletteSource) -> ImgOp {
+            ImgOp::Quantize(QuantizeInputs::new(mode, palette))
+        }
+
+        // A 32x32 image with a red gradient along the x-axis and a blue gradient along the y-axis.
+        fn gradient() -> DynamicImage {
+            DynamicImage::ImageRgb8(RgbImage::from_fn(32, 32, |x, y| {
+                Rgb([(x * 8) as u8, 64, (y * 8) as u8])
+            }))
+        }
+
+        fn expanded(img: &SicImage) -> DynamicImage {
+            DynamicImage::try_from(img.clone()).unwrap()
+        }
+
+        fn only_palette_colors(img: &SicImage) -> bool {
+            expanded(img)
+                .to_rgba8()
+                .pixels()
+                .all(|pixel| PALETTE.contains(pixel))
+        }
+
+        #[test]
+        fn nearest() {
+            let img = DynamicImage::ImageRgb8(RgbImage::from_fn(2, 1, |x, _| {
+                [Rgb([200, 30, 20]), Rgb([20, 40, 90])][x as usize]
+            }));
+
+            let operation = quantize(
+                QuantizeMode::Nearest,
+                PaletteSource::Colors(PALETTE.to_vec()),
+            );
+            let result_img = apply(img, operation).unwrap();
+
+            let SicImage::Indexed(indexed) = &result_img else {
+                panic!("expected an indexed image");
+            };
+            assert_eq!(indexed.palette(), &PALETTE);
+            assert_eq!(indexed.indices().as_raw(), &[2, 0]);
+
+            assert_eq!(expanded(&result_img).color(), ColorType::Rgb8);
+
+            let buffer = expanded(&result_img).to_rgb8();
+            assert_eq!(buffer.get_pixel(0, 0), &Rgb([255, 0, 0]));
+            assert_eq!(buffer.get_pixel(1, 0), &Rgb([0, 0, 0]));
+        }
+
+        #[test]
+        fn animated_frames_hold_palette_colors() {
+            let frames = (0..2).map(|_| Frame::new(gradient().to_rgba8()));
+            let img = SicImage::Animated(AnimatedImage::from_frames(frames));
+
+            let operation = quantize(
+                QuantizeMode::Nearest,
+                PaletteSource::Colors(PALETTE.to_vec()),
+            );
+            let result_img = apply(img, operation).unwrap();
+
+            let SicImage::Animated(animated) = result_img else {
+                panic!("expected an animated image");
+            };
+            assert!(
+                animated
+                    .frames()
+                    .iter()
+                    .flat_map(|frame| frame.buffer().pixels())
+                    .all(|pixel| PALETTE.contains(pixel))
+            );
+        }
+
+        #[test]
+        fn translucent_palette_keeps_alpha() {
+            let palette = vec![Rgba([0, 0, 0, 0]), Rgba([255, 255, 255, 255])];
+
+            let operation = quantize(QuantizeMode::Nearest, PaletteSource::Colors(palette));
+            let result_img = apply(gradient(), operation).unwrap();
+
+            assert_eq!(expanded(&result_img).color(), ColorType::Rgba8);
+        }
+
+        #[test]
+        fn dither_with_each_algorithm() {
+            let nearest = apply(
+                gradient(),
+                quantize(
+                    QuantizeMode::Nearest,
+                    PaletteSource::Colors(PALETTE.to_vec()),
+                ),
+            )
+            .unwrap();
+
+            for algorithm in [DitherAlgorithm::FloydSteinberg, DitherAlgorithm::Bayer4x4] {
+                let instructions = [
+                    Instr::EnvAdd(EnvItem::DitherAlgorithm(algorithm)),
+                    Instr::Operation(quantize(
+                        QuantizeMode::Dither,
+                        PaletteSource::Colors(PALETTE.to_vec()),
+                    )),
+                ];
+                let result_img = ImageEngine::new(SicImage::from(gradient()))
+                    .ignite(&instructions)
+                    .unwrap();
+
+                assert!(only_palette_colors(&result_img), "{:?}", algorithm);
+                assert_ne!(result_img.raw_pixels(), nearest.raw_pixels());
+            }
+        }
+
+        #[test]
+        fn palette_files() {
+            for name in ["palette_4.gpl", "palette_4.hex"] {
+                let path = PathBuf::from(sic_testing::in_!(name));
+
+                let operation = quantize(QuantizeMode::Dither, PaletteSource::Path(path));
+                let result_img = apply(gradient(), operation).unwrap();
+
+                assert!(only_palette_colors(&result_img), "{}", name);
+            }
+        }
+
+        #[test]
+        fn palette_file_not_found() {
+            let path = PathBuf::from(sic_testing::in_!("palette_does_not_exist.gpl"));
+
+            let operation = quantize(QuantizeMode::Nearest, PaletteSource::Path(path));
+            let result = apply(gradient(), operation);
+
+            assert!(matches!(
+                result,
+                Err(SicImageEngineError::PaletteFileLoadError(..))
+            ));
+        }
+
+        #[test]
+        fn empty_palette() {
+            let operation = quantize(QuantizeMode::Nearest, PaletteSource::Colors(Vec::new()));
+            let result = apply(gradient(), operation);
+
+            assert!(matches!(result, Err(SicImageEngineError::PaletteEmpty)));
+        }
+
+        #[test]
+        fn manual_inspection() {
+            let path = PathBuf::from(sic_testing::in_!("palette_4.gpl"));
+
+            let instructions = [
+                Instr::EnvAdd(EnvItem::DitherAlgorithm(DitherAlgorithm::Atkinson)),
+                Instr::Operation(quantize(QuantizeMode::Dither, PaletteSource::Path(path))),
+            ];
+            let result_img = ImageEngine::new(setup_default_test_image())
+                .ignite(&instructions)
+                .unwrap();
+
+            output_test_image_for_manual_inspection(&result_img, out_!("test_quantize.png"));
+        }
+    }
+
+    mod edges {
+        use super::*;
+        use sic_core::image::{DynamicImage, GrayImage, Luma};
+
+        // A 10x10 image, of which the left half is black and the right half is white.
+        fn vertical_edge() -> DynamicImage {
+            DynamicImage::ImageLuma8(GrayImage::from_fn(10, 10, |x, _| {
+                if x < 5 { Luma([0]) } else { Luma([255]) }
+            }))
+        }
+
+        fn assert_vertical_edge(result_img: &SicImage) {
+            let Luma([flat]) = result_img.as_ref().to_luma8()[(1, 5)];
+            let Luma([edge]) = result_img.as_ref().to_luma8()[(5, 5)];
+
+            assert_eq!(flat, 0);
+            assert_eq!(edge, 255);
+        }
+
+        #[test]
+        fn sobel() {
+            let result_img = apply(vertical_edge(), ImgOp::Sobel).unwrap();
+            assert_vertical_edge(&result_img);
+        }
+
+        #[test]
+        fn scharr() {
+            let result_img = apply(vertical_edge(), ImgOp::Scharr).unwrap();
+            assert_vertical_edge(&result_img);
+        }
+
+        #[test]
+        fn laplacian() {
+            let result_img = apply(vertical_edge(), ImgOp::Laplacian).unwrap();
+            assert_vertical_edge(&result_img);
+        }
+
+        #[test]
+        fn canny() {
+            let result_img = apply(vertical_edge(), ImgOp::Canny((50.0, 100.0))).unwrap();
+
+            let edges = result_img.as_ref().to_luma8();
+            let column_has_edge = |x| (0..10).any(|y| edges[(x, y)] == Luma([255]));
+
+            assert!(column_has_edge(4) || column_has_edge(5));
+            assert!(!column_has_edge(0));
+            assert!(!column_has_edge(9));
+        }
+
+        #[test]
+        fn canny_invalid_thresholds() {
+            assert!(apply(vertical_edge(), ImgOp::Canny((100.0, 50.0))).is_err());
+            assert!(apply(vertical_edge(), ImgOp::Canny((-1.0, 50.0))).is_err());
+        }
+
+        #[test]
+        fn edge_maps_for_manual_inspection() {
+            for (operation, name) in [
+                (ImgOp::Sobel, out_!("edges_sobel.png")),
+                (ImgOp::Scharr, out_!("edges_scharr.png")),
+                (ImgOp::Laplacian, out_!("edges_laplacian.png")),
+                (ImgOp::Canny((40.0, 120.0)), out_!("edges_canny.png")),
+            ] {
+                let result_img = ImageEngine::new(setup_default_test_image())
+                    .ignite(&[Instr::Operation(operation)])
+                    .unwrap();
+
+                assert_eq!(result_img.dimensions(), (217, 447));
+                output_test_image_for_manual_inspection(&result_img, name);
+            }
+        }
+    }
+
+    mod filter {
+        use super::*;
+        use crate::wrapper::filter::{Divisor, EdgeMode, FilterInputs, Kernel, KernelShape};
+        use sic_core::image::{DynamicImage, GrayImage, Rgba, RgbaImage};
+
+        fn full(width: u32, height: u32, values: Vec<f32>) -> Kernel {
+            Kernel::new(KernelShape::Full { width, height }, values).unwrap()
+        }
+
+        #[test]
+        fn identity_is_unchanged() {
+            let img = setup_default_test_image();
+            let cmp = setup_default_test_image();
+
+            let mut values = vec![0.0; 25];
+            values[12] = 1.0;
+            let inputs = FilterInputs::new(full(5, 5, values), Divisor::Auto, 0.0, EdgeMode::Clamp);
+
+            let result_img = ImageEngine::new(img)
+                .ignite(&[Instr::Operation(ImgOp::Filter(inputs))])
+                .unwrap();
+
+            assert_eq!(result_img.raw_pixels(), cmp.raw_pixels());
+        }
+
+        #[test]
+        fn box_blur_of_uniform_image_is_uniform() {
+            let img =
+                DynamicImage::ImageRgba8(RgbaImage::from_pixel(4, 3, Rgba([50, 100, 150, 200])));
+            let inputs = FilterInputs::new(
+                full(7, 3, vec![1.0; 21]),
+                Divisor::Auto,
+                0.0,
+                EdgeMode::Mirror,
+            );
+
+            let result_img = apply(img, ImgOp::Filter(inputs)).unwrap();
+
+            for y in 0..3 {
+                for x in 0..4 {
+                    assert_eq!(result_img.get_pixel(x, y), Rgba([50, 100, 150, 200]));
+                }
+            }
+        }
+
+        #[test]
+        fn edge_modes() {
+            // Each output pixel takes the value of the pixel two positions to its left.
+            let kernel = full(5, 1, vec![1.0, 0.0, 0.0, 0.0, 0.0]);
+            let cases = [
+                (EdgeMode::Clamp, [10, 10, 10]),
+                (EdgeMode::Wrap, [20, 30, 10]),
+                (EdgeMode::Mirror, [20, 10, 10]),
+            ];
+
+            for (edge_mode, expected) in cases {
+                let img =
+                    DynamicImage::ImageLuma8(GrayImage::from_raw(3, 1, vec![10, 20, 30]).unwrap());
+                let inputs = FilterInputs::new(kernel.clone(), Divisor::Value(1.0), 0.0, edge_mode);
+                let result_img = apply(img, ImgOp::Filter(inputs)).unwrap();
+
+                let SicImage::Static(DynamicImage::ImageLuma8(buffer)) = result_img else {
+                    panic!("expected the color type to be preserved");
+                };
+
+                assert_eq!(buffer.into_raw(), expected, "{:?}", edge_mode);
+            }
+        }
+
+        #[test]
+        fn separable_equals_full() {
+            let separable = Kernel::new(
+                KernelShape::Separable {
+                    width: 3,
+                    height: 3,
+                },
+                vec![1.0, 2.0, 1.0, 1.0, 2.0, 1.0],
+            )
+            .unwrap();
+            let full = full(3, 3, vec![1.0, 2.0, 1.0, 2.0, 4.0, 2.0, 1.0, 2.0, 1.0]);
+
+            let result_separable = ImageEngine::new(setup_default_test_image())
+                .ignite(&[Instr::Operation(ImgOp::Filter(FilterInputs::new(
+                    separable,
+                    Divisor::Auto,
+                    0.0,
+                    EdgeMode::Clamp,
+                )))])
+                .unwrap();
+            let result_full = ImageEngine::new(setup_default_test_image())
+                .ignite(&[Instr::Operation(ImgOp::Filter(FilterInputs::new(
+                    full,
+                    Divisor::Auto,
+                    0.0,
+                    EdgeMode::Clamp,
+                )))])
+                .unwrap();
+
+            let differences = result_separable
+                .raw_pixels()
+                .iter()
+                .zip(result_full.raw_pixels().iter())
+                .filter(|(lhs, rhs)| lhs.abs_diff(**rhs) > 1)
+                .count();
+
+            assert_eq!(differences, 0);
+
+            output_test_image_for_manual_inspection(
+                &result_separable,
+                out_!("filter_separable.png"),
+            );
+        }
+
+        #[test]
+        fn bias_and_divisor() {
+            let img = DynamicImage::ImageRgba8(RgbaImage::from_pixel(1, 1, Rgba([100, 0, 0, 77])));
+            let inputs = FilterInputs::new(
+                full(1, 1, vec![1.0]),
+                Divisor::Value(2.0),
+                0.5,
+                EdgeMode::Clamp,
+            );
+
+            let result_img = apply(img, ImgOp::Filter(inputs)).unwrap();
+
+            assert_eq!(result_img.get_pixel(0, 0), Rgba([178, 128, 128, 77]));
+        }
+
+        #[test]
+        fn emboss() {
+            let inputs = FilterInputs::new(
+                full(3, 3, vec![-2.0, -1.0, 0.0, -1.0, 1.0, 1.0, 0.0, 1.0, 2.0]),
+                Divisor::Value(1.0),
+                0.0,
+                EdgeMode::Clamp,
+            );
+
+            let result_img = ImageEngine::new(setup_default_test_image())
+                .ignite(&[Instr::Operation(ImgOp::Filter(inputs))])
+                .unwrap();
+
+            assert_eq!(result_img.dimensions(), (217, 447));
+
+            output_test_image_for_manual_inspection(&result_img, out_!("filter_emboss.png"));
+        }
+    }
+
+    mod morphology {
+        use super::*;
+        use crate::wrapper::morphology::{MorphologyInputs, MorphologyNorm};
+        use sic_core::image::{DynamicImage, GrayImage, Luma, Rgba, RgbaImage};
+
+        // A 7x7 black image with a single white pixel in the center.
+        fn single_dot() -> DynamicImage {
+            DynamicImage::ImageLuma8(GrayImage::from_fn(7, 7, |x, y| {
+                if (x, y) == (3, 3) {
+                    Luma([255])
+                } else {
+                    Luma([0])
+                }
+            }))
+        }
+
+        // The coordinates of the white pixels, in row-major order.
+        fn white_pixels(img: &SicImage) -> Vec<(u32, u32)> {
+            let buffer = img.as_ref().to_luma8();
+
+            buffer
+                .enumerate_pixels()
+                .filter(|(_, _, pixel)| pixel[0] == 255)
+                .map(|(x, y, _)| (x, y))
+                .collect()
+        }
+
+        #[test]
+        fn dilate_l1_grows_a_diamond() {
+            let inputs = MorphologyInputs::new(1, MorphologyNorm::L1);
+            let result_img = apply(single_dot(), ImgOp::Dilate(inputs)).unwrap();
+
+            assert_eq!(
+                white_pixels(&result_img),
+                vec![(3, 2), (2, 3), (3, 3), (4, 3), (3, 4)]
+            );
+        }
+
+        #[test]
+        fn dilate_linf_grows_a_square() {
+            let inputs = MorphologyInputs::new(1, MorphologyNorm::LInf);
+            let result_img = apply(single_dot(), ImgOp::Dilate(inputs)).unwrap();
+
+            assert_eq!(white_pixels(&result_img).len(), 9);
+        }
+
+        #[test]
+        fn erode_removes_the_dot() {
+            let inputs = MorphologyInputs::new(1, MorphologyNorm::L1);
+            let result_img = apply(single_dot(), ImgOp::Erode(inputs)).unwrap();
+
+            assert!(white_pixels(&result_img).is_empty());
+        }
+
+        #[test]
+        fn open_removes_small_details_and_keeps_large_regions() {
+            // A white 5x5 square in the top left, and a single white pixel at the bottom right.
+            let img = DynamicImage::ImageLuma8(GrayImage::from_fn(10, 10, |x, y| {
+                if (x < 5 && y < 5) || (x, y) == (8, 8) {
+                    Luma([255])
+                } else {
+                    Luma([0])
+                }
+            }));
+
+            let inputs = MorphologyInputs::new(1, MorphologyNorm::LInf);
+            let result_img = apply(img, ImgOp::Open(inputs)).unwrap();
+            let white = white_pixels(&result_img);
+
+            assert_eq!(white.len(), 25);
+            assert!(!white.contains(&(8, 8)));
+        }
+
+        #[test]
+        fn close_fills_holes() {
+            let img = DynamicImage::ImageLuma8(GrayImage::from_fn(7, 7, |x, y| {
+                if (x, y) == (3, 3) {
+                    Luma([0])
+                } else {
+                    Luma([255])
+                }
+            }));
+
+            let inputs = MorphologyInputs::new(1, MorphologyNorm::L2);
+            let result_img = apply(img, ImgOp::Close(inputs)).unwrap();
+
+            assert_eq!(white_pixels(&result_img).len(), 49);
+        }
+
+        #[test]
+        fn morph_gradient_outlines_regions() {
+            let inputs = MorphologyInputs::new(1, MorphologyNorm::L1);
+            let result_img = apply(single_dot(), ImgOp::MorphGradient(inputs)).unwrap();
+
+            // dilated (diamond) minus eroded (empty)
+            assert_eq!(white_pixels(&result_img).len(), 5);
+        }
+
+        #[test]
+        fn color_channels_are_processed_separately() {
+            let img = DynamicImage::ImageRgba8(RgbaImage::from_fn(3, 1, |x, _| match x {
+                0 => Rgba([255, 0, 0, 100]),
+                _ => Rgba([0, 0, 255, 200]),
+            }));
+
+            let inputs = MorphologyInputs::new(1, MorphologyNorm::LInf);
+            let result_img = apply(img, ImgOp::Dilate(inputs)).unwrap();
+            let buffer = result_img.as_ref().to_rgba8();
+
+            assert_eq!(buffer[(0, 0)], Rgba([255, 0, 255, 100]));
+            assert_eq!(buffer[(1, 0)], Rgba([255, 0, 255, 200]));
+            assert_eq!(buffer[(2, 0)], Rgba([0, 0, 255, 200]));
+        }
+    }
+
+    mod trim {
+        use super::*;
+        use sic_core::AnimatedImage;
+        use sic_core::image::{DynamicImage, Frame, Rgba, RgbaImage};
+
+        const BORDER: Rgba<u8> = Rgba([255, 255, 255, 255]);
+        const CONTENT: Rgba<u8> = Rgba([0, 0, 0, 255]);
+
+        // 10x8 image with a white border, and black content at x=[2, 6), y=[3, 5)
+        fn bordered_image() -> RgbaImage {
+            RgbaImage::from_fn(10, 8, |x, y| {
+                if (2..6).contains(&x) && (3..5).contains(&y) {
+                    CONTENT
+                } else {
+                    BORDER
+                }
+            })
+        }
+
+        fn trim(img: SicImage, tolerance: u8) -> SicImage {
+            ImageEngine::new(img)
+                .ignite(&[Instr::Operation(ImgOp::Trim(tolerance))])
+                .unwrap()
+        }
+
+        #[test]
+        fn trim_border() {
+            let img = SicImage::from(DynamicImage::ImageRgba8(bordered_image()));
+            let result_img = trim(img, 0);
+
+            assert_eq!(result_img.dimensions(), (4, 2));
+            assert_eq!(result_img.get_pixel(0, 0), CONTENT);
+        }
+
+        #[test]
+        fn trim_border_within_tolerance() {
+            let mut buffer = bordered_image();
+            buffer.put_pixel(9, 7, Rgba([250, 255, 250, 255]));
+            buffer.put_pixel(0, 4, Rgba([255, 247, 255, 255]));
+
+            let img = SicImage::from(DynamicImage::ImageRgba8(buffer.clone()));
+            assert_eq!(trim(img, 8).dimensions(), (4, 2));
+
+            // without sufficient tolerance, the off-color pixels are considered content
+            let img = SicImage::from(DynamicImage::ImageRgba8(buffer));
+            assert_eq!(trim(img, 4).dimensions(), (10, 5));
+        }
+
+        #[test]
+        fn trim_uniform_image_is_unchanged() {
+            let img = SicImage::from(DynamicImage::ImageRgba8(RgbaImage::from_pixel(
+                5, 5, BORDER,
+            )));
+
+            assert_eq!(trim(img, 0).dimensions(), (5, 5));
+        }
+
+        #[test]
+        fn trim_without_border_is_unchanged() {
+            let img = setup_default_test_image();
+            let (xa, ya) = img.dimensions();
+
+            assert_eq!(trim(img, 0).dimensions(), (xa, ya));
+        }
+
+        #[test]
+        fn trim_animated_frames() {
+            let frames = (0..3).map(|_| Frame::new(bordered_image()));
+            let img = SicImage::Animated(AnimatedImage::from_frames(frames));
+
+            match trim(img, 0) {
+                SicImage::Animated(animated) => {
+                    assert_eq!(animated.frames().len(), 3);
+                    assert!(
+                        animated
+                            .frames()
+                            .iter()
+                            .all(|frame| frame.buffer().dimensions() == (4, 2))
+                    );
+                }
+                SicImage::Static(_) | SicImage::Indexed(_) => panic!("expected an animated image"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_filter3x3() {
+        let img = setup_default_test_image();
+        let cmp = setup_default_test_image();
+
+        let operation = ImgOp::Filter3x3([1.0, 0.5, 0.0, 1.0, 0.5, 0.0, 1.0, 0.5, 0.0]);
+
+        let operator = ImageEngine::new(img);
+        let done = operator.ignite(&[Instr::Operation(operation)]);
+
+        assert!(done.is_ok());
+
+        let result_img = done.unwrap();
+
+        assert_ne!(cmp.raw_pixels(), result_img.raw_pixels());
+
+        output_test_image_for_manual_inspection(&result_img, out_!("test_filter3x3.png"))
+    }
+
+    #[test]
+    fn test_flip_h() {
+        let img = setup_default_test_image();
+        let operation = ImgOp::FlipHorizontal;
+
+        let (xa, ya) = img.dimensions();
+        let operator = ImageEngine::new(img);
+        let done = operator.ignite(&[Instr::Operation(operation)]);
+
+        assert!(done.is_ok());
+
+        let img_result = done.unwrap();
+        let (xb, yb) = img_result.dimensions();
+
+        assert_eq!(xa, xb);
+        assert_eq!(ya, yb);
+
+        output_test_image_for_manual_inspection(&img_result, out_!("test_fliph.png"));
+    }
+
+    #[test]
+    fn test_flip_v() {
+        let img = setup_default_test_image();
+        let operation = ImgOp::FlipVertical;
+
+        let (xa, ya) = img.dimensions();
+        let operator = ImageEngine::new(img);
+        let done = operator.ignite(&[Instr::Operation(operation)]);
+
+        assert!(done.is_ok());
+
+        let img_result = done.unwrap();
+        let (xb, yb) = img_result.dimensions();
+
+        assert_eq!(xa, xb);
+        assert_eq!(ya, yb);
+
+        output_test_image_for_manual_inspection(&img_result, out_!("test_flipv.png"));
+    }
+
+    #[test]
+    fn test_gray_scale() {
+        use sic_core::image::Pixel;
+
+        let img = open_test_image(in_!("rainbow_8x6.bmp"));
+        let operation = ImgOp::Grayscale;
+
+        let operator = ImageEngine::new(img);
+        let done = operator.ignite(&[Instr::Operation(operation)]);
+
+        assert!(done.is_ok());
+
+        let img_result = done.unwrap();
+
+        // The color type isn't actually changed to luma, so instead of checking color type,
+        // here pixels are checked to have equal (r, g, b) components.
+        for i in 0..8 {
+            for j in 0..6 {
+                let pixel = img_result.get_pixel(i, j);
+                let channels_result = pixel.channels();
+                let r_component = channels_result[0];
+                let g_component = channels_result[1];
+                let b_component = channels_result[2];
+
+                assert_eq!(r_component, g_component);
+                assert_eq!(g_component, b_component);
+            }
+        }
+
+        output_test_image_for_manual_inspection(&img_result, out_!("test_gray_scale.png"));
+    }
+
+    #[test]
+    fn test_hue_rotate_neg() {
+        let img = setup_default_test_image();
+        let cmp = setup_default_test_image();
+
+        let operation = ImgOp::HueRotate(-100);
+
+        let operator = ImageEngine::new(img);
+        let done = operator.ignite(&[Instr::Operation(operation)]);
+
+        assert!(done.is_ok());
+
+        let result_img = done.unwrap();
+
+        assert_ne!(cmp.raw_pixels(), result_img.raw_pixels());
+
+        output_test_image_for_manual_inspection(&result_img, out_!("test_hue_rot_neg_100.png"));
+    }
+
+    #[test]
+    fn test_hue_rotate_pos() {
+        let img = setup_default_test_image();
+        let cmp = setup_default_test_image();
+
+        let operation = ImgOp::HueRotate(100);
+
+        let operator = ImageEngine::new(img);
+        let done = operator.ignite(&[Instr::Operation(operation)]);
+
+        assert!(done.is_ok());
+
+        let result_img = done.unwrap();
+
+        assert_ne!(cmp.raw_pixels(), result_img.raw_pixels());
+
+        output_test_image_for_manual_inspection(&result_img, out_!("test_hue_rot_pos_100.png"));
+    }
+
+    #[test]
+    fn test_hue_rotate_zero() {
+        let img = setup_default_test_image();
+        let cmp = setup_default_test_image();
+
+        let operation = ImgOp::HueRotate(0);
+
+        let operator = ImageEngine::new(img);
+        let done = operator.ignite(&[Instr::Operation(operation)]);
+
+        assert!(done.is_ok());
+
+        let result_img = done.unwrap();
+
+        assert_eq!(cmp.raw_pixels(), result_img.raw_pixels());
 
         output_test_image_for_manual_inspection(&result_img, out_!("test_hue_rot_0.png"));
     }
 
     #[test]
-    fn test_hue_rotate_360() {
+    fn test_hue_rotate_360() {
+        let img = setup_default_test_image();
+        let cmp = setup_default_test_image();
+
+        let operation = ImgOp::HueRotate(360);
+
+        let operator = ImageEngine::new(img);
+        let done = operator.ignite(&[Instr::Operation(operation)]);
+
+        assert!(done.is_ok());
+
+        let result_img = done.unwrap();
+
+        // https://docs.rs/image/0.19.0/image/enum.DynamicImage.html#method.huerotate
+        // huerotate(0) should be huerotate(360), but this doesn't seem the case
+        let expected = SicImage::from(cmp.as_ref().huerotate(360));
+        assert_eq!(expected.raw_pixels(), result_img.raw_pixels());
+
+        output_test_image_for_manual_inspection(&result_img, out_!("test_hue_rot_pos_360.png"));
+    }
+
+    #[test]
+    fn test_hue_rotate_over_rotate_pos() {
+        let img = setup_default_test_image();
+        let cmp = setup_default_test_image();
+
+        let operation = ImgOp::HueRotate(460);
+
+        let operator = ImageEngine::new(img);
+        let done = operator.ignite(&[Instr::Operation(operation)]);
+
+        assert!(done.is_ok());
+
+        let result_img = done.unwrap();
+
+        let expected = SicImage::from(cmp.as_ref().huerotate(100));
+        assert_ne!(expected.raw_pixels(), result_img.raw_pixels());
+
+        output_test_image_for_manual_inspection(&result_img, out_!("test_hue_rot_pos_460.png"));
+    }
+
+    #[test]
+    fn test_invert() {
         let img = setup_default_test_image();
         let cmp = setup_default_test_image();
 
-        let operation = ImgOp::HueRotate(360);
+        let operation = ImgOp::Invert;
+
+        let operator = ImageEngine::new(img);
+        let done = operator.ignite(&[Instr::Operation(operation)]);
+
+        assert!(done.is_ok());
+
+        let result_img = done.unwrap();
+
+        assert_ne!(cmp.raw_pixels(), result_img.raw_pixels());
+
+        output_test_image_for_manual_inspection(&result_img, out_!("test_invert.png"));
+    }
+
+    mod alpha {
+        use super::*;
+        use sic_core::image::{GenericImageView, Rgb, RgbImage, RgbaImage};
+
+        fn translucent_red() -> DynamicImage {
+            DynamicImage::ImageRgba8(RgbaImage::from_fn(2, 1, |x, _| {
+                Rgba([255, 0, 0, [0, 128][x as usize]])
+            }))
+        }
+
+        #[test]
+        fn extract_alpha() {
+            let result = apply(translucent_red(), ImgOp::ExtractAlpha).unwrap();
+            let result = DynamicImage::try_from(result).unwrap();
+
+            assert_eq!(result.as_luma8().unwrap().as_raw(), &[0, 128]);
+        }
+
+        #[test]
+        fn extract_alpha_without_alpha_is_white() {
+            let image = DynamicImage::ImageRgb16(DynamicImage::new_rgb8(2, 1).to_rgb16());
+            let result = apply(image, ImgOp::ExtractAlpha).unwrap();
+            let result = DynamicImage::try_from(result).unwrap();
+
+            assert_eq!(result.as_luma16().unwrap().as_raw(), &[u16::MAX, u16::MAX]);
+        }
+
+        #[test]
+        fn set_alpha_from_mask() {
+            let image = DynamicImage::ImageRgb8(RgbImage::from_pixel(2, 2, Rgb([1, 2, 3])));
+            let mask = in_!("2x2_bwwb.bmp");
+
+            let result = apply(image, ImgOp::SetAlpha(ImageFromPath::new(mask.into()))).unwrap();
+            let result = DynamicImage::try_from(result).unwrap();
+            let expected = sic_core::image::open(in_!("2x2_bwwb.bmp"))
+                .unwrap()
+                .to_luma8();
+
+            assert!(matches!(result, DynamicImage::ImageRgba8(_)));
+            for (x, y, pixel) in result.pixels() {
+                assert_eq!(pixel, Rgba([1, 2, 3, expected.get_pixel(x, y)[0]]));
+            }
+        }
+
+        #[test]
+        fn set_alpha_mask_dimensions_mismatch() {
+            let mask = in_!("1x1_w.png");
+            let result = apply(
+                translucent_red(),
+                ImgOp::SetAlpha(ImageFromPath::new(mask.into())),
+            );
+
+            assert!(matches!(
+                result,
+                Err(SicImageEngineError::MaskDimensionsMismatch(2, 1, 1, 1))
+            ));
+        }
+
+        #[test]
+        fn scale_alpha_adds_alpha_channel() {
+            let image = DynamicImage::ImageRgb8(RgbImage::from_pixel(1, 1, Rgb([1, 2, 3])));
+            let result = apply(image, ImgOp::ScaleAlpha(0.5)).unwrap();
+
+            assert_eq!(result.get_pixel(0, 0), Rgba([1, 2, 3, 128]));
+        }
+
+        #[test]
+        fn scale_alpha_negative() {
+            let result = apply(translucent_red(), ImgOp::ScaleAlpha(-1.0));
+
+            assert!(matches!(
+                result,
+                Err(SicImageEngineError::ScaleAlphaFactorNegative(_))
+            ));
+        }
+
+        #[test]
+        fn premultiply_and_unpremultiply() {
+            let premultiplied = apply(translucent_red(), ImgOp::PremultiplyAlpha).unwrap();
+            assert_eq!(premultiplied.get_pixel(0, 0), Rgba([0, 0, 0, 0]));
+            assert_eq!(premultiplied.get_pixel(1, 0), Rgba([128, 0, 0, 128]));
+
+            let unpremultiplied = apply(premultiplied, ImgOp::UnpremultiplyAlpha).unwrap();
+            assert_eq!(unpremultiplied.get_pixel(1, 0), Rgba([255, 0, 0, 128]));
+        }
+
+        #[test]
+        fn flatten_onto_opaque_background_removes_alpha() {
+            let result = apply(
+                translucent_red(),
+                ImgOp::Flatten(Rgba([255, 255, 255, 255])),
+            )
+            .unwrap();
+
+            let DynamicImage::ImageRgb8(buffer) = DynamicImage::try_from(result).unwrap() else {
+                panic!("expected the alpha channel to be removed");
+            };
+            assert_eq!(buffer.as_raw(), &[255, 255, 255, 255, 127, 127]);
+        }
+
+        #[test]
+        fn flatten_onto_translucent_background_keeps_alpha() {
+            let result = apply(translucent_red(), ImgOp::Flatten(Rgba([0, 0, 255, 128]))).unwrap();
+
+            assert_eq!(result.get_pixel(0, 0), Rgba([0, 0, 255, 128]));
+            assert_eq!(result.get_pixel(1, 0), Rgba([170, 0, 85, 192]));
+        }
+    }
+
+    mod chroma_key {
+        use super::*;
+        use crate::wrapper::chroma_key::ChromaKeyInputs;
+        use sic_core::image::{Rgb, RgbImage};
+
+        fn row(pixels: &[[u8; 3]]) -> DynamicImage {
+            DynamicImage::ImageRgb8(RgbImage::from_fn(pixels.len() as u32, 1, |x, _| {
+                Rgb(pixels[x as usize])
+            }))
+        }
+
+        #[test]
+        fn chroma_key_with_softness() {
+            let image = row(&[[0, 255, 0], [0, 230, 0], [255, 0, 0]]);
+            let inputs = ChromaKeyInputs::new(Rgba([0, 255, 0, 255]), 10.0, 60.0);
+
+            let result = apply(image, ImgOp::ChromaKey(inputs)).unwrap();
+
+            assert_eq!(result.get_pixel(0, 0)[3], 0);
+            assert_eq!(result.get_pixel(1, 0), Rgba([0, 230, 0, 64]));
+            assert_eq!(result.get_pixel(2, 0), Rgba([255, 0, 0, 255]));
+        }
+
+        #[test]
+        fn chroma_key_with_despill() {
+            let image = row(&[[100, 200, 100], [255, 0, 0]]);
+            let inputs = ChromaKeyInputs::new(Rgba([0, 255, 0, 255]), 0.0, 0.0).with_despill(true);
+
+            let result = apply(image, ImgOp::ChromaKey(inputs)).unwrap();
+
+            assert_eq!(result.get_pixel(0, 0), Rgba([133, 133, 133, 255]));
+            assert_eq!(result.get_pixel(1, 0), Rgba([255, 0, 0, 255]));
+        }
+
+        #[test]
+        fn chroma_key_negative_tolerance() {
+            let inputs = ChromaKeyInputs::new(Rgba([0, 255, 0, 255]), -1.0, 0.0);
+            let result = apply(row(&[[0, 0, 0]]), ImgOp::ChromaKey(inputs));
+
+            assert!(matches!(
+                result,
+                Err(SicImageEngineError::ChromaKeyThresholdNegative(_, _))
+            ));
+        }
+
+        #[test]
+        fn color_to_alpha_white() {
+            let image = row(&[[255, 255, 255], [128, 128, 128], [0, 0, 0], [255, 0, 0]]);
+
+            let result = apply(image, ImgOp::ColorToAlpha(Rgba([255, 255, 255, 255]))).unwrap();
+
+            assert_eq!(result.get_pixel(0, 0)[3], 0);
+            assert_eq!(result.get_pixel(1, 0), Rgba([0, 0, 0, 127]));
+            assert_eq!(result.get_pixel(2, 0), Rgba([0, 0, 0, 255]));
+            assert_eq!(result.get_pixel(3, 0), Rgba([255, 0, 0, 255]));
+        }
+    }
+
+    mod channels {
+        use super::*;
+        use crate::wrapper::channel::{Channel, ChannelOrLuma, ChannelScope, MergeChannelsInputs};
+        use sic_core::AnimatedImage;
+        use sic_core::image::{Frame, GenericImageView, Rgb, RgbImage, RgbaImage};
+
+        fn translucent_colors() -> DynamicImage {
+            DynamicImage::ImageRgba8(RgbaImage::from_fn(2, 1, |x, _| {
+                [Rgba([10, 20, 30, 40]), Rgba([50, 60, 70, 80])][x as usize]
+            }))
+        }
+
+        #[test]
+        fn extract_channel() {
+            let result = apply(
+                translucent_colors(),
+                ImgOp::ExtractChannel(ChannelOrLuma::Channel(Channel::Green)),
+            )
+            .unwrap();
+            let result = DynamicImage::try_from(result).unwrap();
+
+            assert_eq!(result.as_luma8().unwrap().as_raw(), &[20, 60]);
+        }
+
+        #[test]
+        fn extract_channel_luma_preserves_bit_depth() {
+            let white = DynamicImage::ImageRgb8(RgbImage::from_pixel(1, 1, Rgb([255; 3])));
+            let image = DynamicImage::ImageRgb16(white.to_rgb16());
+            let result = apply(image, ImgOp::ExtractChannel(ChannelOrLuma::Luma)).unwrap();
+            let result = DynamicImage::try_from(result).unwrap();
+
+            assert_eq!(result.as_luma16().unwrap().as_raw(), &[u16::MAX]);
+        }
+
+        #[test]
+        fn swap_channels() {
+            let result = apply(
+                translucent_colors(),
+                ImgOp::SwapChannels((Channel::Red, Channel::Alpha)),
+            )
+            .unwrap();
+
+            assert_eq!(result.get_pixel(0, 0), Rgba([40, 20, 30, 10]));
+            assert_eq!(result.get_pixel(1, 0), Rgba([80, 60, 70, 50]));
+        }
+
+        #[test]
+        fn swap_channels_with_alpha_converts_gray_image() {
+            let image = DynamicImage::new_luma8(1, 1);
+            let result =
+                apply(image, ImgOp::SwapChannels((Channel::Alpha, Channel::Blue))).unwrap();
+            let result = DynamicImage::try_from(result).unwrap();
+
+            assert!(matches!(result, DynamicImage::ImageRgba8(_)));
+            assert_eq!(result.get_pixel(0, 0), Rgba([0, 0, 255, 0]));
+        }
+
+        #[test]
+        fn merge_channels() {
+            let mask = in_!("2x2_bwwb.bmp");
+            let image = DynamicImage::ImageRgb8(RgbImage::from_pixel(2, 2, Rgb([1, 2, 3])));
+            let inputs = MergeChannelsInputs::new([
+                None,
+                Some(ImageFromPath::new(mask.into())),
+                None,
+                Some(ImageFromPath::new(mask.into())),
+            ]);
+
+            let result = apply(image, ImgOp::MergeChannels(inputs)).unwrap();
+            let result = DynamicImage::try_from(result).unwrap();
+            let expected = sic_core::image::open(mask).unwrap().to_luma8();
+
+            assert!(matches!(result, DynamicImage::ImageRgba8(_)));
+            for (x, y, pixel) in result.pixels() {
+                let value = expected.get_pixel(x, y)[0];
+                assert_eq!(pixel, Rgba([1, value, 3, value]));
+            }
+        }
+
+        #[test]
+        fn merge_channels_dimensions_mismatch() {
+            let inputs = MergeChannelsInputs::new([
+                Some(ImageFromPath::new(in_!("1x1_w.png").into())),
+                None,
+                None,
+                None,
+            ]);
+
+            let result = apply(translucent_colors(), ImgOp::MergeChannels(inputs));
+
+            assert!(matches!(
+                result,
+                Err(SicImageEngineError::ChannelDimensionsMismatch(2, 1, 1, 1))
+            ));
+        }
+
+        #[test]
+        fn channel_scope_applies_to_single_channel() {
+            let result = ImageEngine::new(SicImage::from(translucent_colors()))
+                .ignite(&[
+                    Instr::EnvAdd(EnvItem::ChannelScope(ChannelScope::Only(Channel::Alpha))),
+                    Instr::Operation(ImgOp::Invert),
+                    Instr::EnvAdd(EnvItem::ChannelScope(ChannelScope::All)),
+                    Instr::Operation(ImgOp::Brighten(1)),
+                ])
+                .unwrap();
+
+            assert_eq!(result.get_pixel(0, 0), Rgba([11, 21, 31, 215]));
+            assert_eq!(result.get_pixel(1, 0), Rgba([51, 61, 71, 175]));
+        }
+
+        #[test]
+        fn channel_scope_on_gray_image() {
+            let image = DynamicImage::new_luma8(1, 1);
+            let result = ImageEngine::new(SicImage::from(image))
+                .ignite(&[
+                    Instr::EnvAdd(EnvItem::ChannelScope(ChannelScope::Only(Channel::Red))),
+                    Instr::Operation(ImgOp::Invert),
+                ])
+                .unwrap();
+            let result = DynamicImage::try_from(result).unwrap();
+
+            assert!(matches!(result, DynamicImage::ImageRgb8(_)));
+            assert_eq!(result.get_pixel(0, 0), Rgba([255, 0, 0, 255]));
+        }
+
+        #[test]
+        fn channel_scope_dimensions_changed() {
+            let result = ImageEngine::new(SicImage::from(translucent_colors())).ignite(&[
+                Instr::EnvAdd(EnvItem::ChannelScope(ChannelScope::Only(Channel::Red))),
+                Instr::Operation(ImgOp::Rotate90),
+            ]);
+
+            assert!(matches!(
+                result,
+                Err(SicImageEngineError::ChannelScopeDimensionsChanged(
+                    2, 1, 1, 2
+                ))
+            ));
+        }
+
+        #[test]
+        fn channel_scope_animated_frames() {
+            let frames =
+                (0..2).map(|_| Frame::new(RgbaImage::from_pixel(2, 2, Rgba([10, 20, 30, 40]))));
+            let image = SicImage::Animated(AnimatedImage::from_frames(frames));
+
+            let result = ImageEngine::new(image)
+                .ignite(&[
+                    Instr::EnvAdd(EnvItem::ChannelScope(ChannelScope::Only(Channel::Blue))),
+                    Instr::Operation(ImgOp::Invert),
+                ])
+                .unwrap();
+
+            let SicImage::Animated(animated) = result else {
+                panic!("expected an animated image");
+            };
+            assert!(animated.frames().iter().all(|frame| {
+                frame
+                    .buffer()
+                    .pixels()
+                    .all(|pixel| *pixel == Rgba([10, 20, 225, 40]))
+            }));
+        }
+    }
+
+    mod gradient {
+        use super::*;
+        use crate::wrapper::gradient::{
+            ColorStop, GradientColorSpace, GradientInputs, GradientShape,
+        };
+        use sic_core::image::{GenericImageView, RgbaImage};
+
+        const BLACK: Rgba<u8> = Rgba([0, 0, 0, 255]);
+        const WHITE: Rgba<u8> = Rgba([255, 255, 255, 255]);
+
+        // Gradients are blended over the image, so a transparent image results in the gradient
+        fn canvas(width: u32, height: u32) -> DynamicImage {
+            DynamicImage::ImageRgba8(RgbaImage::new(width, height))
+        }
+
+        fn stops(colors: &[(Rgba<u8>, Option<f32>)]) -> Vec<ColorStop> {
+            colors
+                .iter()
+                .map(|&(color, position)| ColorStop::new(color, position))
+                .collect()
+        }
+
+        fn black_to_white(shape: GradientShape) -> GradientInputs {
+            GradientInputs::new(shape, stops(&[(BLACK, None), (WHITE, None)]))
+        }
+
+        fn reds(image: &SicImage) -> Vec<u8> {
+            let image = DynamicImage::try_from(image.clone()).unwrap();
+            image.pixels().map(|(_, _, pixel)| pixel[0]).collect()
+        }
+
+        #[test]
+        fn linear_left_to_right() {
+            let result = apply(
+                canvas(4, 1),
+                ImgOp::Gradient(black_to_white(GradientShape::Linear { angle: 90.0 })),
+            )
+            .unwrap();
+
+            assert_eq!(reds(&result), vec![32, 96, 159, 223]);
+        }
+
+        #[test]
+        fn linear_bottom_to_top() {
+            let result = apply(
+                canvas(1, 2),
+                ImgOp::Gradient(black_to_white(GradientShape::Linear { angle: 0.0 })),
+            )
+            .unwrap();
+
+            assert_eq!(reds(&result), vec![191, 64]);
+        }
+
+        #[test]
+        fn linear_diagonal_reaches_corners() {
+            let result = apply(
+                canvas(8, 8),
+                ImgOp::Gradient(black_to_white(GradientShape::Linear { angle: 135.0 })),
+            )
+            .unwrap();
+
+            assert!(result.get_pixel(0, 0)[0] < 20);
+            assert!(result.get_pixel(7, 7)[0] > 235);
+            assert_eq!(result.get_pixel(7, 0), result.get_pixel(0, 7));
+        }
+
+        #[test]
+        fn radial_from_center() {
+            let result = apply(
+                canvas(3, 3),
+                ImgOp::Gradient(black_to_white(GradientShape::Radial)),
+            )
+            .unwrap();
+
+            assert_eq!(result.get_pixel(1, 1), BLACK);
+            assert_eq!(result.get_pixel(0, 1), result.get_pixel(1, 2));
+            assert!(result.get_pixel(0, 0)[0] > result.get_pixel(0, 1)[0]);
+        }
+
+        #[test]
+        fn conic_clockwise_from_angle() {
+            let result = apply(
+                canvas(2, 2),
+                ImgOp::Gradient(black_to_white(GradientShape::Conic { angle: 0.0 })),
+            )
+            .unwrap();
+
+            // top-right at 45, bottom-right at 135, bottom-left at 225 and top-left at 315 degrees
+            assert_eq!(reds(&result), vec![223, 32, 159, 96]);
+
+            let rotated = apply(
+                canvas(2, 2),
+                ImgOp::Gradient(black_to_white(GradientShape::Conic { angle: 90.0 })),
+            )
+            .unwrap();
+            assert_eq!(rotated.get_pixel(1, 1), result.get_pixel(1, 0));
+        }
+
+        #[test]
+        fn multiple_stops() {
+            let red = Rgba([255, 0, 0, 255]);
+            let blue = Rgba([0, 0, 255, 255]);
+            let inputs = GradientInputs::new(
+                GradientShape::Linear { angle: 90.0 },
+                stops(&[
+                    (red, None),
+                    (WHITE, Some(0.25)),
+                    (WHITE, Some(0.75)),
+                    (blue, None),
+                ]),
+            );
+            let result = apply(canvas(4, 1), ImgOp::Gradient(inputs)).unwrap();
+
+            assert_eq!(result.get_pixel(0, 0), Rgba([255, 128, 128, 255]));
+            assert_eq!(result.get_pixel(1, 0), WHITE);
+            assert_eq!(result.get_pixel(2, 0), WHITE);
+            assert_eq!(result.get_pixel(3, 0), Rgba([128, 128, 255, 255]));
+        }
+
+        #[test]
+        fn hard_stops() {
+            let inputs = GradientInputs::new(
+                GradientShape::Linear { angle: 90.0 },
+                stops(&[(BLACK, Some(0.5)), (WHITE, Some(0.5))]),
+            );
+            let result = apply(canvas(4, 1), ImgOp::Gradient(inputs)).unwrap();
+
+            assert_eq!(reds(&result), vec![0, 0, 255, 255]);
+        }
+
+        #[test]
+        fn transparent_stop_does_not_tint() {
+            let red = Rgba([255, 0, 0, 255]);
+            let transparent = Rgba([0, 0, 255, 0]);
+            let inputs = GradientInputs::new(
+                GradientShape::Linear { angle: 90.0 },
+                stops(&[(red, None), (transparent, None)]),
+            );
+            let result = apply(canvas(4, 1), ImgOp::Gradient(inputs)).unwrap();
+
+            assert_eq!(result.get_pixel(1, 0), Rgba([255, 0, 0, 159]));
+            assert_eq!(result.get_pixel(2, 0), Rgba([255, 0, 0, 96]));
+        }
+
+        #[test]
+        fn color_spaces() {
+            // the second pixel is halfway between the stops
+            let inputs = GradientInputs::new(
+                GradientShape::Linear { angle: 90.0 },
+                stops(&[(BLACK, Some(0.125)), (WHITE, Some(0.625))]),
+            );
+
+            let halfway = |color_space: GradientColorSpace| {
+                let instructions = [
+                    Instr::EnvAdd(EnvItem::GradientColorSpace(color_space)),
+                    Instr::Operation(ImgOp::Gradient(inputs.clone())),
+                ];
+                ImageEngine::new(SicImage::from(canvas(4, 1)))
+                    .ignite(&instructions)
+                    .unwrap()
+                    .get_pixel(1, 0)
+            };
+
+            assert_eq!(
+                halfway(GradientColorSpace::Srgb),
+                Rgba([128, 128, 128, 255])
+            );
+            assert_eq!(
+                halfway(GradientColorSpace::Linear),
+                Rgba([188, 188, 188, 255])
+            );
+            assert_eq!(halfway(GradientColorSpace::Oklab), Rgba([99, 99, 99, 255]));
+        }
+
+        #[test]
+        fn blends_over_image() {
+            let image = DynamicImage::ImageRgba8(RgbaImage::from_pixel(1, 1, WHITE));
+            let inputs = GradientInputs::new(
+                GradientShape::Radial,
+                stops(&[(Rgba([0, 0, 0, 0]), None), (BLACK, None)]),
+            );
+            let result = apply(image, ImgOp::Gradient(inputs)).unwrap();
+
+            assert_eq!(result.get_pixel(0, 0), WHITE);
+        }
+
+        #[test]
+        fn too_few_stops() {
+            let inputs = GradientInputs::new(GradientShape::Radial, stops(&[(BLACK, None)]));
+
+            assert!(matches!(
+                apply(canvas(2, 2), ImgOp::Gradient(inputs)),
+                Err(SicImageEngineError::GradientTooFewStops(1))
+            ));
+        }
+    }
+
+    mod frame_delay {
+        use super::*;
+        use crate::wrapper::gradient::{ColorStop, GradientInputs, GradientShape};
+        use sic_core::SicCoreError;
+        use sic_core::image::{Frame, RgbaImage};
+
+        // Three frames with distinct delays, placed at distinct offsets.
+        fn offset_frames() -> SicImage {
+            let mut image = animated_image(3, |i| [40, 70, 100][i as usize]);
+
+            let SicImage::Animated(animated) = &mut image else {
+                unreachable!()
+            };
+            for (frame, (left, top)) in
+                animated
+                    .frames_mut()
+                    .iter_mut()
+                    .zip([(0, 0), (1, 2), (3, 1)])
+            {
+                *frame = Frame::from_parts(frame.buffer().clone(), left, top, frame.delay());
+            }
+
+            image
+        }
+
+        fn frame_properties(image: &SicImage) -> Vec<(u32, u32, u32)> {
+            let SicImage::Animated(animated) = image else {
+                panic!("expected an animated image");
+            };
+
+            animated
+                .frames()
+                .iter()
+                .map(|frame| {
+                    let (numerator, denominator) = frame.delay().numer_denom_ms();
+                    (frame.left(), frame.top(), numerator / denominator)
+                })
+                .collect()
+        }
+
+        #[test]
+        fn set_frame_delay() {
+            let result = apply(offset_frames(), ImgOp::SetFrameDelay(20)).unwrap();
+
+            assert_eq!(
+                frame_properties(&result),
+                vec![(0, 0, 20), (1, 2, 20), (3, 1, 20)]
+            );
+        }
+
+        #[test]
+        fn scale_frame_delay() {
+            let result = apply(offset_frames(), ImgOp::ScaleFrameDelay(0.5)).unwrap();
+
+            assert_eq!(
+                frame_properties(&result),
+                vec![(0, 0, 20), (1, 2, 35), (3, 1, 50)]
+            );
+        }
+
+        #[test]
+        fn scale_frame_delay_rounds_to_milliseconds() {
+            let result = apply(offset_frames(), ImgOp::ScaleFrameDelay(1.0 / 3.0)).unwrap();
+
+            assert_eq!(
+                frame_properties(&result),
+                vec![(0, 0, 13), (1, 2, 23), (3, 1, 33)]
+            );
+        }
+
+        #[test]
+        fn scale_frame_delay_factor_not_positive() {
+            for factor in [0.0, -1.0, f32::NAN] {
+                assert!(matches!(
+                    apply(offset_frames(), ImgOp::ScaleFrameDelay(factor)),
+                    Err(SicImageEngineError::ScaleFrameDelayFactorNotPositive(_))
+                ));
+            }
+        }
+
+        #[test]
+        fn set_frame_delay_at() {
+            let result = apply(offset_frames(), ImgOp::SetFrameDelayAt((1, 500))).unwrap();
+
+            assert_eq!(
+                frame_properties(&result),
+                vec![(0, 0, 40), (1, 2, 500), (3, 1, 100)]
+            );
+        }
+
+        #[test]
+        fn set_frame_delay_at_out_of_range() {
+            assert!(matches!(
+                apply(offset_frames(), ImgOp::SetFrameDelayAt((3, 500))),
+                Err(SicImageEngineError::SicCoreError(
+                    SicCoreError::InvalidFrameIndex { index: 3, len: 3 }
+                ))
+            ));
+        }
+
+        #[test]
+        fn static_image_unchanged() {
+            let image = DynamicImage::ImageRgba8(RgbaImage::from_pixel(2, 2, Rgba([1, 2, 3, 4])));
+
+            let result = ImageEngine::new(SicImage::from(image.clone()))
+                .ignite(&[
+                    Instr::Operation(ImgOp::SetFrameDelay(20)),
+                    Instr::Operation(ImgOp::ScaleFrameDelay(2.0)),
+                    Instr::Operation(ImgOp::SetFrameDelayAt((5, 20))),
+                ])
+                .unwrap();
+
+            assert_eq!(DynamicImage::try_from(result).unwrap(), image);
+        }
+
+        #[test]
+        fn operations_preserve_delays_and_offsets() {
+            let stops = vec![
+                ColorStop::new(Rgba([0, 0, 0, 255]), None),
+                ColorStop::new(Rgba([255, 255, 255, 255]), None),
+            ];
+
+            let operations = [
+                ImgOp::Blur(1.0),
+                ImgOp::Brighten(10),
+                ImgOp::Contrast(1.5),
+                ImgOp::Crop((0, 0, 1, 1)),
+                ImgOp::FlipHorizontal,
+                ImgOp::FlipVertical,
+                ImgOp::Gradient(GradientInputs::new(GradientShape::Radial, stops)),
+                ImgOp::Grayscale,
+                ImgOp::HueRotate(90),
+                ImgOp::Invert,
+                ImgOp::Resize((8, 8).into()),
+                ImgOp::Rotate90,
+                ImgOp::Rotate180,
+                ImgOp::Trim(0),
+                ImgOp::Unsharpen((1.0, 1)),
+            ];
+
+            for operation in operations {
+                let result = apply(offset_frames(), operation.clone()).unwrap();
+
+                assert_eq!(
+                    frame_properties(&result),
+                    vec![(0, 0, 40), (1, 2, 70), (3, 1, 100)],
+                    "{operation:?}"
+                );
+            }
+        }
+    }
+
+    mod frame_editing {
+        use super::*;
+        use crate::wrapper::frame_range::FrameRange;
+        use sic_core::SicCoreError;
+        use sic_core::image::RgbaImage;
+        use std::str::FromStr;
+
+        fn frame_ids(image: &SicImage) -> Vec<u32> {
+            let SicImage::Animated(animated) = image else {
+                panic!("expected an animated image");
+            };
+
+            animated
+                .frames()
+                .iter()
+                .map(|frame| u32::from(frame.buffer().get_pixel(0, 0)[0]))
+                .collect()
+        }
+
+        fn range(range: &str) -> FrameRange {
+            FrameRange::from_str(range).unwrap()
+        }
+
+        #[test]
+        fn frames() {
+            let result = apply(animated_image(12, |_| 100), ImgOp::Frames(range("3..10"))).unwrap();
+            assert_eq!(frame_ids(&result), vec![3, 4, 5, 6, 7, 8, 9]);
+
+            let result = apply(animated_image(12, |_| 100), ImgOp::Frames(range("..=1"))).unwrap();
+            assert_eq!(frame_ids(&result), vec![0, 1]);
+
+            let result =
+                apply(animated_image(12, |_| 100), ImgOp::Frames(range("10..20"))).unwrap();
+            assert_eq!(frame_ids(&result), vec![10, 11]);
+        }
+
+        #[test]
+        fn frames_empty() {
+            assert!(matches!(
+                apply(animated_image(12, |_| 100), ImgOp::Frames(range("12.."))),
+                Err(SicImageEngineError::FrameRangeEmpty(_, 12))
+            ));
+        }
+
+        #[test]
+        fn drop_frames() {
+            let result = apply(animated_image(7, |_| 100), ImgOp::DropFrames(3)).unwrap();
+            assert_eq!(frame_ids(&result), vec![0, 1, 3, 4, 6]);
+        }
+
+        #[test]
+        fn drop_frames_interval_too_small() {
+            for interval in [0, 1] {
+                assert!(matches!(
+                    apply(animated_image(7, |_| 100), ImgOp::DropFrames(interval)),
+                    Err(SicImageEngineError::DropFramesIntervalTooSmall(_))
+                ));
+            }
+        }
+
+        #[test]
+        fn reverse_frames() {
+            let result = apply(animated_image(4, |_| 100), ImgOp::ReverseFrames).unwrap();
+            assert_eq!(frame_ids(&result), vec![3, 2, 1, 0]);
+        }
 
-        let operator = ImageEngine::new(img);
-        let done = operator.ignite(&[Instr::Operation(operation)]);
+        #[test]
+        fn boomerang() {
+            let result = apply(animated_image(4, |_| 100), ImgOp::Boomerang).unwrap();
+            assert_eq!(frame_ids(&result), vec![0, 1, 2, 3, 2, 1]);
 
-        assert!(done.is_ok());
+            let result = apply(animated_image(2, |_| 100), ImgOp::Boomerang).unwrap();
+            assert_eq!(frame_ids(&result), vec![0, 1]);
+        }
 
-        let result_img = done.unwrap();
+        #[test]
+        fn duplicate_frame() {
+            let result = apply(animated_image(3, |_| 100), ImgOp::DuplicateFrame((1, 2))).unwrap();
+            assert_eq!(frame_ids(&result), vec![0, 1, 1, 1, 2]);
+        }
 
-        // https://docs.rs/image/0.19.0/image/enum.DynamicImage.html#method.huerotate
-        // huerotate(0) should be huerotate(360), but this doesn't seem the case
-        let expected = SicImage::from(cmp.as_ref().huerotate(360));
-        assert_eq!(expected.raw_pixels(), result_img.raw_pixels());
+        #[test]
+        fn duplicate_frame_out_of_range() {
+            assert!(matches!(
+                apply(animated_image(3, |_| 100), ImgOp::DuplicateFrame((3, 1))),
+                Err(SicImageEngineError::SicCoreError(
+                    SicCoreError::InvalidFrameIndex { index: 3, len: 3 }
+                ))
+            ));
+        }
 
-        output_test_image_for_manual_inspection(&result_img, out_!("test_hue_rot_pos_360.png"));
+        #[test]
+        fn combined() {
+            let result = ImageEngine::new(animated_image(10, |_| 100))
+                .ignite(&[
+                    Instr::Operation(ImgOp::Frames(range("2..6"))),
+                    Instr::Operation(ImgOp::ReverseFrames),
+                    Instr::Operation(ImgOp::Boomerang),
+                ])
+                .unwrap();
+
+            assert_eq!(frame_ids(&result), vec![5, 4, 3, 2, 3, 4]);
+        }
+
+        #[test]
+        fn static_image_unchanged() {
+            let image = DynamicImage::ImageRgba8(RgbaImage::from_pixel(2, 2, Rgba([1, 2, 3, 4])));
+
+            let result = ImageEngine::new(SicImage::from(image.clone()))
+                .ignite(&[
+                    Instr::Operation(ImgOp::Frames(range("3..10"))),
+                    Instr::Operation(ImgOp::DropFrames(2)),
+                    Instr::Operation(ImgOp::ReverseFrames),
+                    Instr::Operation(ImgOp::Boomerang),
+                    Instr::Operation(ImgOp::DuplicateFrame((4, 2))),
+                ])
+                .unwrap();
+
+            assert_eq!(DynamicImage::try_from(result).unwrap(), image);
+        }
     }
 
-    #[test]
-    fn test_hue_rotate_over_rotate_pos() {
-        let img = setup_default_test_image();
-        let cmp = setup_default_test_image();
+    mod sprite_sheet {
+        use super::*;
+        use crate::wrapper::sprite_sheet::{SplitSpriteSheetInputs, SpriteSheetInputs};
+        use sic_core::SicCoreError;
+        use sic_core::image::RgbaImage;
 
-        let operation = ImgOp::HueRotate(460);
+        fn sheet_inputs(columns: u32, spacing: u32) -> SpriteSheetInputs {
+            SpriteSheetInputs::new(columns, spacing, Rgba([0, 0, 255, 255]))
+        }
 
-        let operator = ImageEngine::new(img);
-        let done = operator.ignite(&[Instr::Operation(operation)]);
+        #[test]
+        fn sprite_sheet_grid() {
+            let result = apply(
+                animated_image(5, |_| 50),
+                ImgOp::SpriteSheet(sheet_inputs(2, 1)),
+            )
+            .unwrap();
+            let sheet = DynamicImage::try_from(result).unwrap().into_rgba8();
+
+            // 2 columns and 3 rows of 2x2 cells, with 1 pixel spacing
+            assert_eq!(sheet.dimensions(), (5, 8));
+
+            assert_eq!(sheet.get_pixel(0, 0), &Rgba([0, 0, 0, 255]));
+            assert_eq!(sheet.get_pixel(4, 1), &Rgba([1, 0, 0, 255]));
+            assert_eq!(sheet.get_pixel(1, 3), &Rgba([2, 0, 0, 255]));
+            assert_eq!(sheet.get_pixel(3, 4), &Rgba([3, 0, 0, 255]));
+            assert_eq!(sheet.get_pixel(0, 7), &Rgba([4, 0, 0, 255]));
+
+            // spacing and the empty cell are filled with the background
+            assert_eq!(sheet.get_pixel(2, 0), &Rgba([0, 0, 255, 255]));
+            assert_eq!(sheet.get_pixel(0, 2), &Rgba([0, 0, 255, 255]));
+            assert_eq!(sheet.get_pixel(4, 7), &Rgba([0, 0, 255, 255]));
+        }
 
-        assert!(done.is_ok());
+        #[test]
+        fn sprite_sheet_single_row() {
+            let result = apply(
+                animated_image(3, |_| 50),
+                ImgOp::SpriteSheet(sheet_inputs(8, 0)),
+            )
+            .unwrap();
+            let sheet = DynamicImage::try_from(result).unwrap();
+
+            assert_eq!(sheet.into_rgba8().dimensions(), (6, 2));
+        }
 
-        let result_img = done.unwrap();
+        #[test]
+        fn sprite_sheet_zero_columns() {
+            assert!(matches!(
+                apply(
+                    animated_image(3, |_| 50),
+                    ImgOp::SpriteSheet(sheet_inputs(0, 0))
+                ),
+                Err(SicImageEngineError::SpriteSheetColumnsZero)
+            ));
+        }
 
-        let expected = SicImage::from(cmp.as_ref().huerotate(100));
-        assert_ne!(expected.raw_pixels(), result_img.raw_pixels());
+        #[test]
+        fn sprite_sheet_static_unchanged() {
+            let image = DynamicImage::ImageRgba8(RgbaImage::from_pixel(3, 1, Rgba([1, 2, 3, 4])));
+            let result = apply(
+                SicImage::from(image.clone()),
+                ImgOp::SpriteSheet(sheet_inputs(2, 0)),
+            )
+            .unwrap();
+
+            assert_eq!(DynamicImage::try_from(result).unwrap(), image);
+        }
 
-        output_test_image_for_manual_inspection(&result_img, out_!("test_hue_rot_pos_460.png"));
-    }
+        #[test]
+        fn sprite_sheet_atlas() {
+            let inputs = sheet_inputs(2, 0).with_atlas("sheet.json".into());
 
-    #[test]
-    fn test_invert() {
-        let img = setup_default_test_image();
-        let cmp = setup_default_test_image();
+            let (_, atlases) = ImageEngine::new(animated_image(3, |_| 50))
+                .ignite_with_atlases(&[Instr::Operation(ImgOp::SpriteSheet(inputs))])
+                .unwrap();
 
-        let operation = ImgOp::Invert;
+            assert_eq!(atlases.len(), 1);
+            assert_eq!(atlases[0].path(), std::path::Path::new("sheet.json"));
 
-        let operator = ImageEngine::new(img);
-        let done = operator.ignite(&[Instr::Operation(operation)]);
+            let atlas = atlases[0].to_json();
+            assert!(atlas.contains(r#""width": 4"#));
+            assert!(atlas.contains(
+                r#"{ "index": 2, "x": 0, "y": 2, "width": 2, "height": 2, "delay": 50 }"#
+            ));
+        }
 
-        assert!(done.is_ok());
+        #[test]
+        fn sprite_sheet_without_atlas() {
+            let (_, atlases) = ImageEngine::new(animated_image(3, |_| 50))
+                .ignite_with_atlases(&[Instr::Operation(ImgOp::SpriteSheet(sheet_inputs(2, 0)))])
+                .unwrap();
 
-        let result_img = done.unwrap();
+            assert!(atlases.is_empty());
+        }
 
-        assert_ne!(cmp.raw_pixels(), result_img.raw_pixels());
+        #[test]
+        fn split_sprite_sheet_round_trip() {
+            let sheet = apply(
+                animated_image(4, |_| 50),
+                ImgOp::SpriteSheet(sheet_inputs(3, 1)),
+            )
+            .unwrap();
+            let result = apply(
+                sheet,
+                ImgOp::SplitSpriteSheet(SplitSpriteSheetInputs::new((2, 2), 1)),
+            )
+            .unwrap();
+
+            let SicImage::Animated(animated) = result else {
+                panic!("expected an animated image");
+            };
+
+            // the empty cell is kept as a frame of background pixels
+            let reds = animated
+                .frames()
+                .iter()
+                .map(|frame| frame.buffer().get_pixel(1, 1).0)
+                .collect::<Vec<_>>();
+
+            assert_eq!(
+                reds,
+                vec![
+                    [0, 0, 0, 255],
+                    [1, 0, 0, 255],
+                    [2, 0, 0, 255],
+                    [3, 0, 0, 255],
+                    [0, 0, 255, 255],
+                    [0, 0, 255, 255]
+                ]
+            );
+            assert!(
+                animated
+                    .frames()
+                    .iter()
+                    .all(|frame| frame.delay().numer_denom_ms() == (100, 1))
+            );
+        }
 
-        output_test_image_for_manual_inspection(&result_img, out_!("test_invert.png"));
+        #[test]
+        fn split_sprite_sheet_skips_partial_cells() {
+            let image = DynamicImage::ImageRgba8(RgbaImage::new(7, 5));
+            let result = apply(
+                SicImage::from(image),
+                ImgOp::SplitSpriteSheet(SplitSpriteSheetInputs::new((3, 2), 0)),
+            )
+            .unwrap();
+
+            let SicImage::Animated(animated) = result else {
+                panic!("expected an animated image");
+            };
+
+            assert_eq!(animated.frames().len(), 4);
+            assert!(
+                animated
+                    .frames()
+                    .iter()
+                    .all(|frame| frame.buffer().dimensions() == (3, 2))
+            );
+        }
+
+        #[test]
+        fn split_sprite_sheet_invalid_cells() {
+            let image = || SicImage::from(DynamicImage::ImageRgba8(RgbaImage::new(4, 4)));
+
+            assert!(matches!(
+                apply(
+                    image(),
+                    ImgOp::SplitSpriteSheet(SplitSpriteSheetInputs::new((0, 2), 0))
+                ),
+                Err(SicImageEngineError::SplitSpriteSheetCellEmpty(0, 2))
+            ));
+            assert!(matches!(
+                apply(
+                    image(),
+                    ImgOp::SplitSpriteSheet(SplitSpriteSheetInputs::new((5, 2), 0))
+                ),
+                Err(SicImageEngineError::SplitSpriteSheetCellTooLarge(
+                    4, 4, 5, 2
+                ))
+            ));
+        }
+
+        #[test]
+        fn split_sprite_sheet_requires_static() {
+            assert!(matches!(
+                apply(
+                    animated_image(2, |_| 50),
+                    ImgOp::SplitSpriteSheet(SplitSpriteSheetInputs::new((1, 1), 0))
+                ),
+                Err(SicImageEngineError::SicCoreError(
+                    SicCoreError::RequiresStaticImage
+                ))
+            ));
+        }
     }
 
     mod overlay {
         use super::*;
-        use crate::wrapper::overlay::OverlayInputs;
+        use crate::wrapper::gravity::Gravity;
+        use crate::wrapper::overlay::{BlendMode, OverlayInputs, OverlayPlacement};
+        use crate::wrapper::resize::ResizeValue;
+        use sic_core::image::RgbaImage;
 
         #[test]
         fn overlay_with_self_at_origin() {
@@ -1149,13 +4004,178 @@ mod tests {
                 out_!("test_overlay_self_se_quarter.png"),
             );
         }
+
+        const BLACK: Rgba<u8> = Rgba([0, 0, 0, 255]);
+        const WHITE: Rgba<u8> = Rgba([255, 255, 255, 255]);
+
+        fn overlay_white(
+            width: u32,
+            height: u32,
+            placement: OverlayPlacement,
+            size: Option<ResizeValue>,
+        ) -> SicImage {
+            let img = SicImage::from(DynamicImage::ImageRgba8(RgbaImage::from_pixel(
+                width, height, BLACK,
+            )));
+            let overlay = sic_testing::in_!("1x1_w.png");
+            let inputs = OverlayInputs::new(ImageFromPath::new(overlay.into()), placement);
+            let inputs = match size {
+                Some(size) => inputs.with_width(size),
+                None => inputs,
+            };
+
+            ImageEngine::new(img)
+                .ignite(&[
+                    Instr::EnvAdd(EnvItem::CustomSamplingFilter(FilterTypeWrap::new(
+                        FilterType::Nearest,
+                    ))),
+                    Instr::Operation(ImgOp::Overlay(inputs)),
+                ])
+                .unwrap()
+        }
+
+        fn white_pixels(image: &SicImage) -> Vec<(u32, u32)> {
+            let (width, height) = image.dimensions();
+
+            (0..height)
+                .flat_map(|y| (0..width).map(move |x| (x, y)))
+                .filter(|&(x, y)| image.get_pixel(x, y) == WHITE)
+                .collect()
+        }
+
+        #[test]
+        fn overlay_anchored_with_margin() {
+            let placement = OverlayPlacement::Anchored {
+                gravity: Gravity::SouthEast,
+                margin: (2, 1),
+            };
+            let result_img = overlay_white(10, 8, placement, None);
+
+            assert_eq!(white_pixels(&result_img), vec![(7, 6)]);
+        }
+
+        #[test]
+        fn overlay_anchored_scaled_to_percentage_of_width() {
+            let placement = OverlayPlacement::Anchored {
+                gravity: Gravity::SouthEast,
+                margin: (0, 0),
+            };
+            let result_img = overlay_white(10, 8, placement, Some(ResizeValue::Percentage(20.0)));
+
+            assert_eq!(
+                white_pixels(&result_img),
+                vec![(8, 6), (9, 6), (8, 7), (9, 7)]
+            );
+        }
+
+        #[test]
+        fn overlay_tiled_with_spacing() {
+            let placement = OverlayPlacement::Tiled {
+                spacing: (2, 1),
+                degrees: 0.0,
+            };
+            let result_img = overlay_white(7, 3, placement, None);
+
+            assert_eq!(
+                white_pixels(&result_img),
+                vec![(0, 0), (3, 0), (6, 0), (0, 2), (3, 2), (6, 2)]
+            );
+        }
+
+        #[test]
+        fn overlay_tiled_rotated() {
+            let placement = OverlayPlacement::Tiled {
+                spacing: (0, 0),
+                degrees: 45.0,
+            };
+            let result_img = overlay_white(12, 12, placement, Some(ResizeValue::Pixels(4)));
+
+            // The rotated 4x4 square occupies a 6x6 area, of which the corners stay uncovered
+            for (x, y) in [(3, 3), (9, 3), (3, 9), (9, 9)] {
+                assert_eq!(result_img.get_pixel(x, y), WHITE);
+            }
+
+            for (x, y) in [(0, 0), (6, 0), (0, 6), (6, 6), (11, 11)] {
+                assert_eq!(result_img.get_pixel(x, y), BLACK);
+            }
+        }
+
+        fn overlay_self(blend_mode: BlendMode, opacity: f32) -> Instr {
+            let overlay = sic_testing::in_!("unsplash_763569_cropped.jpg");
+
+            Instr::Operation(ImgOp::Overlay(
+                OverlayInputs::new(ImageFromPath::new(overlay.into()), (0, 0))
+                    .with_blending(blend_mode, opacity),
+            ))
+        }
+
+        #[test]
+        fn overlay_with_self_difference() {
+            let img = setup_default_test_image();
+
+            let engine = ImageEngine::new(img);
+            let res = engine.ignite(&[overlay_self(BlendMode::Difference, 1.0)]);
+
+            let res_image = res.unwrap();
+            assert!(res_image.raw_pixels().iter().all(|&value| value == 0));
+        }
+
+        #[test]
+        fn overlay_with_self_multiply() {
+            let img = setup_default_test_image();
+
+            let engine = ImageEngine::new(img.clone());
+            let res = engine.ignite(&[overlay_self(BlendMode::Multiply, 1.0)]);
+
+            let res_image = res.unwrap();
+            assert!(
+                img.raw_pixels()
+                    .iter()
+                    .zip(res_image.raw_pixels())
+                    .all(|(&before, after)| after <= before)
+            );
+            assert_ne!(img.raw_pixels(), res_image.raw_pixels());
+
+            output_test_image_for_manual_inspection(
+                &res_image,
+                out_!("test_overlay_self_multiply.png"),
+            );
+        }
+
+        #[test]
+        fn overlay_with_zero_opacity() {
+            let img = setup_default_test_image();
+
+            let engine = ImageEngine::new(img.clone());
+            let res = engine.ignite(&[
+                Instr::Operation(ImgOp::Invert),
+                overlay_self(BlendMode::Screen, 0.0),
+                Instr::Operation(ImgOp::Invert),
+            ]);
+
+            let res_image = res.unwrap();
+            assert_eq!(img.raw_pixels(), res_image.raw_pixels());
+        }
+
+        #[test]
+        fn overlay_opacity_out_of_range() {
+            let img = setup_default_test_image();
+
+            let engine = ImageEngine::new(img);
+            let res = engine.ignite(&[overlay_self(BlendMode::Normal, 1.5)]);
+
+            assert!(matches!(
+                res,
+                Err(SicImageEngineError::OverlayOpacityOutOfRange(_))
+            ));
+        }
     }
 
     #[test]
     fn test_resize_down_gaussian() {
         // 217x447px => 100x200
         let img = setup_default_test_image();
-        let operation = ImgOp::Resize((100, 200));
+        let operation = ImgOp::Resize((100, 200).into());
 
         let (xa, ya) = img.dimensions();
 
@@ -1180,7 +4200,7 @@ mod tests {
     fn test_resize_up_gaussian() {
         // 217x447px => 300x500
         let img = setup_default_test_image();
-        let operation = ImgOp::Resize((250, 500));
+        let operation = ImgOp::Resize((250, 500).into());
 
         let (xa, ya) = img.dimensions();
 
@@ -1201,6 +4221,186 @@ mod tests {
         output_test_image_for_manual_inspection(&img_result, out_!("test_scale_250x500.png"));
     }
 
+    mod resize_modes {
+        use super::*;
+        use crate::wrapper::resize::{ResizeInputs, ResizeValue};
+
+        fn resize(inputs: ResizeInputs, env: &[Instr]) -> (u32, u32) {
+            // 217x447px
+            let img = setup_default_test_image();
+            let operator = ImageEngine::new(img);
+
+            let mut instructions = env.to_vec();
+            instructions.push(Instr::Operation(ImgOp::Resize(inputs)));
+
+            let result = operator.ignite(&instructions).unwrap();
+            result.dimensions()
+        }
+
+        #[test]
+        fn exact() {
+            let inputs = ResizeInputs::Exact(ResizeValue::Pixels(100), ResizeValue::Pixels(100));
+            let preserve = Instr::EnvAdd(EnvItem::PreserveAspectRatio(true));
+
+            assert_eq!(resize(inputs, &[preserve]), (100, 100));
+        }
+
+        #[test]
+        fn fit() {
+            let inputs = ResizeInputs::Fit(ResizeValue::Pixels(100), ResizeValue::Pixels(100));
+
+            assert_eq!(resize(inputs, &[]), (49, 100));
+        }
+
+        #[test]
+        fn dimensions_with_preserve_aspect_ratio() {
+            let preserve = Instr::EnvAdd(EnvItem::PreserveAspectRatio(true));
+
+            assert_eq!(resize((100, 100).into(), &[preserve]), (49, 100));
+        }
+
+        #[test]
+        fn cover() {
+            let inputs = ResizeInputs::Cover(ResizeValue::Pixels(100), ResizeValue::Pixels(50));
+
+            assert_eq!(resize(inputs, &[]), (100, 50));
+        }
+
+        #[test]
+        fn width_only() {
+            let inputs = ResizeInputs::WidthOnly(ResizeValue::Pixels(100));
+
+            assert_eq!(resize(inputs, &[]), (100, 206));
+        }
+
+        #[test]
+        fn height_only() {
+            let inputs = ResizeInputs::HeightOnly(ResizeValue::Pixels(100));
+
+            assert_eq!(resize(inputs, &[]), (49, 100));
+        }
+
+        #[test]
+        fn percentage() {
+            let inputs = ResizeInputs::Dimensions(
+                ResizeValue::Percentage(50.0),
+                ResizeValue::Percentage(200.0),
+            );
+
+            assert_eq!(resize(inputs, &[]), (109, 894));
+        }
+
+        #[test]
+        fn width_only_percentage() {
+            let inputs = ResizeInputs::WidthOnly(ResizeValue::Percentage(50.0));
+
+            assert_eq!(resize(inputs, &[]), (109, 225));
+        }
+
+        #[test]
+        fn cover_honours_sampling_filter() {
+            let inputs = ResizeInputs::Cover(ResizeValue::Pixels(60), ResizeValue::Pixels(60));
+            let nearest = Instr::EnvAdd(EnvItem::CustomSamplingFilter(FilterTypeWrap::new(
+                FilterType::Nearest,
+            )));
+
+            let img = setup_default_test_image();
+            let with_filter = ImageEngine::new(img.clone())
+                .ignite(&[nearest, Instr::Operation(ImgOp::Resize(inputs))])
+                .unwrap();
+            let without_filter = ImageEngine::new(img)
+                .ignite(&[Instr::Operation(ImgOp::Resize(inputs))])
+                .unwrap();
+
+            assert_eq!(with_filter.dimensions(), (60, 60));
+            assert_ne!(with_filter.raw_pixels(), without_filter.raw_pixels());
+        }
+    }
+
+    mod rotate {
+        use super::*;
+        use crate::wrapper::rotate::{RotateCanvas, RotateInputs};
+        use sic_core::AnimatedImage;
+        use sic_core::image::{DynamicImage, Frame, RgbaImage};
+
+        const BACKGROUND: Rgba<u8> = Rgba([255, 0, 255, 255]);
+
+        #[test]
+        fn rotate_crop_keeps_dimensions() {
+            let img = setup_default_test_image();
+            let (xa, ya) = img.dimensions();
+
+            let operation = ImgOp::Rotate(RotateInputs::new(30.0, RotateCanvas::Crop, BACKGROUND));
+            let done = ImageEngine::new(img).ignite(&[Instr::Operation(operation)]);
+
+            let result_img = done.unwrap();
+            assert_eq!((xa, ya), result_img.dimensions());
+
+            // the top left corner rotates out of bounds, so it's filled with the background
+            assert_eq!(BACKGROUND, result_img.get_pixel(0, 0));
+
+            output_test_image_for_manual_inspection(&result_img, out_!("test_rotate_crop.png"));
+        }
+
+        #[test]
+        fn rotate_expand_right_angle() {
+            let img = setup_default_test_image();
+            let (xa, ya) = img.dimensions();
+
+            let operation =
+                ImgOp::Rotate(RotateInputs::new(90.0, RotateCanvas::Expand, BACKGROUND));
+            let done = ImageEngine::new(img).ignite(&[Instr::Operation(operation)]);
+
+            let result_img = done.unwrap();
+            assert_eq!((ya, xa), result_img.dimensions());
+
+            output_test_image_for_manual_inspection(
+                &result_img,
+                out_!("test_rotate_expand_right_angle.png"),
+            );
+        }
+
+        #[test]
+        fn rotate_expand_grows_canvas() {
+            let img: SicImage =
+                DynamicImage::ImageRgba8(RgbaImage::from_pixel(10, 10, Rgba([0, 0, 0, 255])))
+                    .into();
+
+            let operation =
+                ImgOp::Rotate(RotateInputs::new(45.0, RotateCanvas::Expand, BACKGROUND));
+            let done = ImageEngine::new(img).ignite(&[Instr::Operation(operation)]);
+
+            let result_img = done.unwrap();
+            // 10 * cos(45°) + 10 * sin(45°) = 14.14..
+            assert_eq!((15, 15), result_img.dimensions());
+            assert_eq!(BACKGROUND, result_img.get_pixel(0, 0));
+            assert_eq!(Rgba([0, 0, 0, 255]), result_img.get_pixel(7, 7));
+        }
+
+        #[test]
+        fn rotate_animated_frames() {
+            let frames = (0..3).map(|_| Frame::new(RgbaImage::new(4, 2)));
+            let img = SicImage::Animated(AnimatedImage::from_frames(frames));
+
+            let operation =
+                ImgOp::Rotate(RotateInputs::new(-90.0, RotateCanvas::Expand, BACKGROUND));
+            let done = ImageEngine::new(img).ignite(&[Instr::Operation(operation)]);
+
+            match done.unwrap() {
+                SicImage::Animated(animated) => {
+                    assert_eq!(animated.frames().len(), 3);
+                    assert!(
+                        animated
+                            .frames()
+                            .iter()
+                            .all(|frame| frame.buffer().dimensions() == (2, 4))
+                    );
+                }
+                SicImage::Static(_) | SicImage::Indexed(_) => panic!("expected an animated image"),
+            }
+        }
+    }
+
     #[test]
     fn test_rotate90() {
         let img = setup_default_test_image();
@@ -1390,6 +4590,23 @@ mod tests {
         output_test_image_for_manual_inspection(&result_img, out_!("test_index_colors.png"));
     }
 
+    #[test]
+    fn test_index_colors_keeps_palette() {
+        let img = DynamicImage::ImageLuma8(GrayImage::from_fn(2, 1, |x, _| Luma([x as u8 * 255])));
+
+        let operator = ImageEngine::new(SicImage::from(img));
+        let done = operator.ignite(&[Instr::Operation(ImgOp::IndexColors)]);
+
+        let Ok(SicImage::Indexed(indexed)) = done else {
+            panic!("expected an indexed image");
+        };
+        assert_eq!(
+            indexed.palette(),
+            &[Rgba([0, 0, 0, 255]), Rgba([255, 255, 255, 255])]
+        );
+        assert_eq!(indexed.indices().as_raw(), &[0, 1]);
+    }
+
     #[test]
     fn test_index_colors_quant() {
         let img = setup_default_test_image();
@@ -1422,6 +4639,20 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_index_colors_quant_too_many_colors() {
+        let img = setup_default_test_image();
+
+        let operation = ImgOp::IndexColorsQuant((257, 10));
+
+        let operator = ImageEngine::new(img);
+        let done = operator.ignite(&[Instr::Operation(operation)]);
+        assert!(matches!(
+            done,
+            Err(SicImageEngineError::IndexColorsQuantColorsOutOfRange(257))
+        ));
+    }
+
     #[test]
     fn test_index_colors_quant_sample_factor_out_of_range() {
         let img = setup_default_test_image();
@@ -1443,7 +4674,7 @@ mod tests {
         // 217x447px original
         let img = setup_default_test_image();
         let operations = vec![
-            Instr::Operation(ImgOp::Resize((80, 100))),
+            Instr::Operation(ImgOp::Resize((80, 100).into())),
             Instr::Operation(ImgOp::Blur(5.0)),
             Instr::Operation(ImgOp::FlipHorizontal),
             Instr::Operation(ImgOp::FlipVertical),
@@ -1,3 +1,4 @@
+use crate::wrapper::frame_range::FrameRange;
 use sic_core::SicCoreError;
 use thiserror::Error;
 
@@ -28,13 +29,15 @@ pub enum SicImageEngineError {
     #[error("Unable to blur; the blur sigma should be a positive value, but was '{0}'")]
     BlurSigmaNotPositive(f32),
 
-    #[error("Unable to dither; the number of colors must be >= 64, but was '{0}'")]
+    #[error("Unable to dither; the number of colors must be in the range [64, 256], but was '{0}'")]
     DitherQuantColorsOutOfRange(u32),
 
     #[error("Unable to dither; the sample factor must be in the range [1, 30], but was '{0}'")]
     DitherQuantSampleFactorOutOfRange(u32),
 
-    #[error("Unable to index colors; the number of colors must be >= 64, but was '{0}'")]
+    #[error(
+        "Unable to index colors; the number of colors must be in the range [64, 256], but was '{0}'"
+    )]
     IndexColorsQuantColorsOutOfRange(u32),
 
     #[error(
@@ -42,6 +45,136 @@ pub enum SicImageEngineError {
     )]
     IndexColorsQuantSampleFactorOutOfRange(u32),
 
+    #[error("Unable to overlay; the opacity must be in the range [0, 1], but was '{0}'")]
+    OverlayOpacityOutOfRange(f32),
+
+    #[error(
+        "Unable to set alpha; the dimensions of the mask (x={2}, y={3}) should be equal to the dimensions of the image (x={0}, y={1})"
+    )]
+    MaskDimensionsMismatch(u32, u32, u32, u32),
+
+    #[error("Unable to scale alpha; the factor should not be negative, but was '{0}'")]
+    ScaleAlphaFactorNegative(f32),
+
+    #[error("Unable to scale frame delays; the factor should be larger than zero, but was '{0}'")]
+    ScaleFrameDelayFactorNotPositive(f32),
+
+    #[error(
+        "Unable to parse frame range '{0}'; expected a range of zero-based frame indices, e.g. '3..10' (up to, but excluding frame 10), '3..=10' (including frame 10), '3..' or '..10'"
+    )]
+    InvalidFrameRange(String),
+
+    #[error("Unable to select frames; the range '{0}' selects none of the {1} frames of the image")]
+    FrameRangeEmpty(FrameRange, usize),
+
+    #[error(
+        "Unable to drop frames; the interval should be at least 2, since every frame would be dropped otherwise, but was '{0}'"
+    )]
+    DropFramesIntervalTooSmall(u32),
+
+    #[error("Unable to create sprite sheet; the number of columns should be at least 1")]
+    SpriteSheetColumnsZero,
+
+    #[error(
+        "Unable to create sprite sheet; the sprite sheet dimensions exceed the maximum image dimensions"
+    )]
+    SpriteSheetDimensionsOverflow,
+
+    #[error(
+        "Unable to split sprite sheet; the cell width and height should be at least 1, but were '{0}' and '{1}'"
+    )]
+    SplitSpriteSheetCellEmpty(u32, u32),
+
+    #[error(
+        "Unable to split sprite sheet; the cell (x={2}, y={3}) should fit within the sprite sheet (x={0}, y={1})"
+    )]
+    SplitSpriteSheetCellTooLarge(u32, u32, u32, u32),
+
+    #[error(
+        "Unable to chroma key; the tolerance and softness should not be negative, but were '{0}' and '{1}'"
+    )]
+    ChromaKeyThresholdNegative(f32, f32),
+
+    #[error(
+        "Unable to merge channels; the dimensions of the channel image (x={2}, y={3}) should be equal to the dimensions of the image (x={0}, y={1})"
+    )]
+    ChannelDimensionsMismatch(u32, u32, u32, u32),
+
+    #[error(
+        "Unable to apply the operation to a single channel; operations which change the dimensions of the image (from x={0}, y={1} to x={2}, y={3}) can't be scoped to a channel"
+    )]
+    ChannelScopeDimensionsChanged(u32, u32, u32, u32),
+
+    #[error(
+        "Unable to apply the operation to a single channel; operations which change the number of frames of the image can't be scoped to a channel"
+    )]
+    ChannelScopeFramesChanged,
+
+    #[error("Unable to draw gradient; at least two color stops are required, but {0} were given")]
+    GradientTooFewStops(usize),
+
+    #[error("Unable to quantize; the palette should contain at least one color")]
+    PaletteEmpty,
+
+    #[error("Unable to parse palette; {0}")]
+    InvalidPalette(String),
+
+    #[error("Unable to read palette file from path '{0}': {1}")]
+    PaletteFileLoadError(String, std::io::Error),
+
+    #[error(
+        "Unable to parse resize value '{0}'; expected a number of pixels (e.g. '100') or a positive percentage (e.g. '50%')"
+    )]
+    InvalidResizeValue(String),
+
+    #[error("Unable to pad; the padded image dimensions exceed the maximum image dimensions")]
+    PadDimensionsOverflow,
+
+    #[error(
+        "Unable to apply gamma correction; the gamma should be a positive value, but was '{0}'"
+    )]
+    GammaNotPositive(f32),
+
+    #[error(
+        "Unable to apply levels; the input black point should be smaller than the input white point, but was: (black={0}, white={1})"
+    )]
+    LevelsInvalidInputRange(u8, u8),
+
+    #[error("Unable to parse curve points; {0}")]
+    InvalidCurvePoints(String),
+
+    #[error(
+        "Unable to parse kernel shape '{0}'; expected '<width>x<height>' or '<width>x<height>-separable', where width and height are odd numbers (e.g. '3x3')"
+    )]
+    InvalidKernelShape(String),
+
+    #[error("Unable to create kernel; expected {0} kernel values, but got {1}")]
+    InvalidKernelValues(usize, usize),
+
+    #[error(
+        "Unable to detect edges; the low threshold ({0}) should be positive and not exceed the high threshold ({1})"
+    )]
+    CannyInvalidThresholds(f32, f32),
+
+    #[error(
+        "Unable to apply bilateral filter; the color sigma ({0}) and spatial sigma ({1}) should be larger than zero"
+    )]
+    BilateralInvalidSigma(f32, f32),
+
+    #[error("Unable to denoise; the strength ({0}) should be larger than zero")]
+    NlMeansInvalidStrength(f32),
+
+    #[error(
+        "Unable to apply clahe; the number of tiles ({0}x{1}) should be at least 1 in both directions"
+    )]
+    ClaheInvalidTiles(u32, u32),
+
+    #[error("Unable to apply clahe; the clip limit ({0}) should be larger than zero")]
+    ClaheInvalidClipLimit(f32),
+
+    #[error("Unable to parse divisor '{0}'; expected 'auto' or a non-zero number")]
+    InvalidDivisor(String),
+
     #[error("Unable to load font: '{0}'")]
     FontError(sic_core::ab_glyph::InvalidFont),
 
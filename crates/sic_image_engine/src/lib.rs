@@ -4,10 +4,31 @@
 #[macro_use]
 extern crate strum_macros;
 
+use crate::wrapper::bilateral::BilateralInputs;
+use crate::wrapper::channel::{Channel, ChannelOrLuma, MergeChannelsInputs};
+use crate::wrapper::chroma_key::ChromaKeyInputs;
+use crate::wrapper::clahe::ClaheInputs;
+use crate::wrapper::color_matrix::ColorMatrixInputs;
+use crate::wrapper::curves::CurvesInputs;
 use crate::wrapper::draw_text_inner::DrawTextInner;
+use crate::wrapper::equalize::EqualizeMode;
+use crate::wrapper::extend_canvas::ExtendCanvasInputs;
+use crate::wrapper::filter::FilterInputs;
+use crate::wrapper::frame_range::FrameRange;
+use crate::wrapper::gradient::GradientInputs;
 use crate::wrapper::gradient_input::GradientInput;
+use crate::wrapper::gravity::Gravity;
 use crate::wrapper::image_path::ImageFromPath;
+use crate::wrapper::levels::LevelsInputs;
+use crate::wrapper::morphology::MorphologyInputs;
+use crate::wrapper::nl_means::NlMeansInputs;
 use crate::wrapper::overlay::OverlayInputs;
+use crate::wrapper::pad::PadInputs;
+use crate::wrapper::palette::QuantizeInputs;
+use crate::wrapper::resize::ResizeInputs;
+use crate::wrapper::rotate::RotateInputs;
+use crate::wrapper::sprite_sheet::{SplitSpriteSheetInputs, SpriteSheetInputs};
+use sic_core::image::Rgba;
 
 pub mod engine;
 pub mod errors;
@@ -16,29 +37,78 @@ pub mod wrapper;
 
 #[derive(Debug, PartialEq, Clone)]
 pub enum ImgOp {
+    Bilateral(BilateralInputs),
     Blur(f32),
+    Boomerang,
     Brighten(i32),
+    Canny((f32, f32)),
+    ChromaKey(ChromaKeyInputs),
+    Clahe(ClaheInputs),
+    Close(MorphologyInputs),
+    ColorMatrix(ColorMatrixInputs),
+    ColorToAlpha(Rgba<u8>),
     Contrast(f32),
     Crop((u32, u32, u32, u32)),
+    CropTo((u32, u32, Gravity)),
+    Curves(CurvesInputs),
     Diff(ImageFromPath),
+    Dilate(MorphologyInputs),
     Dither,
     DitherQuant((u32, u32)),
     DrawText(DrawTextInner),
+    DropFrames(u32),
+    DuplicateFrame((u32, u32)),
+    Equalize(EqualizeMode),
+    Erode(MorphologyInputs),
+    ExtendCanvas(ExtendCanvasInputs),
+    Exposure(f32),
+    ExtractAlpha,
+    ExtractChannel(ChannelOrLuma),
+    Filter(FilterInputs),
     Filter3x3([f32; 9]),
+    Flatten(Rgba<u8>),
     FlipHorizontal,
     FlipVertical,
+    Frames(FrameRange),
+    Gamma(f32),
+    Gradient(GradientInputs),
     Grayscale,
     HueRotate(i32),
     HorizontalGradient(GradientInput),
     IndexColors,
     IndexColorsQuant((u32, u32)),
     Invert,
+    Laplacian,
+    Levels(LevelsInputs),
+    Median(u32),
+    MergeChannels(MergeChannelsInputs),
+    MorphGradient(MorphologyInputs),
+    NlMeans(NlMeansInputs),
+    Open(MorphologyInputs),
     Overlay(OverlayInputs),
-    Resize((u32, u32)),
+    Pad(PadInputs),
+    PremultiplyAlpha,
+    Quantize(QuantizeInputs),
+    Resize(ResizeInputs),
+    ReverseFrames,
+    Rotate(RotateInputs),
     Rotate90,
     Rotate180,
     Rotate270,
+    Saturate(f32),
+    ScaleAlpha(f32),
+    ScaleFrameDelay(f32),
+    Scharr,
+    SetAlpha(ImageFromPath),
+    SetFrameDelay(u32),
+    SetFrameDelayAt((u32, u32)),
+    Sobel,
+    SplitSpriteSheet(SplitSpriteSheetInputs),
+    SpriteSheet(SpriteSheetInputs),
+    SwapChannels((Channel, Channel)),
     Threshold,
+    Trim(u8),
+    UnpremultiplyAlpha,
     Unsharpen((f32, i32)),
     VerticalGradient(GradientInput),
 }
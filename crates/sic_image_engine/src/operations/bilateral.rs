@@ -0,0 +1,73 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::operations::pixel_map::into_color_type;
+use crate::wrapper::bilateral::BilateralInputs;
+use sic_core::image::{DynamicImage, GrayImage, Luma, Rgb, RgbImage};
+use sic_core::imageproc::filter::bilateral::{GaussianEuclideanColorDistance, bilateral_filter};
+
+/// Smooths the color channels of an image, while preserving edges. Each pixel is replaced by the
+/// average of its window, weighted both by spatial distance and by color distance, so pixels
+/// across an edge contribute little. The alpha channel is left unchanged. Images with more than
+/// 8 bits per channel are processed at 8 bits per channel.
+pub struct Bilateral<'inputs> {
+    inputs: &'inputs BilateralInputs,
+}
+
+impl<'inputs> Bilateral<'inputs> {
+    pub fn new(inputs: &'inputs BilateralInputs) -> Self {
+        Self { inputs }
+    }
+}
+
+impl ImageOperation for Bilateral<'_> {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        let sigma_color = self.inputs.sigma_color();
+        let sigma_space = self.inputs.sigma_space();
+
+        let is_valid = |sigma: f32| sigma > 0.0 && sigma.is_finite();
+        if !(is_valid(sigma_color) && is_valid(sigma_space)) {
+            return Err(SicImageEngineError::BilateralInvalidSigma(
+                sigma_color,
+                sigma_space,
+            ));
+        }
+
+        if image.width() == 0 || image.height() == 0 {
+            return Ok(());
+        }
+
+        let radius = self.inputs.radius();
+        let distance = GaussianEuclideanColorDistance::new(sigma_color);
+        let color = image.color();
+        let mut buffer = image.to_rgba8();
+
+        if color.has_color() {
+            let rgb = RgbImage::from_fn(buffer.width(), buffer.height(), |x, y| {
+                let [r, g, b, _] = buffer.get_pixel(x, y).0;
+                Rgb([r, g, b])
+            });
+            let filtered = bilateral_filter(&rgb, radius, sigma_space, distance);
+
+            for (pixel, Rgb([r, g, b])) in buffer.pixels_mut().zip(filtered.pixels()) {
+                pixel[0] = *r;
+                pixel[1] = *g;
+                pixel[2] = *b;
+            }
+        } else {
+            let gray = GrayImage::from_fn(buffer.width(), buffer.height(), |x, y| {
+                Luma([buffer.get_pixel(x, y)[0]])
+            });
+            let filtered = bilateral_filter(&gray, radius, sigma_space, distance);
+
+            for (pixel, Luma([value])) in buffer.pixels_mut().zip(filtered.pixels()) {
+                pixel[0] = *value;
+                pixel[1] = *value;
+                pixel[2] = *value;
+            }
+        }
+
+        *image = into_color_type(DynamicImage::ImageRgba8(buffer), color);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,204 @@
+use crate::wrapper::overlay::BlendMode;
+use sic_core::image::{GenericImage, GenericImageView, Rgba, imageops};
+
+/// Composites `top` onto `bottom` at position `(x, y)`, using the given blend mode, after the
+/// alpha channel of `top` has been multiplied by `opacity`.
+///
+/// Only the pixels of `bottom` which are covered by `top` are changed, also for the Porter-Duff
+/// operators which would otherwise clear the backdrop outside of the source (e.g. `src-in`).
+pub(crate) fn blend<I, J>(bottom: &mut I, top: &J, x: i64, y: i64, mode: BlendMode, opacity: f32)
+where
+    I: GenericImage<Pixel = Rgba<u8>>,
+    J: GenericImageView<Pixel = Rgba<u8>>,
+{
+    // Keep the output of the original overlay operation unchanged
+    if mode == BlendMode::Normal && opacity >= 1.0 {
+        imageops::overlay(bottom, top, x, y);
+        return;
+    }
+
+    let (bottom_width, bottom_height) = bottom.dimensions();
+    let (top_width, top_height) = top.dimensions();
+
+    // The range of `top` pixels, which overlap with `bottom`
+    let range = |offset: i64, top_len: u32, bottom_len: u32| {
+        let start = (-offset).clamp(0, i64::from(top_len));
+        let end = (i64::from(bottom_len) - offset).clamp(start, i64::from(top_len));
+
+        start as u32..end as u32
+    };
+
+    for ty in range(y, top_height, bottom_height) {
+        for tx in range(x, top_width, bottom_width) {
+            let (bx, by) = ((x + i64::from(tx)) as u32, (y + i64::from(ty)) as u32);
+
+            let pixel = blend_pixel(
+                bottom.get_pixel(bx, by),
+                top.get_pixel(tx, ty),
+                mode,
+                opacity,
+            );
+            bottom.put_pixel(bx, by, pixel);
+        }
+    }
+}
+
+/// Composites a source pixel onto a backdrop pixel, as described by the W3C Compositing and
+/// Blending specification.
+fn blend_pixel(backdrop: Rgba<u8>, source: Rgba<u8>, mode: BlendMode, opacity: f32) -> Rgba<u8> {
+    let alpha_b = f32::from(backdrop[3]) / 255.0;
+    let alpha_s = f32::from(source[3]) / 255.0 * opacity;
+
+    let (fraction_s, fraction_b) = porter_duff_fractions(mode, alpha_s, alpha_b);
+    let alpha_o = alpha_s * fraction_s + alpha_b * fraction_b;
+
+    if alpha_o <= 0.0 {
+        return Rgba([0, 0, 0, 0]);
+    }
+
+    let mut output = [0u8; 4];
+
+    for c in 0..3 {
+        let color_b = f32::from(backdrop[c]) / 255.0;
+        let mut color_s = f32::from(source[c]) / 255.0;
+
+        if let Some(mixed) = mix(mode, color_b, color_s) {
+            // Where the backdrop is transparent, the source color is kept as is
+            color_s = (1.0 - alpha_b) * color_s + alpha_b * mixed;
+        }
+
+        let color_o = (alpha_s * fraction_s * color_s + alpha_b * fraction_b * color_b) / alpha_o;
+        output[c] = to_u8(color_o);
+    }
+
+    output[3] = to_u8(alpha_o);
+
+    Rgba(output)
+}
+
+/// The fractions of the source and of the backdrop which remain visible. The modes which mix the
+/// source and backdrop colors are composited like `src-over`.
+fn porter_duff_fractions(mode: BlendMode, alpha_s: f32, alpha_b: f32) -> (f32, f32) {
+    match mode {
+        BlendMode::Clear => (0.0, 0.0),
+        BlendMode::Source => (1.0, 0.0),
+        BlendMode::Destination => (0.0, 1.0),
+        BlendMode::DestinationOver => (1.0 - alpha_b, 1.0),
+        BlendMode::SourceIn => (alpha_b, 0.0),
+        BlendMode::DestinationIn => (0.0, alpha_s),
+        BlendMode::SourceOut => (1.0 - alpha_b, 0.0),
+        BlendMode::DestinationOut => (0.0, 1.0 - alpha_s),
+        BlendMode::SourceAtop => (alpha_b, 1.0 - alpha_s),
+        BlendMode::DestinationAtop => (1.0 - alpha_b, alpha_s),
+        BlendMode::Xor => (1.0 - alpha_b, 1.0 - alpha_s),
+        _ => (1.0, 1.0 - alpha_s),
+    }
+}
+
+/// Mixes a backdrop and source color channel (in the range `[0, 1]`), or `None` if the mode
+/// doesn't mix colors.
+fn mix(mode: BlendMode, backdrop: f32, source: f32) -> Option<f32> {
+    let multiply = |b: f32, s: f32| b * s;
+    let screen = |b: f32, s: f32| b + s - b * s;
+    let hard_light = |b: f32, s: f32| {
+        if s <= 0.5 {
+            multiply(b, 2.0 * s)
+        } else {
+            screen(b, 2.0 * s - 1.0)
+        }
+    };
+
+    let mixed = match mode {
+        BlendMode::Multiply => multiply(backdrop, source),
+        BlendMode::Screen => screen(backdrop, source),
+        BlendMode::Overlay => hard_light(source, backdrop),
+        BlendMode::Darken => backdrop.min(source),
+        BlendMode::Lighten => backdrop.max(source),
+        BlendMode::HardLight => hard_light(backdrop, source),
+        BlendMode::SoftLight => {
+            if source <= 0.5 {
+                backdrop - (1.0 - 2.0 * source) * backdrop * (1.0 - backdrop)
+            } else {
+                let d = if backdrop <= 0.25 {
+                    ((16.0 * backdrop - 12.0) * backdrop + 4.0) * backdrop
+                } else {
+                    backdrop.sqrt()
+                };
+
+                backdrop + (2.0 * source - 1.0) * (d - backdrop)
+            }
+        }
+        BlendMode::Difference => (backdrop - source).abs(),
+        _ => return None,
+    };
+
+    Some(mixed)
+}
+
+fn to_u8(value: f32) -> u8 {
+    (value * 255.0).round().clamp(0.0, 255.0) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: Rgba<u8> = Rgba([255, 0, 0, 255]);
+    const GRAY: Rgba<u8> = Rgba([128, 128, 128, 255]);
+    const TRANSPARENT: Rgba<u8> = Rgba([0, 0, 0, 0]);
+
+    #[test]
+    fn multiply() {
+        let pixel = blend_pixel(GRAY, RED, BlendMode::Multiply, 1.0);
+
+        assert_eq!(pixel, Rgba([128, 0, 0, 255]));
+    }
+
+    #[test]
+    fn screen() {
+        let pixel = blend_pixel(GRAY, RED, BlendMode::Screen, 1.0);
+
+        assert_eq!(pixel, Rgba([255, 128, 128, 255]));
+    }
+
+    #[test]
+    fn difference() {
+        let pixel = blend_pixel(GRAY, RED, BlendMode::Difference, 1.0);
+
+        assert_eq!(pixel, Rgba([127, 128, 128, 255]));
+    }
+
+    #[test]
+    fn normal_with_opacity() {
+        let pixel = blend_pixel(GRAY, RED, BlendMode::Normal, 0.25);
+
+        assert_eq!(pixel, Rgba([160, 96, 96, 255]));
+    }
+
+    #[test]
+    fn mixing_modes_keep_source_over_transparent_backdrop() {
+        let pixel = blend_pixel(TRANSPARENT, RED, BlendMode::Multiply, 1.0);
+
+        assert_eq!(pixel, RED);
+    }
+
+    #[test]
+    fn porter_duff() {
+        assert_eq!(blend_pixel(GRAY, RED, BlendMode::Clear, 1.0), TRANSPARENT);
+        assert_eq!(blend_pixel(GRAY, RED, BlendMode::Source, 1.0), RED);
+        assert_eq!(blend_pixel(GRAY, RED, BlendMode::Destination, 1.0), GRAY);
+        assert_eq!(
+            blend_pixel(GRAY, RED, BlendMode::DestinationOver, 1.0),
+            GRAY
+        );
+        assert_eq!(
+            blend_pixel(TRANSPARENT, RED, BlendMode::SourceIn, 1.0),
+            TRANSPARENT
+        );
+        assert_eq!(
+            blend_pixel(TRANSPARENT, RED, BlendMode::SourceOut, 1.0),
+            RED
+        );
+        assert_eq!(blend_pixel(GRAY, RED, BlendMode::Xor, 1.0), TRANSPARENT);
+    }
+}
@@ -0,0 +1,39 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use sic_core::SicImage;
+use sic_core::image::DynamicImage;
+
+/// Appends the frames of an animated image in reverse order, so it plays forwards and then
+/// backwards (ping-pong). The first and last frame aren't repeated, so the animation loops
+/// smoothly, e.g. the frames `0 1 2 3` become `0 1 2 3 2 1`. Static images are left as is.
+pub struct Boomerang;
+
+impl Boomerang {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl ImageOperation for Boomerang {
+    fn apply_to_frame(&self, _image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        Ok(())
+    }
+
+    fn apply_operation(&self, image: &mut SicImage) -> Result<(), SicImageEngineError> {
+        if let SicImage::Animated(animated) = image {
+            let frames = animated.frames_vec_mut();
+
+            if frames.len() > 2 {
+                let reversed = frames[1..frames.len() - 1]
+                    .iter()
+                    .rev()
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                frames.extend(reversed);
+            }
+        }
+
+        Ok(())
+    }
+}
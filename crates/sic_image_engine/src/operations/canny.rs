@@ -0,0 +1,38 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use sic_core::image::DynamicImage;
+use sic_core::imageproc;
+
+/// Detects edges using the Canny edge detector. The result is a gray image in which edge pixels
+/// are white, and all other pixels are black.
+///
+/// Edges with a strength above the high threshold are always kept, while edges with a strength
+/// above the low threshold are only kept if they are connected to a strong edge. The largest
+/// possible edge strength is approximately `1140.39`.
+pub struct Canny {
+    low_threshold: f32,
+    high_threshold: f32,
+}
+
+impl Canny {
+    pub fn new(low_threshold: f32, high_threshold: f32) -> Self {
+        Self {
+            low_threshold,
+            high_threshold,
+        }
+    }
+}
+
+impl ImageOperation for Canny {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        let (low, high) = (self.low_threshold, self.high_threshold);
+
+        if !(low >= 0.0 && low <= high && high.is_finite()) {
+            return Err(SicImageEngineError::CannyInvalidThresholds(low, high));
+        }
+
+        *image = DynamicImage::ImageLuma8(imageproc::edges::canny(&image.to_luma8(), low, high));
+
+        Ok(())
+    }
+}
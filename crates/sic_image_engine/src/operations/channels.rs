@@ -0,0 +1,142 @@
+//! Helpers for the channel operations: extract-alpha, extract-channel, merge-channels and the
+//! `channel` modifier, which take single channels out of images as grayscale images, and put them
+//! back in.
+
+use crate::errors::SicImageEngineError;
+use crate::operations::pixel_map::{ensure_alpha, ensure_color, map_rgba_enumerated};
+use crate::wrapper::channel::{Channel, ChannelOrLuma};
+use sic_core::image::{DynamicImage, GenericImageView, ImageBuffer, Luma, Pixel};
+use sic_core::{AnimatedImage, SicImage, image};
+
+/// Returns a grayscale image of a channel of the image. 8-bit images result in an 8-bit grayscale
+/// image, and all other images in a 16-bit grayscale image. Images without an alpha channel are
+/// fully opaque, so their alpha channel is white.
+pub(crate) fn channel_to_gray(image: &DynamicImage, channel: ChannelOrLuma) -> DynamicImage {
+    let color = image.color();
+    let is_8bit = color.bytes_per_pixel() == color.channel_count();
+
+    match (channel, is_8bit) {
+        (ChannelOrLuma::Luma, true) => DynamicImage::ImageLuma8(image.to_luma8()),
+        (ChannelOrLuma::Luma, false) => DynamicImage::ImageLuma16(image.to_luma16()),
+        (ChannelOrLuma::Channel(channel), true) => {
+            DynamicImage::ImageLuma8(select_channel(&image.to_rgba8(), channel))
+        }
+        (ChannelOrLuma::Channel(channel), false) => {
+            DynamicImage::ImageLuma16(select_channel(&image.to_rgba16(), channel))
+        }
+    }
+}
+
+fn select_channel<P>(
+    buffer: &ImageBuffer<P, Vec<P::Subpixel>>,
+    channel: Channel,
+) -> ImageBuffer<Luma<P::Subpixel>, Vec<P::Subpixel>>
+where
+    P: Pixel,
+    Luma<P::Subpixel>: Pixel<Subpixel = P::Subpixel>,
+{
+    ImageBuffer::from_fn(buffer.width(), buffer.height(), |x, y| {
+        Luma([buffer.get_pixel(x, y).channels()[channel.index()]])
+    })
+}
+
+/// Replaces a channel of the image by the luma of the `gray` image, which must have the same
+/// dimensions. Grayscale images are converted to color images first, unless the alpha channel is
+/// replaced, and an alpha channel is added if it is replaced but missing.
+pub(crate) fn gray_into_channel(image: &mut DynamicImage, gray: &DynamicImage, channel: Channel) {
+    debug_assert_eq!(image.dimensions(), gray.dimensions());
+
+    let values = gray.to_luma32f();
+
+    match channel {
+        Channel::Alpha => ensure_alpha(image),
+        _ => ensure_color(image),
+    }
+
+    map_rgba_enumerated(image, |index, mut pixel| {
+        pixel[channel.index()] = values.as_raw()[index];
+        pixel
+    });
+}
+
+/// Splits a channel off an image, as a grayscale image for static images, and as frames with
+/// equal color channels for animated images.
+pub(crate) fn split_channel(image: &SicImage, channel: Channel) -> SicImage {
+    let channel = ChannelOrLuma::Channel(channel);
+
+    match image {
+        SicImage::Static(image) => SicImage::Static(channel_to_gray(image, channel)),
+        SicImage::Animated(animated) => {
+            let frames = animated.frames().iter().map(|frame| {
+                let gray =
+                    channel_to_gray(&DynamicImage::ImageRgba8(frame.buffer().clone()), channel);
+
+                image::Frame::from_parts(
+                    gray.into_rgba8(),
+                    frame.left(),
+                    frame.top(),
+                    frame.delay(),
+                )
+            });
+
+            SicImage::Animated(AnimatedImage::from_frames(frames))
+        }
+        SicImage::Indexed(indexed) => {
+            SicImage::Static(channel_to_gray(&indexed.to_dynamic_image(), channel))
+        }
+    }
+}
+
+/// Puts a channel, split off by [`split_channel`] and possibly modified since, back into the image.
+pub(crate) fn merge_channel(
+    image: &mut SicImage,
+    gray: SicImage,
+    channel: Channel,
+) -> Result<(), SicImageEngineError> {
+    image.expand_indexed();
+
+    match (image, gray) {
+        (SicImage::Static(image), SicImage::Static(gray)) => merge_frame(image, &gray, channel),
+        (SicImage::Static(image), SicImage::Indexed(gray)) => {
+            merge_frame(image, &gray.to_dynamic_image(), channel)
+        }
+        (SicImage::Animated(image), SicImage::Animated(gray))
+            if image.frames().len() == gray.frames().len() =>
+        {
+            for (frame, gray) in image.frames_mut().iter_mut().zip(gray.frames()) {
+                let mut buffer = DynamicImage::ImageRgba8(frame.buffer().clone());
+                merge_frame(
+                    &mut buffer,
+                    &DynamicImage::ImageRgba8(gray.buffer().clone()),
+                    channel,
+                )?;
+                *frame.buffer_mut() = buffer.into_rgba8();
+            }
+
+            Ok(())
+        }
+        _ => Err(SicImageEngineError::ChannelScopeFramesChanged),
+    }
+}
+
+fn merge_frame(
+    image: &mut DynamicImage,
+    gray: &DynamicImage,
+    channel: Channel,
+) -> Result<(), SicImageEngineError> {
+    let (width, height) = image.dimensions();
+    let (gray_width, gray_height) = gray.dimensions();
+
+    if (width, height) != (gray_width, gray_height) {
+        return Err(SicImageEngineError::ChannelScopeDimensionsChanged(
+            width,
+            height,
+            gray_width,
+            gray_height,
+        ));
+    }
+
+    gray_into_channel(image, gray, channel);
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::operations::pixel_map::{ensure_alpha, map_rgba};
+use crate::wrapper::chroma_key::ChromaKeyInputs;
+use sic_core::image::DynamicImage;
+
+/// Makes pixels close to a key color transparent, e.g. to remove a green screen or white backdrop.
+/// Pixels within the tolerance of the key color become fully transparent, and pixels within the
+/// softness beyond the tolerance fade linearly to their original opacity.
+pub struct ChromaKey<'inputs> {
+    inputs: &'inputs ChromaKeyInputs,
+}
+
+impl<'inputs> ChromaKey<'inputs> {
+    pub fn new(inputs: &'inputs ChromaKeyInputs) -> Self {
+        Self { inputs }
+    }
+}
+
+impl ImageOperation for ChromaKey<'_> {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        let tolerance = self.inputs.tolerance();
+        let softness = self.inputs.softness();
+
+        if !(tolerance.is_finite() && tolerance >= 0.0 && softness.is_finite() && softness >= 0.0) {
+            return Err(SicImageEngineError::ChromaKeyThresholdNegative(
+                tolerance, softness,
+            ));
+        }
+
+        let key = {
+            let [r, g, b, _] = self.inputs.key().0;
+            [r, g, b].map(|c| f32::from(c) / 255.0)
+        };
+        let tolerance = tolerance / 255.0;
+        let softness = softness / 255.0;
+
+        ensure_alpha(image);
+        map_rgba(image, |[r, g, b, a]| {
+            let distance = [r, g, b]
+                .iter()
+                .zip(key)
+                .map(|(c, k)| (c - k) * (c - k))
+                .sum::<f32>()
+                .sqrt();
+
+            let opacity = if distance <= tolerance {
+                0.0
+            } else if distance >= tolerance + softness {
+                1.0
+            } else {
+                (distance - tolerance) / softness
+            };
+
+            let [r, g, b] = if self.inputs.despill() {
+                suppress_spill([r, g, b], key)
+            } else {
+                [r, g, b]
+            };
+
+            [r, g, b, a * opacity]
+        });
+
+        Ok(())
+    }
+}
+
+/// Removes the part of the chroma of a color which points in the direction of the chroma of the
+/// key color, while keeping its average intensity. For a pure green key, this pulls greenish
+/// colors towards gray, and leaves colors without a green cast unchanged. Keys without chroma
+/// (e.g. white) have no spill to remove.
+fn suppress_spill(rgb: [f32; 3], key: [f32; 3]) -> [f32; 3] {
+    let chroma = |c: [f32; 3]| {
+        let mean = c.iter().sum::<f32>() / 3.0;
+        c.map(|v| v - mean)
+    };
+    let dot = |lhs: [f32; 3], rhs: [f32; 3]| lhs.iter().zip(rhs).map(|(l, r)| l * r).sum::<f32>();
+
+    let key_chroma = chroma(key);
+    let key_norm = dot(key_chroma, key_chroma);
+
+    if key_norm <= f32::EPSILON {
+        return rgb;
+    }
+
+    let spill = dot(chroma(rgb), key_chroma) / key_norm;
+
+    if spill <= 0.0 {
+        return rgb;
+    }
+
+    [0, 1, 2].map(|i| rgb[i] - spill * key_chroma[i])
+}
@@ -0,0 +1,150 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::operations::histogram::{Histogram, Lut, bins_for};
+use crate::operations::pixel_map::{into_color_type, luma};
+use crate::wrapper::clahe::ClaheInputs;
+use rayon::prelude::*;
+use sic_core::image::DynamicImage;
+
+/// Enhances local contrast using contrast limited adaptive histogram equalization (CLAHE). The
+/// image is divided into a grid of tiles, and the luma of each tile is equalized separately, where
+/// the histogram of each tile is clipped at the clip limit to limit the amplification of noise.
+/// The mappings of the four nearest tiles are interpolated for each pixel, so no tile edges are
+/// visible. The alpha channel is left unchanged.
+///
+/// Images with more than 8 bits per channel are equalized with histograms of 4096 bins per tile,
+/// between which the mappings are interpolated.
+pub struct Clahe<'inputs> {
+    inputs: &'inputs ClaheInputs,
+}
+
+impl<'inputs> Clahe<'inputs> {
+    pub fn new(inputs: &'inputs ClaheInputs) -> Self {
+        Self { inputs }
+    }
+}
+
+impl ImageOperation for Clahe<'_> {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        let (tiles_x, tiles_y) = (self.inputs.tiles_x(), self.inputs.tiles_y());
+        let clip_limit = self.inputs.clip_limit();
+
+        if tiles_x == 0 || tiles_y == 0 {
+            return Err(SicImageEngineError::ClaheInvalidTiles(tiles_x, tiles_y));
+        }
+
+        if !(clip_limit > 0.0 && clip_limit.is_finite()) {
+            return Err(SicImageEngineError::ClaheInvalidClipLimit(clip_limit));
+        }
+
+        let (width, height) = (image.width(), image.height());
+        if width == 0 || height == 0 {
+            return Ok(());
+        }
+
+        let color = image.color();
+        let mut buffer = image.to_rgba32f();
+        let lumas: Vec<f32> = buffer.pixels().map(|p| luma([p[0], p[1], p[2]])).collect();
+
+        let columns = Grid::new(tiles_x.min(width), width);
+        let rows = Grid::new(tiles_y.min(height), height);
+        let bins = bins_for(color).min(1 << 12);
+
+        let luts: Vec<Lut> = (0..rows.tiles * columns.tiles)
+            .into_par_iter()
+            .map(|index| {
+                let (column, row) = (index % columns.tiles, index / columns.tiles);
+                let (x0, x1) = columns.bounds(column);
+                let (y0, y1) = rows.bounds(row);
+
+                let mut histogram = Histogram::new(bins);
+                for y in y0..y1 {
+                    for x in x0..x1 {
+                        histogram.add(lumas[(y * width + x) as usize]);
+                    }
+                }
+
+                let pixels = ((x1 - x0) * (y1 - y0)) as f32;
+                let limit = (clip_limit * pixels / bins as f32).max(1.0) as u32;
+                histogram.clip(limit);
+                histogram.cdf_lut()
+            })
+            .collect();
+
+        let lut = |column: u32, row: u32| &luts[(row * columns.tiles + column) as usize];
+        let horizontal: Vec<_> = (0..width).map(|x| columns.neighbours(x)).collect();
+
+        buffer
+            .par_chunks_exact_mut(4 * width as usize)
+            .enumerate()
+            .for_each(|(y, row)| {
+                let (top, bottom, fy) = rows.neighbours(y as u32);
+
+                for (x, pixel) in row.chunks_exact_mut(4).enumerate() {
+                    let (left, right, fx) = horizontal[x];
+                    let l = lumas[y * width as usize + x];
+
+                    let lerp = |a: f32, b: f32, t: f32| a + (b - a) * t;
+                    let upper = lerp(lut(left, top).evaluate(l), lut(right, top).evaluate(l), fx);
+                    let lower = lerp(
+                        lut(left, bottom).evaluate(l),
+                        lut(right, bottom).evaluate(l),
+                        fx,
+                    );
+                    let shift = lerp(upper, lower, fy) - l;
+
+                    for channel in &mut pixel[..3] {
+                        *channel = (*channel + shift).clamp(0.0, 1.0);
+                    }
+                }
+            });
+
+        *image = into_color_type(DynamicImage::ImageRgba32F(buffer), color);
+
+        Ok(())
+    }
+}
+
+/// The division of one image dimension into (almost) equally sized tiles.
+struct Grid {
+    tiles: u32,
+    length: u32,
+}
+
+impl Grid {
+    fn new(tiles: u32, length: u32) -> Self {
+        Self { tiles, length }
+    }
+
+    /// The start (inclusive) and end (exclusive) of a tile.
+    fn bounds(&self, tile: u32) -> (u32, u32) {
+        let bound =
+            |tile: u32| (u64::from(tile) * u64::from(self.length) / u64::from(self.tiles)) as u32;
+        (bound(tile), bound(tile + 1))
+    }
+
+    fn center(&self, tile: u32) -> f32 {
+        let (start, end) = self.bounds(tile);
+        (start + end) as f32 / 2.0 - 0.5
+    }
+
+    /// The two tiles whose centers surround the coordinate, and the interpolation weight of the
+    /// second tile.
+    fn neighbours(&self, coordinate: u32) -> (u32, u32, f32) {
+        let coordinate = coordinate as f32;
+        let first = (0..self.tiles)
+            .rev()
+            .find(|&tile| self.center(tile) <= coordinate)
+            .unwrap_or(0);
+        let second = (first + 1).min(self.tiles - 1);
+
+        if first == second {
+            return (first, second, 0.0);
+        }
+
+        let (start, end) = (self.center(first), self.center(second));
+        let weight = ((coordinate - start) / (end - start)).clamp(0.0, 1.0);
+
+        (first, second, weight)
+    }
+}
@@ -0,0 +1,28 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::operations::morphology::{apply_per_channel, mask};
+use crate::wrapper::morphology::MorphologyInputs;
+use sic_core::image::DynamicImage;
+use sic_core::imageproc::morphology::grayscale_close;
+
+/// Dilates and then erodes the image with the same structuring element. Fills dark details smaller
+/// than the structuring element (e.g. holes in a mask), while preserving the shape of larger
+/// bright regions.
+pub struct Close<'inputs> {
+    inputs: &'inputs MorphologyInputs,
+}
+
+impl<'inputs> Close<'inputs> {
+    pub fn new(inputs: &'inputs MorphologyInputs) -> Self {
+        Self { inputs }
+    }
+}
+
+impl ImageOperation for Close<'_> {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        let mask = mask(self.inputs);
+        apply_per_channel(image, |plane| grayscale_close(plane, &mask));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::operations::pixel_map::{ensure_alpha, map_rgba};
+use crate::wrapper::color_matrix::ColorMatrixInputs;
+use sic_core::image::DynamicImage;
+
+/// Applies a 4x5 color matrix to each pixel.
+/// If the matrix modifies the alpha channel, an alpha channel is added to images which do not have
+/// one yet.
+pub struct ColorMatrix<'inputs> {
+    inputs: &'inputs ColorMatrixInputs,
+}
+
+impl<'inputs> ColorMatrix<'inputs> {
+    pub fn new(inputs: &'inputs ColorMatrixInputs) -> Self {
+        Self { inputs }
+    }
+}
+
+impl ImageOperation for ColorMatrix<'_> {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        if !self.inputs.preserves_alpha() {
+            ensure_alpha(image);
+        }
+
+        map_rgba(image, |pixel| self.inputs.apply(pixel));
+
+        Ok(())
+    }
+}
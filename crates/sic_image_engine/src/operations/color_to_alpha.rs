@@ -0,0 +1,61 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::operations::pixel_map::{ensure_alpha, map_rgba};
+use sic_core::image::{DynamicImage, Rgba};
+
+/// Removes a color from the image, like the 'color to alpha' filter of the GIMP. Each pixel is
+/// given the lowest opacity for which it can still be composited over the color to produce its
+/// original appearance, and its color is adjusted accordingly. Pixels of exactly the given color
+/// become fully transparent, while e.g. a gray shadow on a white backdrop becomes a translucent
+/// black shadow.
+pub struct ColorToAlpha {
+    color: Rgba<u8>,
+}
+
+impl ColorToAlpha {
+    pub fn new(color: Rgba<u8>) -> Self {
+        Self { color }
+    }
+}
+
+impl ImageOperation for ColorToAlpha {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        let color = {
+            let [r, g, b, _] = self.color.0;
+            [r, g, b].map(|c| f32::from(c) / 255.0)
+        };
+
+        ensure_alpha(image);
+        map_rgba(image, |[r, g, b, a]| {
+            let rgb = [r, g, b];
+
+            let alpha = rgb
+                .iter()
+                .zip(color)
+                .map(|(&value, color)| channel_alpha(value, color))
+                .fold(0.0, f32::max);
+
+            if alpha <= 0.0 {
+                return [r, g, b, 0.0];
+            }
+
+            let [r, g, b] = [0, 1, 2].map(|i| (rgb[i] - color[i]) / alpha + color[i]);
+
+            [r, g, b, a * alpha]
+        });
+
+        Ok(())
+    }
+}
+
+/// The minimal opacity with which a channel value can be composited over the channel value of the
+/// removed color, to produce the given value.
+fn channel_alpha(value: f32, color: f32) -> f32 {
+    if value > color {
+        (value - color) / (1.0 - color)
+    } else if value < color {
+        (color - value) / color
+    } else {
+        0.0
+    }
+}
@@ -0,0 +1,43 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::wrapper::gravity::Gravity;
+use sic_core::image::{DynamicImage, GenericImageView};
+
+/// Crops a selection of `width` by `height` pixels, anchored by its gravity.
+/// Unlike [`Crop`], the selection is clamped to the bounds of the image, instead of returning
+/// an error when it does not fit.
+///
+/// [`Crop`]: crate::operations::crop::Crop
+pub struct CropTo {
+    width: u32,
+    height: u32,
+    gravity: Gravity,
+}
+
+impl CropTo {
+    pub fn new(width: u32, height: u32, gravity: Gravity) -> Self {
+        Self {
+            width,
+            height,
+            gravity,
+        }
+    }
+}
+
+impl ImageOperation for CropTo {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        let (image_width, image_height) = image.dimensions();
+
+        let width = self.width.min(image_width);
+        let height = self.height.min(image_height);
+
+        // since the selection is clamped, it always fits, so the position is never negative
+        let (x, y) = self
+            .gravity
+            .position((image_width, image_height), (width, height));
+
+        *image = image.crop_imm(x as u32, y as u32, width, height);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,163 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::operations::pixel_map::{luma, map_rgb};
+use crate::wrapper::curves::{CurvePoints, CurvesChannel, CurvesInputs};
+use sic_core::image::DynamicImage;
+
+/// Maps channel values using a smooth curve through the given control points.
+pub struct Curves<'inputs> {
+    inputs: &'inputs CurvesInputs,
+}
+
+impl<'inputs> Curves<'inputs> {
+    pub fn new(inputs: &'inputs CurvesInputs) -> Self {
+        Self { inputs }
+    }
+}
+
+impl ImageOperation for Curves<'_> {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        let curve = MonotoneCurve::new(self.inputs.points());
+
+        match self.inputs.channel() {
+            CurvesChannel::Rgb => map_rgb(image, |rgb| rgb.map(|c| curve.evaluate(c))),
+            CurvesChannel::Red => map_rgb(image, |[r, g, b]| [curve.evaluate(r), g, b]),
+            CurvesChannel::Green => map_rgb(image, |[r, g, b]| [r, curve.evaluate(g), b]),
+            CurvesChannel::Blue => map_rgb(image, |[r, g, b]| [r, g, curve.evaluate(b)]),
+            CurvesChannel::Luminance => map_rgb(image, |rgb| {
+                let l = luma(rgb);
+                let shift = curve.evaluate(l) - l;
+                rgb.map(|c| c + shift)
+            }),
+        }
+
+        Ok(())
+    }
+}
+
+/// A monotone cubic Hermite spline (Fritsch-Carlson), which passes through each control point,
+/// and does not overshoot between them. Values are normalized to the range `[0, 1]`.
+struct MonotoneCurve {
+    xs: Vec<f32>,
+    ys: Vec<f32>,
+    tangents: Vec<f32>,
+}
+
+impl MonotoneCurve {
+    fn new(points: &CurvePoints) -> Self {
+        let unit = |value: u8| f32::from(value) / f32::from(u8::MAX);
+        let xs = points
+            .points()
+            .iter()
+            .map(|(x, _)| unit(*x))
+            .collect::<Vec<_>>();
+        let ys = points
+            .points()
+            .iter()
+            .map(|(_, y)| unit(*y))
+            .collect::<Vec<_>>();
+        let n = xs.len();
+
+        let secants = (0..n - 1)
+            .map(|k| (ys[k + 1] - ys[k]) / (xs[k + 1] - xs[k]))
+            .collect::<Vec<_>>();
+
+        let mut tangents = (0..n)
+            .map(|k| match k {
+                0 => secants[0],
+                k if k == n - 1 => secants[n - 2],
+                k if secants[k - 1] * secants[k] <= 0.0 => 0.0,
+                k => (secants[k - 1] + secants[k]) / 2.0,
+            })
+            .collect::<Vec<_>>();
+
+        // Limit the tangents, such that the spline is monotone between each pair of points.
+        for (k, secant) in secants.iter().enumerate() {
+            if *secant == 0.0 {
+                tangents[k] = 0.0;
+                tangents[k + 1] = 0.0;
+                continue;
+            }
+
+            let a = tangents[k] / secant;
+            let b = tangents[k + 1] / secant;
+            let s = a * a + b * b;
+
+            if s > 9.0 {
+                let t = 3.0 / s.sqrt();
+                tangents[k] = t * a * secant;
+                tangents[k + 1] = t * b * secant;
+            }
+        }
+
+        Self { xs, ys, tangents }
+    }
+
+    fn evaluate(&self, x: f32) -> f32 {
+        let last = self.xs.len() - 1;
+
+        if x <= self.xs[0] {
+            return self.ys[0];
+        } else if x >= self.xs[last] {
+            return self.ys[last];
+        }
+
+        // the segment [xs[k], xs[k + 1]) which contains x
+        let k = self.xs.partition_point(|xk| *xk <= x) - 1;
+
+        let h = self.xs[k + 1] - self.xs[k];
+        let t = (x - self.xs[k]) / h;
+        let (t2, t3) = (t * t, t * t * t);
+
+        (2.0 * t3 - 3.0 * t2 + 1.0) * self.ys[k]
+            + (t3 - 2.0 * t2 + t) * h * self.tangents[k]
+            + (-2.0 * t3 + 3.0 * t2) * self.ys[k + 1]
+            + (t3 - t2) * h * self.tangents[k + 1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn curve(points: &[(u8, u8)]) -> MonotoneCurve {
+        MonotoneCurve::new(&CurvePoints::new(points.to_vec()).unwrap())
+    }
+
+    #[test]
+    fn passes_through_points() {
+        let curve = curve(&[(0, 0), (64, 100), (192, 200), (255, 255)]);
+
+        for (x, y) in [(0u8, 0u8), (64, 100), (192, 200), (255, 255)] {
+            let value = curve.evaluate(f32::from(x) / 255.0) * 255.0;
+            assert!((value - f32::from(y)).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn two_points_is_linear() {
+        let curve = curve(&[(0, 255), (255, 0)]);
+
+        assert!((curve.evaluate(0.25) - 0.75).abs() < 1e-6);
+    }
+
+    #[test]
+    fn clamps_outside_points() {
+        let curve = curve(&[(50, 20), (200, 220)]);
+
+        assert_eq!(curve.evaluate(0.0), 20.0 / 255.0);
+        assert_eq!(curve.evaluate(1.0), 220.0 / 255.0);
+    }
+
+    #[test]
+    fn monotone() {
+        let curve = curve(&[(0, 0), (10, 200), (20, 210), (255, 255)]);
+
+        let values = (0..=1000)
+            .map(|i| curve.evaluate(i as f32 / 1000.0))
+            .collect::<Vec<_>>();
+
+        assert!(values.windows(2).all(|pair| pair[0] <= pair[1] + 1e-6));
+        assert!(values.iter().all(|value| (0.0..=1.0).contains(value)));
+    }
+}
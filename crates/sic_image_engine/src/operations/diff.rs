@@ -26,6 +26,10 @@ impl ImageOperation for Diff<'_> {
         match image {
             SicImage::Static(image) => self.apply_to_frame(image),
             SicImage::Animated(image) => diff_animated_image(image.frames_mut(), self.path),
+            SicImage::Indexed(_) => {
+                image.expand_indexed();
+                self.apply_operation(image)
+            }
         }
     }
 }
@@ -40,6 +44,7 @@ fn diff_animated_image(
     match other {
         SicImage::Static(image) => diff_animated_with_static(frames, &image),
         SicImage::Animated(other) => diff_animated_with_animated(frames, other.frames()),
+        SicImage::Indexed(other) => diff_animated_with_static(frames, &other.to_dynamic_image()),
     }
 
     Ok(())
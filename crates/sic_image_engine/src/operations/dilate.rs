@@ -0,0 +1,27 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::operations::morphology::{apply_per_channel, mask};
+use crate::wrapper::morphology::MorphologyInputs;
+use sic_core::image::DynamicImage;
+use sic_core::imageproc::morphology::grayscale_dilate;
+
+/// Replaces each pixel by the maximum of its neighbourhood, as defined by the structuring element.
+/// Grows bright regions, and fills dark details smaller than the structuring element.
+pub struct Dilate<'inputs> {
+    inputs: &'inputs MorphologyInputs,
+}
+
+impl<'inputs> Dilate<'inputs> {
+    pub fn new(inputs: &'inputs MorphologyInputs) -> Self {
+        Self { inputs }
+    }
+}
+
+impl ImageOperation for Dilate<'_> {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        let mask = mask(self.inputs);
+        apply_per_channel(image, |plane| grayscale_dilate(plane, &mask));
+
+        Ok(())
+    }
+}
@@ -1,22 +1,273 @@
 use crate::errors::SicImageEngineError;
 use crate::operations::ImageOperation;
-use sic_core::image::DynamicImage;
-use sic_core::image::imageops::colorops::{BiLevel, dither};
+use crate::wrapper::dither_algorithm::DitherAlgorithm;
+use sic_core::image::imageops::colorops::{BiLevel, ColorMap, dither};
+use sic_core::image::{DynamicImage, ImageBuffer, Pixel};
 
-pub struct Dither;
+pub struct Dither {
+    algorithm: DitherAlgorithm,
+    serpentine: bool,
+}
 
 impl Dither {
-    pub fn new() -> Self {
-        Self {}
+    pub fn new(algorithm: DitherAlgorithm, serpentine: bool) -> Self {
+        Self {
+            algorithm,
+            serpentine,
+        }
     }
 }
 
 impl ImageOperation for Dither {
     fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
         let mut buffer = image.to_luma8();
-        dither(&mut buffer, &BiLevel);
+        dither_with(
+            &mut buffer,
+            &BiLevel,
+            self.algorithm,
+            self.serpentine,
+            255.0,
+        );
         *image = DynamicImage::ImageLuma8(buffer);
 
         Ok(())
     }
 }
+
+/// Reduces the colors of `buffer` to those of `color_map`, using the given dithering algorithm.
+///
+/// Only the color channels are dithered; an alpha channel is mapped without distributing its
+/// error. When scanning `serpentine`, every other row is processed from right to left; this has
+/// no effect on ordered dithering. For ordered dithering, `spread` is the distance between two
+/// adjacent levels of a color channel in the color map.
+pub(crate) fn dither_with<P, M>(
+    buffer: &mut ImageBuffer<P, Vec<u8>>,
+    color_map: &M,
+    algorithm: DitherAlgorithm,
+    serpentine: bool,
+    spread: f32,
+) where
+    P: Pixel<Subpixel = u8> + 'static,
+    M: ColorMap<Color = P>,
+{
+    if buffer.width() == 0 || buffer.height() == 0 {
+        return;
+    }
+
+    match algorithm {
+        // Keep the output of the original dither operations unchanged
+        DitherAlgorithm::FloydSteinberg if !serpentine => dither(buffer, color_map),
+        DitherAlgorithm::FloydSteinberg => diffuse(buffer, color_map, &FLOYD_STEINBERG, true),
+        DitherAlgorithm::Atkinson => diffuse(buffer, color_map, &ATKINSON, serpentine),
+        DitherAlgorithm::JarvisJudiceNinke => {
+            diffuse(buffer, color_map, &JARVIS_JUDICE_NINKE, serpentine)
+        }
+        DitherAlgorithm::Stucki => diffuse(buffer, color_map, &STUCKI, serpentine),
+        DitherAlgorithm::Sierra => diffuse(buffer, color_map, &SIERRA, serpentine),
+        DitherAlgorithm::Bayer2x2 => ordered(buffer, color_map, 2, spread),
+        DitherAlgorithm::Bayer4x4 => ordered(buffer, color_map, 4, spread),
+        DitherAlgorithm::Bayer8x8 => ordered(buffer, color_map, 8, spread),
+    }
+}
+
+/// Approximates the distance between two adjacent levels of a color channel for a palette of
+/// the given number of colors, as if the palette was spread evenly over the color cube.
+pub(crate) fn palette_spread(colors: usize) -> f32 {
+    255.0 / ((colors as f32).cbrt() - 1.0).max(1.0)
+}
+
+/// An error diffusion kernel: the error of a pixel is distributed to the neighbouring pixels at
+/// offset `(dx, dy)` in proportion `weight / divisor`. The offsets are given for a left to
+/// right scan.
+struct Kernel {
+    divisor: f32,
+    weights: &'static [(i64, u32, f32)],
+}
+
+const FLOYD_STEINBERG: Kernel = Kernel {
+    divisor: 16.0,
+    weights: &[(1, 0, 7.0), (-1, 1, 3.0), (0, 1, 5.0), (1, 1, 1.0)],
+};
+
+const ATKINSON: Kernel = Kernel {
+    divisor: 8.0,
+    weights: &[
+        (1, 0, 1.0),
+        (2, 0, 1.0),
+        (-1, 1, 1.0),
+        (0, 1, 1.0),
+        (1, 1, 1.0),
+        (0, 2, 1.0),
+    ],
+};
+
+const JARVIS_JUDICE_NINKE: Kernel = Kernel {
+    divisor: 48.0,
+    weights: &[
+        (1, 0, 7.0),
+        (2, 0, 5.0),
+        (-2, 1, 3.0),
+        (-1, 1, 5.0),
+        (0, 1, 7.0),
+        (1, 1, 5.0),
+        (2, 1, 3.0),
+        (-2, 2, 1.0),
+        (-1, 2, 3.0),
+        (0, 2, 5.0),
+        (1, 2, 3.0),
+        (2, 2, 1.0),
+    ],
+};
+
+const STUCKI: Kernel = Kernel {
+    divisor: 42.0,
+    weights: &[
+        (1, 0, 8.0),
+        (2, 0, 4.0),
+        (-2, 1, 2.0),
+        (-1, 1, 4.0),
+        (0, 1, 8.0),
+        (1, 1, 4.0),
+        (2, 1, 2.0),
+        (-2, 2, 1.0),
+        (-1, 2, 2.0),
+        (0, 2, 4.0),
+        (1, 2, 2.0),
+        (2, 2, 1.0),
+    ],
+};
+
+const SIERRA: Kernel = Kernel {
+    divisor: 32.0,
+    weights: &[
+        (1, 0, 5.0),
+        (2, 0, 3.0),
+        (-2, 1, 2.0),
+        (-1, 1, 4.0),
+        (0, 1, 5.0),
+        (1, 1, 4.0),
+        (2, 1, 2.0),
+        (-1, 2, 2.0),
+        (0, 2, 3.0),
+        (1, 2, 2.0),
+    ],
+};
+
+/// The number of channels which are dithered, i.e. all channels except alpha.
+fn color_channels<P: Pixel>() -> usize {
+    usize::from(P::CHANNEL_COUNT) - usize::from(P::HAS_ALPHA)
+}
+
+fn diffuse<P, M>(
+    buffer: &mut ImageBuffer<P, Vec<u8>>,
+    color_map: &M,
+    kernel: &Kernel,
+    serpentine: bool,
+) where
+    P: Pixel<Subpixel = u8> + 'static,
+    M: ColorMap<Color = P>,
+{
+    let (width, height) = buffer.dimensions();
+    let channels = color_channels::<P>();
+    let index = |x: u32, y: u32| (y as usize * width as usize + x as usize) * channels;
+
+    // The error accumulated for each color channel of each pixel
+    let mut errors = vec![0f32; width as usize * height as usize * channels];
+
+    for y in 0..height {
+        let reverse = serpentine && y % 2 == 1;
+
+        for i in 0..width {
+            let x = if reverse { width - 1 - i } else { i };
+            let offset = index(x, y);
+            let pixel = buffer.get_pixel_mut(x, y);
+
+            let mut wanted = [0f32; 4];
+            for (c, channel) in pixel.channels_mut().iter_mut().take(channels).enumerate() {
+                wanted[c] = (f32::from(*channel) + errors[offset + c]).clamp(0.0, 255.0);
+                *channel = wanted[c].round() as u8;
+            }
+
+            color_map.map_color(pixel);
+
+            for &(dx, dy, weight) in kernel.weights {
+                let nx = if reverse {
+                    x as i64 - dx
+                } else {
+                    x as i64 + dx
+                };
+                let ny = y + dy;
+
+                if nx < 0 || nx >= i64::from(width) || ny >= height {
+                    continue;
+                }
+
+                let neighbour = index(nx as u32, ny);
+                let proportion = weight / kernel.divisor;
+                for (c, &channel) in pixel.channels().iter().take(channels).enumerate() {
+                    errors[neighbour + c] += (wanted[c] - f32::from(channel)) * proportion;
+                }
+            }
+        }
+    }
+}
+
+fn ordered<P, M>(buffer: &mut ImageBuffer<P, Vec<u8>>, color_map: &M, size: u32, spread: f32)
+where
+    P: Pixel<Subpixel = u8> + 'static,
+    M: ColorMap<Color = P>,
+{
+    let channels = color_channels::<P>();
+    let matrix = bayer_matrix(size);
+    let levels = (size * size) as f32;
+
+    for (x, y, pixel) in buffer.enumerate_pixels_mut() {
+        let rank = matrix[((y % size) * size + x % size) as usize] as f32;
+        let threshold = ((rank + 0.5) / levels - 0.5) * spread;
+
+        for channel in pixel.channels_mut().iter_mut().take(channels) {
+            *channel = (f32::from(*channel) + threshold).round().clamp(0.0, 255.0) as u8;
+        }
+
+        color_map.map_color(pixel);
+    }
+}
+
+/// The Bayer threshold matrix of the given size (a power of two), in row-major order, with
+/// values `0..size * size`.
+fn bayer_matrix(size: u32) -> Vec<u32> {
+    if size <= 1 {
+        return vec![0];
+    }
+
+    let half = size / 2;
+    let previous = bayer_matrix(half);
+
+    (0..size * size)
+        .map(|i| {
+            let (x, y) = (i % size, i / size);
+            let base = 4 * previous[((y % half) * half + x % half) as usize];
+            base + [0, 2, 3, 1][((y / half) * 2 + x / half) as usize]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bayer_matrix_4x4() {
+        let expected = vec![0, 8, 2, 10, 12, 4, 14, 6, 3, 11, 1, 9, 15, 7, 13, 5];
+
+        assert_eq!(bayer_matrix(4), expected);
+    }
+
+    #[test]
+    fn bayer_matrix_8x8_is_a_permutation() {
+        let mut matrix = bayer_matrix(8);
+        matrix.sort_unstable();
+
+        assert_eq!(matrix, (0..64).collect::<Vec<_>>());
+    }
+}
@@ -1,8 +1,9 @@
 use crate::errors::SicImageEngineError;
 use crate::operations::ImageOperation;
+use crate::operations::dither::{dither_with, palette_spread};
+use crate::wrapper::dither_algorithm::DitherAlgorithm;
 use sic_core::color_quant::NeuQuant;
 use sic_core::image::DynamicImage;
-use sic_core::image::imageops::colorops::dither;
 
 const MIN_COLORS: u32 = 64;
 const SAMPLE_FACTOR_RANGE: std::ops::RangeInclusive<u32> = 1..=30;
@@ -10,13 +11,22 @@ const SAMPLE_FACTOR_RANGE: std::ops::RangeInclusive<u32> = 1..=30;
 pub struct DitherQuant {
     colors: u32,
     sample_factor: u32,
+    algorithm: DitherAlgorithm,
+    serpentine: bool,
 }
 
 impl DitherQuant {
-    pub fn new(colors: u32, sample_factor: u32) -> Self {
+    pub fn new(
+        colors: u32,
+        sample_factor: u32,
+        algorithm: DitherAlgorithm,
+        serpentine: bool,
+    ) -> Self {
         Self {
             colors,
             sample_factor,
+            algorithm,
+            serpentine,
         }
     }
 }
@@ -42,7 +52,8 @@ impl ImageOperation for DitherQuant {
             self.colors as usize,
             buffer.as_raw(),
         );
-        dither(&mut buffer, &quant);
+        let spread = palette_spread(self.colors as usize);
+        dither_with(&mut buffer, &quant, self.algorithm, self.serpentine, spread);
         *image = DynamicImage::ImageRgba8(buffer);
 
         Ok(())
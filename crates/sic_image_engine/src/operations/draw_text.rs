@@ -24,6 +24,10 @@ impl ImageOperation for DrawText<'_> {
         match image {
             SicImage::Static(image) => self.apply_to_frame(image),
             SicImage::Animated(image) => draw_text_animated_image(image.frames_mut(), self.text),
+            SicImage::Indexed(_) => {
+                image.expand_indexed();
+                self.apply_operation(image)
+            }
         }
     }
 }
@@ -0,0 +1,42 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use sic_core::SicImage;
+use sic_core::image::DynamicImage;
+
+/// Removes every n-th frame of an animated image, counting from the first frame, e.g. an interval
+/// of 3 removes the 3rd, 6th, 9th, ... frame. Static images are left as is.
+pub struct DropFrames {
+    interval: u32,
+}
+
+impl DropFrames {
+    pub fn new(interval: u32) -> Self {
+        Self { interval }
+    }
+}
+
+impl ImageOperation for DropFrames {
+    fn apply_to_frame(&self, _image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        Ok(())
+    }
+
+    fn apply_operation(&self, image: &mut SicImage) -> Result<(), SicImageEngineError> {
+        if self.interval < 2 {
+            return Err(SicImageEngineError::DropFramesIntervalTooSmall(
+                self.interval,
+            ));
+        }
+
+        if let SicImage::Animated(animated) = image {
+            let interval = self.interval as usize;
+            let mut position = 0;
+
+            animated.frames_vec_mut().retain(|_| {
+                position += 1;
+                position % interval != 0
+            });
+        }
+
+        Ok(())
+    }
+}
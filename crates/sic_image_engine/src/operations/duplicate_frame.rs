@@ -0,0 +1,43 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use sic_core::image::DynamicImage;
+use sic_core::{SicCoreError, SicImage};
+
+/// Inserts copies of a single frame of an animated image, selected by its zero-based index,
+/// directly after that frame. Static images are left as is.
+pub struct DuplicateFrame {
+    index: u32,
+    copies: u32,
+}
+
+impl DuplicateFrame {
+    pub fn new(index: u32, copies: u32) -> Self {
+        Self { index, copies }
+    }
+}
+
+impl ImageOperation for DuplicateFrame {
+    fn apply_to_frame(&self, _image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        Ok(())
+    }
+
+    fn apply_operation(&self, image: &mut SicImage) -> Result<(), SicImageEngineError> {
+        if let SicImage::Animated(animated) = image {
+            let frames = animated.frames_vec_mut();
+            let len = frames.len();
+            let index = self.index as usize;
+
+            let frame = frames
+                .get(index)
+                .cloned()
+                .ok_or(SicCoreError::InvalidFrameIndex { index, len })?;
+
+            frames.splice(
+                index + 1..index + 1,
+                std::iter::repeat_n(frame, self.copies as usize),
+            );
+        }
+
+        Ok(())
+    }
+}
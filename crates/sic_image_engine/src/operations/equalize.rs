@@ -0,0 +1,63 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::operations::histogram::{Histogram, bins_for};
+use crate::operations::pixel_map::{luma, map_rgb};
+use crate::wrapper::equalize::EqualizeMode;
+use sic_core::image::DynamicImage;
+
+/// Spreads the values of an image over the full range, such that each value occurs about equally
+/// often (global histogram equalization). The alpha channel is left unchanged.
+///
+/// Images with more than 8 bits per channel are equalized with a histogram of 65536 bins, so their
+/// precision is preserved.
+pub struct Equalize {
+    mode: EqualizeMode,
+}
+
+impl Equalize {
+    pub fn new(mode: EqualizeMode) -> Self {
+        Self { mode }
+    }
+}
+
+impl ImageOperation for Equalize {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        let bins = bins_for(image.color());
+        let pixels = image.to_rgba32f();
+
+        match self.mode {
+            EqualizeMode::Luma => {
+                let mut histogram = Histogram::new(bins);
+                for pixel in pixels.pixels() {
+                    histogram.add(luma([pixel[0], pixel[1], pixel[2]]));
+                }
+
+                let lut = histogram.equalization_lut();
+                map_rgb(image, |rgb| {
+                    let l = luma(rgb);
+                    let shift = lut.evaluate(l) - l;
+                    rgb.map(|c| c + shift)
+                });
+            }
+            EqualizeMode::Channels => {
+                let mut histograms = [(); 3].map(|_| Histogram::new(bins));
+                for pixel in pixels.pixels() {
+                    for (histogram, value) in histograms.iter_mut().zip(pixel.0) {
+                        histogram.add(value);
+                    }
+                }
+
+                let luts = histograms.map(|histogram| histogram.equalization_lut());
+                map_rgb(image, |[r, g, b]| {
+                    [
+                        luts[0].evaluate(r),
+                        luts[1].evaluate(g),
+                        luts[2].evaluate(b),
+                    ]
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,27 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::operations::morphology::{apply_per_channel, mask};
+use crate::wrapper::morphology::MorphologyInputs;
+use sic_core::image::DynamicImage;
+use sic_core::imageproc::morphology::grayscale_erode;
+
+/// Replaces each pixel by the minimum of its neighbourhood, as defined by the structuring element.
+/// Shrinks bright regions, and removes bright details smaller than the structuring element.
+pub struct Erode<'inputs> {
+    inputs: &'inputs MorphologyInputs,
+}
+
+impl<'inputs> Erode<'inputs> {
+    pub fn new(inputs: &'inputs MorphologyInputs) -> Self {
+        Self { inputs }
+    }
+}
+
+impl ImageOperation for Erode<'_> {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        let mask = mask(self.inputs);
+        apply_per_channel(image, |plane| grayscale_erode(plane, &mask));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::operations::pixel_map::{linear_to_srgb, map_channels, srgb_to_linear};
+use sic_core::image::DynamicImage;
+
+/// Adjusts the exposure by a number of stops, where each stop doubles (or for negative stops,
+/// halves) the amount of light. The image is assumed to be sRGB encoded; the exposure is adjusted
+/// in linear light.
+pub struct Exposure {
+    stops: f32,
+}
+
+impl Exposure {
+    pub fn new(stops: f32) -> Self {
+        Self { stops }
+    }
+}
+
+impl ImageOperation for Exposure {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        let multiplier = self.stops.exp2();
+        map_channels(image, |c| {
+            linear_to_srgb((srgb_to_linear(c) * multiplier).min(1.0))
+        });
+
+        Ok(())
+    }
+}
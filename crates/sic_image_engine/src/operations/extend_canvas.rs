@@ -0,0 +1,40 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::wrapper::extend_canvas::ExtendCanvasInputs;
+use sic_core::image::{DynamicImage, GenericImageView, Rgba, RgbaImage, imageops};
+
+pub struct ExtendCanvas<'inputs> {
+    inputs: &'inputs ExtendCanvasInputs,
+}
+
+impl<'inputs> ExtendCanvas<'inputs> {
+    pub fn new(inputs: &'inputs ExtendCanvasInputs) -> Self {
+        Self { inputs }
+    }
+}
+
+impl ImageOperation for ExtendCanvas<'_> {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        let canvas = self.inputs.dimensions();
+        let position = self.inputs.gravity().position(canvas, image.dimensions());
+
+        place_on_canvas(image, canvas, position, self.inputs.fill());
+
+        Ok(())
+    }
+}
+
+/// Replaces the image by a canvas of the given dimensions, filled with the `fill` color, on which
+/// the image is placed with its top-left corner at `position`. Parts of the image which fall
+/// outside the canvas are cut off.
+pub(crate) fn place_on_canvas(
+    image: &mut DynamicImage,
+    (width, height): (u32, u32),
+    (x, y): (i64, i64),
+    fill: Rgba<u8>,
+) {
+    let mut canvas = RgbaImage::from_pixel(width, height, fill);
+    imageops::replace(&mut canvas, &image.to_rgba8(), x, y);
+
+    *image = DynamicImage::ImageRgba8(canvas);
+}
@@ -0,0 +1,26 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::operations::channels::channel_to_gray;
+use crate::wrapper::channel::{Channel, ChannelOrLuma};
+use sic_core::image::DynamicImage;
+
+/// Replaces the image by a grayscale image of its alpha channel. Images without an alpha channel
+/// result in a white image, since they're fully opaque.
+///
+/// 8-bit images result in an 8-bit grayscale image, and all other images in a 16-bit grayscale
+/// image.
+pub struct ExtractAlpha;
+
+impl ExtractAlpha {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl ImageOperation for ExtractAlpha {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        *image = channel_to_gray(image, ChannelOrLuma::Channel(Channel::Alpha));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,27 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::operations::channels::channel_to_gray;
+use crate::wrapper::channel::ChannelOrLuma;
+use sic_core::image::DynamicImage;
+
+/// Replaces the image by a grayscale image of one of its channels, or of its luma.
+///
+/// 8-bit images result in an 8-bit grayscale image, and all other images in a 16-bit grayscale
+/// image.
+pub struct ExtractChannel {
+    channel: ChannelOrLuma,
+}
+
+impl ExtractChannel {
+    pub fn new(channel: ChannelOrLuma) -> Self {
+        Self { channel }
+    }
+}
+
+impl ImageOperation for ExtractChannel {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        *image = channel_to_gray(image, self.channel);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,126 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::operations::pixel_map::into_color_type;
+use crate::wrapper::filter::{EdgeMode, FilterInputs, Kernel};
+use rayon::prelude::*;
+use sic_core::image::{DynamicImage, Rgba32FImage};
+
+/// Convolves the color channels of an image with a kernel of arbitrary (odd) size. The alpha
+/// channel is left unchanged.
+pub struct Filter<'inputs> {
+    inputs: &'inputs FilterInputs,
+}
+
+impl<'inputs> Filter<'inputs> {
+    pub fn new(inputs: &'inputs FilterInputs) -> Self {
+        Self { inputs }
+    }
+}
+
+impl ImageOperation for Filter<'_> {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        let color = image.color();
+        let mut buffer = image.to_rgba32f();
+
+        let planes = RgbPlanes::from_image(&buffer);
+        let convolved = match self.inputs.kernel() {
+            Kernel::Full {
+                width,
+                height,
+                values,
+            } => planes.correlate(values, (*width, *height), self.inputs.edge_mode()),
+            Kernel::Separable { row, column } => planes
+                .correlate(row, (row.len() as u32, 1), self.inputs.edge_mode())
+                .correlate(column, (1, column.len() as u32), self.inputs.edge_mode()),
+        };
+
+        let divisor = self.inputs.divisor();
+        let bias = self.inputs.bias();
+
+        for (pixel, rgb) in buffer.pixels_mut().zip(convolved.pixels) {
+            for (channel, value) in pixel.0.iter_mut().zip(rgb) {
+                *channel = (value / divisor + bias).clamp(0.0, 1.0);
+            }
+        }
+
+        *image = into_color_type(DynamicImage::ImageRgba32F(buffer), color);
+
+        Ok(())
+    }
+}
+
+/// The color channels of an image, as 32-bit floats.
+pub(crate) struct RgbPlanes {
+    width: u32,
+    height: u32,
+    pixels: Vec<[f32; 3]>,
+}
+
+impl RgbPlanes {
+    pub(crate) fn from_image(image: &Rgba32FImage) -> Self {
+        Self {
+            width: image.width(),
+            height: image.height(),
+            pixels: image.pixels().map(|p| [p[0], p[1], p[2]]).collect(),
+        }
+    }
+
+    /// Computes the weighted sum of the neighbourhood of each pixel, where the weights are given
+    /// by a (centered) `kernel` of `kernel_width` by `kernel_height` values in row-major order.
+    /// The kernel is not flipped, i.e. it is applied as a cross-correlation.
+    pub(crate) fn correlate(
+        &self,
+        kernel: &[f32],
+        (kernel_width, kernel_height): (u32, u32),
+        edge_mode: EdgeMode,
+    ) -> Self {
+        let (width, height) = (self.width, self.height);
+        let mut pixels = vec![[0.0; 3]; self.pixels.len()];
+
+        if width == 0 || height == 0 {
+            return Self {
+                width,
+                height,
+                pixels,
+            };
+        }
+
+        let center_x = i64::from(kernel_width / 2);
+        let center_y = i64::from(kernel_height / 2);
+
+        pixels
+            .par_chunks_exact_mut(width as usize)
+            .enumerate()
+            .for_each(|(y, row)| {
+                for (x, pixel) in row.iter_mut().enumerate() {
+                    let mut sum = [0.0; 3];
+
+                    for ky in 0..kernel_height {
+                        let sy = edge_mode.resolve(y as i64 + i64::from(ky) - center_y, height);
+
+                        for kx in 0..kernel_width {
+                            let weight = kernel[(ky * kernel_width + kx) as usize];
+                            if weight == 0.0 {
+                                continue;
+                            }
+
+                            let sx = edge_mode.resolve(x as i64 + i64::from(kx) - center_x, width);
+                            let sample = self.pixels[(sy * width + sx) as usize];
+
+                            for (channel, value) in sum.iter_mut().zip(sample) {
+                                *channel += weight * value;
+                            }
+                        }
+                    }
+
+                    *pixel = sum;
+                }
+            });
+
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::operations::pixel_map::{map_rgba, remove_alpha};
+use sic_core::image::{DynamicImage, Rgba};
+
+/// Composites the image over a background color. If the background color is fully opaque, the
+/// alpha channel is removed afterwards, which makes the image suitable for formats without alpha
+/// channel support, such as JPEG.
+pub struct Flatten {
+    background: Rgba<u8>,
+}
+
+impl Flatten {
+    pub fn new(background: Rgba<u8>) -> Self {
+        Self { background }
+    }
+}
+
+impl ImageOperation for Flatten {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        let [br, bg, bb, ba] = self.background.0.map(|c| f32::from(c) / 255.0);
+
+        map_rgba(image, |[r, g, b, a]| {
+            let alpha = a + ba * (1.0 - a);
+
+            if alpha <= 0.0 {
+                return [0.0; 4];
+            }
+
+            let over =
+                |source: f32, backdrop: f32| (source * a + backdrop * ba * (1.0 - a)) / alpha;
+
+            [over(r, br), over(g, bg), over(b, bb), alpha]
+        });
+
+        if self.background[3] == u8::MAX {
+            remove_alpha(image);
+        }
+
+        Ok(())
+    }
+}
@@ -23,6 +23,10 @@ impl ImageOperation for FlipHorizontal {
         match image {
             SicImage::Static(image) => self.apply_to_frame(image)?,
             SicImage::Animated(image) => flip_horizontal_animated_image(image.frames_mut()),
+            SicImage::Indexed(_) => {
+                image.expand_indexed();
+                self.apply_operation(image)?
+            }
         }
 
         Ok(())
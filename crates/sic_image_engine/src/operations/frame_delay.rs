@@ -0,0 +1,14 @@
+use sic_core::image::{Delay, Frame};
+use std::mem;
+
+/// Replaces the delay of a frame, while keeping its buffer and offsets.
+pub(crate) fn set_delay(frame: &mut Frame, delay: Delay) {
+    let (left, top) = (frame.left(), frame.top());
+    *frame = Frame::from_parts(mem::take(frame.buffer_mut()), left, top, delay);
+}
+
+/// The delay of a frame in milliseconds.
+pub(crate) fn delay_ms(frame: &Frame) -> f64 {
+    let (numerator, denominator) = frame.delay().numer_denom_ms();
+    f64::from(numerator) / f64::from(denominator.max(1))
+}
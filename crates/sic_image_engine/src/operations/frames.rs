@@ -0,0 +1,41 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::wrapper::frame_range::FrameRange;
+use sic_core::SicImage;
+use sic_core::image::DynamicImage;
+
+/// Keeps the frames of an animated image within a range, and removes all other frames.
+/// Static images are left as is.
+pub struct Frames {
+    range: FrameRange,
+}
+
+impl Frames {
+    pub fn new(range: FrameRange) -> Self {
+        Self { range }
+    }
+}
+
+impl ImageOperation for Frames {
+    fn apply_to_frame(&self, _image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        Ok(())
+    }
+
+    fn apply_operation(&self, image: &mut SicImage) -> Result<(), SicImageEngineError> {
+        if let SicImage::Animated(animated) = image {
+            let frames = animated.frames_vec_mut();
+            let range =
+                self.range
+                    .resolve(frames.len())
+                    .ok_or(SicImageEngineError::FrameRangeEmpty(
+                        self.range,
+                        frames.len(),
+                    ))?;
+
+            frames.truncate(range.end);
+            frames.drain(..range.start);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::operations::pixel_map::map_channels;
+use sic_core::image::DynamicImage;
+
+/// Applies gamma correction, i.e. `out = in ^ (1 / gamma)`. A gamma larger than `1.0` brightens
+/// the mid-tones, while a gamma smaller than `1.0` darkens them.
+pub struct Gamma {
+    gamma: f32,
+}
+
+impl Gamma {
+    pub fn new(gamma: f32) -> Self {
+        Self { gamma }
+    }
+}
+
+impl ImageOperation for Gamma {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        if !(self.gamma > 0.0 && self.gamma.is_finite()) {
+            return Err(SicImageEngineError::GammaNotPositive(self.gamma));
+        }
+
+        let exponent = self.gamma.recip();
+        map_channels(image, |c| c.powf(exponent));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,191 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::operations::pixel_map::{linear_to_srgb, srgb_to_linear};
+use crate::wrapper::gradient::{GradientColorSpace, GradientShape};
+use crate::wrapper::gradient_fn::blend_static_image;
+use sic_core::image::{DynamicImage, GenericImageView, Rgba, RgbaImage};
+
+/// Draws a linear, radial or conic gradient with any number of color stops over the image.
+pub struct Gradient<'stops> {
+    shape: GradientShape,
+    stops: &'stops [(Rgba<u8>, f32)],
+    color_space: GradientColorSpace,
+}
+
+impl<'stops> Gradient<'stops> {
+    /// Takes the color stops with their resolved positions, ordered by position.
+    pub fn new(
+        shape: GradientShape,
+        stops: &'stops [(Rgba<u8>, f32)],
+        color_space: GradientColorSpace,
+    ) -> Self {
+        Self {
+            shape,
+            stops,
+            color_space,
+        }
+    }
+}
+
+impl ImageOperation for Gradient<'_> {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        let (width, height) = image.dimensions();
+        let layer = gradient_layer(width, height, self.shape, self.stops, self.color_space);
+        blend_static_image(image, &layer);
+
+        Ok(())
+    }
+}
+
+fn gradient_layer(
+    width: u32,
+    height: u32,
+    shape: GradientShape,
+    stops: &[(Rgba<u8>, f32)],
+    color_space: GradientColorSpace,
+) -> RgbaImage {
+    let stops = stops
+        .iter()
+        .map(|&(color, position)| (to_color_space(color, color_space), position))
+        .collect::<Vec<_>>();
+
+    let position_of = position_fn(width, height, shape);
+
+    RgbaImage::from_fn(width, height, |x, y| {
+        let color = color_at(&stops, position_of(x as f32 + 0.5, y as f32 + 0.5));
+        from_color_space(color, color_space)
+    })
+}
+
+/// Returns a function which maps the center of a pixel to a position on the gradient, where `0.0`
+/// is the start and `1.0` is the end of the gradient.
+fn position_fn(width: u32, height: u32, shape: GradientShape) -> Box<dyn Fn(f32, f32) -> f32> {
+    let (width, height) = (width as f32, height as f32);
+    let (center_x, center_y) = (width / 2.0, height / 2.0);
+
+    match shape {
+        GradientShape::Linear { angle } => {
+            let (sin, cos) = angle.to_radians().sin_cos();
+            // Like CSS, the gradient line is as long as needed for its start and end to touch the
+            // corners of the image.
+            let length = (width * sin).abs() + (height * cos).abs();
+
+            Box::new(move |x, y| {
+                ((x - center_x) * sin - (y - center_y) * cos) / length.max(f32::EPSILON) + 0.5
+            })
+        }
+        GradientShape::Radial => {
+            let radius = center_x.hypot(center_y);
+
+            Box::new(move |x, y| (x - center_x).hypot(y - center_y) / radius.max(f32::EPSILON))
+        }
+        GradientShape::Conic { angle } => Box::new(move |x, y| {
+            let degrees = (x - center_x).atan2(center_y - y).to_degrees();
+            (degrees - angle).rem_euclid(360.0) / 360.0
+        }),
+    }
+}
+
+/// Interpolates between the two color stops which surround the given position.
+fn color_at(stops: &[([f32; 4], f32)], position: f32) -> [f32; 4] {
+    let (first, last) = (stops[0], stops[stops.len() - 1]);
+
+    if position <= first.1 {
+        return first.0;
+    } else if position >= last.1 {
+        return last.0;
+    }
+
+    let next = stops.partition_point(|&(_, stop)| stop <= position);
+    let ((from, start), (to, end)) = (stops[next - 1], stops[next]);
+    let factor = (position - start) / (end - start);
+
+    std::array::from_fn(|i| from[i] + (to[i] - from[i]) * factor)
+}
+
+/// Converts a color to the given color space, with its channels premultiplied by alpha, so
+/// transparent stops don't tint their neighbours.
+fn to_color_space(color: Rgba<u8>, color_space: GradientColorSpace) -> [f32; 4] {
+    let [r, g, b, a] = color.0.map(|c| f32::from(c) / 255.0);
+
+    let [x, y, z] = match color_space {
+        GradientColorSpace::Srgb => [r, g, b],
+        GradientColorSpace::Linear => [r, g, b].map(srgb_to_linear),
+        GradientColorSpace::Oklab => linear_srgb_to_oklab([r, g, b].map(srgb_to_linear)),
+    };
+
+    [x * a, y * a, z * a, a]
+}
+
+fn from_color_space([x, y, z, a]: [f32; 4], color_space: GradientColorSpace) -> Rgba<u8> {
+    if a <= 0.0 {
+        return Rgba([0, 0, 0, 0]);
+    }
+
+    let values = [x / a, y / a, z / a];
+
+    let [r, g, b] = match color_space {
+        GradientColorSpace::Srgb => values,
+        GradientColorSpace::Linear => values.map(|c| linear_to_srgb(c.clamp(0.0, 1.0))),
+        GradientColorSpace::Oklab => {
+            oklab_to_linear_srgb(values).map(|c| linear_to_srgb(c.clamp(0.0, 1.0)))
+        }
+    };
+
+    Rgba([r, g, b, a].map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8))
+}
+
+// See https://bottosson.github.io/posts/oklab/
+fn linear_srgb_to_oklab([r, g, b]: [f32; 3]) -> [f32; 3] {
+    let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+    let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+    let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+
+    [
+        0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+        1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+        0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+    ]
+}
+
+fn oklab_to_linear_srgb([lightness, a, b]: [f32; 3]) -> [f32; 3] {
+    let l = (lightness + 0.396_337_78 * a + 0.215_803_76 * b).powi(3);
+    let m = (lightness - 0.105_561_346 * a - 0.063_854_17 * b).powi(3);
+    let s = (lightness - 0.089_484_18 * a - 1.291_485_5 * b).powi(3);
+
+    [
+        4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+        -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+        -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn oklab_round_trip() {
+        for color in [[1.0, 0.0, 0.0], [0.2, 0.5, 0.8], [1.0, 1.0, 1.0]] {
+            let round_trip = oklab_to_linear_srgb(linear_srgb_to_oklab(color));
+
+            for (expected, actual) in color.iter().zip(round_trip) {
+                assert!((expected - actual).abs() < 1e-4);
+            }
+        }
+    }
+
+    #[test]
+    fn color_at_hard_stop() {
+        let stops = [
+            ([0.0; 4], 0.0),
+            ([0.0; 4], 0.5),
+            ([1.0; 4], 0.5),
+            ([1.0; 4], 1.0),
+        ];
+
+        assert_eq!(color_at(&stops, 0.49), [0.0; 4]);
+        assert_eq!(color_at(&stops, 0.5), [1.0; 4]);
+        assert_eq!(color_at(&stops, 2.0), [1.0; 4]);
+    }
+}
@@ -0,0 +1,166 @@
+//! Helpers for the histogram based operations: equalize and clahe.
+
+use sic_core::image::ColorType;
+
+/// The number of histogram bins which covers each distinct value of an image with the given color
+/// type: 256 for 8-bit images, and 65536 for images with a higher bit depth.
+pub(crate) fn bins_for(color: ColorType) -> usize {
+    if color.bytes_per_pixel() / color.channel_count() == 1 {
+        1 << 8
+    } else {
+        1 << 16
+    }
+}
+
+/// A histogram of values normalized to the range `[0, 1]`.
+pub(crate) struct Histogram {
+    counts: Vec<u32>,
+}
+
+impl Histogram {
+    pub(crate) fn new(bins: usize) -> Self {
+        Self {
+            counts: vec![0; bins],
+        }
+    }
+
+    pub(crate) fn add(&mut self, value: f32) {
+        let last = self.counts.len() - 1;
+        let bin = (value.clamp(0.0, 1.0) * last as f32).round() as usize;
+
+        self.counts[bin] += 1;
+    }
+
+    /// Limits the height of each bin to `limit`, and redistributes the excess evenly over all
+    /// bins.
+    pub(crate) fn clip(&mut self, limit: u32) {
+        let excess: u32 = self
+            .counts
+            .iter_mut()
+            .map(|count| {
+                let clipped = count.saturating_sub(limit);
+                *count -= clipped;
+                clipped
+            })
+            .sum();
+
+        let bins = self.counts.len() as u32;
+        let (increment, remainder) = (excess / bins, excess % bins);
+
+        for count in self.counts.iter_mut() {
+            *count += increment;
+        }
+
+        // Spread the remainder evenly over the range
+        if let Some(step) = bins.checked_div(remainder) {
+            let spread = self.counts.iter_mut().step_by(step as usize);
+            for count in spread.take(remainder as usize) {
+                *count += 1;
+            }
+        }
+    }
+
+    /// The mapping which equalizes this histogram, stretched so the least frequent value which
+    /// occurs maps to `0`. If all values are the same, the mapping is the identity.
+    pub(crate) fn equalization_lut(&self) -> Lut {
+        let total: u32 = self.counts.iter().sum();
+        let cumulative = self.cumulative();
+        let minimum = cumulative.iter().copied().find(|&c| c > 0).unwrap_or(0);
+
+        if total == minimum {
+            return Lut::identity(self.counts.len());
+        }
+
+        let range = (total - minimum) as f32;
+        Lut(cumulative
+            .iter()
+            .map(|&c| c.saturating_sub(minimum) as f32 / range)
+            .collect())
+    }
+
+    /// The cumulative distribution function of this histogram.
+    pub(crate) fn cdf_lut(&self) -> Lut {
+        let total = self.counts.iter().sum::<u32>().max(1) as f32;
+
+        Lut(self
+            .cumulative()
+            .iter()
+            .map(|&c| c as f32 / total)
+            .collect())
+    }
+
+    fn cumulative(&self) -> Vec<u32> {
+        self.counts
+            .iter()
+            .scan(0, |sum, &count| {
+                *sum += count;
+                Some(*sum)
+            })
+            .collect()
+    }
+}
+
+/// A lookup table which maps values in the range `[0, 1]` to values in the range `[0, 1]`, given
+/// by evenly spaced samples.
+pub(crate) struct Lut(Vec<f32>);
+
+impl Lut {
+    fn identity(samples: usize) -> Self {
+        let last = (samples - 1) as f32;
+        Self((0..samples).map(|i| i as f32 / last).collect())
+    }
+
+    /// Linearly interpolates between the two nearest samples.
+    pub(crate) fn evaluate(&self, value: f32) -> f32 {
+        let last = self.0.len() - 1;
+        let position = value.clamp(0.0, 1.0) * last as f32;
+
+        let lower = (position.floor() as usize).min(last);
+        let upper = (lower + 1).min(last);
+        let fraction = position - lower as f32;
+
+        self.0[lower] + (self.0[upper] - self.0[lower]) * fraction
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clip_preserves_total() {
+        let mut histogram = Histogram::new(4);
+        for value in [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0] {
+            histogram.add(value);
+        }
+
+        histogram.clip(2);
+
+        assert_eq!(histogram.counts.iter().sum::<u32>(), 8);
+        assert_eq!(histogram.counts, vec![4, 1, 1, 2]);
+    }
+
+    #[test]
+    fn equalization_lut() {
+        let mut histogram = Histogram::new(4);
+        for value in [1.0 / 3.0, 1.0 / 3.0, 2.0 / 3.0, 2.0 / 3.0] {
+            histogram.add(value);
+        }
+
+        let lut = histogram.equalization_lut();
+
+        assert_eq!(lut.evaluate(1.0 / 3.0), 0.0);
+        assert_eq!(lut.evaluate(2.0 / 3.0), 1.0);
+        assert_eq!(lut.evaluate(0.5), 0.5);
+    }
+
+    #[test]
+    fn equalization_lut_of_single_value() {
+        let mut histogram = Histogram::new(256);
+        histogram.add(0.5);
+
+        let lut = histogram.equalization_lut();
+
+        assert!((lut.evaluate(0.25) - 0.25).abs() < 1e-6);
+    }
+}
@@ -32,6 +32,10 @@ impl ImageOperation for HorizontalGradient {
                 self.colors,
                 imageops::horizontal_gradient,
             ),
+            SicImage::Indexed(_) => {
+                image.expand_indexed();
+                self.apply_operation(image)?
+            }
         }
 
         Ok(())
@@ -1,7 +1,10 @@
 use crate::errors::SicImageEngineError;
-use crate::operations::ImageOperation;
-use sic_core::image::DynamicImage;
+use crate::operations::{ImageOperation, apply_operation_to_frames};
 use sic_core::image::imageops::colorops::{BiLevel, index_colors};
+use sic_core::image::{DynamicImage, Rgba};
+use sic_core::{IndexedImage, SicImage};
+
+const PALETTE: [Rgba<u8>; 2] = [Rgba([0, 0, 0, 255]), Rgba([255, 255, 255, 255])];
 
 pub struct IndexColors;
 
@@ -9,15 +12,35 @@ impl IndexColors {
     pub fn new() -> Self {
         Self {}
     }
+
+    fn index(&self, image: &DynamicImage) -> Result<IndexedImage, SicImageEngineError> {
+        let buffer = image.to_luma8();
+        let indices = index_colors(&buffer, &BiLevel);
+
+        Ok(IndexedImage::try_new(indices, PALETTE.to_vec())?)
+    }
 }
 
 impl ImageOperation for IndexColors {
     fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
-        let buffer = image.to_luma8();
-        // The result holds the palette index of each pixel, not the palette color itself.
-        let indices = index_colors(&buffer, &BiLevel);
-        *image = DynamicImage::ImageLuma8(indices);
+        // Frames of an animated image can't hold palette indices, so they are expanded to the
+        // palette colors instead.
+        *image = self.index(image)?.to_dynamic_image();
 
         Ok(())
     }
+
+    fn apply_operation(&self, image: &mut SicImage) -> Result<(), SicImageEngineError> {
+        match image {
+            SicImage::Static(frame) => {
+                *image = SicImage::Indexed(self.index(frame)?);
+                Ok(())
+            }
+            SicImage::Animated(animated) => apply_operation_to_frames(self, animated.frames_mut()),
+            SicImage::Indexed(_) => {
+                image.expand_indexed();
+                self.apply_operation(image)
+            }
+        }
+    }
 }
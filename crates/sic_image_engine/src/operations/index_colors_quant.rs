@@ -1,8 +1,9 @@
 use crate::errors::SicImageEngineError;
-use crate::operations::ImageOperation;
+use crate::operations::{ImageOperation, apply_operation_to_frames};
 use sic_core::color_quant::NeuQuant;
-use sic_core::image::DynamicImage;
 use sic_core::image::imageops::colorops::index_colors;
+use sic_core::image::{DynamicImage, Rgba};
+use sic_core::{IndexedImage, MAX_PALETTE_COLORS, SicImage};
 
 const MIN_COLORS: u32 = 64;
 const SAMPLE_FACTOR_RANGE: std::ops::RangeInclusive<u32> = 1..=30;
@@ -19,11 +20,9 @@ impl IndexColorsQuant {
             sample_factor,
         }
     }
-}
 
-impl ImageOperation for IndexColorsQuant {
-    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
-        if self.colors < MIN_COLORS {
+    fn index(&self, image: &DynamicImage) -> Result<IndexedImage, SicImageEngineError> {
+        if self.colors < MIN_COLORS || self.colors as usize > MAX_PALETTE_COLORS {
             return Err(SicImageEngineError::IndexColorsQuantColorsOutOfRange(
                 self.colors,
             ));
@@ -42,10 +41,37 @@ impl ImageOperation for IndexColorsQuant {
             self.colors as usize,
             buffer.as_raw(),
         );
-        // The result holds the palette index of each pixel, not the palette color itself.
         let indices = index_colors(&buffer, &quant);
-        *image = DynamicImage::ImageLuma8(indices);
+        let palette = quant
+            .color_map_rgba()
+            .chunks_exact(4)
+            .map(|color| Rgba([color[0], color[1], color[2], color[3]]))
+            .collect();
+
+        Ok(IndexedImage::try_new(indices, palette)?)
+    }
+}
+
+impl ImageOperation for IndexColorsQuant {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        // Frames of an animated image can't hold palette indices, so they are expanded to the
+        // palette colors instead.
+        *image = self.index(image)?.to_dynamic_image();
 
         Ok(())
     }
+
+    fn apply_operation(&self, image: &mut SicImage) -> Result<(), SicImageEngineError> {
+        match image {
+            SicImage::Static(frame) => {
+                *image = SicImage::Indexed(self.index(frame)?);
+                Ok(())
+            }
+            SicImage::Animated(animated) => apply_operation_to_frames(self, animated.frames_mut()),
+            SicImage::Indexed(_) => {
+                image.expand_indexed();
+                self.apply_operation(image)
+            }
+        }
+    }
 }
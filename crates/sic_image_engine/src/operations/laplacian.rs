@@ -0,0 +1,24 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::operations::sobel::saturate_to_luma8;
+use sic_core::image::DynamicImage;
+use sic_core::imageproc;
+
+/// Applies a 3x3 Laplacian filter to the intensity of each pixel. The result is a gray image of the
+/// absolute filter responses, where responses larger than the maximum channel value are clamped.
+pub struct Laplacian;
+
+impl Laplacian {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl ImageOperation for Laplacian {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        let responses = imageproc::filter::laplacian_filter(&image.to_luma8());
+        *image = DynamicImage::ImageLuma8(saturate_to_luma8(&responses));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::operations::pixel_map::map_channels;
+use crate::wrapper::levels::LevelsInputs;
+use sic_core::image::DynamicImage;
+
+/// Maps the input range onto the output range, with gamma correction applied in between.
+/// Values outside the input range are clipped. If the output black point is larger than the
+/// output white point, the image is inverted.
+pub struct Levels<'inputs> {
+    inputs: &'inputs LevelsInputs,
+}
+
+impl<'inputs> Levels<'inputs> {
+    pub fn new(inputs: &'inputs LevelsInputs) -> Self {
+        Self { inputs }
+    }
+}
+
+impl ImageOperation for Levels<'_> {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        let (input_black, input_white) = self.inputs.input_range();
+        let (output_black, output_white) = self.inputs.output_range();
+        let gamma = self.inputs.gamma();
+
+        if input_black >= input_white {
+            return Err(SicImageEngineError::LevelsInvalidInputRange(
+                input_black,
+                input_white,
+            ));
+        }
+
+        if !(gamma > 0.0 && gamma.is_finite()) {
+            return Err(SicImageEngineError::GammaNotPositive(gamma));
+        }
+
+        let unit = |value: u8| f32::from(value) / f32::from(u8::MAX);
+        let (input_black, input_white) = (unit(input_black), unit(input_white));
+        let (output_black, output_white) = (unit(output_black), unit(output_white));
+        let exponent = gamma.recip();
+
+        map_channels(image, |c| {
+            let c = ((c - input_black) / (input_white - input_black)).clamp(0.0, 1.0);
+            output_black + c.powf(exponent) * (output_white - output_black)
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::operations::pixel_map::into_color_type;
+use sic_core::image::DynamicImage;
+use sic_core::imageproc::filter::median_filter;
+
+/// Replaces each channel of each pixel by the median of that channel within a square window of
+/// `2 * radius + 1` pixels wide. Removes salt-and-pepper noise while preserving edges. Images
+/// with more than 8 bits per channel are processed at 8 bits per channel.
+pub struct Median {
+    radius: u32,
+}
+
+impl Median {
+    pub fn new(radius: u32) -> Self {
+        Self { radius }
+    }
+}
+
+impl ImageOperation for Median {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        let radius = self.radius;
+
+        match image {
+            DynamicImage::ImageLuma8(buffer) => *buffer = median_filter(buffer, radius, radius),
+            DynamicImage::ImageLumaA8(buffer) => *buffer = median_filter(buffer, radius, radius),
+            DynamicImage::ImageRgb8(buffer) => *buffer = median_filter(buffer, radius, radius),
+            DynamicImage::ImageRgba8(buffer) => *buffer = median_filter(buffer, radius, radius),
+            _ => {
+                let color = image.color();
+                let buffer = median_filter(&image.to_rgba8(), radius, radius);
+                *image = into_color_type(DynamicImage::ImageRgba8(buffer), color);
+            }
+        }
+
+        Ok(())
+    }
+}
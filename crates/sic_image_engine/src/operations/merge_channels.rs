@@ -0,0 +1,44 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::operations::channels::gray_into_channel;
+use crate::wrapper::channel::Channel;
+use sic_core::image::{DynamicImage, GenericImageView};
+
+/// Replaces the channels of the image by the luma of other images, e.g. to pack grayscale texture
+/// maps into the channels of a single image. Channels without an image keep their values.
+pub struct MergeChannels<'images> {
+    images: &'images [Option<DynamicImage>; 4],
+}
+
+impl<'images> MergeChannels<'images> {
+    pub fn new(images: &'images [Option<DynamicImage>; 4]) -> Self {
+        Self { images }
+    }
+}
+
+impl ImageOperation for MergeChannels<'_> {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        let (width, height) = image.dimensions();
+
+        for (channel, source) in Channel::ALL.into_iter().zip(self.images) {
+            let Some(source) = source else {
+                continue;
+            };
+
+            let (source_width, source_height) = source.dimensions();
+
+            if (width, height) != (source_width, source_height) {
+                return Err(SicImageEngineError::ChannelDimensionsMismatch(
+                    width,
+                    height,
+                    source_width,
+                    source_height,
+                ));
+            }
+
+            gray_into_channel(image, source, channel);
+        }
+
+        Ok(())
+    }
+}
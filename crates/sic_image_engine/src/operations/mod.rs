@@ -6,29 +6,84 @@ use sic_core::image::DynamicImage;
 use sic_core::{SicImage, image};
 use std::mem;
 
+pub mod bilateral;
+pub(crate) mod blend;
 pub mod blur;
+pub mod boomerang;
 pub mod brighten;
+pub mod canny;
+pub(crate) mod channels;
+pub mod chroma_key;
+pub mod clahe;
+pub mod close;
+pub mod color_matrix;
+pub mod color_to_alpha;
 pub mod contrast;
 pub mod crop;
+pub mod crop_to;
+pub mod curves;
 pub mod diff;
+pub mod dilate;
 pub mod dither;
 pub mod dither_quant;
 pub mod draw_text;
+pub mod drop_frames;
+pub mod duplicate_frame;
+pub mod equalize;
+pub mod erode;
+pub mod exposure;
+pub mod extend_canvas;
+pub mod extract_alpha;
+pub mod extract_channel;
+pub mod filter;
 pub mod filter3x3;
+pub mod flatten;
 pub mod flip_horizontal;
 pub mod flip_vertical;
+pub(crate) mod frame_delay;
+pub mod frames;
+pub mod gamma;
+pub mod gradient;
 pub mod grayscale;
+pub(crate) mod histogram;
 pub mod horizontal_gradient;
 pub mod hue_rotate;
 pub mod index_colors;
 pub mod index_colors_quant;
 pub mod invert;
+pub mod laplacian;
+pub mod levels;
+pub mod median;
+pub mod merge_channels;
+pub mod morph_gradient;
+pub(crate) mod morphology;
+pub mod nl_means;
+pub mod open;
 pub mod overlay;
+pub mod pad;
+pub(crate) mod pixel_map;
+pub mod premultiply_alpha;
+pub mod quantize;
 pub mod resize;
+pub mod reverse_frames;
+pub mod rotate;
 pub mod rotate180;
 pub mod rotate270;
 pub mod rotate90;
+pub mod saturate;
+pub mod scale_alpha;
+pub mod scale_frame_delay;
+pub mod scharr;
+pub mod set_alpha;
+pub mod set_frame_delay;
+pub mod set_frame_delay_at;
+pub mod sobel;
+pub mod split_sprite_sheet;
+pub mod sprite_sheet;
+pub mod swap_channels;
 pub mod threshold;
+pub mod trim;
+pub mod unpremultiply_alpha;
 pub mod unsharpen;
 pub mod vertical_gradient;
 
@@ -56,11 +111,15 @@ pub trait ImageOperation: Sync {
         match image {
             SicImage::Static(image) => self.apply_to_frame(image),
             SicImage::Animated(animated) => apply_operation_to_frames(self, animated.frames_mut()),
+            SicImage::Indexed(_) => {
+                image.expand_indexed();
+                self.apply_operation(image)
+            }
         }
     }
 }
 
-fn apply_operation_to_frames<O: ImageOperation + ?Sized>(
+pub(crate) fn apply_operation_to_frames<O: ImageOperation + ?Sized>(
     operation: &O,
     frames: &mut [image::Frame],
 ) -> Result<(), SicImageEngineError> {
@@ -0,0 +1,36 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::operations::morphology::{apply_per_channel, mask};
+use crate::wrapper::morphology::MorphologyInputs;
+use sic_core::image::DynamicImage;
+use sic_core::imageproc::morphology::{grayscale_dilate, grayscale_erode};
+
+/// Subtracts the eroded image from the dilated image, which highlights the outlines of regions.
+pub struct MorphGradient<'inputs> {
+    inputs: &'inputs MorphologyInputs,
+}
+
+impl<'inputs> MorphGradient<'inputs> {
+    pub fn new(inputs: &'inputs MorphologyInputs) -> Self {
+        Self { inputs }
+    }
+}
+
+impl ImageOperation for MorphGradient<'_> {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        let mask = mask(self.inputs);
+
+        apply_per_channel(image, |plane| {
+            let mut dilated = grayscale_dilate(plane, &mask);
+            let eroded = grayscale_erode(plane, &mask);
+
+            for (dilated, eroded) in dilated.pixels_mut().zip(eroded.pixels()) {
+                dilated[0] = dilated[0].saturating_sub(eroded[0]);
+            }
+
+            dilated
+        });
+
+        Ok(())
+    }
+}
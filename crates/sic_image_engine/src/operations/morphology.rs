@@ -0,0 +1,54 @@
+//! Helpers for the morphological operations: erode, dilate, open, close and morph-gradient.
+
+use crate::operations::pixel_map::into_color_type;
+use crate::wrapper::morphology::{MorphologyInputs, MorphologyNorm};
+use sic_core::image::{DynamicImage, GrayImage, Luma};
+use sic_core::imageproc::morphology::Mask;
+
+/// The structuring element described by the inputs.
+pub(crate) fn mask(inputs: &MorphologyInputs) -> Mask {
+    match inputs.norm() {
+        MorphologyNorm::L1 => Mask::diamond(inputs.radius()),
+        MorphologyNorm::L2 => Mask::disk(inputs.radius()),
+        MorphologyNorm::LInf => Mask::square(inputs.radius()),
+    }
+}
+
+/// Applies `f` to each color channel of the image separately; the alpha channel is left
+/// unchanged. Images with more than 8 bits per channel are processed at 8 bits per channel, after
+/// which the original color type is restored.
+pub(crate) fn apply_per_channel<F>(image: &mut DynamicImage, f: F)
+where
+    F: Fn(&GrayImage) -> GrayImage,
+{
+    if let DynamicImage::ImageLuma8(buffer) = image {
+        *buffer = f(buffer);
+        return;
+    }
+
+    let color = image.color();
+    let mut buffer = image.to_rgba8();
+    let (width, height) = buffer.dimensions();
+
+    // Gray images have equal red, green and blue channels, so a single channel suffices.
+    let channels = if color.has_color() { 0..3 } else { 0..1 };
+
+    for channel in channels {
+        let plane = GrayImage::from_fn(width, height, |x, y| {
+            Luma([buffer.get_pixel(x, y)[channel]])
+        });
+        let processed = f(&plane);
+
+        for (pixel, Luma([value])) in buffer.pixels_mut().zip(processed.pixels()) {
+            if color.has_color() {
+                pixel[channel] = *value;
+            } else {
+                pixel[0] = *value;
+                pixel[1] = *value;
+                pixel[2] = *value;
+            }
+        }
+    }
+
+    *image = into_color_type(DynamicImage::ImageRgba8(buffer), color);
+}
@@ -0,0 +1,121 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::operations::pixel_map::into_color_type;
+use crate::wrapper::filter::EdgeMode;
+use crate::wrapper::nl_means::NlMeansInputs;
+use rayon::prelude::*;
+use sic_core::image::DynamicImage;
+
+/// Denoises the color channels of an image using non-local means. Each pixel is replaced by the
+/// weighted average of the pixels within its search window, where the weight of a pixel depends
+/// on how similar the patch around it is to the patch around the pixel being denoised. Unlike
+/// local filters, this averages pixels with a similar structure, so textures and edges are
+/// preserved. The alpha channel is left unchanged.
+///
+/// The cost grows with the number of pixels in the search window times the number of pixels in a
+/// patch, so small radii (e.g. a patch radius of `1` and a search radius of `5`) are recommended.
+pub struct NlMeans<'inputs> {
+    inputs: &'inputs NlMeansInputs,
+}
+
+impl<'inputs> NlMeans<'inputs> {
+    pub fn new(inputs: &'inputs NlMeansInputs) -> Self {
+        Self { inputs }
+    }
+}
+
+impl ImageOperation for NlMeans<'_> {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        let strength = self.inputs.strength();
+
+        if !(strength > 0.0 && strength.is_finite()) {
+            return Err(SicImageEngineError::NlMeansInvalidStrength(strength));
+        }
+
+        let (width, height) = (image.width(), image.height());
+        if width == 0 || height == 0 {
+            return Ok(());
+        }
+
+        let color = image.color();
+        let mut buffer = image.to_rgba32f();
+        let pixels: Vec<[f32; 3]> = buffer.pixels().map(|p| [p[0], p[1], p[2]]).collect();
+
+        let patch_radius = i64::from(self.inputs.patch_radius());
+        let search_radius = i64::from(self.inputs.search_radius());
+
+        // The patch distance is the mean squared difference per channel, on a scale of 0 to 1.
+        // A tiny strength would underflow to zero here, which makes the weight of a pixel with
+        // itself NaN, so it's clamped to the smallest normal value instead.
+        let h = strength / 255.0;
+        let h_squared = (h * h).max(f32::MIN_POSITIVE);
+        let patch_values = (3 * (2 * patch_radius + 1).pow(2)) as f32;
+
+        let at = |x: i64, y: i64| {
+            let x = EdgeMode::Clamp.resolve(x, width);
+            let y = EdgeMode::Clamp.resolve(y, height);
+            pixels[(y * width + x) as usize]
+        };
+
+        let patch_distance = |(x, y): (i64, i64), (qx, qy): (i64, i64)| {
+            let mut sum = 0.0;
+
+            for py in -patch_radius..=patch_radius {
+                for px in -patch_radius..=patch_radius {
+                    let a = at(x + px, y + py);
+                    let b = at(qx + px, qy + py);
+
+                    sum += (0..3).map(|c| (a[c] - b[c]).powi(2)).sum::<f32>();
+                }
+            }
+
+            sum / patch_values
+        };
+
+        let mut denoised = vec![[0.0; 3]; pixels.len()];
+
+        denoised
+            .par_chunks_exact_mut(width as usize)
+            .enumerate()
+            .for_each(|(y, row)| {
+                let y = y as i64;
+
+                for (x, pixel) in row.iter_mut().enumerate() {
+                    let x = x as i64;
+                    let mut sum = [0.0; 3];
+                    let mut total_weight = 0.0;
+
+                    for qy in
+                        (y - search_radius).max(0)..=(y + search_radius).min(height as i64 - 1)
+                    {
+                        for qx in
+                            (x - search_radius).max(0)..=(x + search_radius).min(width as i64 - 1)
+                        {
+                            let distance = patch_distance((x, y), (qx, qy));
+                            let weight = (-distance / h_squared).exp();
+                            let sample = at(qx, qy);
+
+                            for (channel, value) in sum.iter_mut().zip(sample) {
+                                *channel += weight * value;
+                            }
+                            total_weight += weight;
+                        }
+                    }
+
+                    // The pixel itself has a weight of 1, since `h_squared` is never zero, so the total
+                    // weight is never zero
+                    *pixel = sum.map(|channel| channel / total_weight);
+                }
+            });
+
+        for (pixel, rgb) in buffer.pixels_mut().zip(denoised) {
+            pixel[0] = rgb[0];
+            pixel[1] = rgb[1];
+            pixel[2] = rgb[2];
+        }
+
+        *image = into_color_type(DynamicImage::ImageRgba32F(buffer), color);
+
+        Ok(())
+    }
+}
@@ -1,30 +1,47 @@
 use crate::errors::SicImageEngineError;
 use crate::operations::ImageOperation;
-use crate::wrapper::overlay::OverlayInputs;
+use crate::operations::blend::blend;
+use crate::operations::rotate::rotate;
+use crate::wrapper::overlay::{OverlayInputs, OverlayPlacement};
+use crate::wrapper::rotate::RotateCanvas;
 use rayon::iter::{IndexedParallelIterator, IntoParallelRefMutIterator, ParallelIterator};
-use sic_core::image::{DynamicImage, imageops};
+use sic_core::image::imageops::FilterType;
+use sic_core::image::{DynamicImage, GenericImage, GenericImageView, Rgba, RgbaImage, imageops};
 use sic_core::{SicImage, image};
 use std::convert::TryFrom;
 
 pub struct Overlay<'overlay> {
     inputs: &'overlay OverlayInputs,
+    filter_type: FilterType,
 }
 
 impl<'overlay> Overlay<'overlay> {
-    pub fn new(inputs: &'overlay OverlayInputs) -> Self {
-        Self { inputs }
+    pub fn new(inputs: &'overlay OverlayInputs, filter_type: FilterType) -> Self {
+        Self {
+            inputs,
+            filter_type,
+        }
     }
 }
 
 impl ImageOperation for Overlay<'_> {
     fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
-        overlay_static(image, self.inputs)
+        check_opacity(self.inputs)?;
+        overlay_static(image, self.inputs, self.filter_type)
     }
 
     fn apply_operation(&self, image: &mut SicImage) -> Result<(), SicImageEngineError> {
+        check_opacity(self.inputs)?;
+
         match image {
             SicImage::Static(image) => self.apply_to_frame(image),
-            SicImage::Animated(image) => overlay_animated_image(image.frames_mut(), self.inputs),
+            SicImage::Animated(image) => {
+                overlay_animated_image(image.frames_mut(), self.inputs, self.filter_type)
+            }
+            SicImage::Indexed(_) => {
+                image.expand_indexed();
+                self.apply_operation(image)
+            }
         }
     }
 }
@@ -32,14 +49,21 @@ impl ImageOperation for Overlay<'_> {
 fn overlay_animated_image(
     frames: &mut [image::Frame],
     inputs: &OverlayInputs,
+    filter_type: FilterType,
 ) -> Result<(), SicImageEngineError> {
     // Open matching image
     let overlay_image = inputs.image_path().open_image()?;
-    let (x, y) = inputs.position();
 
     match overlay_image {
-        SicImage::Static(image) => overlay_animated_with_static(frames, &image, x, y),
-        SicImage::Animated(other) => overlay_animated_with_animated(frames, other.frames(), x, y),
+        SicImage::Static(image) => {
+            overlay_animated_with_static(frames, &image, inputs, filter_type)
+        }
+        SicImage::Animated(other) => {
+            overlay_animated_with_animated(frames, other.frames(), inputs, filter_type)
+        }
+        SicImage::Indexed(other) => {
+            overlay_animated_with_static(frames, &other.to_dynamic_image(), inputs, filter_type)
+        }
     }
 
     Ok(())
@@ -48,29 +72,100 @@ fn overlay_animated_image(
 fn overlay_animated_with_animated(
     frames: &mut [image::Frame],
     other: &[image::Frame],
-    x: i64,
-    y: i64,
+    inputs: &OverlayInputs,
+    filter_type: FilterType,
 ) {
     frames.par_iter_mut().zip(other).for_each(|(lhs, rhs)| {
-        imageops::overlay(lhs.buffer_mut(), rhs.buffer(), x, y);
+        composite(lhs.buffer_mut(), rhs.buffer(), inputs, filter_type);
     });
 }
 
-fn overlay_animated_with_static(frames: &mut [image::Frame], other: &DynamicImage, x: i64, y: i64) {
+fn overlay_animated_with_static(
+    frames: &mut [image::Frame],
+    other: &DynamicImage,
+    inputs: &OverlayInputs,
+    filter_type: FilterType,
+) {
     frames.par_iter_mut().for_each(|frame| {
-        imageops::overlay(frame.buffer_mut(), other, x, y);
+        composite(frame.buffer_mut(), other, inputs, filter_type);
     });
 }
 
 fn overlay_static(
     image: &mut DynamicImage,
     overlay: &OverlayInputs,
+    filter_type: FilterType,
 ) -> Result<(), SicImageEngineError> {
     let overlay_image = overlay.image_path().open_image()?;
     let overlay_image = DynamicImage::try_from(overlay_image)?;
 
-    let (x, y) = overlay.position();
-    imageops::overlay(image, &overlay_image, x, y);
+    composite(image, &overlay_image, overlay, filter_type);
 
     Ok(())
 }
+
+/// Scales and places the `top` image as described by the overlay inputs, and blends each placed
+/// copy onto `bottom`.
+fn composite<I, J>(bottom: &mut I, top: &J, inputs: &OverlayInputs, filter_type: FilterType)
+where
+    I: GenericImage<Pixel = Rgba<u8>>,
+    J: GenericImageView<Pixel = Rgba<u8>>,
+{
+    let rotation = match inputs.placement() {
+        OverlayPlacement::Tiled { degrees, .. } if degrees % 360.0 != 0.0 => Some(degrees),
+        _ => None,
+    };
+
+    if inputs.width().is_none() && rotation.is_none() {
+        place(bottom, top, inputs);
+        return;
+    }
+
+    let (width, height) = top.dimensions();
+    let mut prepared = RgbaImage::from_fn(width, height, |x, y| top.get_pixel(x, y));
+
+    if let Some(target) = inputs.width() {
+        let target_width = target.resolve(bottom.width()).max(1);
+        let target_height = (f64::from(height) * f64::from(target_width) / f64::from(width.max(1)))
+            .round()
+            .max(1.0) as u32;
+
+        prepared = imageops::resize(&prepared, target_width, target_height, filter_type);
+    }
+
+    if let Some(degrees) = rotation {
+        prepared = rotate(
+            &prepared,
+            degrees,
+            RotateCanvas::Expand,
+            Rgba([0, 0, 0, 0]),
+            filter_type,
+        );
+    }
+
+    place(bottom, &prepared, inputs);
+}
+
+fn place<I, J>(bottom: &mut I, top: &J, inputs: &OverlayInputs)
+where
+    I: GenericImage<Pixel = Rgba<u8>>,
+    J: GenericImageView<Pixel = Rgba<u8>>,
+{
+    let positions = inputs
+        .placement()
+        .positions(bottom.dimensions(), top.dimensions());
+
+    for (x, y) in positions {
+        blend(bottom, top, x, y, inputs.blend_mode(), inputs.opacity());
+    }
+}
+
+fn check_opacity(inputs: &OverlayInputs) -> Result<(), SicImageEngineError> {
+    if (0.0..=1.0).contains(&inputs.opacity()) {
+        Ok(())
+    } else {
+        Err(SicImageEngineError::OverlayOpacityOutOfRange(
+            inputs.opacity(),
+        ))
+    }
+}
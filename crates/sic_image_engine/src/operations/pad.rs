@@ -0,0 +1,42 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::operations::extend_canvas::place_on_canvas;
+use crate::wrapper::pad::PadInputs;
+use sic_core::image::{DynamicImage, GenericImageView};
+
+pub struct Pad<'inputs> {
+    inputs: &'inputs PadInputs,
+}
+
+impl<'inputs> Pad<'inputs> {
+    pub fn new(inputs: &'inputs PadInputs) -> Self {
+        Self { inputs }
+    }
+}
+
+impl ImageOperation for Pad<'_> {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        let (width, height) = image.dimensions();
+        let inputs = self.inputs;
+
+        let canvas_width = [width, inputs.left(), inputs.right()]
+            .into_iter()
+            .try_fold(0u32, u32::checked_add);
+        let canvas_height = [height, inputs.top(), inputs.bottom()]
+            .into_iter()
+            .try_fold(0u32, u32::checked_add);
+
+        let (Some(canvas_width), Some(canvas_height)) = (canvas_width, canvas_height) else {
+            return Err(SicImageEngineError::PadDimensionsOverflow);
+        };
+
+        place_on_canvas(
+            image,
+            (canvas_width, canvas_height),
+            (i64::from(inputs.left()), i64::from(inputs.top())),
+            inputs.fill(),
+        );
+
+        Ok(())
+    }
+}
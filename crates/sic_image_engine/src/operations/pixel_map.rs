@@ -0,0 +1,336 @@
+//! Helpers for operations which map the color of each pixel independently, while preserving the
+//! color type (and thus bit depth) of the image.
+
+use rayon::prelude::*;
+use sic_core::image::{ColorType, DynamicImage};
+
+/// Rec. 709 luma coefficients.
+pub(crate) const LUMA: [f32; 3] = [0.2126, 0.7152, 0.0722];
+
+pub(crate) fn luma([r, g, b]: [f32; 3]) -> f32 {
+    LUMA[0] * r + LUMA[1] * g + LUMA[2] * b
+}
+
+/// Applies `f` to the color channels of each pixel, i.e. all channels except alpha. Channel values
+/// are normalized to the range `[0, 1]`, and the results of `f` are clamped to this range.
+///
+/// Gray images are provided to `f` as `[l, l, l]`, and the luma of the result of `f` is stored.
+pub(crate) fn map_rgb<F>(image: &mut DynamicImage, f: F)
+where
+    F: Fn([f32; 3]) -> [f32; 3] + Sync,
+{
+    map_rgba(image, |[r, g, b, a]| {
+        let [r, g, b] = f([r, g, b]);
+        [r, g, b, a]
+    });
+}
+
+/// Applies `f` to each color channel of each pixel independently.
+/// See [`map_rgb`] for details.
+pub(crate) fn map_channels<F>(image: &mut DynamicImage, f: F)
+where
+    F: Fn(f32) -> f32 + Sync,
+{
+    map_rgb(image, |[r, g, b]| [f(r), f(g), f(b)]);
+}
+
+/// Applies `f` to all channels of each pixel, including alpha. Like [`map_rgb`], but images
+/// without an alpha channel are provided to `f` as fully opaque, and the alpha value returned by
+/// `f` is discarded for these images.
+pub(crate) fn map_rgba<F>(image: &mut DynamicImage, f: F)
+where
+    F: Fn([f32; 4]) -> [f32; 4] + Sync,
+{
+    map_rgba_enumerated(image, |_, pixel| f(pixel));
+}
+
+/// Like [`map_rgba`], but `f` also receives the index of each pixel, in row-major order.
+pub(crate) fn map_rgba_enumerated<F>(image: &mut DynamicImage, f: F)
+where
+    F: Fn(usize, [f32; 4]) -> [f32; 4] + Sync,
+{
+    match image {
+        DynamicImage::ImageLuma8(buffer) => map_samples(buffer, 1, &f),
+        DynamicImage::ImageLumaA8(buffer) => map_samples(buffer, 2, &f),
+        DynamicImage::ImageRgb8(buffer) => map_samples(buffer, 3, &f),
+        DynamicImage::ImageRgba8(buffer) => map_samples(buffer, 4, &f),
+        DynamicImage::ImageLuma16(buffer) => map_samples(buffer, 1, &f),
+        DynamicImage::ImageLumaA16(buffer) => map_samples(buffer, 2, &f),
+        DynamicImage::ImageRgb16(buffer) => map_samples(buffer, 3, &f),
+        DynamicImage::ImageRgba16(buffer) => map_samples(buffer, 4, &f),
+        DynamicImage::ImageRgb32F(buffer) => map_samples(buffer, 3, &f),
+        DynamicImage::ImageRgba32F(buffer) => map_samples(buffer, 4, &f),
+        _ => {
+            let mut buffer = image.to_rgba32f();
+            map_samples(&mut buffer, 4, &f);
+            *image = DynamicImage::ImageRgba32F(buffer);
+        }
+    }
+}
+
+/// Adds an alpha channel to images which do not have one yet, while preserving the bit depth.
+pub(crate) fn ensure_alpha(image: &mut DynamicImage) {
+    let with_alpha = match image {
+        DynamicImage::ImageLuma8(_) => DynamicImage::ImageLumaA8(image.to_luma_alpha8()),
+        DynamicImage::ImageRgb8(_) => DynamicImage::ImageRgba8(image.to_rgba8()),
+        DynamicImage::ImageLuma16(_) => DynamicImage::ImageLumaA16(image.to_luma_alpha16()),
+        DynamicImage::ImageRgb16(_) => DynamicImage::ImageRgba16(image.to_rgba16()),
+        DynamicImage::ImageRgb32F(_) => DynamicImage::ImageRgba32F(image.to_rgba32f()),
+        _ => return,
+    };
+
+    *image = with_alpha;
+}
+
+/// Converts grayscale images to RGB images, while preserving the bit depth and alpha channel.
+pub(crate) fn ensure_color(image: &mut DynamicImage) {
+    let with_color = match image {
+        DynamicImage::ImageLuma8(_) => DynamicImage::ImageRgb8(image.to_rgb8()),
+        DynamicImage::ImageLumaA8(_) => DynamicImage::ImageRgba8(image.to_rgba8()),
+        DynamicImage::ImageLuma16(_) => DynamicImage::ImageRgb16(image.to_rgb16()),
+        DynamicImage::ImageLumaA16(_) => DynamicImage::ImageRgba16(image.to_rgba16()),
+        _ => return,
+    };
+
+    *image = with_color;
+}
+
+/// Removes the alpha channel of images which have one, while preserving the bit depth.
+pub(crate) fn remove_alpha(image: &mut DynamicImage) {
+    let without_alpha = match image {
+        DynamicImage::ImageLumaA8(_) => DynamicImage::ImageLuma8(image.to_luma8()),
+        DynamicImage::ImageRgba8(_) => DynamicImage::ImageRgb8(image.to_rgb8()),
+        DynamicImage::ImageLumaA16(_) => DynamicImage::ImageLuma16(image.to_luma16()),
+        DynamicImage::ImageRgba16(_) => DynamicImage::ImageRgb16(image.to_rgb16()),
+        DynamicImage::ImageRgba32F(_) => DynamicImage::ImageRgb32F(image.to_rgb32f()),
+        _ => return,
+    };
+
+    *image = without_alpha;
+}
+
+/// Converts an image to the given color type. Used to restore the original color type of an image,
+/// after it has been processed in a different color type (e.g. 32-bit float RGBA).
+pub(crate) fn into_color_type(image: DynamicImage, color: ColorType) -> DynamicImage {
+    match color {
+        ColorType::L8 => DynamicImage::ImageLuma8(image.to_luma8()),
+        ColorType::La8 => DynamicImage::ImageLumaA8(image.to_luma_alpha8()),
+        ColorType::Rgb8 => DynamicImage::ImageRgb8(image.to_rgb8()),
+        ColorType::Rgba8 => DynamicImage::ImageRgba8(image.to_rgba8()),
+        ColorType::L16 => DynamicImage::ImageLuma16(image.to_luma16()),
+        ColorType::La16 => DynamicImage::ImageLumaA16(image.to_luma_alpha16()),
+        ColorType::Rgb16 => DynamicImage::ImageRgb16(image.to_rgb16()),
+        ColorType::Rgba16 => DynamicImage::ImageRgba16(image.to_rgba16()),
+        ColorType::Rgb32F => DynamicImage::ImageRgb32F(image.to_rgb32f()),
+        ColorType::Rgba32F => DynamicImage::ImageRgba32F(image.to_rgba32f()),
+        _ => image,
+    }
+}
+
+fn map_samples<S, F>(samples: &mut [S], channels: usize, f: &F)
+where
+    S: Sample,
+    F: Fn(usize, [f32; 4]) -> [f32; 4] + Sync,
+{
+    let has_alpha = matches!(channels, 2 | 4);
+
+    samples
+        .par_chunks_exact_mut(channels)
+        .enumerate()
+        .for_each(|(index, pixel)| {
+            let alpha = if has_alpha {
+                pixel[channels - 1].to_unit()
+            } else {
+                1.0
+            };
+
+            if channels >= 3 {
+                let rgba = f(
+                    index,
+                    [
+                        pixel[0].to_unit(),
+                        pixel[1].to_unit(),
+                        pixel[2].to_unit(),
+                        alpha,
+                    ],
+                );
+                pixel[0] = S::from_unit(rgba[0]);
+                pixel[1] = S::from_unit(rgba[1]);
+                pixel[2] = S::from_unit(rgba[2]);
+
+                if has_alpha {
+                    pixel[3] = S::from_unit(rgba[3]);
+                }
+            } else {
+                let l = pixel[0].to_unit();
+                let [r, g, b, a] = f(index, [l, l, l, alpha]);
+                pixel[0] = S::from_unit(luma([r, g, b]));
+
+                if has_alpha {
+                    pixel[1] = S::from_unit(a);
+                }
+            }
+        });
+}
+
+/// A channel value, which can be converted from and to the normalized range `[0, 1]`.
+trait Sample: Copy + Send + Sync {
+    fn to_unit(self) -> f32;
+
+    fn from_unit(value: f32) -> Self;
+}
+
+impl Sample for u8 {
+    fn to_unit(self) -> f32 {
+        f32::from(self) / f32::from(u8::MAX)
+    }
+
+    fn from_unit(value: f32) -> Self {
+        (value.clamp(0.0, 1.0) * f32::from(u8::MAX)).round() as u8
+    }
+}
+
+impl Sample for u16 {
+    fn to_unit(self) -> f32 {
+        f32::from(self) / f32::from(u16::MAX)
+    }
+
+    fn from_unit(value: f32) -> Self {
+        (value.clamp(0.0, 1.0) * f32::from(u16::MAX)).round() as u16
+    }
+}
+
+impl Sample for f32 {
+    fn to_unit(self) -> f32 {
+        self
+    }
+
+    fn from_unit(value: f32) -> Self {
+        value.clamp(0.0, 1.0)
+    }
+}
+
+/// Converts an sRGB encoded channel value to linear light.
+pub(crate) fn srgb_to_linear(value: f32) -> f32 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Converts a linear light channel value to sRGB encoding.
+pub(crate) fn linear_to_srgb(value: f32) -> f32 {
+    if value <= 0.003_130_8 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sic_core::image::{GenericImageView, Luma, Rgba};
+
+    #[test]
+    fn map_rgb_preserves_color_type_and_alpha() {
+        let mut image = DynamicImage::new_rgba16(2, 2);
+        map_channels(&mut image, |_| 1.0);
+
+        let DynamicImage::ImageRgba16(buffer) = &image else {
+            panic!("expected the color type to be preserved");
+        };
+
+        assert_eq!(buffer.get_pixel(0, 0).0, [u16::MAX, u16::MAX, u16::MAX, 0]);
+    }
+
+    #[test]
+    fn map_rgb_gray_stores_luma() {
+        let mut image = DynamicImage::new_luma8(1, 1);
+        map_rgb(&mut image, |_| [1.0, 0.0, 0.0]);
+
+        let DynamicImage::ImageLuma8(buffer) = &image else {
+            panic!("expected the color type to be preserved");
+        };
+
+        assert_eq!(*buffer.get_pixel(0, 0), Luma([54]));
+    }
+
+    #[test]
+    fn map_rgb_clamps() {
+        let mut image = DynamicImage::new_rgba8(1, 1);
+        map_rgb(&mut image, |_| [2.0, -1.0, 0.5]);
+
+        assert_eq!(image.get_pixel(0, 0), Rgba([255, 0, 128, 0]));
+    }
+
+    #[test]
+    fn map_rgba_maps_alpha() {
+        let mut image = DynamicImage::new_luma_a8(1, 1);
+        map_rgba(&mut image, |[r, g, b, _]| [r, g, b, 0.5]);
+
+        let DynamicImage::ImageLumaA8(buffer) = &image else {
+            panic!("expected the color type to be preserved");
+        };
+
+        assert_eq!(buffer.get_pixel(0, 0).0, [0, 128]);
+    }
+
+    #[test]
+    fn map_rgba_without_alpha_is_opaque() {
+        let mut image = DynamicImage::new_rgb8(1, 1);
+        map_rgba(&mut image, |[_, _, _, a]| [a, a, a, 0.0]);
+
+        assert_eq!(image.get_pixel(0, 0), Rgba([255, 255, 255, 255]));
+    }
+
+    #[test]
+    fn ensure_alpha_preserves_bit_depth() {
+        let mut image = DynamicImage::new_luma16(1, 1);
+        ensure_alpha(&mut image);
+
+        assert!(matches!(image, DynamicImage::ImageLumaA16(_)));
+    }
+
+    #[test]
+    fn ensure_color_preserves_bit_depth_and_alpha() {
+        let mut image = DynamicImage::new_luma_a16(1, 1);
+        ensure_color(&mut image);
+
+        assert!(matches!(image, DynamicImage::ImageRgba16(_)));
+    }
+
+    #[test]
+    fn remove_alpha_preserves_bit_depth() {
+        let mut image = DynamicImage::new_rgba16(1, 1);
+        remove_alpha(&mut image);
+
+        assert!(matches!(image, DynamicImage::ImageRgb16(_)));
+    }
+
+    #[test]
+    fn map_rgba_enumerated_in_row_major_order() {
+        let mut image = DynamicImage::new_luma8(3, 2);
+        map_rgba_enumerated(&mut image, |index, _| [index as f32 / 255.0; 4]);
+
+        assert_eq!(image.to_luma8().into_raw(), vec![0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn into_color_type_restores_color_type() {
+        let image = DynamicImage::new_luma16(1, 1);
+        let color = image.color();
+
+        let image = into_color_type(DynamicImage::ImageRgba32F(image.to_rgba32f()), color);
+        assert!(matches!(image, DynamicImage::ImageLuma16(_)));
+    }
+
+    #[test]
+    fn srgb_round_trip() {
+        for value in [0.0, 0.01, 0.2, 0.5, 0.9, 1.0] {
+            let round_trip = linear_to_srgb(srgb_to_linear(value));
+            assert!((round_trip - value).abs() < 1e-5);
+        }
+    }
+}
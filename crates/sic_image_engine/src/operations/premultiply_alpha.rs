@@ -0,0 +1,22 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::operations::pixel_map::map_rgba;
+use sic_core::image::DynamicImage;
+
+/// Multiplies the color channels of each pixel by its alpha value. Images without an alpha
+/// channel are unchanged.
+pub struct PremultiplyAlpha;
+
+impl PremultiplyAlpha {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl ImageOperation for PremultiplyAlpha {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        map_rgba(image, |[r, g, b, a]| [r * a, g * a, b * a, a]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,136 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::dither::{dither_with, palette_spread};
+use crate::operations::{ImageOperation, apply_operation_to_frames};
+use crate::wrapper::dither_algorithm::DitherAlgorithm;
+use crate::wrapper::palette::{Palette, QuantizeMode};
+use sic_core::image::imageops::colorops::ColorMap;
+use sic_core::image::{DynamicImage, GrayImage, Luma, Rgba, RgbaImage};
+use sic_core::{IndexedImage, MAX_PALETTE_COLORS, SicImage};
+
+pub struct Quantize<'palette> {
+    palette: &'palette Palette,
+    mode: QuantizeMode,
+    algorithm: DitherAlgorithm,
+    serpentine: bool,
+}
+
+impl<'palette> Quantize<'palette> {
+    pub fn new(
+        palette: &'palette Palette,
+        mode: QuantizeMode,
+        algorithm: DitherAlgorithm,
+        serpentine: bool,
+    ) -> Self {
+        Self {
+            palette,
+            mode,
+            algorithm,
+            serpentine,
+        }
+    }
+
+    fn quantize(&self, image: &DynamicImage) -> RgbaImage {
+        let mut buffer = image.to_rgba8();
+
+        match self.mode {
+            QuantizeMode::Nearest => buffer
+                .pixels_mut()
+                .for_each(|pixel| self.palette.map_color(pixel)),
+            QuantizeMode::Dither => dither_with(
+                &mut buffer,
+                self.palette,
+                self.algorithm,
+                self.serpentine,
+                palette_spread(self.palette.colors().len()),
+            ),
+        }
+
+        buffer
+    }
+
+    /// Like [`Quantize::quantize`], but keeps the palette index of each pixel instead of its
+    /// color.
+    fn index(&self, image: &DynamicImage) -> Result<IndexedImage, SicImageEngineError> {
+        let buffer = self.quantize(image);
+        let indices = GrayImage::from_fn(buffer.width(), buffer.height(), |x, y| {
+            Luma([self.palette.index_of(&buffer[(x, y)]) as u8])
+        });
+
+        Ok(IndexedImage::try_new(
+            indices,
+            self.palette.colors().to_vec(),
+        )?)
+    }
+}
+
+impl ImageOperation for Quantize<'_> {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        let buffer = self.quantize(image);
+
+        let opaque = self
+            .palette
+            .colors()
+            .iter()
+            .all(|color| color[3] == u8::MAX);
+
+        *image = if opaque && !image.color().has_alpha() {
+            DynamicImage::ImageRgb8(DynamicImage::ImageRgba8(buffer).into_rgb8())
+        } else {
+            DynamicImage::ImageRgba8(buffer)
+        };
+
+        Ok(())
+    }
+
+    fn apply_operation(&self, image: &mut SicImage) -> Result<(), SicImageEngineError> {
+        match image {
+            // Palettes which are too large to be indexed by a byte are applied by color
+            SicImage::Static(frame) if self.palette.colors().len() <= MAX_PALETTE_COLORS => {
+                *image = SicImage::Indexed(self.index(frame)?);
+                Ok(())
+            }
+            SicImage::Static(frame) => self.apply_to_frame(frame),
+            SicImage::Animated(animated) => apply_operation_to_frames(self, animated.frames_mut()),
+            SicImage::Indexed(_) => {
+                image.expand_indexed();
+                self.apply_operation(image)
+            }
+        }
+    }
+}
+
+impl ColorMap for Palette {
+    type Color = Rgba<u8>;
+
+    /// The index of the palette color with the smallest (squared) Euclidean distance to `color`.
+    /// On a tie, the color defined first is picked.
+    fn index_of(&self, color: &Rgba<u8>) -> usize {
+        let distance = |other: &Rgba<u8>| -> u32 {
+            color
+                .0
+                .iter()
+                .zip(other.0.iter())
+                .map(|(&a, &b)| u32::from(a.abs_diff(b)).pow(2))
+                .sum()
+        };
+
+        self.colors()
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, other)| distance(other))
+            .map(|(index, _)| index)
+            .unwrap_or_default()
+    }
+
+    fn lookup(&self, index: usize) -> Option<Rgba<u8>> {
+        self.colors().get(index).copied()
+    }
+
+    fn has_lookup(&self) -> bool {
+        true
+    }
+
+    fn map_color(&self, color: &mut Rgba<u8>) {
+        *color = self.colors()[self.index_of(color)];
+    }
+}
@@ -1,21 +1,20 @@
 use crate::errors::SicImageEngineError;
 use crate::operations::ImageOperation;
-use sic_core::image::DynamicImage;
+use crate::wrapper::resize::ResizeInputs;
 use sic_core::image::imageops::FilterType;
+use sic_core::image::{DynamicImage, GenericImageView};
 
 #[derive(Debug)]
 pub struct Resize {
-    x: u32,
-    y: u32,
+    inputs: ResizeInputs,
     preserve_aspect_ratio: bool,
     filter_type: FilterType,
 }
 
 impl Resize {
-    pub fn new(x: u32, y: u32, preserve_aspect_ratio: bool, filter_type: FilterType) -> Self {
+    pub fn new(inputs: ResizeInputs, preserve_aspect_ratio: bool, filter_type: FilterType) -> Self {
         Self {
-            x,
-            y,
+            inputs,
             preserve_aspect_ratio,
             filter_type,
         }
@@ -24,10 +23,39 @@ impl Resize {
 
 impl ImageOperation for Resize {
     fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
-        if self.preserve_aspect_ratio {
-            resize_with_preserve_aspect_ratio(image, self.x, self.y, self.filter_type)
-        } else {
-            resize_regularly(image, self.x, self.y, self.filter_type)
+        let (width, height) = image.dimensions();
+
+        match self.inputs {
+            ResizeInputs::Dimensions(x, y) if self.preserve_aspect_ratio => {
+                resize_with_preserve_aspect_ratio(
+                    image,
+                    x.resolve(width),
+                    y.resolve(height),
+                    self.filter_type,
+                )
+            }
+            ResizeInputs::Dimensions(x, y) | ResizeInputs::Exact(x, y) => {
+                resize_regularly(image, x.resolve(width), y.resolve(height), self.filter_type)
+            }
+            ResizeInputs::Fit(x, y) => resize_with_preserve_aspect_ratio(
+                image,
+                x.resolve(width),
+                y.resolve(height),
+                self.filter_type,
+            ),
+            ResizeInputs::Cover(x, y) => {
+                resize_to_cover(image, x.resolve(width), y.resolve(height), self.filter_type)
+            }
+            ResizeInputs::WidthOnly(x) => {
+                let x = x.resolve(width);
+                let y = scale_dimension(height, x, width);
+                resize_regularly(image, x, y, self.filter_type)
+            }
+            ResizeInputs::HeightOnly(y) => {
+                let y = y.resolve(height);
+                let x = scale_dimension(width, y, height);
+                resize_regularly(image, x, y, self.filter_type)
+            }
         }
 
         Ok(())
@@ -46,3 +74,15 @@ fn resize_with_preserve_aspect_ratio(
 ) {
     *image = image.resize(x, y, filter_type);
 }
+
+fn resize_to_cover(image: &mut DynamicImage, x: u32, y: u32, filter_type: FilterType) {
+    *image = image.resize_to_fill(x, y, filter_type);
+}
+
+/// Scale `dimension` by `target / source`, such that the aspect ratio is preserved.
+fn scale_dimension(dimension: u32, target: u32, source: u32) -> u32 {
+    let scaled = (u64::from(dimension) * u64::from(target) + u64::from(source) / 2)
+        / u64::from(source.max(1));
+
+    (scaled as u32).max(1)
+}
@@ -0,0 +1,28 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use sic_core::SicImage;
+use sic_core::image::DynamicImage;
+
+/// Reverses the order of the frames of an animated image, so it plays backwards. Each frame keeps
+/// its own delay. Static images are left as is.
+pub struct ReverseFrames;
+
+impl ReverseFrames {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl ImageOperation for ReverseFrames {
+    fn apply_to_frame(&self, _image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        Ok(())
+    }
+
+    fn apply_operation(&self, image: &mut SicImage) -> Result<(), SicImageEngineError> {
+        if let SicImage::Animated(animated) = image {
+            animated.frames_mut().reverse();
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::wrapper::rotate::{RotateCanvas, RotateInputs};
+use sic_core::image::imageops::FilterType;
+use sic_core::image::{DynamicImage, Rgba, RgbaImage};
+use sic_core::imageproc::geometric_transformations::{
+    Border, Interpolation, Projection, warp_into,
+};
+
+pub struct Rotate<'inputs> {
+    inputs: &'inputs RotateInputs,
+    filter_type: FilterType,
+}
+
+impl<'inputs> Rotate<'inputs> {
+    pub fn new(inputs: &'inputs RotateInputs, filter_type: FilterType) -> Self {
+        Self {
+            inputs,
+            filter_type,
+        }
+    }
+}
+
+impl ImageOperation for Rotate<'_> {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        let rotated = rotate(
+            &image.to_rgba8(),
+            self.inputs.degrees(),
+            self.inputs.canvas(),
+            self.inputs.background(),
+            self.filter_type,
+        );
+
+        *image = DynamicImage::ImageRgba8(rotated);
+
+        Ok(())
+    }
+}
+
+/// Rotates the image clockwise by `degrees` around its center. Uncovered pixels are filled with
+/// the background color.
+pub(crate) fn rotate(
+    buffer: &RgbaImage,
+    degrees: f32,
+    canvas: RotateCanvas,
+    background: Rgba<u8>,
+    filter_type: FilterType,
+) -> RgbaImage {
+    let theta = degrees.to_radians();
+
+    let (width, height) = match canvas {
+        RotateCanvas::Crop => buffer.dimensions(),
+        RotateCanvas::Expand => expanded_dimensions(buffer.dimensions(), theta),
+    };
+
+    let mut out = RgbaImage::new(width, height);
+    let (cx, cy) = (buffer.width() as f32 / 2.0, buffer.height() as f32 / 2.0);
+    let (ocx, ocy) = (width as f32 / 2.0, height as f32 / 2.0);
+
+    // Rotate around the center of the input image, and move it to the center of the output image.
+    let projection = Projection::translate(ocx, ocy)
+        * Projection::rotate(theta)
+        * Projection::translate(-cx, -cy);
+
+    warp_into(
+        buffer,
+        projection,
+        interpolation(filter_type),
+        Border::Constant(background),
+        &mut out,
+    );
+
+    out
+}
+
+fn expanded_dimensions((width, height): (u32, u32), theta: f32) -> (u32, u32) {
+    let (sin, cos) = theta.sin_cos();
+    let (sin, cos) = (sin.abs(), cos.abs());
+
+    // The small offset prevents rounding errors for right angles from adding an extra pixel row or
+    // column, e.g. cos(π/2) is not exactly zero for f32.
+    let new_width = (width as f32 * cos + height as f32 * sin - 1e-3).ceil();
+    let new_height = (width as f32 * sin + height as f32 * cos - 1e-3).ceil();
+
+    (new_width.max(1.0) as u32, new_height.max(1.0) as u32)
+}
+
+// The sampling filters are defined for resizing; rotation only supports a subset of interpolation
+// methods, so we pick the nearest equivalent.
+fn interpolation(filter_type: FilterType) -> Interpolation {
+    match filter_type {
+        FilterType::Nearest => Interpolation::Nearest,
+        FilterType::Triangle => Interpolation::Bilinear,
+        FilterType::CatmullRom | FilterType::Gaussian | FilterType::Lanczos3 => {
+            Interpolation::Bicubic
+        }
+    }
+}
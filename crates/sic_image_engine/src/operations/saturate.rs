@@ -0,0 +1,30 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::operations::pixel_map::{luma, map_rgb};
+use sic_core::image::DynamicImage;
+
+/// Scales the distance of each color channel to the luma of the pixel by `factor`.
+/// A factor of `0.0` results in a gray image, `1.0` leaves the image unchanged and larger
+/// factors increase the saturation.
+pub struct Saturate {
+    factor: f32,
+}
+
+impl Saturate {
+    pub fn new(factor: f32) -> Self {
+        Self { factor }
+    }
+}
+
+impl ImageOperation for Saturate {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        let factor = self.factor;
+
+        map_rgb(image, |rgb| {
+            let l = luma(rgb);
+            rgb.map(|c| l + (c - l) * factor)
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::operations::pixel_map::{ensure_alpha, map_rgba};
+use sic_core::image::DynamicImage;
+
+/// Multiplies the alpha channel of each pixel by a factor. An alpha channel is added to images
+/// which do not have one yet.
+pub struct ScaleAlpha {
+    factor: f32,
+}
+
+impl ScaleAlpha {
+    pub fn new(factor: f32) -> Self {
+        Self { factor }
+    }
+}
+
+impl ImageOperation for ScaleAlpha {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        if !(self.factor.is_finite() && self.factor >= 0.0) {
+            return Err(SicImageEngineError::ScaleAlphaFactorNegative(self.factor));
+        }
+
+        ensure_alpha(image);
+        map_rgba(image, |[r, g, b, a]| [r, g, b, a * self.factor]);
+
+        Ok(())
+    }
+}
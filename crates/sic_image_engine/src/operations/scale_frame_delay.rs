@@ -0,0 +1,41 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::operations::frame_delay::{delay_ms, set_delay};
+use sic_core::SicImage;
+use sic_core::image::{Delay, DynamicImage};
+
+/// Multiplies the delay of every frame of an animated image by a factor, rounded to whole
+/// milliseconds. A factor smaller than one speeds the animation up, while a factor larger than one
+/// slows it down. Static images have no frame delays, and are left as is.
+pub struct ScaleFrameDelay {
+    factor: f32,
+}
+
+impl ScaleFrameDelay {
+    pub fn new(factor: f32) -> Self {
+        Self { factor }
+    }
+}
+
+impl ImageOperation for ScaleFrameDelay {
+    fn apply_to_frame(&self, _image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        Ok(())
+    }
+
+    fn apply_operation(&self, image: &mut SicImage) -> Result<(), SicImageEngineError> {
+        if !(self.factor.is_finite() && self.factor > 0.0) {
+            return Err(SicImageEngineError::ScaleFrameDelayFactorNotPositive(
+                self.factor,
+            ));
+        }
+
+        if let SicImage::Animated(animated) = image {
+            for frame in animated.frames_mut() {
+                let delay = (delay_ms(frame) * f64::from(self.factor)).round();
+                set_delay(frame, Delay::from_numer_denom_ms(delay as u32, 1));
+            }
+        }
+
+        Ok(())
+    }
+}
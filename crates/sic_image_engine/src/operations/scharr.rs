@@ -0,0 +1,29 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::operations::sobel::saturate_to_luma8;
+use sic_core::image::DynamicImage;
+use sic_core::imageproc;
+
+/// Computes the magnitude of the intensity gradient of each pixel, using the Scharr operator,
+/// which is more rotationally symmetric than the Sobel operator. The result is a gray image,
+/// where magnitudes larger than the maximum channel value are clamped.
+pub struct Scharr;
+
+impl Scharr {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl ImageOperation for Scharr {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        let gradients = imageproc::gradients::gradients_grayscale(
+            &image.to_luma8(),
+            imageproc::kernel::SCHARR_HORIZONTAL_3X3,
+            imageproc::kernel::SCHARR_VERTICAL_3X3,
+        );
+        *image = DynamicImage::ImageLuma8(saturate_to_luma8(&gradients));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::operations::pixel_map::{ensure_alpha, map_rgba_enumerated};
+use sic_core::image::DynamicImage;
+
+/// Replaces the alpha channel of the image by a mask. The alpha value of each pixel becomes the
+/// luma of the matching mask pixel, multiplied by the alpha value of that mask pixel, so white
+/// keeps a pixel opaque, and black or transparent mask pixels make it transparent.
+///
+/// An alpha channel is added to images which do not have one yet.
+pub struct SetAlpha<'mask> {
+    mask: &'mask DynamicImage,
+}
+
+impl<'mask> SetAlpha<'mask> {
+    pub fn new(mask: &'mask DynamicImage) -> Self {
+        Self { mask }
+    }
+}
+
+impl ImageOperation for SetAlpha<'_> {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        let (width, height) = (image.width(), image.height());
+        let (mask_width, mask_height) = (self.mask.width(), self.mask.height());
+
+        if (width, height) != (mask_width, mask_height) {
+            return Err(SicImageEngineError::MaskDimensionsMismatch(
+                width,
+                height,
+                mask_width,
+                mask_height,
+            ));
+        }
+
+        let mask = self
+            .mask
+            .to_luma_alpha32f()
+            .pixels()
+            .map(|pixel| pixel[0] * pixel[1])
+            .collect::<Vec<_>>();
+
+        ensure_alpha(image);
+        map_rgba_enumerated(image, |index, [r, g, b, _]| [r, g, b, mask[index]]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::operations::frame_delay::set_delay;
+use sic_core::SicImage;
+use sic_core::image::{Delay, DynamicImage};
+
+/// Sets the delay of every frame of an animated image to the same number of milliseconds.
+/// Static images have no frame delays, and are left as is.
+pub struct SetFrameDelay {
+    delay: u32,
+}
+
+impl SetFrameDelay {
+    pub fn new(delay: u32) -> Self {
+        Self { delay }
+    }
+}
+
+impl ImageOperation for SetFrameDelay {
+    fn apply_to_frame(&self, _image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        Ok(())
+    }
+
+    fn apply_operation(&self, image: &mut SicImage) -> Result<(), SicImageEngineError> {
+        if let SicImage::Animated(animated) = image {
+            let delay = Delay::from_numer_denom_ms(self.delay, 1);
+
+            for frame in animated.frames_mut() {
+                set_delay(frame, delay);
+            }
+        }
+
+        Ok(())
+    }
+}
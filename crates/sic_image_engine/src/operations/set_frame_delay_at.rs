@@ -0,0 +1,40 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::operations::frame_delay::set_delay;
+use sic_core::image::{Delay, DynamicImage};
+use sic_core::{SicCoreError, SicImage};
+
+/// Sets the delay of a single frame of an animated image, selected by its zero-based index, to
+/// the given number of milliseconds. Static images have no frame delays, and are left as is.
+pub struct SetFrameDelayAt {
+    index: u32,
+    delay: u32,
+}
+
+impl SetFrameDelayAt {
+    pub fn new(index: u32, delay: u32) -> Self {
+        Self { index, delay }
+    }
+}
+
+impl ImageOperation for SetFrameDelayAt {
+    fn apply_to_frame(&self, _image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        Ok(())
+    }
+
+    fn apply_operation(&self, image: &mut SicImage) -> Result<(), SicImageEngineError> {
+        if let SicImage::Animated(animated) = image {
+            let frames = animated.frames_mut();
+            let len = frames.len();
+            let index = self.index as usize;
+
+            let frame = frames
+                .get_mut(index)
+                .ok_or(SicCoreError::InvalidFrameIndex { index, len })?;
+
+            set_delay(frame, Delay::from_numer_denom_ms(self.delay, 1));
+        }
+
+        Ok(())
+    }
+}
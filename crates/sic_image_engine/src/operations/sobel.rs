@@ -0,0 +1,35 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use sic_core::image::{DynamicImage, GrayImage, ImageBuffer, Luma};
+use sic_core::imageproc;
+
+/// Computes the magnitude of the intensity gradient of each pixel, using the Sobel operator.
+/// The result is a gray image, where magnitudes larger than the maximum channel value are clamped.
+pub struct Sobel;
+
+impl Sobel {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl ImageOperation for Sobel {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        let gradients = imageproc::gradients::sobel_gradients(&image.to_luma8());
+        *image = DynamicImage::ImageLuma8(saturate_to_luma8(&gradients));
+
+        Ok(())
+    }
+}
+
+/// Maps a gray image with wider samples (e.g. gradient magnitudes) to a 8-bit gray image, by
+/// taking the absolute value of each sample and clamping it to the range of a `u8`.
+pub(crate) fn saturate_to_luma8<S>(image: &ImageBuffer<Luma<S>, Vec<S>>) -> GrayImage
+where
+    S: sic_core::image::Primitive + Into<i32>,
+{
+    GrayImage::from_fn(image.width(), image.height(), |x, y| {
+        let value: i32 = image.get_pixel(x, y)[0].into();
+        Luma([value.unsigned_abs().min(u32::from(u8::MAX)) as u8])
+    })
+}
@@ -0,0 +1,88 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::wrapper::sprite_sheet::SplitSpriteSheetInputs;
+use sic_core::image::{Delay, DynamicImage, Frame, GenericImageView, imageops};
+use sic_core::{AnimatedImage, SicCoreError, SicImage};
+
+/// The delay of each frame of a split sprite sheet, in milliseconds.
+const FRAME_DELAY_MS: u32 = 100;
+
+/// Splits a sprite sheet into an animated image, with one frame for each cell, from left to right
+/// and top to bottom. Cells are separated by the given spacing; cells which don't fit entirely
+/// within the sprite sheet are skipped. Each frame is shown for 100 milliseconds, which can be
+/// changed with `set-frame-delay`.
+pub struct SplitSpriteSheet {
+    inputs: SplitSpriteSheetInputs,
+}
+
+impl SplitSpriteSheet {
+    pub fn new(inputs: SplitSpriteSheetInputs) -> Self {
+        Self { inputs }
+    }
+}
+
+impl ImageOperation for SplitSpriteSheet {
+    fn apply_to_frame(&self, _image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        Ok(())
+    }
+
+    fn apply_operation(&self, image: &mut SicImage) -> Result<(), SicImageEngineError> {
+        image.expand_indexed();
+
+        let SicImage::Static(sheet) = image else {
+            return Err(SicCoreError::RequiresStaticImage.into());
+        };
+
+        let (cell_width, cell_height) = (self.inputs.cell_width(), self.inputs.cell_height());
+
+        if cell_width == 0 || cell_height == 0 {
+            return Err(SicImageEngineError::SplitSpriteSheetCellEmpty(
+                cell_width,
+                cell_height,
+            ));
+        }
+
+        let (width, height) = sheet.dimensions();
+
+        if cell_width > width || cell_height > height {
+            return Err(SicImageEngineError::SplitSpriteSheetCellTooLarge(
+                width,
+                height,
+                cell_width,
+                cell_height,
+            ));
+        }
+
+        let spacing = u64::from(self.inputs.spacing());
+        let count =
+            |length: u32, cell: u32| (u64::from(length) + spacing) / (u64::from(cell) + spacing);
+        let columns = count(width, cell_width);
+        let rows = count(height, cell_height);
+
+        let sheet = sheet.to_rgba8();
+        let delay = Delay::from_numer_denom_ms(FRAME_DELAY_MS, 1);
+
+        // The cells fit within the sheet, so their positions fit within an u32
+        let position = |index: u64, cell: u32| (index * (u64::from(cell) + spacing)) as u32;
+
+        let frames = (0..rows)
+            .flat_map(|row| (0..columns).map(move |column| (column, row)))
+            .map(|(column, row)| {
+                let cell = imageops::crop_imm(
+                    &sheet,
+                    position(column, cell_width),
+                    position(row, cell_height),
+                    cell_width,
+                    cell_height,
+                )
+                .to_image();
+
+                Frame::from_parts(cell, 0, 0, delay)
+            })
+            .collect::<Vec<_>>();
+
+        *image = SicImage::Animated(AnimatedImage::from_frames(frames));
+
+        Ok(())
+    }
+}
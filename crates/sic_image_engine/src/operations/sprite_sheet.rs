@@ -0,0 +1,225 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::operations::frame_delay::delay_ms;
+use crate::wrapper::sprite_sheet::SpriteSheetInputs;
+use sic_core::SicImage;
+use sic_core::image::{DynamicImage, Frame, RgbaImage, imageops};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+/// Packs the frames of an animated image into a grid with the given number of columns, from left
+/// to right and top to bottom, with the given spacing between the cells and a background color.
+/// Each cell is as large as the largest frame (including its offset). The result is a static
+/// image; static images are left as is.
+pub struct SpriteSheet<'inputs> {
+    inputs: &'inputs SpriteSheetInputs,
+}
+
+impl<'inputs> SpriteSheet<'inputs> {
+    pub fn new(inputs: &'inputs SpriteSheetInputs) -> Self {
+        Self { inputs }
+    }
+
+    /// Packs the frames into a sprite sheet, and returns the atlas which describes the rectangle
+    /// of each frame if an atlas path was given. Writing the atlas is left to the caller.
+    pub fn pack(
+        &self,
+        image: &mut SicImage,
+    ) -> Result<Option<SpriteSheetAtlas>, SicImageEngineError> {
+        if self.inputs.columns() == 0 {
+            return Err(SicImageEngineError::SpriteSheetColumnsZero);
+        }
+
+        let SicImage::Animated(animated) = image else {
+            return Ok(None);
+        };
+
+        let frames = animated.frames();
+        let cells = cell_rectangles(frames, self.inputs.columns(), self.inputs.spacing())
+            .ok_or(SicImageEngineError::SpriteSheetDimensionsOverflow)?;
+
+        let (width, height) = cells.iter().fold((0, 0), |(w, h), cell| {
+            (w.max(cell.right()), h.max(cell.bottom()))
+        });
+
+        let mut sheet = RgbaImage::from_pixel(width, height, self.inputs.background());
+
+        for (frame, cell) in frames.iter().zip(&cells) {
+            imageops::overlay(
+                &mut sheet,
+                frame.buffer(),
+                i64::from(cell.x + frame.left()),
+                i64::from(cell.y + frame.top()),
+            );
+        }
+
+        let atlas = self.inputs.atlas().map(|path| SpriteSheetAtlas {
+            path: path.to_path_buf(),
+            width,
+            height,
+            frames: frames
+                .iter()
+                .zip(cells)
+                .map(|(frame, cell)| (cell, delay_ms(frame).round() as u32))
+                .collect(),
+        });
+
+        *image = SicImage::Static(DynamicImage::ImageRgba8(sheet));
+
+        Ok(atlas)
+    }
+}
+
+impl ImageOperation for SpriteSheet<'_> {
+    fn apply_to_frame(&self, _image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        Ok(())
+    }
+
+    fn apply_operation(&self, image: &mut SicImage) -> Result<(), SicImageEngineError> {
+        self.pack(image).map(|_| ())
+    }
+}
+
+/// Describes the dimensions of a sprite sheet, and the rectangle and delay (in milliseconds) of
+/// each frame, to be written as JSON to the atlas path.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpriteSheetAtlas {
+    path: PathBuf,
+    width: u32,
+    height: u32,
+    frames: Vec<(Cell, u32)>,
+}
+
+impl SpriteSheetAtlas {
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn to_json(&self) -> String {
+        let mut json = format!(
+            "{{\n  \"width\": {},\n  \"height\": {},\n  \"frames\": [",
+            self.width, self.height
+        );
+
+        for (index, (cell, delay)) in self.frames.iter().enumerate() {
+            let separator = if index == 0 { "" } else { "," };
+
+            // Writing to a String can't fail
+            let _ = write!(
+                json,
+                "{separator}\n    {{ \"index\": {index}, \"x\": {}, \"y\": {}, \"width\": {}, \"height\": {}, \"delay\": {delay} }}",
+                cell.x, cell.y, cell.width, cell.height,
+            );
+        }
+
+        json.push_str("\n  ]\n}\n");
+        json
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Cell {
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+}
+
+impl Cell {
+    fn right(&self) -> u32 {
+        self.x + self.width
+    }
+
+    fn bottom(&self) -> u32 {
+        self.y + self.height
+    }
+}
+
+/// The rectangle of each frame on the sprite sheet, or `None` if the sprite sheet would exceed
+/// the maximum image dimensions.
+fn cell_rectangles(frames: &[Frame], columns: u32, spacing: u32) -> Option<Vec<Cell>> {
+    let (width, height) = frames.iter().try_fold((0u32, 0u32), |(w, h), frame| {
+        let buffer = frame.buffer();
+        Some((
+            w.max(frame.left().checked_add(buffer.width())?),
+            h.max(frame.top().checked_add(buffer.height())?),
+        ))
+    })?;
+
+    let step_x = width.checked_add(spacing)?;
+    let step_y = height.checked_add(spacing)?;
+
+    (0..frames.len())
+        .map(|index| {
+            let index = u32::try_from(index).ok()?;
+            let x = (index % columns).checked_mul(step_x)?;
+            let y = (index / columns).checked_mul(step_y)?;
+
+            // the cell itself must fit as well
+            x.checked_add(width)?;
+            y.checked_add(height)?;
+
+            Some(Cell {
+                x,
+                y,
+                width,
+                height,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sic_core::AnimatedImage;
+    use sic_core::image::{Delay, Rgba};
+
+    fn frame(width: u32, height: u32, delay: u32) -> Frame {
+        Frame::from_parts(
+            RgbaImage::new(width, height),
+            0,
+            0,
+            Delay::from_numer_denom_ms(delay, 1),
+        )
+    }
+
+    #[test]
+    fn cells_in_rows_with_spacing() {
+        let frames = vec![frame(2, 3, 10); 5];
+        let cells = cell_rectangles(&frames, 2, 1).unwrap();
+
+        let positions = cells.iter().map(|c| (c.x, c.y)).collect::<Vec<_>>();
+        assert_eq!(positions, vec![(0, 0), (3, 0), (0, 4), (3, 4), (0, 8)]);
+        assert!(cells.iter().all(|c| (c.width, c.height) == (2, 3)));
+    }
+
+    #[test]
+    fn cells_overflow() {
+        let frames = vec![frame(1, 1, 10); 2];
+        assert!(cell_rectangles(&frames, 1, u32::MAX).is_none());
+    }
+
+    #[test]
+    fn atlas() {
+        let mut image = SicImage::Animated(AnimatedImage::from_frames(vec![
+            frame(2, 2, 40),
+            frame(2, 2, 60),
+        ]));
+        let inputs = SpriteSheetInputs::new(2, 0, Rgba([0, 0, 0, 0])).with_atlas("a.json".into());
+        let atlas = SpriteSheet::new(&inputs).pack(&mut image).unwrap().unwrap();
+
+        let expected = r#"{
+  "width": 4,
+  "height": 2,
+  "frames": [
+    { "index": 0, "x": 0, "y": 0, "width": 2, "height": 2, "delay": 40 },
+    { "index": 1, "x": 2, "y": 0, "width": 2, "height": 2, "delay": 60 }
+  ]
+}
+"#;
+
+        assert_eq!(atlas.path(), Path::new("a.json"));
+        assert_eq!(atlas.to_json(), expected);
+    }
+}
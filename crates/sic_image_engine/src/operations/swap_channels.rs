@@ -0,0 +1,41 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::operations::pixel_map::{ensure_alpha, ensure_color, map_rgba};
+use crate::wrapper::channel::Channel;
+use sic_core::image::DynamicImage;
+
+/// Swaps the values of two channels of each pixel. Swapping a color channel with the alpha channel
+/// converts grayscale images to color images, and adds an alpha channel if necessary.
+pub struct SwapChannels {
+    channels: (Channel, Channel),
+}
+
+impl SwapChannels {
+    pub fn new(channels: (Channel, Channel)) -> Self {
+        Self { channels }
+    }
+}
+
+impl ImageOperation for SwapChannels {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        let (lhs, rhs) = self.channels;
+
+        if lhs == rhs {
+            return Ok(());
+        }
+
+        // Swapping color channels of a grayscale image has no effect, so it only needs to be
+        // converted when the alpha channel is involved.
+        if lhs == Channel::Alpha || rhs == Channel::Alpha {
+            ensure_color(image);
+            ensure_alpha(image);
+        }
+
+        map_rgba(image, |mut pixel| {
+            pixel.swap(lhs.index(), rhs.index());
+            pixel
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use sic_core::image::{DynamicImage, GenericImageView, Rgba};
+
+/// Removes borders which match the color of the top-left corner pixel.
+/// A pixel matches if none of its channels differ more than `tolerance` from the corner color.
+pub struct Trim {
+    tolerance: u8,
+}
+
+impl Trim {
+    pub fn new(tolerance: u8) -> Self {
+        Self { tolerance }
+    }
+}
+
+impl ImageOperation for Trim {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        if let Some((x, y, width, height)) = trim_selection(image, self.tolerance) {
+            *image = image.crop_imm(x, y, width, height);
+        }
+
+        Ok(())
+    }
+}
+
+/// Finds the smallest selection which contains all pixels which don't match the border color.
+/// Returns `None` if the image is empty, or if all pixels match the border color, in which case
+/// the image is left as is.
+fn trim_selection(image: &DynamicImage, tolerance: u8) -> Option<(u32, u32, u32, u32)> {
+    let (width, height) = image.dimensions();
+
+    if width == 0 || height == 0 {
+        return None;
+    }
+
+    let border = image.get_pixel(0, 0);
+    let is_border = |x: u32, y: u32| matches_color(image.get_pixel(x, y), border, tolerance);
+
+    let row_is_border = |y: u32| (0..width).all(|x| is_border(x, y));
+    let column_is_border = |x: u32, top: u32, bottom: u32| (top..bottom).all(|y| is_border(x, y));
+
+    let top = (0..height).find(|&y| !row_is_border(y))?;
+    let bottom = (top..height).rev().find(|&y| !row_is_border(y))? + 1;
+    let left = (0..width).find(|&x| !column_is_border(x, top, bottom))?;
+    let right = (left..width)
+        .rev()
+        .find(|&x| !column_is_border(x, top, bottom))?
+        + 1;
+
+    Some((left, top, right - left, bottom - top))
+}
+
+fn matches_color(pixel: Rgba<u8>, border: Rgba<u8>, tolerance: u8) -> bool {
+    pixel
+        .0
+        .iter()
+        .zip(border.0.iter())
+        .all(|(lhs, rhs)| lhs.abs_diff(*rhs) <= tolerance)
+}
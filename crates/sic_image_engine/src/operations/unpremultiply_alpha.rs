@@ -0,0 +1,29 @@
+use crate::errors::SicImageEngineError;
+use crate::operations::ImageOperation;
+use crate::operations::pixel_map::map_rgba;
+use sic_core::image::DynamicImage;
+
+/// Divides the color channels of each pixel by its alpha value; the inverse of
+/// [`PremultiplyAlpha`](crate::operations::premultiply_alpha::PremultiplyAlpha). Fully transparent
+/// pixels and images without an alpha channel are unchanged.
+pub struct UnpremultiplyAlpha;
+
+impl UnpremultiplyAlpha {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl ImageOperation for UnpremultiplyAlpha {
+    fn apply_to_frame(&self, image: &mut DynamicImage) -> Result<(), SicImageEngineError> {
+        map_rgba(image, |[r, g, b, a]| {
+            if a > 0.0 {
+                [r / a, g / a, b / a, a]
+            } else {
+                [r, g, b, a]
+            }
+        });
+
+        Ok(())
+    }
+}
@@ -33,6 +33,10 @@ impl ImageOperation for VerticalGradient {
                 self.colors,
                 imageops::vertical_gradient,
             ),
+            SicImage::Indexed(_) => {
+                image.expand_indexed();
+                self.apply_operation(image)?
+            }
         }
 
         Ok(())
@@ -0,0 +1,31 @@
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BilateralInputs {
+    radius: u8,
+    sigma_color: f32,
+    sigma_space: f32,
+}
+
+impl BilateralInputs {
+    pub fn new(radius: u8, sigma_color: f32, sigma_space: f32) -> Self {
+        Self {
+            radius,
+            sigma_color,
+            sigma_space,
+        }
+    }
+
+    /// The radius of the window; a radius of `2` gives a window of 5x5 pixels.
+    pub fn radius(&self) -> u8 {
+        self.radius
+    }
+
+    /// The standard deviation of the color distance, on a scale of `0` to `255`.
+    pub fn sigma_color(&self) -> f32 {
+        self.sigma_color
+    }
+
+    /// The standard deviation of the spatial distance, in pixels.
+    pub fn sigma_space(&self) -> f32 {
+        self.sigma_space
+    }
+}
@@ -0,0 +1,140 @@
+use std::str::FromStr;
+
+use sic_core::image::DynamicImage;
+
+use crate::errors::SicImageEngineError;
+use crate::wrapper::image_path::ImageFromPath;
+
+/// A channel of an RGBA pixel.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, EnumString)]
+#[strum(ascii_case_insensitive)]
+pub enum Channel {
+    #[strum(serialize = "red", serialize = "r")]
+    Red,
+    #[strum(serialize = "green", serialize = "g")]
+    Green,
+    #[strum(serialize = "blue", serialize = "b")]
+    Blue,
+    #[strum(serialize = "alpha", serialize = "a")]
+    Alpha,
+}
+
+impl Channel {
+    pub const ALL: [Channel; 4] = [Channel::Red, Channel::Green, Channel::Blue, Channel::Alpha];
+
+    /// The index of the channel within an RGBA pixel.
+    pub fn index(self) -> usize {
+        match self {
+            Channel::Red => 0,
+            Channel::Green => 1,
+            Channel::Blue => 2,
+            Channel::Alpha => 3,
+        }
+    }
+}
+
+/// A channel of an RGBA pixel, or the luma of its color channels.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ChannelOrLuma {
+    Channel(Channel),
+    Luma,
+}
+
+impl FromStr for ChannelOrLuma {
+    type Err = strum::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("luma") {
+            Ok(Self::Luma)
+        } else {
+            Channel::from_str(s).map(Self::Channel)
+        }
+    }
+}
+
+/// The channels to which image operations are applied. Used by the `channel` modifier.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum ChannelScope {
+    /// Apply image operations to the complete image.
+    #[default]
+    All,
+    /// Apply image operations to a single channel only, as if it were a grayscale image.
+    Only(Channel),
+}
+
+impl FromStr for ChannelScope {
+    type Err = strum::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("all") {
+            Ok(Self::All)
+        } else {
+            Channel::from_str(s).map(Self::Only)
+        }
+    }
+}
+
+/// The images which make up the red, green, blue and alpha channels of a merged image. For each
+/// channel, the luma of the given image is used; channels without an image keep their values.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MergeChannelsInputs {
+    sources: [Option<ImageFromPath>; 4],
+}
+
+impl MergeChannelsInputs {
+    pub fn new(sources: [Option<ImageFromPath>; 4]) -> Self {
+        Self { sources }
+    }
+
+    /// The image for each channel, in RGBA order.
+    pub fn sources(&self) -> &[Option<ImageFromPath>; 4] {
+        &self.sources
+    }
+
+    /// Opens the image of each channel.
+    pub fn load(&self) -> Result<[Option<DynamicImage>; 4], SicImageEngineError> {
+        let mut images = [None, None, None, None];
+
+        for (image, source) in images.iter_mut().zip(&self.sources) {
+            if let Some(path) = source {
+                *image = Some(DynamicImage::try_from(path.open_image()?)?);
+            }
+        }
+
+        Ok(images)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn channel_from_str() {
+        assert_eq!(Channel::from_str("Red").unwrap(), Channel::Red);
+        assert_eq!(Channel::from_str("a").unwrap(), Channel::Alpha);
+        assert!(Channel::from_str("luma").is_err());
+    }
+
+    #[test]
+    fn channel_or_luma_from_str() {
+        assert_eq!(
+            ChannelOrLuma::from_str("LUMA").unwrap(),
+            ChannelOrLuma::Luma
+        );
+        assert_eq!(
+            ChannelOrLuma::from_str("g").unwrap(),
+            ChannelOrLuma::Channel(Channel::Green)
+        );
+    }
+
+    #[test]
+    fn channel_scope_from_str() {
+        assert_eq!(ChannelScope::from_str("all").unwrap(), ChannelScope::All);
+        assert_eq!(
+            ChannelScope::from_str("alpha").unwrap(),
+            ChannelScope::Only(Channel::Alpha)
+        );
+        assert!(ChannelScope::from_str("luma").is_err());
+    }
+}
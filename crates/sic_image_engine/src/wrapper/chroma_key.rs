@@ -0,0 +1,47 @@
+use sic_core::image::Rgba;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ChromaKeyInputs {
+    key: Rgba<u8>,
+    tolerance: f32,
+    softness: f32,
+    despill: bool,
+}
+
+impl ChromaKeyInputs {
+    pub fn new(key: Rgba<u8>, tolerance: f32, softness: f32) -> Self {
+        Self {
+            key,
+            tolerance,
+            softness,
+            despill: false,
+        }
+    }
+
+    pub fn with_despill(self, despill: bool) -> Self {
+        Self { despill, ..self }
+    }
+
+    /// The key color; its alpha channel is ignored.
+    pub fn key(&self) -> Rgba<u8> {
+        self.key
+    }
+
+    /// Pixels of which the color lies within this distance of the key color become fully
+    /// transparent. Distances are measured in RGB space, on a scale of `0` to `255` per channel.
+    pub fn tolerance(&self) -> f32 {
+        self.tolerance
+    }
+
+    /// The width of the transition beyond the tolerance, over which pixels fade from fully
+    /// transparent to their original opacity.
+    pub fn softness(&self) -> f32 {
+        self.softness
+    }
+
+    /// Whether the tint of the key color is removed from the remaining pixels, e.g. a green glow
+    /// reflected on a product shot in front of a green screen.
+    pub fn despill(&self) -> bool {
+        self.despill
+    }
+}
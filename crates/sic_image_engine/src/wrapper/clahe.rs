@@ -0,0 +1,32 @@
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ClaheInputs {
+    tiles_x: u32,
+    tiles_y: u32,
+    clip_limit: f32,
+}
+
+impl ClaheInputs {
+    pub fn new(tiles_x: u32, tiles_y: u32, clip_limit: f32) -> Self {
+        Self {
+            tiles_x,
+            tiles_y,
+            clip_limit,
+        }
+    }
+
+    /// The number of tiles in horizontal direction.
+    pub fn tiles_x(&self) -> u32 {
+        self.tiles_x
+    }
+
+    /// The number of tiles in vertical direction.
+    pub fn tiles_y(&self) -> u32 {
+        self.tiles_y
+    }
+
+    /// The maximum height of a histogram bin, as a multiple of the average bin height of a tile.
+    /// Lower values limit the contrast enhancement (and the amplification of noise).
+    pub fn clip_limit(&self) -> f32 {
+        self.clip_limit
+    }
+}
@@ -0,0 +1,157 @@
+/// A 4x5 matrix, in row-major order, which maps each RGBA pixel to a new RGBA pixel.
+///
+/// Each row computes one output channel (red, green, blue and alpha respectively), as the
+/// weighted sum of the red, green, blue and alpha input channels, plus the offset in the fifth
+/// column. Channel values are normalized to the range `[0, 1]`, so an offset of `1.0` equals the
+/// maximum channel value.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ColorMatrixInputs {
+    matrix: [f32; 20],
+}
+
+impl ColorMatrixInputs {
+    pub fn new(matrix: [f32; 20]) -> Self {
+        Self { matrix }
+    }
+
+    pub fn matrix(&self) -> &[f32; 20] {
+        &self.matrix
+    }
+
+    /// Multiplies the matrix with a normalized RGBA pixel.
+    pub fn apply(&self, pixel: [f32; 4]) -> [f32; 4] {
+        std::array::from_fn(|row| {
+            let row = &self.matrix[row * 5..row * 5 + 5];
+            row[0] * pixel[0] + row[1] * pixel[1] + row[2] * pixel[2] + row[3] * pixel[3] + row[4]
+        })
+    }
+
+    /// Whether the alpha channel is left unchanged by this matrix.
+    pub fn preserves_alpha(&self) -> bool {
+        self.matrix[15..20] == [0.0, 0.0, 0.0, 1.0, 0.0]
+    }
+}
+
+/// Named, built-in color matrices.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, EnumString)]
+#[strum(serialize_all = "kebab_case", ascii_case_insensitive)]
+pub enum ColorMatrixPreset {
+    /// Gives the image a warm, brown tone.
+    Sepia,
+    /// Swaps the red and blue channels.
+    SwapRb,
+    /// Replaces each color channel by the luminance (Rec. 709) of the pixel.
+    Desaturate,
+    /// Simulates how the image is perceived with protanopia (red-blindness), based on
+    /// Machado, Oliveira and Fernandes (2009).
+    Protanopia,
+    /// Stores the luminance (Rec. 709) of the pixel in the alpha channel, and sets the color
+    /// channels to black.
+    LuminanceToAlpha,
+}
+
+impl ColorMatrixPreset {
+    pub fn matrix(self) -> ColorMatrixInputs {
+        #[rustfmt::skip]
+        let matrix = match self {
+            Self::Sepia => [
+                0.393, 0.769, 0.189, 0.0, 0.0,
+                0.349, 0.686, 0.168, 0.0, 0.0,
+                0.272, 0.534, 0.131, 0.0, 0.0,
+                0.0, 0.0, 0.0, 1.0, 0.0,
+            ],
+            Self::SwapRb => [
+                0.0, 0.0, 1.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0, 0.0,
+                1.0, 0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 1.0, 0.0,
+            ],
+            Self::Desaturate => [
+                0.2126, 0.7152, 0.0722, 0.0, 0.0,
+                0.2126, 0.7152, 0.0722, 0.0, 0.0,
+                0.2126, 0.7152, 0.0722, 0.0, 0.0,
+                0.0, 0.0, 0.0, 1.0, 0.0,
+            ],
+            Self::Protanopia => [
+                0.152_286, 1.052_583, -0.204_868, 0.0, 0.0,
+                0.114_503, 0.786_281, 0.099_216, 0.0, 0.0,
+                -0.003_882, -0.048_116, 1.051_998, 0.0, 0.0,
+                0.0, 0.0, 0.0, 1.0, 0.0,
+            ],
+            Self::LuminanceToAlpha => [
+                0.0, 0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0, 0.0,
+                0.2126, 0.7152, 0.0722, 0.0, 0.0,
+            ],
+        };
+
+        ColorMatrixInputs::new(matrix)
+    }
+}
+
+impl From<ColorMatrixPreset> for ColorMatrixInputs {
+    fn from(preset: ColorMatrixPreset) -> Self {
+        preset.matrix()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn apply_identity() {
+        #[rustfmt::skip]
+        let identity = ColorMatrixInputs::new([
+            1.0, 0.0, 0.0, 0.0, 0.0,
+            0.0, 1.0, 0.0, 0.0, 0.0,
+            0.0, 0.0, 1.0, 0.0, 0.0,
+            0.0, 0.0, 0.0, 1.0, 0.0,
+        ]);
+
+        assert_eq!(identity.apply([0.1, 0.2, 0.3, 0.4]), [0.1, 0.2, 0.3, 0.4]);
+        assert!(identity.preserves_alpha());
+    }
+
+    #[test]
+    fn apply_offset() {
+        let mut matrix = [0.0; 20];
+        matrix[4] = 0.5;
+        matrix[19] = 1.0;
+
+        let inputs = ColorMatrixInputs::new(matrix);
+        assert_eq!(inputs.apply([1.0, 1.0, 1.0, 0.0]), [0.5, 0.0, 0.0, 1.0]);
+        assert!(!inputs.preserves_alpha());
+    }
+
+    #[test]
+    fn swap_rb() {
+        let inputs = ColorMatrixInputs::from(ColorMatrixPreset::SwapRb);
+        assert_eq!(inputs.apply([0.1, 0.2, 0.3, 0.4]), [0.3, 0.2, 0.1, 0.4]);
+    }
+
+    #[test]
+    fn luminance_to_alpha() {
+        let inputs = ColorMatrixPreset::LuminanceToAlpha.matrix();
+        let [r, g, b, a] = inputs.apply([1.0, 1.0, 1.0, 0.0]);
+
+        assert_eq!([r, g, b], [0.0; 3]);
+        assert!((a - 1.0).abs() < 1e-6);
+        assert!(!inputs.preserves_alpha());
+    }
+
+    #[test]
+    fn preset_from_str() {
+        assert_eq!(
+            ColorMatrixPreset::from_str("swap-rb").unwrap(),
+            ColorMatrixPreset::SwapRb
+        );
+        assert_eq!(
+            ColorMatrixPreset::from_str("Luminance-To-Alpha").unwrap(),
+            ColorMatrixPreset::LuminanceToAlpha
+        );
+        assert!(ColorMatrixPreset::from_str("swap").is_err());
+    }
+}
@@ -0,0 +1,112 @@
+use crate::errors::SicImageEngineError;
+use std::str::FromStr;
+
+/// The channel(s) a curve is applied to.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, EnumString)]
+#[strum(serialize_all = "kebab_case", ascii_case_insensitive)]
+pub enum CurvesChannel {
+    /// Apply the curve to each of the red, green and blue channels.
+    Rgb,
+    Red,
+    Green,
+    Blue,
+    /// Apply the curve to the luminance of each pixel, and shift the color channels accordingly.
+    Luminance,
+}
+
+/// The control points of a curve, as `(input, output)` pairs on a scale of `0` to `255`.
+/// The points are sorted by their input value, and there are at least two of them.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CurvePoints(Vec<(u8, u8)>);
+
+impl CurvePoints {
+    pub fn new(mut points: Vec<(u8, u8)>) -> Result<Self, SicImageEngineError> {
+        points.sort_by_key(|(input, _)| *input);
+
+        if points.len() < 2 {
+            return Err(SicImageEngineError::InvalidCurvePoints(String::from(
+                "at least two points are required",
+            )));
+        }
+
+        if points.windows(2).any(|pair| pair[0].0 == pair[1].0) {
+            return Err(SicImageEngineError::InvalidCurvePoints(String::from(
+                "each point should have a unique input value",
+            )));
+        }
+
+        Ok(Self(points))
+    }
+
+    pub fn points(&self) -> &[(u8, u8)] {
+        &self.0
+    }
+}
+
+/// Parses points of the form `<input>:<output>`, separated by commas, e.g. `0:0,64:50,255:255`.
+impl FromStr for CurvePoints {
+    type Err = SicImageEngineError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = |point: &str| {
+            SicImageEngineError::InvalidCurvePoints(format!(
+                "expected a point of the form '<input>:<output>', where both values are in the range 0 to 255, but got '{}'",
+                point
+            ))
+        };
+
+        let points = s
+            .split(',')
+            .map(|point| {
+                let (input, output) = point.split_once(':').ok_or_else(|| invalid(point))?;
+                let input = input.trim().parse::<u8>().map_err(|_| invalid(point))?;
+                let output = output.trim().parse::<u8>().map_err(|_| invalid(point))?;
+
+                Ok((input, output))
+            })
+            .collect::<Result<Vec<_>, SicImageEngineError>>()?;
+
+        Self::new(points)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CurvesInputs {
+    channel: CurvesChannel,
+    points: CurvePoints,
+}
+
+impl CurvesInputs {
+    pub fn new(channel: CurvesChannel, points: CurvePoints) -> Self {
+        Self { channel, points }
+    }
+
+    pub fn channel(&self) -> CurvesChannel {
+        self.channel
+    }
+
+    pub fn points(&self) -> &CurvePoints {
+        &self.points
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_points_sorted() {
+        let points = "255:200,0:10,128:128".parse::<CurvePoints>().unwrap();
+        assert_eq!(points.points(), &[(0, 10), (128, 128), (255, 200)]);
+    }
+
+    #[test]
+    fn parse_points_invalid() {
+        assert!("0:0".parse::<CurvePoints>().is_err());
+        assert!("0:0,0:255".parse::<CurvePoints>().is_err());
+        assert!("0:0,256:255".parse::<CurvePoints>().is_err());
+        assert!("0:0,255".parse::<CurvePoints>().is_err());
+        assert!("0:0;255:255".parse::<CurvePoints>().is_err());
+        assert!("".parse::<CurvePoints>().is_err());
+    }
+}
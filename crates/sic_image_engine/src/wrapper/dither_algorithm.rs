@@ -0,0 +1,49 @@
+/// The algorithm used by the dithering operations to distribute the quantization error.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, EnumString)]
+#[strum(ascii_case_insensitive)]
+pub enum DitherAlgorithm {
+    /// Floyd–Steinberg error diffusion.
+    #[default]
+    #[strum(serialize = "floyd-steinberg")]
+    FloydSteinberg,
+    /// Atkinson error diffusion, which only diffuses three quarters of the error.
+    #[strum(serialize = "atkinson")]
+    Atkinson,
+    /// Jarvis–Judice–Ninke error diffusion.
+    #[strum(serialize = "jarvis-judice-ninke")]
+    JarvisJudiceNinke,
+    /// Stucki error diffusion.
+    #[strum(serialize = "stucki")]
+    Stucki,
+    /// Sierra (three row) error diffusion.
+    #[strum(serialize = "sierra")]
+    Sierra,
+    /// Ordered dithering with a 2x2 Bayer threshold matrix.
+    #[strum(serialize = "bayer2x2")]
+    Bayer2x2,
+    /// Ordered dithering with a 4x4 Bayer threshold matrix.
+    #[strum(serialize = "bayer4x4")]
+    Bayer4x4,
+    /// Ordered dithering with an 8x8 Bayer threshold matrix.
+    #[strum(serialize = "bayer8x8")]
+    Bayer8x8,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn from_str() {
+        assert_eq!(
+            DitherAlgorithm::from_str("Jarvis-Judice-Ninke").unwrap(),
+            DitherAlgorithm::JarvisJudiceNinke
+        );
+        assert_eq!(
+            DitherAlgorithm::from_str("bayer8x8").unwrap(),
+            DitherAlgorithm::Bayer8x8
+        );
+        assert!(DitherAlgorithm::from_str("bayer3x3").is_err());
+    }
+}
@@ -0,0 +1,10 @@
+/// Determines which values are equalized.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, EnumString)]
+#[strum(serialize_all = "kebab_case", ascii_case_insensitive)]
+pub enum EqualizeMode {
+    /// Equalize the luma of each pixel, shifting its color channels together. Preserves the hue
+    /// of the image.
+    Luma,
+    /// Equalize the red, green and blue channels independently. May shift the hue of the image.
+    Channels,
+}
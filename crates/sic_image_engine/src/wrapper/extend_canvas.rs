@@ -0,0 +1,35 @@
+use crate::wrapper::gravity::Gravity;
+use sic_core::image::Rgba;
+
+/// The dimensions of the new canvas, where the image is placed on it, and the color of the
+/// pixels of the canvas which are not covered by the image.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ExtendCanvasInputs {
+    width: u32,
+    height: u32,
+    gravity: Gravity,
+    fill: Rgba<u8>,
+}
+
+impl ExtendCanvasInputs {
+    pub fn new(width: u32, height: u32, gravity: Gravity, fill: Rgba<u8>) -> Self {
+        Self {
+            width,
+            height,
+            gravity,
+            fill,
+        }
+    }
+
+    pub fn dimensions(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    pub fn gravity(&self) -> Gravity {
+        self.gravity
+    }
+
+    pub fn fill(&self) -> Rgba<u8> {
+        self.fill
+    }
+}
@@ -0,0 +1,336 @@
+use crate::errors::SicImageEngineError;
+use std::str::FromStr;
+
+/// The shape of a convolution kernel, written as `<width>x<height>` for a full kernel, or as
+/// `<width>x<height>-separable` for a separable kernel. Both dimensions should be odd, so the
+/// kernel has a center pixel.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum KernelShape {
+    /// A kernel of `width * height` values, in row-major order.
+    Full { width: u32, height: u32 },
+    /// A kernel given as a row vector of `width` values, followed by a column vector of `height`
+    /// values. Equivalent to the full kernel which is the outer product of both vectors, but
+    /// faster to apply.
+    Separable { width: u32, height: u32 },
+}
+
+impl KernelShape {
+    /// The number of kernel values which follow from the shape.
+    pub fn number_of_values(self) -> usize {
+        match self {
+            Self::Full { width, height } => width as usize * height as usize,
+            Self::Separable { width, height } => width as usize + height as usize,
+        }
+    }
+}
+
+impl FromStr for KernelShape {
+    type Err = SicImageEngineError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || SicImageEngineError::InvalidKernelShape(s.to_string());
+
+        let lowercase = s.to_ascii_lowercase();
+        let (dimensions, separable) = match lowercase.strip_suffix("-separable") {
+            Some(dimensions) => (dimensions, true),
+            None => (lowercase.as_str(), false),
+        };
+
+        let (width, height) = dimensions.split_once('x').ok_or_else(invalid)?;
+        let parse_odd = |value: &str| {
+            value
+                .parse::<u32>()
+                .ok()
+                .filter(|value| value % 2 == 1)
+                .ok_or_else(invalid)
+        };
+
+        let (width, height) = (parse_odd(width)?, parse_odd(height)?);
+
+        Ok(if separable {
+            Self::Separable { width, height }
+        } else {
+            Self::Full { width, height }
+        })
+    }
+}
+
+/// A convolution kernel, with odd dimensions.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Kernel {
+    Full {
+        width: u32,
+        height: u32,
+        values: Vec<f32>,
+    },
+    Separable {
+        row: Vec<f32>,
+        column: Vec<f32>,
+    },
+}
+
+impl Kernel {
+    /// Creates a kernel from its shape and values; the number of values should match the shape.
+    pub fn new(shape: KernelShape, values: Vec<f32>) -> Result<Self, SicImageEngineError> {
+        if values.len() != shape.number_of_values() {
+            return Err(SicImageEngineError::InvalidKernelValues(
+                shape.number_of_values(),
+                values.len(),
+            ));
+        }
+
+        Ok(match shape {
+            KernelShape::Full { width, height } => Self::Full {
+                width,
+                height,
+                values,
+            },
+            KernelShape::Separable { width, .. } => {
+                let mut row = values;
+                let column = row.split_off(width as usize);
+
+                Self::Separable { row, column }
+            }
+        })
+    }
+
+    /// The sum of the weights of the (equivalent full) kernel.
+    pub fn sum(&self) -> f32 {
+        match self {
+            Self::Full { values, .. } => values.iter().sum(),
+            Self::Separable { row, column } => row.iter().sum::<f32>() * column.iter().sum::<f32>(),
+        }
+    }
+}
+
+/// The value each convolved pixel is divided by.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Divisor {
+    /// Divide by the sum of the kernel weights, so the kernel preserves the overall brightness of
+    /// the image. If the weights sum up to zero, the divisor is `1.0`.
+    Auto,
+    Value(f32),
+}
+
+impl FromStr for Divisor {
+    type Err = SicImageEngineError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("auto") {
+            return Ok(Self::Auto);
+        }
+
+        s.parse::<f32>()
+            .ok()
+            .filter(|value| value.is_finite() && *value != 0.0)
+            .map(Self::Value)
+            .ok_or_else(|| SicImageEngineError::InvalidDivisor(s.to_string()))
+    }
+}
+
+/// Determines which pixels are sampled when the kernel extends beyond the edges of the image.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, EnumString)]
+#[strum(serialize_all = "kebab_case", ascii_case_insensitive)]
+pub enum EdgeMode {
+    /// Repeat the pixels at the edge of the image.
+    Clamp,
+    /// Sample from the opposite side of the image, as if the image is tiled.
+    Wrap,
+    /// Sample from the image mirrored at its edges.
+    Mirror,
+}
+
+impl EdgeMode {
+    /// Maps a (possibly out of bounds) coordinate to a coordinate within `0..length`.
+    pub fn resolve(self, coordinate: i64, length: u32) -> u32 {
+        let length = i64::from(length);
+
+        let resolved = match self {
+            Self::Clamp => coordinate.clamp(0, length - 1),
+            Self::Wrap => coordinate.rem_euclid(length),
+            Self::Mirror => {
+                let period = coordinate.rem_euclid(2 * length);
+
+                if period < length {
+                    period
+                } else {
+                    2 * length - 1 - period
+                }
+            }
+        };
+
+        resolved as u32
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FilterInputs {
+    kernel: Kernel,
+    divisor: Divisor,
+    bias: f32,
+    edge_mode: EdgeMode,
+}
+
+impl FilterInputs {
+    pub fn new(kernel: Kernel, divisor: Divisor, bias: f32, edge_mode: EdgeMode) -> Self {
+        Self {
+            kernel,
+            divisor,
+            bias,
+            edge_mode,
+        }
+    }
+
+    pub fn kernel(&self) -> &Kernel {
+        &self.kernel
+    }
+
+    /// The resolved divisor, see [`Divisor`].
+    pub fn divisor(&self) -> f32 {
+        match self.divisor {
+            Divisor::Auto => {
+                let sum = self.kernel.sum();
+
+                if sum.abs() < f32::EPSILON { 1.0 } else { sum }
+            }
+            Divisor::Value(value) => value,
+        }
+    }
+
+    /// The value added to each convolved channel, on a scale of `0.0` to `1.0`.
+    pub fn bias(&self) -> f32 {
+        self.bias
+    }
+
+    pub fn edge_mode(&self) -> EdgeMode {
+        self.edge_mode
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kernel_shape_from_str() {
+        let cases = [
+            (
+                "3x3",
+                KernelShape::Full {
+                    width: 3,
+                    height: 3,
+                },
+            ),
+            (
+                "5X1",
+                KernelShape::Full {
+                    width: 5,
+                    height: 1,
+                },
+            ),
+            (
+                "1x7-separable",
+                KernelShape::Separable {
+                    width: 1,
+                    height: 7,
+                },
+            ),
+            (
+                "3x3-Separable",
+                KernelShape::Separable {
+                    width: 3,
+                    height: 3,
+                },
+            ),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(KernelShape::from_str(input).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn kernel_shape_from_str_err() {
+        for input in [
+            "3",
+            "2x3",
+            "3x0",
+            "3x3x3",
+            "x3",
+            "3x3-",
+            "3x3separable",
+            "-3x3",
+        ] {
+            assert!(KernelShape::from_str(input).is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn separable_kernel() {
+        let shape = KernelShape::Separable {
+            width: 3,
+            height: 1,
+        };
+        let kernel = Kernel::new(shape, vec![1.0, 2.0, 1.0, 2.0]).unwrap();
+
+        assert_eq!(
+            kernel,
+            Kernel::Separable {
+                row: vec![1.0, 2.0, 1.0],
+                column: vec![2.0],
+            }
+        );
+        assert_eq!(kernel.sum(), 8.0);
+    }
+
+    #[test]
+    fn kernel_values_mismatch() {
+        let shape = KernelShape::Full {
+            width: 3,
+            height: 3,
+        };
+
+        assert!(Kernel::new(shape, vec![1.0; 8]).is_err());
+    }
+
+    #[test]
+    fn divisor() {
+        assert_eq!(Divisor::from_str("auto").unwrap(), Divisor::Auto);
+        assert_eq!(Divisor::from_str("-2.5").unwrap(), Divisor::Value(-2.5));
+        assert!(Divisor::from_str("0").is_err());
+        assert!(Divisor::from_str("a").is_err());
+    }
+
+    #[test]
+    fn auto_divisor_of_zero_sum_kernel() {
+        let shape = KernelShape::Full {
+            width: 3,
+            height: 1,
+        };
+        let kernel = Kernel::new(shape, vec![-1.0, 0.0, 1.0]).unwrap();
+        let inputs = FilterInputs::new(kernel, Divisor::Auto, 0.0, EdgeMode::Clamp);
+
+        assert_eq!(inputs.divisor(), 1.0);
+    }
+
+    #[test]
+    fn edge_mode_resolve() {
+        // (coordinate, (clamp, wrap, mirror)), for a length of 3
+        let cases = [
+            (-3, (0, 0, 2)),
+            (-2, (0, 1, 1)),
+            (-1, (0, 2, 0)),
+            (0, (0, 0, 0)),
+            (2, (2, 2, 2)),
+            (3, (2, 0, 2)),
+            (4, (2, 1, 1)),
+            (7, (2, 1, 1)),
+        ];
+
+        for (coordinate, (clamp, wrap, mirror)) in cases {
+            assert_eq!(EdgeMode::Clamp.resolve(coordinate, 3), clamp);
+            assert_eq!(EdgeMode::Wrap.resolve(coordinate, 3), wrap);
+            assert_eq!(EdgeMode::Mirror.resolve(coordinate, 3), mirror);
+        }
+    }
+}
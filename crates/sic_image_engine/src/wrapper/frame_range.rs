@@ -0,0 +1,122 @@
+use crate::errors::SicImageEngineError;
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+
+/// A range of zero-based frame indices, like a Rust range: `3..10` selects the frames 3 up to
+/// (but excluding) 10, `3..=10` also includes frame 10, and the start or end may be omitted to
+/// select the frames from the first frame or up to the last frame.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FrameRange {
+    start: Option<u32>,
+    end: Option<u32>,
+    inclusive: bool,
+}
+
+impl FrameRange {
+    pub fn new(start: Option<u32>, end: Option<u32>, inclusive: bool) -> Self {
+        Self {
+            start,
+            end,
+            inclusive: inclusive && end.is_some(),
+        }
+    }
+
+    /// Resolves the range for an animation with `len` frames. An end past the last frame selects
+    /// up to the last frame. Returns `None` if the range selects no frames.
+    pub fn resolve(&self, len: usize) -> Option<Range<usize>> {
+        let start = self.start.map_or(0, |start| start as usize);
+        let end = match self.end {
+            Some(end) if self.inclusive => (end as usize).saturating_add(1),
+            Some(end) => end as usize,
+            None => len,
+        }
+        .min(len);
+
+        (start < end).then_some(start..end)
+    }
+}
+
+impl FromStr for FrameRange {
+    type Err = SicImageEngineError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || SicImageEngineError::InvalidFrameRange(s.to_string());
+        let bound = |bound: &str| match bound {
+            "" => Ok(None),
+            bound => bound.parse::<u32>().map(Some).map_err(|_| invalid()),
+        };
+
+        let (start, end) = s.split_once("..").ok_or_else(invalid)?;
+
+        match end.strip_prefix('=') {
+            Some(end) if !end.is_empty() => Ok(Self::new(bound(start)?, bound(end)?, true)),
+            Some(_) => Err(invalid()),
+            None => Ok(Self::new(bound(start)?, bound(end)?, false)),
+        }
+    }
+}
+
+impl fmt::Display for FrameRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(start) = self.start {
+            write!(f, "{start}")?;
+        }
+
+        f.write_str(if self.inclusive { "..=" } else { ".." })?;
+
+        if let Some(end) = self.end {
+            write!(f, "{end}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_str() {
+        assert_eq!(
+            FrameRange::from_str("3..10").unwrap(),
+            FrameRange::new(Some(3), Some(10), false)
+        );
+        assert_eq!(
+            FrameRange::from_str("..=4").unwrap(),
+            FrameRange::new(None, Some(4), true)
+        );
+        assert_eq!(
+            FrameRange::from_str("2..").unwrap(),
+            FrameRange::new(Some(2), None, false)
+        );
+        assert_eq!(
+            FrameRange::from_str("..").unwrap(),
+            FrameRange::new(None, None, false)
+        );
+
+        for invalid in ["3", "3..=", "a..4", "-1..4", "1...4"] {
+            assert!(FrameRange::from_str(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn resolve() {
+        let range = |s: &str| FrameRange::from_str(s).unwrap();
+
+        assert_eq!(range("3..10").resolve(20), Some(3..10));
+        assert_eq!(range("3..=10").resolve(20), Some(3..11));
+        assert_eq!(range("3..").resolve(20), Some(3..20));
+        assert_eq!(range("..30").resolve(20), Some(0..20));
+        assert_eq!(range("20..").resolve(20), None);
+        assert_eq!(range("5..5").resolve(20), None);
+    }
+
+    #[test]
+    fn display() {
+        for range in ["3..10", "3..=10", "3..", "..10", ".."] {
+            assert_eq!(FrameRange::from_str(range).unwrap().to_string(), range);
+        }
+    }
+}
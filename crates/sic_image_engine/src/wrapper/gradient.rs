@@ -0,0 +1,185 @@
+use sic_core::image::Rgba;
+
+use crate::errors::SicImageEngineError;
+
+/// The shape of a gradient, which determines how the position of a pixel maps to a position on
+/// the gradient.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GradientShape {
+    /// Colors change along a line through the center of the image, at the given angle in degrees.
+    /// An angle of 0 degrees runs from bottom to top, and angles increase clockwise, e.g. 90
+    /// degrees runs from left to right.
+    Linear { angle: f32 },
+    /// Colors change with the distance to the center of the image, up to its corners.
+    Radial,
+    /// Colors change with the angle around the center of the image, starting at the given angle
+    /// in degrees (0 degrees is at the top) and increasing clockwise.
+    Conic { angle: f32 },
+}
+
+/// A color of a gradient, optionally at a fixed position on the gradient, where `0.0` is the start
+/// and `1.0` is the end of the gradient.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ColorStop {
+    color: Rgba<u8>,
+    position: Option<f32>,
+}
+
+impl ColorStop {
+    pub fn new(color: Rgba<u8>, position: Option<f32>) -> Self {
+        Self { color, position }
+    }
+
+    pub fn color(&self) -> Rgba<u8> {
+        self.color
+    }
+
+    pub fn position(&self) -> Option<f32> {
+        self.position
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GradientInputs {
+    shape: GradientShape,
+    stops: Vec<ColorStop>,
+}
+
+impl GradientInputs {
+    pub fn new(shape: GradientShape, stops: Vec<ColorStop>) -> Self {
+        Self { shape, stops }
+    }
+
+    pub fn shape(&self) -> GradientShape {
+        self.shape
+    }
+
+    pub fn stops(&self) -> &[ColorStop] {
+        &self.stops
+    }
+
+    /// The color stops with their positions resolved, like CSS gradients: a missing first and
+    /// last position default to the start and end of the gradient, a position never precedes the
+    /// position of an earlier stop, and stops without a position are spread evenly between their
+    /// neighbours.
+    pub fn resolved_stops(&self) -> Result<Vec<(Rgba<u8>, f32)>, SicImageEngineError> {
+        let count = self.stops.len();
+
+        if count < 2 {
+            return Err(SicImageEngineError::GradientTooFewStops(count));
+        }
+
+        let mut positions = self
+            .stops
+            .iter()
+            .map(ColorStop::position)
+            .collect::<Vec<_>>();
+
+        positions[0] = positions[0].or(Some(0.0));
+        positions[count - 1] = positions[count - 1].or(Some(1.0));
+
+        let mut max_position = f32::MIN;
+        for position in positions.iter_mut().flatten() {
+            max_position = position.max(max_position);
+            *position = max_position;
+        }
+
+        let mut start = 0;
+        for end in 1..count {
+            if let (Some(from), Some(to)) = (positions[start], positions[end]) {
+                let steps = (end - start) as f32;
+
+                for (step, position) in positions[start + 1..end].iter_mut().enumerate() {
+                    *position = Some(from + (to - from) * (step + 1) as f32 / steps);
+                }
+
+                start = end;
+            }
+        }
+
+        Ok(self
+            .stops
+            .iter()
+            .zip(positions)
+            .map(|(stop, position)| (stop.color, position.unwrap_or_default()))
+            .collect())
+    }
+}
+
+/// The color space in which the colors of a gradient are interpolated. Used by the
+/// `gradient-color-space` modifier.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, EnumString)]
+#[strum(ascii_case_insensitive)]
+pub enum GradientColorSpace {
+    /// Interpolate the sRGB encoded color channels.
+    #[default]
+    #[strum(serialize = "srgb")]
+    Srgb,
+    /// Interpolate the color channels in linear light.
+    #[strum(serialize = "linear")]
+    Linear,
+    /// Interpolate in the perceptually uniform OKLab color space.
+    #[strum(serialize = "oklab")]
+    Oklab,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn stops(positions: &[Option<f32>]) -> GradientInputs {
+        GradientInputs::new(
+            GradientShape::Radial,
+            positions
+                .iter()
+                .map(|&position| ColorStop::new(Rgba([0, 0, 0, 255]), position))
+                .collect(),
+        )
+    }
+
+    fn resolved_positions(inputs: &GradientInputs) -> Vec<f32> {
+        inputs
+            .resolved_stops()
+            .unwrap()
+            .into_iter()
+            .map(|(_, position)| position)
+            .collect()
+    }
+
+    #[test]
+    fn resolve_without_positions() {
+        let inputs = stops(&[None, None, None, None, None]);
+        assert_eq!(resolved_positions(&inputs), vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+    }
+
+    #[test]
+    fn resolve_spreads_between_positions() {
+        let inputs = stops(&[Some(0.25), None, Some(0.75), None]);
+        assert_eq!(resolved_positions(&inputs), vec![0.25, 0.5, 0.75, 1.0]);
+    }
+
+    #[test]
+    fn resolve_clamps_to_earlier_positions() {
+        let inputs = stops(&[None, Some(0.5), Some(0.3), None]);
+        assert_eq!(resolved_positions(&inputs), vec![0.0, 0.5, 0.5, 1.0]);
+    }
+
+    #[test]
+    fn resolve_too_few_stops() {
+        let inputs = stops(&[Some(0.5)]);
+        assert!(matches!(
+            inputs.resolved_stops(),
+            Err(SicImageEngineError::GradientTooFewStops(1))
+        ));
+    }
+
+    #[test]
+    fn color_space_from_str() {
+        assert_eq!(
+            GradientColorSpace::from_str("OKLab").unwrap(),
+            GradientColorSpace::Oklab
+        );
+        assert!(GradientColorSpace::from_str("hsl").is_err());
+    }
+}
@@ -14,7 +14,7 @@ where
     blend_static_image(img, &gradient_buffer);
 }
 
-fn blend_static_image(img: &mut DynamicImage, layer: &RgbaImage) {
+pub(crate) fn blend_static_image(img: &mut DynamicImage, layer: &RgbaImage) {
     let mut blended_buffer = img.to_rgba8();
     blended_buffer
         .pixels_mut()
@@ -0,0 +1,113 @@
+/// Anchors an image (or a selection of an image) to a side, corner or the center of an area.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, EnumString)]
+#[strum(serialize_all = "kebab_case", ascii_case_insensitive)]
+pub enum Gravity {
+    NorthWest,
+    North,
+    NorthEast,
+    West,
+    Center,
+    East,
+    SouthWest,
+    South,
+    SouthEast,
+}
+
+impl Gravity {
+    /// Computes the top-left position of an area with dimensions `inner`, when it's anchored
+    /// within an area with dimensions `outer`. If the inner area is larger than the outer area,
+    /// the position may be negative.
+    pub fn position(self, outer: (u32, u32), inner: (u32, u32)) -> (i64, i64) {
+        let free_x = i64::from(outer.0) - i64::from(inner.0);
+        let free_y = i64::from(outer.1) - i64::from(inner.1);
+
+        let x = match self {
+            Self::NorthWest | Self::West | Self::SouthWest => 0,
+            Self::North | Self::Center | Self::South => free_x / 2,
+            Self::NorthEast | Self::East | Self::SouthEast => free_x,
+        };
+
+        let y = match self {
+            Self::NorthWest | Self::North | Self::NorthEast => 0,
+            Self::West | Self::Center | Self::East => free_y / 2,
+            Self::SouthWest | Self::South | Self::SouthEast => free_y,
+        };
+
+        (x, y)
+    }
+
+    /// Like [`Gravity::position`], but moves the inner area away from the sides of the outer area
+    /// it's anchored to, by `margin` pixels horizontally and vertically.
+    pub fn position_with_margin(
+        self,
+        outer: (u32, u32),
+        inner: (u32, u32),
+        margin: (u32, u32),
+    ) -> (i64, i64) {
+        let (x, y) = self.position(outer, inner);
+        let (margin_x, margin_y) = (i64::from(margin.0), i64::from(margin.1));
+
+        let x = match self {
+            Self::NorthWest | Self::West | Self::SouthWest => x + margin_x,
+            Self::North | Self::Center | Self::South => x,
+            Self::NorthEast | Self::East | Self::SouthEast => x - margin_x,
+        };
+
+        let y = match self {
+            Self::NorthWest | Self::North | Self::NorthEast => y + margin_y,
+            Self::West | Self::Center | Self::East => y,
+            Self::SouthWest | Self::South | Self::SouthEast => y - margin_y,
+        };
+
+        (x, y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_gravity() {
+        assert_eq!("center".parse::<Gravity>().unwrap(), Gravity::Center);
+        assert_eq!("North-East".parse::<Gravity>().unwrap(), Gravity::NorthEast);
+        assert!("middle".parse::<Gravity>().is_err());
+    }
+
+    #[test]
+    fn position_within() {
+        let outer = (10, 8);
+        let inner = (4, 2);
+
+        assert_eq!(Gravity::NorthWest.position(outer, inner), (0, 0));
+        assert_eq!(Gravity::Center.position(outer, inner), (3, 3));
+        assert_eq!(Gravity::SouthEast.position(outer, inner), (6, 6));
+        assert_eq!(Gravity::South.position(outer, inner), (3, 6));
+        assert_eq!(Gravity::West.position(outer, inner), (0, 3));
+    }
+
+    #[test]
+    fn position_with_margin() {
+        let outer = (10, 8);
+        let inner = (4, 2);
+
+        assert_eq!(
+            Gravity::NorthWest.position_with_margin(outer, inner, (1, 2)),
+            (1, 2)
+        );
+        assert_eq!(
+            Gravity::SouthEast.position_with_margin(outer, inner, (1, 2)),
+            (5, 4)
+        );
+        assert_eq!(
+            Gravity::North.position_with_margin(outer, inner, (1, 2)),
+            (3, 2)
+        );
+    }
+
+    #[test]
+    fn position_larger_than_outer() {
+        assert_eq!(Gravity::Center.position((4, 4), (8, 6)), (-2, -1));
+        assert_eq!(Gravity::SouthEast.position((4, 4), (8, 6)), (-4, -2));
+    }
+}
@@ -0,0 +1,39 @@
+/// Input and output ranges for the levels operation.
+/// The input and output black and white points are given on a scale of `0` to `255`, independent
+/// of the bit depth of the image.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LevelsInputs {
+    input_black: u8,
+    input_white: u8,
+    gamma: f32,
+    output_black: u8,
+    output_white: u8,
+}
+
+impl LevelsInputs {
+    pub fn new(
+        (input_black, input_white): (u8, u8),
+        gamma: f32,
+        (output_black, output_white): (u8, u8),
+    ) -> Self {
+        Self {
+            input_black,
+            input_white,
+            gamma,
+            output_black,
+            output_white,
+        }
+    }
+
+    pub fn input_range(&self) -> (u8, u8) {
+        (self.input_black, self.input_white)
+    }
+
+    pub fn gamma(&self) -> f32 {
+        self.gamma
+    }
+
+    pub fn output_range(&self) -> (u8, u8) {
+        (self.output_black, self.output_white)
+    }
+}
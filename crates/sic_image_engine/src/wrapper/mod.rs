@@ -1,7 +1,28 @@
+pub mod bilateral;
+pub mod channel;
+pub mod chroma_key;
+pub mod clahe;
+pub mod color_matrix;
+pub mod curves;
+pub mod dither_algorithm;
 pub mod draw_text_inner;
+pub mod equalize;
+pub mod extend_canvas;
+pub mod filter;
 pub mod filter_type;
 pub mod font_options;
+pub mod frame_range;
+pub mod gradient;
 pub mod gradient_fn;
 pub mod gradient_input;
+pub mod gravity;
 pub mod image_path;
+pub mod levels;
+pub mod morphology;
+pub mod nl_means;
 pub mod overlay;
+pub mod pad;
+pub mod palette;
+pub mod resize;
+pub mod rotate;
+pub mod sprite_sheet;
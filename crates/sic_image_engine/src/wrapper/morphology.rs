@@ -0,0 +1,53 @@
+/// The norm which determines the shape of the structuring element of a morphological operation.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, EnumString)]
+#[strum(ascii_case_insensitive)]
+pub enum MorphologyNorm {
+    /// A diamond shaped structuring element (Manhattan distance).
+    #[strum(serialize = "l1")]
+    L1,
+    /// A disk shaped structuring element (Euclidean distance).
+    #[strum(serialize = "l2")]
+    L2,
+    /// A square shaped structuring element (Chebyshev distance).
+    #[strum(serialize = "linf")]
+    LInf,
+}
+
+/// The structuring element of a morphological operation: all pixels within `radius` of the
+/// center pixel, as measured by `norm`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MorphologyInputs {
+    radius: u8,
+    norm: MorphologyNorm,
+}
+
+impl MorphologyInputs {
+    pub fn new(radius: u8, norm: MorphologyNorm) -> Self {
+        Self { radius, norm }
+    }
+
+    pub fn radius(&self) -> u8 {
+        self.radius
+    }
+
+    pub fn norm(&self) -> MorphologyNorm {
+        self.norm
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn norm_from_str() {
+        assert_eq!(MorphologyNorm::from_str("l1").unwrap(), MorphologyNorm::L1);
+        assert_eq!(MorphologyNorm::from_str("L2").unwrap(), MorphologyNorm::L2);
+        assert_eq!(
+            MorphologyNorm::from_str("LInf").unwrap(),
+            MorphologyNorm::LInf
+        );
+        assert!(MorphologyNorm::from_str("l3").is_err());
+    }
+}
@@ -0,0 +1,32 @@
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NlMeansInputs {
+    strength: f32,
+    patch_radius: u32,
+    search_radius: u32,
+}
+
+impl NlMeansInputs {
+    pub fn new(strength: f32, patch_radius: u32, search_radius: u32) -> Self {
+        Self {
+            strength,
+            patch_radius,
+            search_radius,
+        }
+    }
+
+    /// The filtering strength, on a scale of `0` to `255`. Patches which differ less than the
+    /// strength are averaged.
+    pub fn strength(&self) -> f32 {
+        self.strength
+    }
+
+    /// The radius of the patches which are compared; a radius of `1` gives patches of 3x3 pixels.
+    pub fn patch_radius(&self) -> u32 {
+        self.patch_radius
+    }
+
+    /// The radius of the window in which similar patches are searched.
+    pub fn search_radius(&self) -> u32 {
+        self.search_radius
+    }
+}
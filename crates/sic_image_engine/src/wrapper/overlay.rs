@@ -1,24 +1,211 @@
+use crate::wrapper::gravity::Gravity;
 use crate::wrapper::image_path::ImageFromPath;
+use crate::wrapper::resize::ResizeValue;
 
-#[derive(Clone, Debug)]
-pub struct OverlayInputs(ImageFromPath, (i64, i64));
+#[derive(Clone, Debug, PartialEq)]
+pub struct OverlayInputs {
+    image_path: ImageFromPath,
+    placement: OverlayPlacement,
+    width: Option<ResizeValue>,
+    blend_mode: BlendMode,
+    opacity: f32,
+}
 
 impl OverlayInputs {
-    pub fn new(image_path: ImageFromPath, pos: (i64, i64)) -> Self {
-        OverlayInputs(image_path, pos)
+    /// Overlays an image at the given placement, using the `normal` blend mode and full opacity.
+    pub fn new(image_path: ImageFromPath, placement: impl Into<OverlayPlacement>) -> Self {
+        Self {
+            image_path,
+            placement: placement.into(),
+            width: None,
+            blend_mode: BlendMode::default(),
+            opacity: 1.0,
+        }
+    }
+
+    /// Composites the overlay image using the given blend mode, after multiplying its alpha
+    /// channel by `opacity`, which should be in the range `[0, 1]`.
+    pub fn with_blending(self, blend_mode: BlendMode, opacity: f32) -> Self {
+        Self {
+            blend_mode,
+            opacity,
+            ..self
+        }
+    }
+
+    /// Scales the overlay image to the given width before it's placed, while preserving its aspect
+    /// ratio. A percentage is relative to the width of the image below.
+    pub fn with_width(self, width: ResizeValue) -> Self {
+        Self {
+            width: Some(width),
+            ..self
+        }
     }
 
     pub fn image_path(&self) -> &ImageFromPath {
-        &self.0
+        &self.image_path
+    }
+
+    pub fn placement(&self) -> OverlayPlacement {
+        self.placement
     }
 
-    pub fn position(&self) -> (i64, i64) {
-        self.1
+    pub fn width(&self) -> Option<ResizeValue> {
+        self.width
+    }
+
+    pub fn blend_mode(&self) -> BlendMode {
+        self.blend_mode
+    }
+
+    pub fn opacity(&self) -> f32 {
+        self.opacity
     }
 }
 
-impl PartialEq for OverlayInputs {
-    fn eq(&self, other: &Self) -> bool {
-        self.0 == other.0 && self.1 == other.1
+/// Determines where the overlay image is placed on the image below.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OverlayPlacement {
+    /// The top-left corner of the overlay image is placed at the given position.
+    Position(i64, i64),
+    /// The overlay image is anchored to a side, corner or the center of the image below. The
+    /// margins move the overlay image away from the sides it's anchored to.
+    Anchored {
+        gravity: Gravity,
+        margin: (u32, u32),
+    },
+    /// The overlay image, rotated clockwise by `degrees`, is repeated from the top-left corner
+    /// onwards until it covers the image below. The spacing separates adjacent copies.
+    Tiled { spacing: (u32, u32), degrees: f32 },
+}
+
+impl OverlayPlacement {
+    /// Computes the top-left position of each copy of an overlay image with dimensions `overlay`,
+    /// placed on an image with dimensions `base`. For a tiled placement, `overlay` should hold
+    /// the dimensions after rotation.
+    pub fn positions(self, base: (u32, u32), overlay: (u32, u32)) -> Vec<(i64, i64)> {
+        match self {
+            Self::Position(x, y) => vec![(x, y)],
+            Self::Anchored { gravity, margin } => {
+                vec![gravity.position_with_margin(base, overlay, margin)]
+            }
+            Self::Tiled { spacing, .. } => {
+                let step_x = (overlay.0 + spacing.0).max(1) as usize;
+                let step_y = (overlay.1 + spacing.1).max(1) as usize;
+
+                (0..base.1)
+                    .step_by(step_y)
+                    .flat_map(|y| {
+                        (0..base.0)
+                            .step_by(step_x)
+                            .map(move |x| (i64::from(x), i64::from(y)))
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+impl From<(i64, i64)> for OverlayPlacement {
+    fn from((x, y): (i64, i64)) -> Self {
+        Self::Position(x, y)
+    }
+}
+
+/// Determines how the colors of an overlay image (the source) are combined with the colors of the
+/// image below (the backdrop).
+///
+/// The Porter-Duff operators determine which parts of the source and backdrop remain visible,
+/// based on their alpha channels. The remaining modes mix the colors of the source and backdrop,
+/// and composite the result over the backdrop.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, EnumString)]
+#[strum(serialize_all = "kebab_case", ascii_case_insensitive)]
+pub enum BlendMode {
+    /// The source over the backdrop; equal to `src-over`.
+    #[default]
+    #[strum(serialize = "normal", serialize = "src-over")]
+    Normal,
+    /// Neither the source nor the backdrop.
+    Clear,
+    /// The source only.
+    #[strum(serialize = "src")]
+    Source,
+    /// The backdrop only.
+    #[strum(serialize = "dst")]
+    Destination,
+    /// The backdrop over the source.
+    #[strum(serialize = "dst-over")]
+    DestinationOver,
+    /// The part of the source which overlaps the backdrop.
+    #[strum(serialize = "src-in")]
+    SourceIn,
+    /// The part of the backdrop which overlaps the source.
+    #[strum(serialize = "dst-in")]
+    DestinationIn,
+    /// The part of the source which doesn't overlap the backdrop.
+    #[strum(serialize = "src-out")]
+    SourceOut,
+    /// The part of the backdrop which doesn't overlap the source.
+    #[strum(serialize = "dst-out")]
+    DestinationOut,
+    /// The part of the source which overlaps the backdrop, over the backdrop.
+    #[strum(serialize = "src-atop")]
+    SourceAtop,
+    /// The part of the backdrop which overlaps the source, over the source.
+    #[strum(serialize = "dst-atop")]
+    DestinationAtop,
+    /// The parts of the source and the backdrop which don't overlap.
+    Xor,
+    Multiply,
+    Screen,
+    Overlay,
+    Darken,
+    Lighten,
+    SoftLight,
+    HardLight,
+    Difference,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn blend_mode_from_str() {
+        assert_eq!(BlendMode::from_str("normal").unwrap(), BlendMode::Normal);
+        assert_eq!(BlendMode::from_str("src-over").unwrap(), BlendMode::Normal);
+        assert_eq!(
+            BlendMode::from_str("dst-atop").unwrap(),
+            BlendMode::DestinationAtop
+        );
+        assert_eq!(
+            BlendMode::from_str("Soft-Light").unwrap(),
+            BlendMode::SoftLight
+        );
+        assert!(BlendMode::from_str("burn").is_err());
+    }
+
+    #[test]
+    fn anchored_positions() {
+        let placement = OverlayPlacement::Anchored {
+            gravity: Gravity::SouthEast,
+            margin: (2, 3),
+        };
+
+        assert_eq!(placement.positions((20, 10), (4, 4)), vec![(14, 3)]);
+    }
+
+    #[test]
+    fn tiled_positions() {
+        let placement = OverlayPlacement::Tiled {
+            spacing: (1, 2),
+            degrees: 0.0,
+        };
+
+        assert_eq!(
+            placement.positions((8, 5), (3, 2)),
+            vec![(0, 0), (4, 0), (0, 4), (4, 4)]
+        );
     }
 }
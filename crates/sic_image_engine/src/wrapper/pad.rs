@@ -0,0 +1,43 @@
+use sic_core::image::Rgba;
+
+/// The number of pixels to add to each side of the image, and the color of the added pixels.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PadInputs {
+    top: u32,
+    right: u32,
+    bottom: u32,
+    left: u32,
+    fill: Rgba<u8>,
+}
+
+impl PadInputs {
+    pub fn new((top, right, bottom, left): (u32, u32, u32, u32), fill: Rgba<u8>) -> Self {
+        Self {
+            top,
+            right,
+            bottom,
+            left,
+            fill,
+        }
+    }
+
+    pub fn top(&self) -> u32 {
+        self.top
+    }
+
+    pub fn right(&self) -> u32 {
+        self.right
+    }
+
+    pub fn bottom(&self) -> u32 {
+        self.bottom
+    }
+
+    pub fn left(&self) -> u32 {
+        self.left
+    }
+
+    pub fn fill(&self) -> Rgba<u8> {
+        self.fill
+    }
+}
@@ -0,0 +1,236 @@
+use std::path::{Path, PathBuf};
+
+use sic_core::image::Rgba;
+
+use crate::errors::SicImageEngineError;
+use crate::wrapper::image_path::ImageFromPath;
+
+/// Determines how the colors of an image are mapped to the colors of a palette.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, EnumString)]
+#[strum(serialize_all = "kebab_case", ascii_case_insensitive)]
+pub enum QuantizeMode {
+    /// Map each pixel to the nearest palette color.
+    Nearest,
+    /// Map each pixel to a palette color, distributing the quantization error using the
+    /// `dither-algorithm` modifier.
+    Dither,
+}
+
+/// Where the colors of a palette come from.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PaletteSource {
+    /// A palette file: a GIMP palette (`.gpl`), a list of hex colors (`.hex` or `.txt`), or an
+    /// image of which each distinct color is a palette color (e.g. a swatch strip).
+    Path(PathBuf),
+    /// The palette colors themselves.
+    Colors(Vec<Rgba<u8>>),
+}
+
+impl PaletteSource {
+    pub fn load(&self) -> Result<Palette, SicImageEngineError> {
+        let colors = match self {
+            Self::Path(path) => read_palette_file(path)?,
+            Self::Colors(colors) => colors.clone(),
+        };
+
+        if colors.is_empty() {
+            return Err(SicImageEngineError::PaletteEmpty);
+        }
+
+        Ok(Palette(colors))
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QuantizeInputs {
+    mode: QuantizeMode,
+    palette: PaletteSource,
+}
+
+impl QuantizeInputs {
+    pub fn new(mode: QuantizeMode, palette: PaletteSource) -> Self {
+        Self { mode, palette }
+    }
+
+    pub fn mode(&self) -> QuantizeMode {
+        self.mode
+    }
+
+    pub fn palette(&self) -> &PaletteSource {
+        &self.palette
+    }
+}
+
+/// A non-empty list of colors, in the order in which they were defined.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Palette(Vec<Rgba<u8>>);
+
+impl Palette {
+    pub fn colors(&self) -> &[Rgba<u8>] {
+        &self.0
+    }
+}
+
+fn read_palette_file(path: &Path) -> Result<Vec<Rgba<u8>>, SicImageEngineError> {
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(str::to_ascii_lowercase);
+
+    match extension.as_deref() {
+        Some("gpl") => parse_gimp_palette(&read_to_string(path)?),
+        Some("hex") | Some("txt") => parse_hex_palette(&read_to_string(path)?),
+        _ => {
+            let image = ImageFromPath::new(path.to_path_buf()).open_image()?;
+            Ok(distinct_colors(&image.as_ref().to_rgba8()))
+        }
+    }
+}
+
+fn read_to_string(path: &Path) -> Result<String, SicImageEngineError> {
+    std::fs::read_to_string(path)
+        .map_err(|err| SicImageEngineError::PaletteFileLoadError(path.display().to_string(), err))
+}
+
+/// Parses a GIMP palette, which starts with a `GIMP Palette` header, optionally followed by
+/// `Name:` and `Columns:` attributes, and then lists one `<red> <green> <blue> [<name>]` color
+/// per line. Empty lines and lines starting with `#` are ignored.
+fn parse_gimp_palette(text: &str) -> Result<Vec<Rgba<u8>>, SicImageEngineError> {
+    let mut lines = text.lines().map(str::trim);
+
+    if lines.next() != Some("GIMP Palette") {
+        return Err(SicImageEngineError::InvalidPalette(
+            "a GIMP palette should start with the 'GIMP Palette' header".to_string(),
+        ));
+    }
+
+    lines
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter(|line| !line.starts_with("Name:") && !line.starts_with("Columns:"))
+        .map(|line| {
+            let mut channels = line.split_whitespace().map(str::parse::<u8>);
+
+            match (channels.next(), channels.next(), channels.next()) {
+                (Some(Ok(r)), Some(Ok(g)), Some(Ok(b))) => Ok(Rgba([r, g, b, 255])),
+                _ => Err(SicImageEngineError::InvalidPalette(format!(
+                    "expected a line with three color values in the range [0, 255], but was '{}'",
+                    line
+                ))),
+            }
+        })
+        .collect()
+}
+
+/// Parses a list of hex colors, one `rrggbb` or `rrggbbaa` color per line, optionally prefixed
+/// with a `#`. Empty lines are ignored.
+fn parse_hex_palette(text: &str) -> Result<Vec<Rgba<u8>>, SicImageEngineError> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            parse_hex_color(line).ok_or_else(|| {
+                SicImageEngineError::InvalidPalette(format!(
+                    "expected a hex color ('rrggbb' or 'rrggbbaa'), but was '{}'",
+                    line
+                ))
+            })
+        })
+        .collect()
+}
+
+fn parse_hex_color(text: &str) -> Option<Rgba<u8>> {
+    let digits = text.strip_prefix('#').unwrap_or(text);
+
+    if !matches!(digits.len(), 6 | 8) || !digits.is_ascii() {
+        return None;
+    }
+
+    let channel = |i: usize| u8::from_str_radix(digits.get(i..i + 2)?, 16).ok();
+    let alpha = if digits.len() == 8 { channel(6)? } else { 255 };
+
+    Some(Rgba([channel(0)?, channel(2)?, channel(4)?, alpha]))
+}
+
+/// The distinct colors of an image, in row-major order of first occurrence.
+fn distinct_colors(image: &sic_core::image::RgbaImage) -> Vec<Rgba<u8>> {
+    let mut seen = std::collections::HashSet::new();
+
+    image
+        .pixels()
+        .filter(|pixel| seen.insert(**pixel))
+        .copied()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sic_testing::in_;
+
+    #[test]
+    fn gimp_palette() {
+        let text =
+            "GIMP Palette\nName: Test\nColumns: 2\n#\n  0   0   0\tBlack\n255 128 1 Orange\n";
+
+        let colors = parse_gimp_palette(text).unwrap();
+
+        assert_eq!(colors, vec![Rgba([0, 0, 0, 255]), Rgba([255, 128, 1, 255])]);
+    }
+
+    #[test]
+    fn gimp_palette_without_header() {
+        assert!(parse_gimp_palette("0 0 0\n").is_err());
+    }
+
+    #[test]
+    fn gimp_palette_invalid_color() {
+        assert!(parse_gimp_palette("GIMP Palette\n0 0 256\n").is_err());
+    }
+
+    #[test]
+    fn hex_palette() {
+        let colors = parse_hex_palette("#000000\nff8001\n\n#10203040\n").unwrap();
+
+        assert_eq!(
+            colors,
+            vec![
+                Rgba([0, 0, 0, 255]),
+                Rgba([255, 128, 1, 255]),
+                Rgba([16, 32, 48, 64])
+            ]
+        );
+    }
+
+    #[test]
+    fn hex_palette_invalid_color() {
+        for text in ["#fff", "gg0000", "#00000000ff", "ff00é0"] {
+            assert!(parse_hex_palette(text).is_err(), "{}", text);
+        }
+    }
+
+    #[test]
+    fn swatch_strip() {
+        let source = PaletteSource::Path(PathBuf::from(in_!("2x3_rrgrbb.bmp")));
+
+        let palette = source.load().unwrap();
+
+        assert_eq!(
+            palette.colors(),
+            &[
+                Rgba([255, 0, 0, 255]),
+                Rgba([0, 255, 0, 255]),
+                Rgba([0, 0, 255, 255])
+            ]
+        );
+    }
+
+    #[test]
+    fn empty_palette() {
+        let source = PaletteSource::Colors(Vec::new());
+
+        assert!(matches!(
+            source.load(),
+            Err(SicImageEngineError::PaletteEmpty)
+        ));
+    }
+}
@@ -0,0 +1,122 @@
+use crate::errors::SicImageEngineError;
+use std::str::FromStr;
+
+/// A single resize dimension, either as absolute number of pixels or relative to the matching
+/// dimension of the input image.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ResizeValue {
+    Pixels(u32),
+    Percentage(f32),
+}
+
+impl ResizeValue {
+    /// Resolve the value to a number of pixels, given the matching dimension of the input image.
+    pub fn resolve(&self, dimension: u32) -> u32 {
+        match self {
+            Self::Pixels(pixels) => *pixels,
+            Self::Percentage(percentage) => {
+                let scaled = (dimension as f32 * percentage / 100.0).round();
+                (scaled as u32).max(1)
+            }
+        }
+    }
+}
+
+impl FromStr for ResizeValue {
+    type Err = SicImageEngineError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || SicImageEngineError::InvalidResizeValue(s.to_string());
+
+        match s.strip_suffix('%') {
+            Some(percentage) => percentage
+                .parse::<f32>()
+                .ok()
+                .filter(|percentage| percentage.is_finite() && *percentage > 0.0)
+                .map(Self::Percentage)
+                .ok_or_else(invalid),
+            None => s.parse::<u32>().map(Self::Pixels).map_err(|_| invalid()),
+        }
+    }
+}
+
+/// The resize mode, which can be given as the first argument of the resize operation.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, EnumString)]
+#[strum(serialize_all = "kebab_case", ascii_case_insensitive)]
+pub enum ResizeMode {
+    Exact,
+    Fit,
+    Cover,
+    WidthOnly,
+    HeightOnly,
+}
+
+impl ResizeMode {
+    /// The number of resize values which follow the mode.
+    pub fn takes_number_of_values(self) -> usize {
+        match self {
+            Self::Exact | Self::Fit | Self::Cover => 2,
+            Self::WidthOnly | Self::HeightOnly => 1,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ResizeInputs {
+    /// Resize to the given width and height. If the `preserve-aspect-ratio` modifier is set,
+    /// behaves like [`ResizeInputs::Fit`], otherwise like [`ResizeInputs::Exact`].
+    Dimensions(ResizeValue, ResizeValue),
+    /// Resize to exactly the given width and height, ignoring the aspect ratio.
+    Exact(ResizeValue, ResizeValue),
+    /// Resize to the largest size which fits within the given width and height, while preserving
+    /// the aspect ratio.
+    Fit(ResizeValue, ResizeValue),
+    /// Resize to the smallest size which covers the given width and height, while preserving the
+    /// aspect ratio, then crop the center to the given width and height.
+    Cover(ResizeValue, ResizeValue),
+    /// Resize to the given width; the height follows from the aspect ratio.
+    WidthOnly(ResizeValue),
+    /// Resize to the given height; the width follows from the aspect ratio.
+    HeightOnly(ResizeValue),
+}
+
+impl From<(u32, u32)> for ResizeInputs {
+    fn from((x, y): (u32, u32)) -> Self {
+        Self::Dimensions(ResizeValue::Pixels(x), ResizeValue::Pixels(y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_pixels() {
+        assert_eq!(
+            "100".parse::<ResizeValue>().unwrap(),
+            ResizeValue::Pixels(100)
+        );
+    }
+
+    #[test]
+    fn parse_percentage() {
+        assert_eq!(
+            "12.5%".parse::<ResizeValue>().unwrap(),
+            ResizeValue::Percentage(12.5)
+        );
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!("-1".parse::<ResizeValue>().is_err());
+        assert!("0%".parse::<ResizeValue>().is_err());
+        assert!("%".parse::<ResizeValue>().is_err());
+        assert!("a%".parse::<ResizeValue>().is_err());
+    }
+
+    #[test]
+    fn resolve_percentage() {
+        assert_eq!(ResizeValue::Percentage(50.0).resolve(217), 109);
+        assert_eq!(ResizeValue::Percentage(1.0).resolve(10), 1);
+    }
+}
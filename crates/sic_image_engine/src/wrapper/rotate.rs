@@ -0,0 +1,40 @@
+use sic_core::image::Rgba;
+
+/// Determines the dimensions of the output image after an arbitrary angle rotation.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, EnumString)]
+#[strum(serialize_all = "kebab_case", ascii_case_insensitive)]
+pub enum RotateCanvas {
+    /// Keep the dimensions of the input image; corners which rotate out of bounds are cut off.
+    Crop,
+    /// Grow the dimensions of the output image, so the complete rotated image fits.
+    Expand,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RotateInputs {
+    degrees: f32,
+    canvas: RotateCanvas,
+    background: Rgba<u8>,
+}
+
+impl RotateInputs {
+    pub fn new(degrees: f32, canvas: RotateCanvas, background: Rgba<u8>) -> Self {
+        Self {
+            degrees,
+            canvas,
+            background,
+        }
+    }
+
+    pub fn degrees(&self) -> f32 {
+        self.degrees
+    }
+
+    pub fn canvas(&self) -> RotateCanvas {
+        self.canvas
+    }
+
+    pub fn background(&self) -> Rgba<u8> {
+        self.background
+    }
+}
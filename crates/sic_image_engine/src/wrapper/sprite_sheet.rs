@@ -0,0 +1,77 @@
+use sic_core::image::Rgba;
+use std::path::{Path, PathBuf};
+
+/// The layout of a sprite sheet: the number of columns of the grid, the spacing between its cells,
+/// and the color of the background, plus an optional path to which a JSON atlas describing the
+/// rectangle of each frame is written.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpriteSheetInputs {
+    columns: u32,
+    spacing: u32,
+    background: Rgba<u8>,
+    atlas: Option<PathBuf>,
+}
+
+impl SpriteSheetInputs {
+    pub fn new(columns: u32, spacing: u32, background: Rgba<u8>) -> Self {
+        Self {
+            columns,
+            spacing,
+            background,
+            atlas: None,
+        }
+    }
+
+    pub fn with_atlas(self, atlas: PathBuf) -> Self {
+        Self {
+            atlas: Some(atlas),
+            ..self
+        }
+    }
+
+    pub fn columns(&self) -> u32 {
+        self.columns
+    }
+
+    pub fn spacing(&self) -> u32 {
+        self.spacing
+    }
+
+    pub fn background(&self) -> Rgba<u8> {
+        self.background
+    }
+
+    pub fn atlas(&self) -> Option<&Path> {
+        self.atlas.as_deref()
+    }
+}
+
+/// The size of the cells of a sprite sheet, and the spacing between them.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SplitSpriteSheetInputs {
+    cell_width: u32,
+    cell_height: u32,
+    spacing: u32,
+}
+
+impl SplitSpriteSheetInputs {
+    pub fn new((cell_width, cell_height): (u32, u32), spacing: u32) -> Self {
+        Self {
+            cell_width,
+            cell_height,
+            spacing,
+        }
+    }
+
+    pub fn cell_width(&self) -> u32 {
+        self.cell_width
+    }
+
+    pub fn cell_height(&self) -> u32 {
+        self.cell_height
+    }
+
+    pub fn spacing(&self) -> u32 {
+        self.spacing
+    }
+}
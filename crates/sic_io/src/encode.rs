@@ -8,8 +8,10 @@ use sic_core::{AnimatedImage, SicImage, image};
 use crate::errors::SicIoError;
 use crate::preprocessor::Preprocessors;
 
+pub mod animated;
 pub mod bmp;
 pub mod dynamic;
+pub mod indexed;
 pub mod jpeg;
 
 pub struct SicImageEncoder {
@@ -39,6 +41,7 @@ fn encode<W: Write + Seek>(encoder: DynamicEncoder<W>, image: &SicImage) -> Resu
     match image {
         SicImage::Static(img) => encode_static_image(encoder, img),
         SicImage::Animated(img) => encode_animated_image(encoder, img),
+        SicImage::Indexed(img) => encoder.write_indexed_image(img),
     }
 }
 
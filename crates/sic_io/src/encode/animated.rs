@@ -0,0 +1,474 @@
+//! Encoders which write the frames of an [`AnimatedImage`], including the delay and offsets of
+//! each frame.
+//!
+//! [`AnimatedImage`]: sic_core::AnimatedImage
+
+use crate::encode::indexed::{encoding_error, gif_error, png_error};
+use crate::encode_settings::gif::RepeatAnimation;
+use sic_core::image::codecs::webp::WebPEncoder;
+use sic_core::image::{
+    ExtendedColorType, Frame, ImageEncoder, ImageFormat, ImageResult, RgbaImage, imageops,
+};
+use std::io::Write;
+
+/// Writes an animated GIF image. The logical screen is made large enough to hold each frame at
+/// its offset, and frame delays are rounded to the 10 milliseconds precision of a GIF image.
+pub fn write_gif<W: Write>(
+    writer: W,
+    frames: Vec<Frame>,
+    repeat: RepeatAnimation,
+) -> ImageResult<()> {
+    let (width, height) = canvas_size(&frames);
+
+    let mut encoder =
+        gif::Encoder::new(writer, to_u16(width)?, to_u16(height)?, &[]).map_err(gif_error)?;
+    encoder.set_repeat(repeat.into()).map_err(gif_error)?;
+
+    for frame in frames {
+        let delay = (delay_ms(&frame) / 10.0).round();
+        let (left, top) = (to_u16(frame.left())?, to_u16(frame.top())?);
+
+        let mut buffer = frame.into_buffer();
+        let (width, height) = (to_u16(buffer.width())?, to_u16(buffer.height())?);
+
+        let mut frame = gif::Frame::from_rgba_speed(width, height, &mut buffer, 10);
+        frame.left = left;
+        frame.top = top;
+        frame.delay = delay.min(f64::from(u16::MAX)) as u16;
+        frame.dispose = gif::DisposalMethod::Background;
+
+        encoder.write_frame(&frame).map_err(gif_error)?;
+    }
+
+    Ok(())
+}
+
+/// Writes an animated PNG (APNG) image. Each frame is placed at its offset on a canvas large
+/// enough to hold every frame, and frame delays are rounded to whole milliseconds.
+pub fn write_apng<W: Write>(
+    writer: W,
+    frames: Vec<Frame>,
+    repeat: RepeatAnimation,
+) -> ImageResult<()> {
+    let (width, height) = canvas_size(&frames);
+    let num_frames = u32::try_from(frames.len()).map_err(|_| {
+        encoding_error(
+            ImageFormat::Png,
+            "The number of frames of an APNG image can't exceed 4294967295",
+        )
+    })?;
+
+    let mut encoder = png::Encoder::new(writer, width, height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder
+        .set_animated(num_frames, u32::from(repeat.num_plays()))
+        .map_err(png_error)?;
+    // Each frame covers the complete canvas, and replaces the previous frame including its
+    // transparent pixels
+    encoder
+        .set_blend_op(png::BlendOp::Source)
+        .map_err(png_error)?;
+    encoder
+        .set_dispose_op(png::DisposeOp::None)
+        .map_err(png_error)?;
+
+    let mut writer = encoder.write_header().map_err(png_error)?;
+
+    for frame in frames {
+        let (numerator, denominator) = apng_delay(delay_ms(&frame));
+        writer
+            .set_frame_delay(numerator, denominator)
+            .map_err(png_error)?;
+        writer
+            .write_image_data(into_canvas(frame, width, height).as_raw())
+            .map_err(png_error)?;
+    }
+
+    writer.finish().map_err(png_error)
+}
+
+/// Writes an animated, lossless WebP image. Each frame is placed at its offset on a canvas large
+/// enough to hold every frame, and frame delays are rounded to whole milliseconds.
+pub fn write_webp<W: Write>(
+    mut writer: W,
+    frames: Vec<Frame>,
+    repeat: RepeatAnimation,
+) -> ImageResult<()> {
+    let (width, height) = canvas_size(&frames);
+
+    if width == 0 || height == 0 {
+        return Err(encoding_error(
+            ImageFormat::WebP,
+            "An animated WebP image requires at least one frame which isn't empty",
+        ));
+    }
+
+    let mut vp8x = vec![WEBP_FLAG_ALPHA | WEBP_FLAG_ANIMATION, 0, 0, 0];
+    vp8x.extend_from_slice(&to_u24(width - 1)?);
+    vp8x.extend_from_slice(&to_u24(height - 1)?);
+
+    // A transparent background color, followed by the loop count
+    let mut anim = vec![0; 4];
+    anim.extend_from_slice(&repeat.num_plays().to_le_bytes());
+
+    let mut body = b"WEBP".to_vec();
+    write_webp_chunk(&mut body, b"VP8X", &vp8x);
+    write_webp_chunk(&mut body, b"ANIM", &anim);
+
+    for frame in frames {
+        let duration = to_u24(delay_ms(&frame).round().min(f64::from(U24_MAX)) as u32)?;
+        let canvas = into_canvas(frame, width, height);
+
+        // The frame is positioned at the origin of the canvas, which is written as zero offsets
+        let mut anmf = vec![0; 6];
+        anmf.extend_from_slice(&to_u24(width - 1)?);
+        anmf.extend_from_slice(&to_u24(height - 1)?);
+        anmf.extend_from_slice(&duration);
+        // Don't blend with the previous frame, and don't dispose of the frame
+        anmf.push(WEBP_FRAME_NO_BLEND);
+        anmf.extend_from_slice(&lossless_webp_bitstream(&canvas)?);
+
+        write_webp_chunk(&mut body, b"ANMF", &anmf);
+    }
+
+    let size = u32::try_from(body.len()).map_err(|_| {
+        encoding_error(
+            ImageFormat::WebP,
+            "The size of a WebP image can't exceed 4 GiB",
+        )
+    })?;
+
+    writer.write_all(b"RIFF")?;
+    writer.write_all(&size.to_le_bytes())?;
+    writer.write_all(&body)?;
+
+    Ok(())
+}
+
+const WEBP_FLAG_ANIMATION: u8 = 1 << 1;
+const WEBP_FLAG_ALPHA: u8 = 1 << 4;
+const WEBP_FRAME_NO_BLEND: u8 = 1 << 1;
+const U24_MAX: u32 = (1 << 24) - 1;
+
+/// Encodes a single frame as a lossless WebP image, and returns its `VP8L` chunk, which holds the
+/// compressed bitstream.
+fn lossless_webp_bitstream(image: &RgbaImage) -> ImageResult<Vec<u8>> {
+    let mut buffer = Vec::new();
+    WebPEncoder::new_lossless(&mut buffer).write_image(
+        image.as_raw(),
+        image.width(),
+        image.height(),
+        ExtendedColorType::Rgba8,
+    )?;
+
+    // A still WebP image without metadata consists of a RIFF header, followed by a single chunk
+    match buffer.get(12..) {
+        Some(chunk) if chunk.starts_with(b"VP8L") => Ok(chunk.to_vec()),
+        _ => Err(encoding_error(
+            ImageFormat::WebP,
+            "Unable to encode frame as lossless WebP bitstream",
+        )),
+    }
+}
+
+fn write_webp_chunk(buffer: &mut Vec<u8>, name: &[u8; 4], data: &[u8]) {
+    buffer.extend_from_slice(name);
+    // Chunks are part of a RIFF container, which is limited to 4 GiB as a whole
+    buffer.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    buffer.extend_from_slice(data);
+
+    // Chunks are padded to an even size
+    if data.len() % 2 == 1 {
+        buffer.push(0);
+    }
+}
+
+fn to_u24(value: u32) -> ImageResult<[u8; 3]> {
+    if value > U24_MAX {
+        return Err(encoding_error(
+            ImageFormat::WebP,
+            "The width and height of an animated WebP image can't exceed 16777216 pixels",
+        ));
+    }
+
+    let [b0, b1, b2, _] = value.to_le_bytes();
+    Ok([b0, b1, b2])
+}
+
+/// The delay of an APNG frame as a fraction of seconds. Delays which don't fit in milliseconds are
+/// rounded to whole seconds instead.
+fn apng_delay(delay_ms: f64) -> (u16, u16) {
+    let milliseconds = delay_ms.round();
+
+    if milliseconds <= f64::from(u16::MAX) {
+        (milliseconds as u16, 1000)
+    } else {
+        let seconds = (milliseconds / 1000.0).round().min(f64::from(u16::MAX));
+        (seconds as u16, 1)
+    }
+}
+
+/// The smallest canvas which holds each frame at its offset.
+fn canvas_size(frames: &[Frame]) -> (u32, u32) {
+    frames.iter().fold((0, 0), |(width, height), frame| {
+        (
+            width.max(frame.left().saturating_add(frame.buffer().width())),
+            height.max(frame.top().saturating_add(frame.buffer().height())),
+        )
+    })
+}
+
+/// Places a frame at its offset on a transparent canvas.
+fn into_canvas(frame: Frame, width: u32, height: u32) -> RgbaImage {
+    let (left, top) = (frame.left(), frame.top());
+    let buffer = frame.into_buffer();
+
+    if (left, top) == (0, 0) && buffer.dimensions() == (width, height) {
+        return buffer;
+    }
+
+    let mut canvas = RgbaImage::new(width, height);
+    imageops::replace(&mut canvas, &buffer, i64::from(left), i64::from(top));
+    canvas
+}
+
+fn delay_ms(frame: &Frame) -> f64 {
+    let (numerator, denominator) = frame.delay().numer_denom_ms();
+    f64::from(numerator) / f64::from(denominator.max(1))
+}
+
+fn to_u16(value: u32) -> ImageResult<u16> {
+    u16::try_from(value).map_err(|_| {
+        encoding_error(
+            ImageFormat::Gif,
+            "The width, height and offsets of the frames of a GIF image can't exceed 65535 pixels",
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sic_core::image::codecs::gif::GifDecoder;
+    use sic_core::image::codecs::png::PngDecoder;
+    use sic_core::image::codecs::webp::WebPDecoder;
+    use sic_core::image::{AnimationDecoder, Delay, Rgba};
+    use std::io::Cursor;
+
+    fn frame(color: Rgba<u8>, left: u32, top: u32, delay: u32) -> Frame {
+        Frame::from_parts(
+            RgbaImage::from_pixel(2, 2, color),
+            left,
+            top,
+            Delay::from_numer_denom_ms(delay, 1),
+        )
+    }
+
+    fn encode(frames: Vec<Frame>) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        write_gif(&mut buffer, frames, RepeatAnimation::Infinite).unwrap();
+        buffer
+    }
+
+    #[test]
+    fn delays_survive_round_trip() {
+        let buffer = encode(vec![
+            frame(Rgba([255, 0, 0, 255]), 0, 0, 40),
+            frame(Rgba([0, 255, 0, 255]), 0, 0, 75),
+            frame(Rgba([0, 0, 255, 255]), 0, 0, 1234),
+        ]);
+
+        let frames = GifDecoder::new(Cursor::new(buffer))
+            .unwrap()
+            .into_frames()
+            .collect_frames()
+            .unwrap();
+
+        let delays = frames
+            .iter()
+            .map(|frame| frame.delay().numer_denom_ms())
+            .map(|(numerator, denominator)| numerator / denominator)
+            .collect::<Vec<_>>();
+
+        assert_eq!(delays, vec![40, 80, 1230]);
+    }
+
+    #[test]
+    fn offsets_are_written() {
+        let buffer = encode(vec![
+            frame(Rgba([255, 0, 0, 255]), 0, 0, 40),
+            frame(Rgba([0, 0, 255, 255]), 3, 1, 40),
+        ]);
+
+        let mut decoder = gif::DecodeOptions::new()
+            .read_info(Cursor::new(buffer))
+            .unwrap();
+        assert_eq!((decoder.width(), decoder.height()), (5, 3));
+
+        let first = decoder.read_next_frame().unwrap().unwrap();
+        assert_eq!((first.left, first.top, first.delay), (0, 0, 4));
+
+        let second = decoder.read_next_frame().unwrap().unwrap();
+        assert_eq!((second.left, second.top), (3, 1));
+    }
+
+    fn delays(frames: &[Frame]) -> Vec<u32> {
+        frames
+            .iter()
+            .map(|frame| frame.delay().numer_denom_ms())
+            .map(|(numerator, denominator)| numerator / denominator)
+            .collect()
+    }
+
+    fn encode_apng(frames: Vec<Frame>, repeat: RepeatAnimation) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        write_apng(&mut buffer, frames, repeat).unwrap();
+        buffer
+    }
+
+    fn decode_apng(buffer: Vec<u8>) -> Vec<Frame> {
+        PngDecoder::new(Cursor::new(buffer))
+            .unwrap()
+            .apng()
+            .unwrap()
+            .into_frames()
+            .collect_frames()
+            .unwrap()
+    }
+
+    #[test]
+    fn apng_round_trip() {
+        let buffer = encode_apng(
+            vec![
+                frame(Rgba([255, 0, 0, 255]), 0, 0, 40),
+                frame(Rgba([0, 255, 0, 128]), 0, 0, 75),
+                frame(Rgba([0, 0, 255, 255]), 0, 0, 1234),
+            ],
+            RepeatAnimation::Infinite,
+        );
+
+        let frames = decode_apng(buffer);
+
+        assert_eq!(delays(&frames), vec![40, 75, 1234]);
+        assert_eq!(frames[0].buffer().get_pixel(1, 1), &Rgba([255, 0, 0, 255]));
+        assert_eq!(frames[1].buffer().get_pixel(1, 1), &Rgba([0, 255, 0, 128]));
+        assert_eq!(frames[2].buffer().get_pixel(1, 1), &Rgba([0, 0, 255, 255]));
+    }
+
+    #[test]
+    fn apng_frames_are_placed_at_offset() {
+        let buffer = encode_apng(
+            vec![
+                frame(Rgba([255, 0, 0, 255]), 0, 0, 40),
+                frame(Rgba([0, 0, 255, 255]), 3, 1, 40),
+            ],
+            RepeatAnimation::Infinite,
+        );
+
+        let frames = decode_apng(buffer);
+        let second = frames[1].buffer();
+
+        assert_eq!(second.dimensions(), (5, 3));
+        assert_eq!(second.get_pixel(0, 0), &Rgba([0, 0, 0, 0]));
+        assert_eq!(second.get_pixel(4, 2), &Rgba([0, 0, 255, 255]));
+    }
+
+    #[test]
+    fn apng_num_plays() {
+        let cases = [
+            (RepeatAnimation::Infinite, 0),
+            (RepeatAnimation::Never, 1),
+            (RepeatAnimation::Finite(3), 4),
+        ];
+
+        for (repeat, expected) in cases {
+            let buffer = encode_apng(vec![frame(Rgba([0, 0, 0, 255]), 0, 0, 40)], repeat);
+            let reader = png::Decoder::new(Cursor::new(buffer)).read_info().unwrap();
+
+            let animation = reader.info().animation_control.unwrap();
+            assert_eq!(animation.num_plays, expected);
+        }
+    }
+
+    #[test]
+    fn apng_long_delay_in_seconds() {
+        assert_eq!(apng_delay(40.4), (40, 1000));
+        assert_eq!(apng_delay(65535.0), (65535, 1000));
+        assert_eq!(apng_delay(90_600.0), (91, 1));
+    }
+
+    fn encode_webp(frames: Vec<Frame>, repeat: RepeatAnimation) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        write_webp(&mut buffer, frames, repeat).unwrap();
+        buffer
+    }
+
+    fn decode_webp(buffer: Vec<u8>) -> Vec<Frame> {
+        WebPDecoder::new(Cursor::new(buffer))
+            .unwrap()
+            .into_frames()
+            .collect_frames()
+            .unwrap()
+    }
+
+    #[test]
+    fn webp_round_trip() {
+        let buffer = encode_webp(
+            vec![
+                frame(Rgba([255, 0, 0, 255]), 0, 0, 40),
+                frame(Rgba([0, 255, 0, 128]), 0, 0, 75),
+                frame(Rgba([0, 0, 255, 255]), 0, 0, 1234),
+            ],
+            RepeatAnimation::Infinite,
+        );
+
+        let frames = decode_webp(buffer);
+
+        assert_eq!(delays(&frames), vec![40, 75, 1234]);
+        assert_eq!(frames[0].buffer().get_pixel(1, 1), &Rgba([255, 0, 0, 255]));
+        assert_eq!(frames[1].buffer().get_pixel(1, 1), &Rgba([0, 255, 0, 128]));
+        assert_eq!(frames[2].buffer().get_pixel(1, 1), &Rgba([0, 0, 255, 255]));
+    }
+
+    #[test]
+    fn webp_frames_are_placed_at_offset() {
+        let buffer = encode_webp(
+            vec![
+                frame(Rgba([255, 0, 0, 255]), 0, 0, 40),
+                frame(Rgba([0, 0, 255, 255]), 3, 1, 40),
+            ],
+            RepeatAnimation::Infinite,
+        );
+
+        let frames = decode_webp(buffer);
+        let second = frames[1].buffer();
+
+        assert_eq!(second.dimensions(), (5, 3));
+        assert_eq!(second.get_pixel(0, 0), &Rgba([0, 0, 0, 0]));
+        assert_eq!(second.get_pixel(4, 2), &Rgba([0, 0, 255, 255]));
+    }
+
+    #[test]
+    fn webp_loop_count() {
+        let cases = [
+            (RepeatAnimation::Infinite, 0),
+            (RepeatAnimation::Never, 1),
+            (RepeatAnimation::Finite(3), 4),
+        ];
+
+        for (repeat, expected) in cases {
+            let buffer = encode_webp(vec![frame(Rgba([0, 0, 0, 255]), 0, 0, 40)], repeat);
+
+            // RIFF header (12 bytes), VP8X chunk (18 bytes), ANIM chunk header and background color
+            assert_eq!(&buffer[30..34], b"ANIM");
+            assert_eq!(u16::from_le_bytes([buffer[42], buffer[43]]), expected);
+        }
+    }
+
+    #[test]
+    fn webp_without_frames() {
+        let mut buffer = Vec::new();
+        assert!(write_webp(&mut buffer, Vec::new(), RepeatAnimation::Infinite).is_err());
+    }
+}
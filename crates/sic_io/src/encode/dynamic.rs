@@ -1,9 +1,15 @@
+use crate::encode::animated;
 use crate::encode::bmp::BmpEncoder;
+use crate::encode::indexed;
 use crate::encode::jpeg::JpegEncoder;
 use crate::encode_settings::EncodeSettings;
+use crate::encode_settings::gif::RepeatAnimation;
 use crate::errors::{EncodingError, SicIoError, UnknownImageFormatError};
-use sic_core::image;
 use sic_core::image::codecs::pnm::PnmSubtype;
+use sic_core::image::{
+    ImageEncoder, codecs::gif::GifEncoder, codecs::png::PngEncoder, codecs::webp::WebPEncoder,
+};
+use sic_core::{IndexedImage, image};
 use std::fmt;
 use std::fmt::Formatter;
 use std::io::{Seek, Write};
@@ -99,14 +105,10 @@ impl<W: Write + Seek> IntoImageEncoder<W> for DynamicEncoder<W> {
                 Farbfeld(image::codecs::farbfeld::FarbfeldEncoder::new(writer)),
                 DynamicImageFormat::Farbfeld,
             ),
-            "gif" => {
-                let mut encoder = image::codecs::gif::GifEncoder::new(writer);
-                encoder
-                    .set_repeat(settings.repeat_animation.into())
-                    .map_err(SicIoError::ImageError)?;
-
-                (Gif(encoder), DynamicImageFormat::Gif)
-            }
+            "gif" => (
+                Gif(writer, settings.repeat_animation),
+                DynamicImageFormat::Gif,
+            ),
             "ico" => (
                 Ico(image::codecs::ico::IcoEncoder::new(writer)),
                 DynamicImageFormat::Ico,
@@ -134,7 +136,7 @@ impl<W: Write + Seek> IntoImageEncoder<W> for DynamicEncoder<W> {
                 (Pnm(enc), DynamicImageFormat::Pnm { subtype })
             }
             "png" => (
-                Png(image::codecs::png::PngEncoder::new(writer)),
+                Png(writer, settings.repeat_animation),
                 DynamicImageFormat::Png,
             ),
             "ppm" => {
@@ -156,7 +158,7 @@ impl<W: Write + Seek> IntoImageEncoder<W> for DynamicEncoder<W> {
                 DynamicImageFormat::Tiff,
             ),
             "webp" => (
-                Webp(image::codecs::webp::WebPEncoder::new_lossless(writer)),
+                Webp(writer, settings.repeat_animation),
                 DynamicImageFormat::Webp,
             ),
             _ => {
@@ -183,7 +185,7 @@ impl<W: Write + Seek> image::ImageEncoder for DynamicEncoder<W> {
             DynamicEncoderInner::Bmp(enc) => enc.write_image(buf, width, height, color_type),
             DynamicEncoderInner::Exr(enc) => enc.write_image(buf, width, height, color_type),
             DynamicEncoderInner::Farbfeld(enc) => enc.write_image(buf, width, height, color_type),
-            DynamicEncoderInner::Gif(mut enc) => {
+            DynamicEncoderInner::Gif(writer, repeat) => {
                 // The `ColorTypePreprocessor` will, if enabled, convert the image to `RgbaImage`
                 // if necessary.
                 // This is unfortunate though, we're making a copy for sauce.
@@ -195,16 +197,22 @@ impl<W: Write + Seek> image::ImageEncoder for DynamicEncoder<W> {
                         ))
                     })?;
 
+                let mut enc = GifEncoder::new(writer);
+                enc.set_repeat(repeat.into())?;
                 enc.encode_frame(image::Frame::new(image_buffer))
             }
             DynamicEncoderInner::Ico(enc) => enc.write_image(buf, width, height, color_type),
             DynamicEncoderInner::Jpeg(enc) => enc.write_image(buf, width, height, color_type),
             DynamicEncoderInner::Pnm(enc) => enc.write_image(buf, width, height, color_type),
-            DynamicEncoderInner::Png(enc) => enc.write_image(buf, width, height, color_type),
+            DynamicEncoderInner::Png(writer, _) => {
+                PngEncoder::new(writer).write_image(buf, width, height, color_type)
+            }
             DynamicEncoderInner::Qoi(enc) => enc.write_image(buf, width, height, color_type),
             DynamicEncoderInner::Tga(enc) => enc.write_image(buf, width, height, color_type),
             DynamicEncoderInner::Tiff(enc) => enc.write_image(buf, width, height, color_type),
-            DynamicEncoderInner::Webp(enc) => enc.write_image(buf, width, height, color_type),
+            DynamicEncoderInner::Webp(writer, _) => {
+                WebPEncoder::new_lossless(writer).write_image(buf, width, height, color_type)
+            }
         }
     }
 }
@@ -212,8 +220,14 @@ impl<W: Write + Seek> image::ImageEncoder for DynamicEncoder<W> {
 impl<W: Write + Seek> DynamicEncoder<W> {
     pub fn write_image_frames(self, frames: Vec<image::Frame>) -> Result<(), SicIoError> {
         match self.encoder {
-            DynamicEncoderInner::Gif(mut enc) => {
-                enc.encode_frames(frames).map_err(SicIoError::ImageError)
+            DynamicEncoderInner::Gif(writer, repeat) => {
+                animated::write_gif(writer, frames, repeat).map_err(SicIoError::ImageError)
+            }
+            DynamicEncoderInner::Png(writer, repeat) => {
+                animated::write_apng(writer, frames, repeat).map_err(SicIoError::ImageError)
+            }
+            DynamicEncoderInner::Webp(writer, repeat) => {
+                animated::write_webp(writer, frames, repeat).map_err(SicIoError::ImageError)
             }
             // Use SingleFramePreprocessor to avoid this error, by picking a single frame
             // from the animated image instead.
@@ -224,6 +238,33 @@ impl<W: Write + Seek> DynamicEncoder<W> {
     }
 }
 
+impl<W: Write + Seek> DynamicEncoder<W> {
+    /// Writes an indexed image. PNG and GIF images are written as palette based images, while
+    /// for other formats each palette index is replaced by its color.
+    pub fn write_indexed_image(self, image: &IndexedImage) -> Result<(), SicIoError> {
+        match self.encoder {
+            DynamicEncoderInner::Png(writer, _) => indexed::write_png(writer, image)?,
+            DynamicEncoderInner::Gif(writer, _) => indexed::write_gif(writer, image)?,
+            encoder => {
+                let expanded = image.to_dynamic_image();
+                let encoder = Self {
+                    encoder,
+                    format: self.format,
+                };
+
+                encoder.write_image(
+                    expanded.as_bytes(),
+                    expanded.width(),
+                    expanded.height(),
+                    expanded.color().into(),
+                )?
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl<W: Write + Seek> DynamicEncoder<W> {
     pub fn image_format(&self) -> image::ImageFormat {
         self.encoder.image_format()
@@ -236,15 +277,15 @@ enum DynamicEncoderInner<W: Write + Seek> {
     Bmp(BmpEncoder<W>),
     Exr(image::codecs::openexr::OpenExrEncoder<W>),
     Farbfeld(image::codecs::farbfeld::FarbfeldEncoder<W>),
-    Gif(image::codecs::gif::GifEncoder<W>),
+    Gif(W, RepeatAnimation),
     Ico(image::codecs::ico::IcoEncoder<W>),
     Jpeg(JpegEncoder<W>),
     Pnm(image::codecs::pnm::PnmEncoder<W>),
-    Png(image::codecs::png::PngEncoder<W>),
+    Png(W, RepeatAnimation),
     Qoi(image::codecs::qoi::QoiEncoder<W>),
     Tga(image::codecs::tga::TgaEncoder<W>),
     Tiff(image::codecs::tiff::TiffEncoder<W>),
-    Webp(image::codecs::webp::WebPEncoder<W>),
+    Webp(W, RepeatAnimation),
 }
 
 impl<W: Write + Seek> fmt::Debug for DynamicEncoderInner<W> {
@@ -256,15 +297,15 @@ impl<W: Write + Seek> fmt::Debug for DynamicEncoderInner<W> {
             Bmp(_) => f.write_str("DynamicEncoder(Bmp)"),
             Exr(_) => f.write_str("DynamicEncoder(Exr)"),
             Farbfeld(_) => f.write_str("DynamicEncoder(Farbfeld)"),
-            Gif(_) => f.write_str("DynamicEncoder(Gif)"),
+            Gif(..) => f.write_str("DynamicEncoder(Gif)"),
             Ico(_) => f.write_str("DynamicEncoder(Ico)"),
             Jpeg(_) => f.write_str("DynamicEncoder(Jpeg)"),
             Pnm(_) => f.write_str("DynamicEncoder(Pnm)"),
-            Png(_) => f.write_str("DynamicEncoder(Png)"),
+            Png(..) => f.write_str("DynamicEncoder(Png)"),
             Qoi(_) => f.write_str("DynamicEncoder(Qoi)"),
             Tga(_) => f.write_str("DynamicEncoder(Tga)"),
             Tiff(_) => f.write_str("DynamicEncoder(Tiff)"),
-            Webp(_) => f.write_str("DynamicEncoder(Webp)"),
+            Webp(..) => f.write_str("DynamicEncoder(Webp)"),
         }
     }
 }
@@ -276,15 +317,15 @@ impl<W: Write + Seek> DynamicEncoderInner<W> {
             Self::Bmp(_) => image::ImageFormat::Bmp,
             Self::Exr(_) => image::ImageFormat::OpenExr,
             Self::Farbfeld(_) => image::ImageFormat::Farbfeld,
-            Self::Gif(_) => image::ImageFormat::Gif,
+            Self::Gif(..) => image::ImageFormat::Gif,
             Self::Ico(_) => image::ImageFormat::Ico,
             Self::Jpeg(_) => image::ImageFormat::Jpeg,
             Self::Pnm(_) => image::ImageFormat::Pnm,
-            Self::Png(_) => image::ImageFormat::Png,
+            Self::Png(..) => image::ImageFormat::Png,
             Self::Qoi(_) => image::ImageFormat::Qoi,
             Self::Tga(_) => image::ImageFormat::Tga,
             Self::Tiff(_) => image::ImageFormat::Tiff,
-            Self::Webp(_) => image::ImageFormat::WebP,
+            Self::Webp(..) => image::ImageFormat::WebP,
         }
     }
 }
@@ -0,0 +1,219 @@
+//! Encoders which write an [`IndexedImage`] as a palette based image, instead of expanding each
+//! palette index to its color.
+
+use sic_core::IndexedImage;
+use sic_core::image::error::{EncodingError, ImageFormatHint};
+use sic_core::image::{ImageError, ImageFormat, ImageResult};
+use std::io::Write;
+
+/// Writes a PNG image with a `PLTE` chunk holding the palette colors, and a `tRNS` chunk holding
+/// their alpha values if any of them isn't fully opaque.
+///
+/// The smallest bit depth which can index every palette color is used, so palettes of at most 2,
+/// 4 or 16 colors store respectively 8, 4 or 2 pixels per byte.
+pub fn write_png<W: Write>(writer: W, image: &IndexedImage) -> ImageResult<()> {
+    let palette = image.palette();
+    let (depth, bits) = match palette.len() {
+        0..=2 => (png::BitDepth::One, 1),
+        3..=4 => (png::BitDepth::Two, 2),
+        5..=16 => (png::BitDepth::Four, 4),
+        _ => (png::BitDepth::Eight, 8),
+    };
+
+    let mut encoder = png::Encoder::new(writer, image.width(), image.height());
+    encoder.set_color(png::ColorType::Indexed);
+    encoder.set_depth(depth);
+    encoder.set_palette(
+        palette
+            .iter()
+            .flat_map(|color| [color[0], color[1], color[2]])
+            .collect::<Vec<_>>(),
+    );
+
+    if image.has_alpha() {
+        // Entries past the last translucent color are implicitly fully opaque
+        let len = palette
+            .iter()
+            .rposition(|color| color[3] != u8::MAX)
+            .map_or(0, |position| position + 1);
+
+        encoder.set_trns(
+            palette[..len]
+                .iter()
+                .map(|color| color[3])
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    let mut writer = encoder.write_header().map_err(png_error)?;
+    writer
+        .write_image_data(&pack_rows(image, bits))
+        .map_err(png_error)?;
+    writer.finish().map_err(png_error)
+}
+
+/// Writes a GIF image with a global color table holding the palette colors.
+///
+/// A GIF image supports a single fully transparent color only: all palette colors with an alpha
+/// value of zero share the first of them as transparent index, while the alpha value of all other
+/// palette colors is ignored.
+pub fn write_gif<W: Write>(writer: W, image: &IndexedImage) -> ImageResult<()> {
+    let (width, height) = match (u16::try_from(image.width()), u16::try_from(image.height())) {
+        (Ok(width), Ok(height)) => (width, height),
+        _ => {
+            return Err(encoding_error(
+                ImageFormat::Gif,
+                "The width and height of a GIF image can't exceed 65535 pixels",
+            ));
+        }
+    };
+
+    let palette = image.palette();
+    let rgb = palette
+        .iter()
+        .flat_map(|color| [color[0], color[1], color[2]])
+        .collect::<Vec<_>>();
+    let transparent = palette.iter().position(|color| color[3] == 0);
+
+    let indices = image
+        .indices()
+        .as_raw()
+        .iter()
+        .map(|&index| match transparent {
+            Some(transparent) if palette[usize::from(index)][3] == 0 => transparent as u8,
+            _ => index,
+        })
+        .collect::<Vec<_>>();
+
+    let mut encoder = gif::Encoder::new(writer, width, height, &rgb).map_err(gif_error)?;
+    let frame =
+        gif::Frame::from_indexed_pixels(width, height, indices, transparent.map(|i| i as u8));
+
+    encoder.write_frame(&frame).map_err(gif_error)
+}
+
+/// Packs the palette indices of each row into bytes of `bits` bits per pixel, starting with the
+/// most significant bits. Each row starts at a new byte.
+fn pack_rows(image: &IndexedImage, bits: usize) -> Vec<u8> {
+    let pixels_per_byte = 8 / bits;
+
+    if image.width() == 0 {
+        return Vec::new();
+    }
+
+    image
+        .indices()
+        .as_raw()
+        .chunks_exact(image.width() as usize)
+        .flat_map(|row| {
+            row.chunks(pixels_per_byte).map(|pixels| {
+                pixels.iter().enumerate().fold(0u8, |byte, (i, &index)| {
+                    byte | (index << (8 - bits * (i + 1)))
+                })
+            })
+        })
+        .collect()
+}
+
+pub(crate) fn encoding_error(format: ImageFormat, message: impl Into<String>) -> ImageError {
+    ImageError::Encoding(EncodingError::new(
+        ImageFormatHint::Exact(format),
+        message.into(),
+    ))
+}
+
+pub(crate) fn png_error(err: png::EncodingError) -> ImageError {
+    ImageError::Encoding(EncodingError::new(
+        ImageFormatHint::Exact(ImageFormat::Png),
+        err,
+    ))
+}
+
+pub(crate) fn gif_error(err: gif::EncodingError) -> ImageError {
+    ImageError::Encoding(EncodingError::new(
+        ImageFormatHint::Exact(ImageFormat::Gif),
+        err,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sic_core::image::{GrayImage, Luma, Rgba};
+    use std::io::Cursor;
+
+    const PALETTE: [Rgba<u8>; 3] = [
+        Rgba([255, 0, 0, 255]),
+        Rgba([0, 0, 0, 0]),
+        Rgba([0, 0, 255, 255]),
+    ];
+
+    fn indexed_image() -> IndexedImage {
+        let indices = GrayImage::from_fn(5, 2, |x, y| Luma([((x + y) % 3) as u8]));
+
+        IndexedImage::try_new(indices, PALETTE.to_vec()).unwrap()
+    }
+
+    fn expected_colors(image: &IndexedImage) -> Vec<Rgba<u8>> {
+        image
+            .indices()
+            .pixels()
+            .map(|index| PALETTE[usize::from(index[0])])
+            .collect()
+    }
+
+    #[test]
+    fn png_is_palette_based() {
+        let image = indexed_image();
+        let mut buffer = Vec::new();
+        write_png(&mut buffer, &image).unwrap();
+
+        let reader = png::Decoder::new(Cursor::new(&buffer)).read_info().unwrap();
+        let info = reader.info();
+        assert_eq!(info.color_type, png::ColorType::Indexed);
+        assert_eq!(info.bit_depth, png::BitDepth::Two);
+        assert_eq!(
+            info.palette.as_deref(),
+            Some(&[255, 0, 0, 0, 0, 0, 0, 0, 255][..])
+        );
+        assert_eq!(info.trns.as_deref(), Some(&[255, 0][..]));
+
+        let decoded = sic_core::image::load_from_memory(&buffer).unwrap();
+        let colors = decoded.to_rgba8().pixels().copied().collect::<Vec<_>>();
+        assert_eq!(colors, expected_colors(&image));
+    }
+
+    #[test]
+    fn gif_is_palette_based() {
+        let image = indexed_image();
+        let mut buffer = Vec::new();
+        write_gif(&mut buffer, &image).unwrap();
+
+        let mut options = gif::DecodeOptions::new();
+        options.set_color_output(gif::ColorOutput::Indexed);
+        let mut decoder = options.read_info(Cursor::new(&buffer)).unwrap();
+        let frame = decoder.read_next_frame().unwrap().unwrap();
+        assert_eq!(frame.transparent, Some(1));
+        assert_eq!(&*frame.buffer, image.indices().as_raw().as_slice());
+
+        let decoded = sic_core::image::load_from_memory(&buffer).unwrap();
+        let colors = decoded.to_rgba8().pixels().copied().collect::<Vec<_>>();
+        assert_eq!(colors, expected_colors(&image));
+    }
+
+    #[test]
+    fn gif_too_large() {
+        let indices = GrayImage::new(65536, 1);
+        let image = IndexedImage::try_new(indices, PALETTE.to_vec()).unwrap();
+
+        assert!(write_gif(&mut Vec::new(), &image).is_err());
+    }
+
+    #[test]
+    fn pack_rows_starts_each_row_at_a_byte() {
+        let indices = GrayImage::from_raw(3, 2, vec![1, 0, 1, 0, 1, 1]).unwrap();
+        let image = IndexedImage::try_new(indices, PALETTE[..2].to_vec()).unwrap();
+
+        assert_eq!(pack_rows(&image, 1), [0b1010_0000, 0b0110_0000]);
+    }
+}
@@ -22,6 +22,19 @@ impl RepeatAnimation {
     }
 }
 
+impl RepeatAnimation {
+    /// The number of times an animation is played, where `0` plays it infinitely, as stored by
+    /// animated PNG and WebP images. Like for GIF images, `Finite(n)` plays the animation once,
+    /// and then repeats it `n` times.
+    pub fn num_plays(self) -> u16 {
+        match self {
+            RepeatAnimation::Finite(v) => v.saturating_add(1),
+            RepeatAnimation::Infinite => 0,
+            RepeatAnimation::Never => 1,
+        }
+    }
+}
+
 impl From<RepeatAnimation> for image::codecs::gif::Repeat {
     fn from(value: RepeatAnimation) -> Self {
         match value {
@@ -31,3 +44,13 @@ impl From<RepeatAnimation> for image::codecs::gif::Repeat {
         }
     }
 }
+
+impl From<RepeatAnimation> for gif::Repeat {
+    fn from(value: RepeatAnimation) -> Self {
+        match value {
+            RepeatAnimation::Finite(v) => gif::Repeat::Finite(v),
+            RepeatAnimation::Infinite => gif::Repeat::Infinite,
+            RepeatAnimation::Never => gif::Repeat::Finite(0),
+        }
+    }
+}
@@ -45,6 +45,12 @@ impl Preprocess for ColorTypePreprocessor {
                 _ => Ok(SicImage::Static(image)),
             },
             SicImage::Animated(image) => Ok(SicImage::Animated(image)),
+            // PNG and GIF images can hold palette indices; for other formats, each palette index
+            // is replaced by its color first
+            SicImage::Indexed(image) => match self.format {
+                DynamicImageFormat::Png | DynamicImageFormat::Gif => Ok(SicImage::Indexed(image)),
+                _ => self.preprocess(SicImage::Static(image.to_dynamic_image())),
+            },
         }
     }
 }
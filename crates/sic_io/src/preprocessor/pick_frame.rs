@@ -17,7 +17,7 @@ impl Preprocess for PickFramePreprocessor {
 
     fn preprocess(&self, image: SicImage) -> Result<SicImage, Self::Err> {
         match image {
-            SicImage::Animated(animated) if self.image_format != image::ImageFormat::Gif => {
+            SicImage::Animated(animated) if !supports_animation(self.image_format) => {
                 eprintln!(
                     "WARN: Unable to encode animated image buffer with format '{:?}': encoding first frame only",
                     self.image_format
@@ -29,3 +29,11 @@ impl Preprocess for PickFramePreprocessor {
         }
     }
 }
+
+/// Image formats which can be encoded with multiple frames.
+fn supports_animation(format: image::ImageFormat) -> bool {
+    matches!(
+        format,
+        image::ImageFormat::Gif | image::ImageFormat::Png | image::ImageFormat::WebP
+    )
+}
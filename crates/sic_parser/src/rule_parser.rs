@@ -6,11 +6,36 @@ use crate::value_parser::ParseInputsFromIter;
 use pest::iterators::{Pair, Pairs};
 use sic_image_engine::engine::{EnvItem, Instr, ItemName};
 
+use sic_core::image::Rgba;
 use sic_image_engine::ImgOp;
+use sic_image_engine::wrapper::bilateral::BilateralInputs;
+use sic_image_engine::wrapper::channel::{
+    Channel, ChannelOrLuma, ChannelScope, MergeChannelsInputs,
+};
+use sic_image_engine::wrapper::chroma_key::ChromaKeyInputs;
+use sic_image_engine::wrapper::clahe::ClaheInputs;
+use sic_image_engine::wrapper::color_matrix::ColorMatrixInputs;
+use sic_image_engine::wrapper::curves::CurvesInputs;
+use sic_image_engine::wrapper::dither_algorithm::DitherAlgorithm;
 use sic_image_engine::wrapper::draw_text_inner::DrawTextInner;
+use sic_image_engine::wrapper::equalize::EqualizeMode;
+use sic_image_engine::wrapper::extend_canvas::ExtendCanvasInputs;
+use sic_image_engine::wrapper::filter::FilterInputs;
 use sic_image_engine::wrapper::filter_type::FilterTypeWrap;
+use sic_image_engine::wrapper::frame_range::FrameRange;
+use sic_image_engine::wrapper::gradient::{GradientColorSpace, GradientInputs};
+use sic_image_engine::wrapper::gravity::Gravity;
 use sic_image_engine::wrapper::image_path::ImageFromPath;
-use sic_image_engine::wrapper::overlay::OverlayInputs;
+use sic_image_engine::wrapper::levels::LevelsInputs;
+use sic_image_engine::wrapper::morphology::MorphologyInputs;
+use sic_image_engine::wrapper::nl_means::NlMeansInputs;
+use sic_image_engine::wrapper::overlay::{BlendMode, OverlayInputs, OverlayPlacement};
+use sic_image_engine::wrapper::pad::PadInputs;
+use sic_image_engine::wrapper::palette::QuantizeInputs;
+use sic_image_engine::wrapper::resize::{ResizeInputs, ResizeValue};
+use sic_image_engine::wrapper::rotate::{RotateCanvas, RotateInputs};
+use sic_image_engine::wrapper::sprite_sheet::{SplitSpriteSheetInputs, SpriteSheetInputs};
+use std::str::FromStr;
 
 // This function parses statements provided as a single 'script' to an image operations program.
 // An image operations program is currently a linear list of image operations which are applied
@@ -27,33 +52,84 @@ pub fn parse_image_operations(pairs: Pairs<'_, Rule>) -> Result<Vec<Instr>, SicP
     pairs
         .filter(|pair| pair.as_rule() != Rule::EOI)
         .map(|pair| match pair.as_rule() {
+            Rule::bilateral => Bilateral(pair),
             Rule::blur => Blur(pair),
+            Rule::boomerang => Ok(Instr::Operation(ImgOp::Boomerang)),
             Rule::brighten => Brighten(pair),
+            Rule::canny => Canny(pair),
+            Rule::chroma_key => ChromaKey(pair),
+            Rule::clahe => Clahe(pair),
+            Rule::close => Close(pair),
+            Rule::color_matrix => ColorMatrix(pair),
+            Rule::color_to_alpha => ColorToAlpha(pair),
             Rule::contrast => Contrast(pair),
             Rule::crop => Crop(pair),
+            Rule::crop_to => CropTo(pair),
+            Rule::curves => Curves(pair),
             Rule::diff => Diff(
                 pair.into_inner()
                     .next()
                     .ok_or(SicParserError::NoInnerString)?,
             ),
 
+            Rule::dilate => Dilate(pair),
             Rule::dither => Ok(Instr::Operation(ImgOp::Dither)),
             Rule::dither_quant => DitherQuant(pair),
             Rule::draw_text => Ok(parse_draw_text(pair)?),
+            Rule::drop_frames => DropFrames(pair),
+            Rule::duplicate_frame => DuplicateFrame(pair),
+            Rule::equalize => Equalize(pair),
+            Rule::erode => Erode(pair),
+            Rule::exposure => Exposure(pair),
+            Rule::extract_alpha => Ok(Instr::Operation(ImgOp::ExtractAlpha)),
+            Rule::extract_channel => ExtractChannel(pair),
+            Rule::extend_canvas => ExtendCanvas(pair),
+            Rule::filter => Filter(pair),
             Rule::filter3x3 => Filter3x3(pair),
+            Rule::flatten => Flatten(pair),
             Rule::flip_horizontal => Ok(Instr::Operation(ImgOp::FlipHorizontal)),
             Rule::flip_vertical => Ok(Instr::Operation(ImgOp::FlipVertical)),
+            Rule::frames => Frames(pair),
+            Rule::gamma => Gamma(pair),
+            Rule::gradient => Gradient(pair),
             Rule::grayscale => Ok(Instr::Operation(ImgOp::Grayscale)),
             Rule::huerotate => HueRotate(pair),
             Rule::horizontal_gradient => Ok(parse_horizontal_gradient(pair)?),
             Rule::index_colors => Ok(Instr::Operation(ImgOp::IndexColors)),
             Rule::index_colors_quant => IndexColorsQuant(pair),
             Rule::invert => Ok(Instr::Operation(ImgOp::Invert)),
+            Rule::laplacian => Ok(Instr::Operation(ImgOp::Laplacian)),
+            Rule::levels => Levels(pair),
+            Rule::median => Median(pair),
+            Rule::merge_channels => parse_merge_channels(pair),
+            Rule::morph_gradient => MorphGradient(pair),
+            Rule::nl_means => NlMeans(pair),
+            Rule::open => Open(pair),
             Rule::overlay => parse_overlay(pair),
+            Rule::pad => Pad(pair),
+            Rule::premultiply_alpha => Ok(Instr::Operation(ImgOp::PremultiplyAlpha)),
+            Rule::quantize => parse_quantize(pair),
             Rule::resize => Resize(pair),
+            Rule::reverse_frames => Ok(Instr::Operation(ImgOp::ReverseFrames)),
+            Rule::rotate => parse_rotate(pair),
             Rule::rotate90 => Ok(Instr::Operation(ImgOp::Rotate90)),
             Rule::rotate180 => Ok(Instr::Operation(ImgOp::Rotate180)),
             Rule::rotate270 => Ok(Instr::Operation(ImgOp::Rotate270)),
+            Rule::saturate => Saturate(pair),
+            Rule::scale_alpha => ScaleAlpha(pair),
+            Rule::scale_frame_delay => ScaleFrameDelay(pair),
+            Rule::scharr => Ok(Instr::Operation(ImgOp::Scharr)),
+            Rule::set_alpha => SetAlpha(
+                pair.into_inner()
+                    .next()
+                    .ok_or(SicParserError::NoInnerString)?,
+            ),
+            Rule::set_frame_delay => SetFrameDelay(pair),
+            Rule::set_frame_delay_at => SetFrameDelayAt(pair),
+            Rule::sobel => Ok(Instr::Operation(ImgOp::Sobel)),
+            Rule::split_sprite_sheet => SplitSpriteSheet(pair),
+            Rule::sprite_sheet => parse_sprite_sheet(pair),
+            Rule::swap_channels => SwapChannels(pair),
             Rule::unsharpen => Unsharpen(pair),
             Rule::setopt => {
                 parse_set_environment(pair.into_inner().next().ok_or({
@@ -62,6 +138,8 @@ pub fn parse_image_operations(pairs: Pairs<'_, Rule>) -> Result<Vec<Instr>, SicP
             }
 
             Rule::threshold => Ok(Instr::Operation(ImgOp::Threshold)),
+            Rule::trim => Trim(pair),
+            Rule::unpremultiply_alpha => Ok(Instr::Operation(ImgOp::UnpremultiplyAlpha)),
             Rule::vertical_gradient => Ok(parse_vertical_gradient(pair)?),
             // this is called 'del' for users
             Rule::unsetopt => parse_unset_environment(pair.into_inner().next().ok_or({
@@ -92,17 +170,54 @@ macro_rules! parse_op_from_pair {
     };
 }
 
+parse_op_from_pair!(Bilateral, BilateralInputs);
 parse_op_from_pair!(Blur, f32);
 parse_op_from_pair!(Brighten, i32);
+parse_op_from_pair!(Canny, (f32, f32));
+parse_op_from_pair!(ChromaKey, ChromaKeyInputs);
+parse_op_from_pair!(Clahe, ClaheInputs);
+parse_op_from_pair!(Close, MorphologyInputs);
+parse_op_from_pair!(ColorMatrix, ColorMatrixInputs);
+parse_op_from_pair!(ColorToAlpha, Rgba<u8>);
 parse_op_from_pair!(Contrast, f32);
 parse_op_from_pair!(Crop, (u32, u32, u32, u32));
+parse_op_from_pair!(CropTo, (u32, u32, Gravity));
+parse_op_from_pair!(Curves, CurvesInputs);
 parse_op_from_pair!(Diff, ImageFromPath);
+parse_op_from_pair!(Dilate, MorphologyInputs);
+parse_op_from_pair!(Equalize, EqualizeMode);
+parse_op_from_pair!(Erode, MorphologyInputs);
+parse_op_from_pair!(Exposure, f32);
+parse_op_from_pair!(ExtendCanvas, ExtendCanvasInputs);
+parse_op_from_pair!(ExtractChannel, ChannelOrLuma);
+parse_op_from_pair!(Gamma, f32);
+parse_op_from_pair!(Gradient, GradientInputs);
 parse_op_from_pair!(DitherQuant, (u32, u32));
+parse_op_from_pair!(DropFrames, u32);
+parse_op_from_pair!(DuplicateFrame, (u32, u32));
+parse_op_from_pair!(Frames, FrameRange);
 parse_op_from_pair!(IndexColorsQuant, (u32, u32));
 parse_op_from_pair!(HueRotate, i32);
-parse_op_from_pair!(Resize, (u32, u32));
+parse_op_from_pair!(Levels, LevelsInputs);
+parse_op_from_pair!(Median, u32);
+parse_op_from_pair!(MorphGradient, MorphologyInputs);
+parse_op_from_pair!(NlMeans, NlMeansInputs);
+parse_op_from_pair!(Open, MorphologyInputs);
+parse_op_from_pair!(Pad, PadInputs);
+parse_op_from_pair!(Resize, ResizeInputs);
+parse_op_from_pair!(Saturate, f32);
+parse_op_from_pair!(ScaleAlpha, f32);
+parse_op_from_pair!(ScaleFrameDelay, f32);
+parse_op_from_pair!(SetAlpha, ImageFromPath);
+parse_op_from_pair!(SetFrameDelay, u32);
+parse_op_from_pair!(SetFrameDelayAt, (u32, u32));
+parse_op_from_pair!(SplitSpriteSheet, SplitSpriteSheetInputs);
+parse_op_from_pair!(SwapChannels, (Channel, Channel));
 parse_op_from_pair!(Unsharpen, (f32, i32));
+parse_op_from_pair!(Filter, FilterInputs);
 parse_op_from_pair!(Filter3x3, [f32; 9]);
+parse_op_from_pair!(Flatten, Rgba<u8>);
+parse_op_from_pair!(Trim, u8);
 
 macro_rules! parse_setenv_from_pair {
     ($env_item:tt, $ty:ty) => {
@@ -121,11 +236,19 @@ macro_rules! parse_setenv_from_pair {
 
 parse_setenv_from_pair!(CustomSamplingFilter, FilterTypeWrap);
 parse_setenv_from_pair!(PreserveAspectRatio, bool);
+parse_setenv_from_pair!(DitherAlgorithm, DitherAlgorithm);
+parse_setenv_from_pair!(DitherSerpentine, bool);
+parse_setenv_from_pair!(ChannelScope, ChannelScope);
+parse_setenv_from_pair!(GradientColorSpace, GradientColorSpace);
 
 fn parse_set_environment(pair: Pair<'_, Rule>) -> Result<Instr, SicParserError> {
     let environment_item = match pair.as_rule() {
         Rule::set_resize_sampling_filter => CustomSamplingFilter(pair)?,
         Rule::set_resize_preserve_aspect_ratio => PreserveAspectRatio(pair)?,
+        Rule::set_dither_algorithm => DitherAlgorithm(pair)?,
+        Rule::set_dither_serpentine => DitherSerpentine(pair)?,
+        Rule::set_channel_scope => ChannelScope(pair)?,
+        Rule::set_gradient_color_space => GradientColorSpace(pair)?,
         _ => {
             return Err(SicParserError::OperationError(
                 OperationParamError::SetEnvironmentElement(format!("{}", pair)),
@@ -140,6 +263,10 @@ fn parse_unset_environment(pair: Pair<'_, Rule>) -> Result<Instr, SicParserError
     let environment_item = match pair.as_rule() {
         Rule::env_resize_sampling_filter_name => ItemName::CustomSamplingFilter,
         Rule::env_resize_preserve_aspect_ratio_name => ItemName::PreserveAspectRatio,
+        Rule::env_dither_algorithm_name => ItemName::DitherAlgorithm,
+        Rule::env_dither_serpentine_name => ItemName::DitherSerpentine,
+        Rule::env_channel_scope_name => ItemName::ChannelScope,
+        Rule::env_gradient_color_space_name => ItemName::GradientColorSpace,
         _ => {
             return Err(SicParserError::OperationError(
                 OperationParamError::UnsetEnvironmentElement(format!("{}", pair)),
@@ -150,25 +277,197 @@ fn parse_unset_environment(pair: Pair<'_, Rule>) -> Result<Instr, SicParserError
     Ok(Instr::EnvRemove(environment_item))
 }
 
+// expected pair with inner pairs:
+// - rule: 'blend_mode' (optional); which: normal | multiply | ...; represents: how colors are combined
+// - rule: 'fp' (if blend_mode is given); represents: opacity in the range [0, 1]
+// - rule: 'overlay_width' (optional); represents: width to which the overlay image is scaled
+// - rule: 'string_unicode'; represents: path to the overlay image
+// - rule: 'overlay_tile' | 'overlay_anchor' | 'overlay_position'; represents: placement of the
+//   overlay image
 fn parse_overlay(pair: Pair<'_, Rule>) -> Result<Instr, SicParserError> {
-    let mut pairs = pair.into_inner();
+    let mut pairs = pair.into_inner().peekable();
+
+    let blending = match pairs.next_if(|pair| pair.as_rule() == Rule::blend_mode) {
+        Some(blend_mode) => {
+            let blend_mode = parse_keyword::<BlendMode>(blend_mode.as_str())?;
+
+            let opacity = pairs
+                .next()
+                .ok_or_else(|| SicParserError::ExpectedValue("fp".to_string()))?;
+            let opacity: f32 = ParseInputsFromIter::parse(&[opacity.as_str()])?;
+
+            Some((blend_mode, opacity))
+        }
+        None => None,
+    };
+
+    let width = match pairs.next_if(|pair| pair.as_rule() == Rule::overlay_width) {
+        Some(width) => {
+            let value = width
+                .into_inner()
+                .next()
+                .ok_or_else(|| SicParserError::ExpectedValue("resize_value".to_string()))?;
+
+            Some(parse_keyword::<ResizeValue>(value.as_str())?)
+        }
+        None => None,
+    };
 
     let image_path = parse_primitive_from_pair!(
         pairs.next().ok_or(SicParserError::NoInnerString)?,
         ImageFromPath
     )?;
 
-    let x = pairs
+    let placement = pairs
+        .next()
+        .ok_or_else(|| SicParserError::ExpectedValue("overlay placement".to_string()))?;
+    let placement_rule = placement.as_rule();
+    let mut values = placement.into_inner();
+    let mut next_value = || {
+        values
+            .next()
+            .map(|pair| pair.as_str())
+            .ok_or_else(|| SicParserError::ExpectedValue("uint".to_string()))
+    };
+
+    let placement = match placement_rule {
+        Rule::overlay_tile => OverlayPlacement::Tiled {
+            spacing: ParseInputsFromIter::parse(&[next_value()?, next_value()?])?,
+            degrees: ParseInputsFromIter::parse(&[next_value()?])?,
+        },
+        Rule::overlay_anchor => OverlayPlacement::Anchored {
+            gravity: parse_keyword::<Gravity>(next_value()?)?,
+            margin: ParseInputsFromIter::parse(&[next_value()?, next_value()?])?,
+        },
+        _ => {
+            let (x, y): (i64, i64) = ParseInputsFromIter::parse(&[next_value()?, next_value()?])?;
+            OverlayPlacement::Position(x, y)
+        }
+    };
+
+    let inputs = OverlayInputs::new(image_path, placement);
+    let inputs = match blending {
+        Some((blend_mode, opacity)) => inputs.with_blending(blend_mode, opacity),
+        None => inputs,
+    };
+    let inputs = match width {
+        Some(width) => inputs.with_width(width),
+        None => inputs,
+    };
+
+    Ok(Instr::Operation(ImgOp::Overlay(inputs)))
+}
+
+fn parse_keyword<T>(keyword: &str) -> Result<T, SicParserError>
+where
+    T: FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    keyword.parse::<T>().map_err(|err| {
+        SicParserError::ValueParsingErrorWithInnerError(keyword.to_string(), Box::new(err))
+    })
+}
+
+// expected pair with inner pairs:
+// - rule: 'quantize_mode'; which: nearest | dither; represents: how colors are mapped to the palette
+// - rule: 'string_unicode'; represents: path to a palette file
+//   or
+// - rule: 'named_value'+; which: rgba(r, g, b, a) with r,g,b,a =: u8; represents: palette colors
+// expected pair with four inner pairs, one for each of the red, green, blue and alpha channels:
+// - rule: 'string_unicode'; represents: path to the image of which the luma becomes the channel
+// - rule: 'merge_channels_keep'; represents: the channel keeps its values
+fn parse_merge_channels(pair: Pair<'_, Rule>) -> Result<Instr, SicParserError> {
+    let values = pair
+        .into_inner()
+        .map(|pair| match pair.as_rule() {
+            Rule::string_unicode => pair
+                .into_inner()
+                .next()
+                .map(|inner| inner.as_str())
+                .ok_or(SicParserError::NoInnerString),
+            _ => Ok(pair.as_str()),
+        })
+        .collect::<Result<Vec<&str>, SicParserError>>()?;
+
+    let inputs: MergeChannelsInputs = ParseInputsFromIter::parse(values)?;
+
+    Ok(Instr::Operation(ImgOp::MergeChannels(inputs)))
+}
+
+// expected pair with inner pairs:
+// - rule: 'sprite_sheet_atlas' and 'string_unicode' (optional); represents: path of the atlas
+// - rule: 'uint'; represents: number of columns
+// - rule: 'uint'; represents: spacing between the cells
+// - rule: 'named_value'; which: rgba(r, g, b, a) with r,g,b,a =: u8; represents: background color
+fn parse_sprite_sheet(pair: Pair<'_, Rule>) -> Result<Instr, SicParserError> {
+    let values = pair
+        .into_inner()
+        .map(|pair| match pair.as_rule() {
+            Rule::string_unicode => pair
+                .into_inner()
+                .next()
+                .map(|inner| inner.as_str())
+                .ok_or(SicParserError::NoInnerString),
+            _ => Ok(pair.as_str()),
+        })
+        .collect::<Result<Vec<&str>, SicParserError>>()?;
+
+    let inputs: SpriteSheetInputs = ParseInputsFromIter::parse(values)?;
+
+    Ok(Instr::Operation(ImgOp::SpriteSheet(inputs)))
+}
+
+fn parse_quantize(pair: Pair<'_, Rule>) -> Result<Instr, SicParserError> {
+    let values = pair
+        .into_inner()
+        .map(|pair| match pair.as_rule() {
+            Rule::string_unicode => pair
+                .into_inner()
+                .next()
+                .map(|inner| inner.as_str())
+                .ok_or(SicParserError::NoInnerString),
+            _ => Ok(pair.as_str()),
+        })
+        .collect::<Result<Vec<&str>, SicParserError>>()?;
+
+    let inputs: QuantizeInputs = ParseInputsFromIter::parse(values)?;
+
+    Ok(Instr::Operation(ImgOp::Quantize(inputs)))
+}
+
+// expected pair with inner pairs:
+// - rule: 'fp'; represents: the angle in degrees (clockwise)
+// - rule: 'rotate_canvas'; which: crop | expand; represents: dimensions of the output image
+// - rule: 'named_value'; which: rgba(r, g, b, a) with r,g,b,a =: u8; represents: background color
+fn parse_rotate(pair: Pair<'_, Rule>) -> Result<Instr, SicParserError> {
+    use crate::named_value::parse_named_value;
+    use sic_core::image::Rgba;
+
+    let mut pairs = pair.into_inner();
+
+    let degrees = pairs
         .next()
-        .ok_or_else(|| SicParserError::ExpectedValue("uint".to_string()))?;
-    let y = pairs
+        .ok_or_else(|| SicParserError::ExpectedValue(String::from("fp")))?;
+    let degrees: f32 = ParseInputsFromIter::parse(&[degrees.as_str()])?;
+
+    let canvas = pairs
         .next()
-        .ok_or_else(|| SicParserError::ExpectedValue("uint".to_string()))?;
+        .ok_or_else(|| SicParserError::ExpectedValue(String::from("crop | expand")))?;
+    let canvas = parse_keyword::<RotateCanvas>(canvas.as_str())?;
 
-    let position: (i64, i64) = ParseInputsFromIter::parse(&[x.as_str(), y.as_str()])?;
+    let color = pairs.next().ok_or_else(|| {
+        SicParserError::ExpectedNamedValue(String::from("rgba(r: Byte, g: Byte, b: Byte, a: Byte)"))
+    })?;
+    let color = parse_named_value(color).map_err(SicParserError::NamedValueParsingError)?;
 
-    Ok(Instr::Operation(ImgOp::Overlay(OverlayInputs::new(
-        image_path, position,
+    Ok(Instr::Operation(ImgOp::Rotate(RotateInputs::new(
+        degrees,
+        canvas,
+        Rgba(
+            color
+                .extract_rgba()
+                .map_err(SicParserError::NamedValueParsingError)?,
+        ),
     ))))
 }
 
@@ -569,78 +868,1025 @@ mod tests {
         // rX = bottom right X coordinate
         // rY = bottom right Y coordinate
 
-        let pairs = SICParser::parse(Rule::main, "crop 1 1 1 1;")
-            .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
-        assert_eq!(
-            vec![Instr::Operation(ImgOp::Crop((1, 1, 1, 1)))],
-            parse_image_operations(pairs).unwrap()
-        );
+        let pairs = SICParser::parse(Rule::main, "crop 1 1 1 1;")
+            .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
+        assert_eq!(
+            vec![Instr::Operation(ImgOp::Crop((1, 1, 1, 1)))],
+            parse_image_operations(pairs).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_crop_zeros_parse_correct() {
+        let pairs = SICParser::parse(Rule::main, "crop 0 0 0 0;")
+            .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
+        assert_eq!(
+            vec![Instr::Operation(ImgOp::Crop((0, 0, 0, 0)))],
+            parse_image_operations(pairs).unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_crop_args_negative_parse_err() {
+        SICParser::parse(Rule::main, "crop -1 -1 -1 -1;")
+            .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_crop_arg_negative_p1_parse_err() {
+        SICParser::parse(Rule::main, "crop -1 0 0 0;")
+            .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_crop_arg_negative_p2_parse_err() {
+        SICParser::parse(Rule::main, "crop 0 -1 0 0;")
+            .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_crop_arg_negative_p3_parse_err() {
+        SICParser::parse(Rule::main, "crop 0 0 -1 0;")
+            .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_crop_arg_negative_p4_parse_err() {
+        SICParser::parse(Rule::main, "crop 0 0 0 -1;")
+            .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
+    }
+
+    #[test]
+    fn test_crop_arg_to_big_p4_parse_err() {
+        // 4294967296 == std::u32::MAX + 1
+        let pairs = SICParser::parse(Rule::main, "crop 0 0 0 4294967296")
+            .unwrap_or_else(|_| panic!("Unable to parse image operations script."));
+
+        assert!(parse_image_operations(pairs).is_err())
+    }
+
+    #[test]
+    fn test_crop_arg_just_in_range_p4_parse_ok() {
+        // 4294967296 == std::u32::MAX
+        let pairs = SICParser::parse(Rule::main, "crop 0 0 0 4294967295")
+            .unwrap_or_else(|_| panic!("Unable to parse image operations script."));
+
+        assert_eq!(
+            vec![Instr::Operation(ImgOp::Crop((0, 0, 0, u32::MAX,)))],
+            parse_image_operations(pairs).unwrap()
+        );
+    }
+
+    mod crop_to_test {
+        use super::*;
+
+        ide!();
+
+        #[parameterized(
+            input = {
+                "crop-to 10 20 center;",
+                "crop-to 0 0 NORTH-WEST",
+                "crop-to 1 2 south-east; crop 0 0 1 1",
+            },
+            expected_ops = {
+                vec![Instr::Operation(ImgOp::CropTo((10, 20, Gravity::Center)))],
+                vec![Instr::Operation(ImgOp::CropTo((0, 0, Gravity::NorthWest)))],
+                vec![Instr::Operation(ImgOp::CropTo((1, 2, Gravity::SouthEast))), Instr::Operation(ImgOp::Crop((0, 0, 1, 1)))],
+            }
+        )]
+        fn test_crop_to_ok(input: &str, expected_ops: Vec<Instr>) {
+            let pairs = SICParser::parse(Rule::main, input)
+                .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
+
+            assert_eq!(parse_image_operations(pairs).unwrap(), expected_ops);
+        }
+
+        #[parameterized(
+            input = {
+                "crop-to 10 20",
+                "crop-to 10 center",
+                "crop-to -1 20 center",
+                "crop-to 10 20 middle",
+                "crop-to 10 20 north west",
+            }
+        )]
+        fn test_crop_to_err(input: &str) {
+            let pairs = SICParser::parse(Rule::main, input);
+            assert!(pairs.is_err());
+        }
+    }
+
+    mod color_matrix_test {
+        use super::*;
+        use sic_image_engine::wrapper::color_matrix::ColorMatrixPreset;
+
+        ide!();
+
+        #[rustfmt::skip]
+        const MATRIX: [f32; 20] = [
+            0.5, 0.0, 0.0, 0.0, 0.1,
+            0.0, 1.0, 0.0, 0.0, 0.0,
+            0.0, 0.0, 1.0, 0.0, 0.0,
+            0.0, 0.0, 0.0, -1.0, 1.0,
+        ];
+
+        #[parameterized(
+            input = {
+                "color-matrix sepia",
+                "color-matrix Swap-RB;",
+                "color-matrix desaturate",
+                "color-matrix protanopia",
+                "color-matrix luminance-to-alpha",
+                "color-matrix 0.5 0 0 0 0.1 0 1 0 0 0 0 0 1 0 0 0 0 0 -1 1",
+                "color-matrix 0.5 0 0 0 0.1 | 0 1 0 0 0 | 0 0 1 0 0 | 0 0 0 -1 1",
+            },
+            expected_ops = {
+                vec![Instr::Operation(ImgOp::ColorMatrix(ColorMatrixPreset::Sepia.matrix()))],
+                vec![Instr::Operation(ImgOp::ColorMatrix(ColorMatrixPreset::SwapRb.matrix()))],
+                vec![Instr::Operation(ImgOp::ColorMatrix(ColorMatrixPreset::Desaturate.matrix()))],
+                vec![Instr::Operation(ImgOp::ColorMatrix(ColorMatrixPreset::Protanopia.matrix()))],
+                vec![Instr::Operation(ImgOp::ColorMatrix(ColorMatrixPreset::LuminanceToAlpha.matrix()))],
+                vec![Instr::Operation(ImgOp::ColorMatrix(ColorMatrixInputs::new(MATRIX)))],
+                vec![Instr::Operation(ImgOp::ColorMatrix(ColorMatrixInputs::new(MATRIX)))],
+            }
+        )]
+        fn test_color_matrix_ok(input: &str, expected_ops: Vec<Instr>) {
+            let pairs = SICParser::parse(Rule::main, input)
+                .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
+
+            assert_eq!(parse_image_operations(pairs).unwrap(), expected_ops);
+        }
+
+        #[parameterized(
+            input = {
+                "color-matrix",
+                "color-matrix sepa",
+                "color-matrix 1 0 0 0 0 0 1 0 0 0 0 0 1 0 0",
+                "color-matrix 1 0 0 0 0 0 1 0 0 0 0 0 1 0 0 0 0 0 1",
+                "color-matrix 1 0 0 0 | 0 0 1 0 0 0 | 0 0 1 0 0 | 0 0 0 1 0",
+            }
+        )]
+        fn test_color_matrix_err(input: &str) {
+            let pairs = SICParser::parse(Rule::main, input);
+            assert!(pairs.is_err());
+        }
+    }
+
+    mod edges_test {
+        use super::*;
+
+        ide!();
+
+        #[parameterized(
+            input = {
+                "sobel",
+                "SCHARR;",
+                "laplacian",
+                "canny 50 100",
+                "canny 20.5 80.25; sobel",
+            },
+            expected_ops = {
+                vec![Instr::Operation(ImgOp::Sobel)],
+                vec![Instr::Operation(ImgOp::Scharr)],
+                vec![Instr::Operation(ImgOp::Laplacian)],
+                vec![Instr::Operation(ImgOp::Canny((50.0, 100.0)))],
+                vec![Instr::Operation(ImgOp::Canny((20.5, 80.25))), Instr::Operation(ImgOp::Sobel)],
+            }
+        )]
+        fn test_edges_ok(input: &str, expected_ops: Vec<Instr>) {
+            let pairs = SICParser::parse(Rule::main, input)
+                .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
+
+            assert_eq!(parse_image_operations(pairs).unwrap(), expected_ops);
+        }
+
+        #[parameterized(
+            input = {
+                "canny",
+                "canny 50",
+                "canny a 100",
+                "sobel 1",
+            }
+        )]
+        fn test_edges_err(input: &str) {
+            let pairs = SICParser::parse(Rule::main, input);
+            assert!(pairs.is_err());
+        }
+    }
+
+    mod filter_test {
+        use super::*;
+        use sic_image_engine::wrapper::filter::{Divisor, EdgeMode, Kernel, KernelShape};
+
+        ide!();
+
+        fn filter(
+            shape: KernelShape,
+            values: Vec<f32>,
+            divisor: Divisor,
+            bias: f32,
+            edge_mode: EdgeMode,
+        ) -> Vec<Instr> {
+            let kernel = Kernel::new(shape, values).unwrap();
+            vec![Instr::Operation(ImgOp::Filter(FilterInputs::new(
+                kernel, divisor, bias, edge_mode,
+            )))]
+        }
+
+        const BOX_3X3: KernelShape = KernelShape::Full {
+            width: 3,
+            height: 3,
+        };
+        const MOTION_5X1: KernelShape = KernelShape::Full {
+            width: 5,
+            height: 1,
+        };
+        const SEPARABLE_3X3: KernelShape = KernelShape::Separable {
+            width: 3,
+            height: 3,
+        };
+
+        #[parameterized(
+            input = {
+                "filter 3x3 auto 0 clamp 1 1 1 1 1 1 1 1 1",
+                "filter 3x3 auto 0 clamp 1 1 1 | 1 1 1 | 1 1 1;",
+                "filter 5x1 5 0.1 wrap 1 1 1 1 1",
+                "FILTER 5X1 -5 -0.1 Mirror 1 1 1 1 1",
+                "filter 3x3-separable auto 0 mirror 1 2 1 1 2 1",
+                "filter 5x1 wrap 1 1 1 1 1",
+                "filter 5x1 5 mirror 1 1 1 1 1",
+            },
+            expected_ops = {
+                filter(BOX_3X3, vec![1.0; 9], Divisor::Auto, 0.0, EdgeMode::Clamp),
+                filter(BOX_3X3, vec![1.0; 9], Divisor::Auto, 0.0, EdgeMode::Clamp),
+                filter(MOTION_5X1, vec![1.0; 5], Divisor::Value(5.0), 0.1, EdgeMode::Wrap),
+                filter(MOTION_5X1, vec![1.0; 5], Divisor::Value(-5.0), -0.1, EdgeMode::Mirror),
+                filter(SEPARABLE_3X3, vec![1.0, 2.0, 1.0, 1.0, 2.0, 1.0], Divisor::Auto, 0.0, EdgeMode::Mirror),
+                filter(MOTION_5X1, vec![1.0; 5], Divisor::Auto, 0.0, EdgeMode::Wrap),
+                filter(MOTION_5X1, vec![1.0; 5], Divisor::Value(5.0), 0.0, EdgeMode::Mirror),
+            }
+        )]
+        fn test_filter_ok(input: &str, expected_ops: Vec<Instr>) {
+            let pairs = SICParser::parse(Rule::main, input)
+                .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
+
+            assert_eq!(parse_image_operations(pairs).unwrap(), expected_ops);
+        }
+
+        #[parameterized(
+            input = {
+                "filter",
+                "filter 3x3",
+                "filter 3x3 auto 0 clamp",
+                "filter 3x1 auto 0 1 clamp 1 1 1",
+                "filter 3 auto 0 clamp 1 1 1",
+                "filter 3x1 none 0 clamp 1 1 1",
+                "filter 3x1 auto 0 repeat 1 1 1",
+                "filter 3x1 auto 0 clamp 1 a 1",
+            }
+        )]
+        fn test_filter_err(input: &str) {
+            let pairs = SICParser::parse(Rule::main, input);
+            assert!(pairs.is_err());
+        }
+
+        #[parameterized(
+            input = {
+                "filter 2x2 auto 0 clamp 1 1 1 1",
+                "filter 3x1 0 0 clamp 1 1 1",
+                "filter 3x3 auto 0 clamp 1 1 1",
+                "filter 3x1 auto 0 clamp 1 1 1 1",
+            }
+        )]
+        fn test_filter_value_err(input: &str) {
+            let pairs = SICParser::parse(Rule::main, input).unwrap();
+            assert!(parse_image_operations(pairs).is_err());
+        }
+    }
+
+    mod histogram_equalization_test {
+        use super::*;
+
+        ide!();
+
+        #[parameterized(
+            input = {
+                "equalize luma",
+                "equalize CHANNELS",
+                "clahe 8 8 2.5",
+                "clahe 4 2 3; equalize luma",
+            },
+            expected_ops = {
+                vec![Instr::Operation(ImgOp::Equalize(EqualizeMode::Luma))],
+                vec![Instr::Operation(ImgOp::Equalize(EqualizeMode::Channels))],
+                vec![Instr::Operation(ImgOp::Clahe(ClaheInputs::new(8, 8, 2.5)))],
+                vec![Instr::Operation(ImgOp::Clahe(ClaheInputs::new(4, 2, 3.0))), Instr::Operation(ImgOp::Equalize(EqualizeMode::Luma))],
+            }
+        )]
+        fn test_histogram_equalization_ok(input: &str, expected_ops: Vec<Instr>) {
+            let pairs = SICParser::parse(Rule::main, input)
+                .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
+
+            assert_eq!(parse_image_operations(pairs).unwrap(), expected_ops);
+        }
+
+        #[parameterized(
+            input = {
+                "equalize",
+                "equalize rgb",
+                "clahe 8 8",
+                "clahe 8.5 8 2",
+                "clahe -8 8 2",
+            }
+        )]
+        fn test_histogram_equalization_err(input: &str) {
+            let pairs = SICParser::parse(Rule::main, input);
+            assert!(pairs.is_err());
+        }
+    }
+
+    mod denoise_test {
+        use super::*;
+
+        ide!();
+
+        #[parameterized(
+            input = {
+                "median 2",
+                "bilateral 3 25 3.5",
+                "nl-means 10 1 5",
+                "MEDIAN 1; nl-means 12.5 2 7",
+            },
+            expected_ops = {
+                vec![Instr::Operation(ImgOp::Median(2))],
+                vec![Instr::Operation(ImgOp::Bilateral(BilateralInputs::new(3, 25.0, 3.5)))],
+                vec![Instr::Operation(ImgOp::NlMeans(NlMeansInputs::new(10.0, 1, 5)))],
+                vec![Instr::Operation(ImgOp::Median(1)), Instr::Operation(ImgOp::NlMeans(NlMeansInputs::new(12.5, 2, 7)))],
+            }
+        )]
+        fn test_denoise_ok(input: &str, expected_ops: Vec<Instr>) {
+            let pairs = SICParser::parse(Rule::main, input)
+                .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
+
+            assert_eq!(parse_image_operations(pairs).unwrap(), expected_ops);
+        }
+
+        #[parameterized(
+            input = {
+                "median",
+                "median 1.5",
+                "bilateral 3 25",
+                "bilateral 1.5 25 3",
+                "nl-means 10 1",
+                "nl-means 10 1.5 5",
+            }
+        )]
+        fn test_denoise_err(input: &str) {
+            let pairs = SICParser::parse(Rule::main, input);
+            assert!(pairs.is_err());
+        }
+
+        #[parameterized(
+            input = {
+                "bilateral 256 25 3",
+            }
+        )]
+        fn test_denoise_value_err(input: &str) {
+            let pairs = SICParser::parse(Rule::main, input).unwrap();
+            assert!(parse_image_operations(pairs).is_err());
+        }
+    }
+
+    mod morphology_test {
+        use super::*;
+        use sic_image_engine::wrapper::morphology::MorphologyNorm;
+
+        ide!();
+
+        #[parameterized(
+            input = {
+                "erode 1 l1",
+                "dilate 2 LINF",
+                "open 3 l2",
+                "close 1 linf; threshold",
+                "morph-gradient 0 l1",
+            },
+            expected_ops = {
+                vec![Instr::Operation(ImgOp::Erode(MorphologyInputs::new(1, MorphologyNorm::L1)))],
+                vec![Instr::Operation(ImgOp::Dilate(MorphologyInputs::new(2, MorphologyNorm::LInf)))],
+                vec![Instr::Operation(ImgOp::Open(MorphologyInputs::new(3, MorphologyNorm::L2)))],
+                vec![Instr::Operation(ImgOp::Close(MorphologyInputs::new(1, MorphologyNorm::LInf))), Instr::Operation(ImgOp::Threshold)],
+                vec![Instr::Operation(ImgOp::MorphGradient(MorphologyInputs::new(0, MorphologyNorm::L1)))],
+            }
+        )]
+        fn test_morphology_ok(input: &str, expected_ops: Vec<Instr>) {
+            let pairs = SICParser::parse(Rule::main, input)
+                .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
+
+            assert_eq!(parse_image_operations(pairs).unwrap(), expected_ops);
+        }
+
+        #[parameterized(
+            input = {
+                "erode",
+                "erode 1",
+                "dilate l1 1",
+                "open -1 l1",
+                "close 1 l3",
+                "morph-gradient 1.5 l1",
+            }
+        )]
+        fn test_morphology_err(input: &str) {
+            let pairs = SICParser::parse(Rule::main, input);
+            assert!(pairs.is_err());
+        }
+
+        #[parameterized(
+            input = {
+                "erode 256 l1",
+                "dilate 1000 linf",
+            }
+        )]
+        fn test_morphology_value_err(input: &str) {
+            let pairs = SICParser::parse(Rule::main, input).unwrap();
+            assert!(parse_image_operations(pairs).is_err());
+        }
+    }
+
+    mod color_adjustments_test {
+        use super::*;
+        use sic_image_engine::wrapper::curves::{CurvePoints, CurvesChannel};
+
+        ide!();
+
+        #[parameterized(
+            input = {
+                "saturate 1.5;",
+                "saturate 0",
+                "gamma 2.2",
+                "exposure -1.5",
+                "EXPOSURE 2",
+                "levels 10 240 1.2 0 255",
+                "curves rgb 0:0,255:255",
+                "curves Luminance 0:10,64:50,255:250; gamma 0.5",
+            },
+            expected_ops = {
+                vec![Instr::Operation(ImgOp::Saturate(1.5))],
+                vec![Instr::Operation(ImgOp::Saturate(0.0))],
+                vec![Instr::Operation(ImgOp::Gamma(2.2))],
+                vec![Instr::Operation(ImgOp::Exposure(-1.5))],
+                vec![Instr::Operation(ImgOp::Exposure(2.0))],
+                vec![Instr::Operation(ImgOp::Levels(LevelsInputs::new((10, 240), 1.2, (0, 255))))],
+                vec![Instr::Operation(ImgOp::Curves(CurvesInputs::new(CurvesChannel::Rgb, CurvePoints::new(vec![(0, 0), (255, 255)]).unwrap())))],
+                vec![Instr::Operation(ImgOp::Curves(CurvesInputs::new(CurvesChannel::Luminance, CurvePoints::new(vec![(0, 10), (64, 50), (255, 250)]).unwrap()))), Instr::Operation(ImgOp::Gamma(0.5))],
+            }
+        )]
+        fn test_color_adjustments_ok(input: &str, expected_ops: Vec<Instr>) {
+            let pairs = SICParser::parse(Rule::main, input)
+                .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
+
+            assert_eq!(parse_image_operations(pairs).unwrap(), expected_ops);
+        }
+
+        #[parameterized(
+            input = {
+                "saturate",
+                "gamma a",
+                "exposure",
+                "levels 10 240 1.2 0",
+                "levels 10 240 0 255",
+                "curves rgb",
+                "curves 0:0,255:255",
+                "curves alpha 0:0,255:255",
+                "curves rgb 0:0, 255:255",
+                "curves rgb 0-0,255-255",
+            }
+        )]
+        fn test_color_adjustments_err(input: &str) {
+            let pairs = SICParser::parse(Rule::main, input);
+            assert!(pairs.is_err());
+        }
+
+        #[parameterized(
+            input = {
+                "levels 10 256 1.0 0 255",
+                "curves rgb 0:0",
+                "curves rgb 0:0,0:255",
+            }
+        )]
+        fn test_color_adjustments_value_err(input: &str) {
+            let pairs = SICParser::parse(Rule::main, input).unwrap();
+            assert!(parse_image_operations(pairs).is_err());
+        }
+    }
+
+    mod alpha_test {
+        use super::*;
+
+        ide!();
+
+        #[parameterized(
+            input = {
+                "extract-alpha",
+                "set-alpha 'mask.png'",
+                "set-alpha \"C:/Users/Some Name/mask.png\"",
+                "scale-alpha 0.5",
+                "premultiply-alpha; unpremultiply-alpha",
+                "flatten rgba(255, 255, 255, 255)",
+                "FLATTEN rgba(0, 0, 0, 128); extract-alpha",
+            },
+            expected_ops = {
+                vec![Instr::Operation(ImgOp::ExtractAlpha)],
+                vec![Instr::Operation(ImgOp::SetAlpha(ImageFromPath::new("mask.png".into())))],
+                vec![Instr::Operation(ImgOp::SetAlpha(ImageFromPath::new("C:/Users/Some Name/mask.png".into())))],
+                vec![Instr::Operation(ImgOp::ScaleAlpha(0.5))],
+                vec![Instr::Operation(ImgOp::PremultiplyAlpha), Instr::Operation(ImgOp::UnpremultiplyAlpha)],
+                vec![Instr::Operation(ImgOp::Flatten(Rgba([255, 255, 255, 255])))],
+                vec![Instr::Operation(ImgOp::Flatten(Rgba([0, 0, 0, 128]))), Instr::Operation(ImgOp::ExtractAlpha)],
+            }
+        )]
+        fn test_alpha_ok(input: &str, expected_ops: Vec<Instr>) {
+            let pairs = SICParser::parse(Rule::main, input)
+                .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
+
+            assert_eq!(parse_image_operations(pairs).unwrap(), expected_ops);
+        }
+
+        #[parameterized(
+            input = {
+                "extract-alpha 1",
+                "set-alpha mask.png",
+                "scale-alpha",
+                "scale-alpha a",
+                "flatten",
+                "flatten 255 255 255 255",
+            }
+        )]
+        fn test_alpha_err(input: &str) {
+            let pairs = SICParser::parse(Rule::main, input);
+            assert!(pairs.is_err());
+        }
+
+        #[test]
+        fn test_flatten_not_rgba_err() {
+            let pairs = SICParser::parse(Rule::main, "flatten size(1.0)").unwrap();
+            assert!(parse_image_operations(pairs).is_err());
+        }
+    }
+
+    mod frame_editing_test {
+        use super::*;
+
+        ide!();
+
+        #[parameterized(
+            input = {
+                "frames 3..10",
+                "frames ..=4; reverse-frames",
+                "FRAMES 2..; boomerang",
+                "drop-frames 3",
+                "duplicate-frame 0 5",
+            },
+            expected_ops = {
+                vec![Instr::Operation(ImgOp::Frames(FrameRange::new(Some(3), Some(10), false)))],
+                vec![Instr::Operation(ImgOp::Frames(FrameRange::new(None, Some(4), true))), Instr::Operation(ImgOp::ReverseFrames)],
+                vec![Instr::Operation(ImgOp::Frames(FrameRange::new(Some(2), None, false))), Instr::Operation(ImgOp::Boomerang)],
+                vec![Instr::Operation(ImgOp::DropFrames(3))],
+                vec![Instr::Operation(ImgOp::DuplicateFrame((0, 5)))],
+            }
+        )]
+        fn test_frame_editing_ok(input: &str, expected_ops: Vec<Instr>) {
+            let pairs = SICParser::parse(Rule::main, input)
+                .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
+
+            assert_eq!(parse_image_operations(pairs).unwrap(), expected_ops);
+        }
+
+        #[parameterized(
+            input = {
+                "frames",
+                "frames 3",
+                "frames 3..=",
+                "frames -1..4",
+                "drop-frames",
+                "duplicate-frame 1",
+                "reverse-frames 1",
+            }
+        )]
+        fn test_frame_editing_err(input: &str) {
+            let pairs = SICParser::parse(Rule::main, input);
+            assert!(pairs.is_err());
+        }
+    }
+
+    mod sprite_sheet_test {
+        use super::*;
+        use sic_core::image::Rgba;
+
+        ide!();
+
+        #[parameterized(
+            input = {
+                "sprite-sheet 4 2 rgba(0, 0, 0, 0)",
+                "sprite-sheet atlas \"sheet.json\" 8 0 rgba(255, 0, 255, 255);",
+                "SPRITE-SHEET ATLAS 'atlas dir/sheet.json' 1 1 rgba(0, 0, 0, 255)",
+                "split-sprite-sheet 32 16 1; set-frame-delay 50",
+            },
+            expected_ops = {
+                vec![Instr::Operation(ImgOp::SpriteSheet(SpriteSheetInputs::new(4, 2, Rgba([0, 0, 0, 0]))))],
+                vec![Instr::Operation(ImgOp::SpriteSheet(SpriteSheetInputs::new(8, 0, Rgba([255, 0, 255, 255])).with_atlas("sheet.json".into())))],
+                vec![Instr::Operation(ImgOp::SpriteSheet(SpriteSheetInputs::new(1, 1, Rgba([0, 0, 0, 255])).with_atlas("atlas dir/sheet.json".into())))],
+                vec![Instr::Operation(ImgOp::SplitSpriteSheet(SplitSpriteSheetInputs::new((32, 16), 1))), Instr::Operation(ImgOp::SetFrameDelay(50))],
+            }
+        )]
+        fn test_sprite_sheet_ok(input: &str, expected_ops: Vec<Instr>) {
+            let pairs = SICParser::parse(Rule::main, input)
+                .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
+
+            assert_eq!(parse_image_operations(pairs).unwrap(), expected_ops);
+        }
+
+        #[parameterized(
+            input = {
+                "sprite-sheet 4 2",
+                "sprite-sheet -4 2 rgba(0, 0, 0, 0)",
+                "sprite-sheet atlas 4 2 rgba(0, 0, 0, 0)",
+                "sprite-sheet 4 2 rgba(0, 0, 0, 0) atlas \"sheet.json\"",
+                "split-sprite-sheet 32 16",
+                "split-sprite-sheet 32 16 1 1",
+            }
+        )]
+        fn test_sprite_sheet_err(input: &str) {
+            let pairs = SICParser::parse(Rule::main, input);
+            assert!(pairs.is_err());
+        }
+    }
+
+    mod gradient_test {
+        use super::*;
+        use sic_image_engine::wrapper::gradient::{
+            ColorStop, GradientColorSpace, GradientInputs, GradientShape,
+        };
+
+        ide!();
+
+        const RED: Rgba<u8> = Rgba([255, 0, 0, 255]);
+        const BLUE: Rgba<u8> = Rgba([0, 0, 255, 255]);
+
+        #[parameterized(
+            input = {
+                "gradient linear 90 rgba(255, 0, 0, 255) rgba(0, 0, 255, 255)",
+                "gradient RADIAL rgba(255, 0, 0, 255) 10% rgba(0, 0, 255, 255) 90%",
+                "gradient conic -45.5 rgba(255, 0, 0, 255) rgba(0, 0, 255, 255) 50% rgba(255, 0, 0, 255)",
+                "set gradient-color-space oklab; gradient radial rgba(255, 0, 0, 255) rgba(0, 0, 255, 255); del gradient-color-space",
+            },
+            expected_ops = {
+                vec![Instr::Operation(ImgOp::Gradient(GradientInputs::new(GradientShape::Linear { angle: 90.0 }, vec![ColorStop::new(RED, None), ColorStop::new(BLUE, None)])))],
+                vec![Instr::Operation(ImgOp::Gradient(GradientInputs::new(GradientShape::Radial, vec![ColorStop::new(RED, Some(0.1)), ColorStop::new(BLUE, Some(0.9))])))],
+                vec![Instr::Operation(ImgOp::Gradient(GradientInputs::new(GradientShape::Conic { angle: -45.5 }, vec![ColorStop::new(RED, None), ColorStop::new(BLUE, Some(0.5)), ColorStop::new(RED, None)])))],
+                vec![
+                    Instr::EnvAdd(EnvItem::GradientColorSpace(GradientColorSpace::Oklab)),
+                    Instr::Operation(ImgOp::Gradient(GradientInputs::new(GradientShape::Radial, vec![ColorStop::new(RED, None), ColorStop::new(BLUE, None)]))),
+                    Instr::EnvRemove(ItemName::GradientColorSpace),
+                ],
+            }
+        )]
+        fn test_gradient_ok(input: &str, expected_ops: Vec<Instr>) {
+            let pairs = SICParser::parse(Rule::main, input)
+                .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
+
+            assert_eq!(parse_image_operations(pairs).unwrap(), expected_ops);
+        }
+
+        #[parameterized(
+            input = {
+                "gradient linear rgba(255, 0, 0, 255) rgba(0, 0, 255, 255)",
+                "gradient radial 90 rgba(255, 0, 0, 255) rgba(0, 0, 255, 255)",
+                "gradient radial rgba(255, 0, 0, 255)",
+                "gradient radial 10% rgba(255, 0, 0, 255) rgba(0, 0, 255, 255)",
+                "gradient elliptic rgba(255, 0, 0, 255) rgba(0, 0, 255, 255)",
+                "set gradient-color-space hsl",
+            }
+        )]
+        fn test_gradient_err(input: &str) {
+            let pairs = SICParser::parse(Rule::main, input);
+            assert!(pairs.is_err());
+        }
+    }
+
+    mod frame_delay_test {
+        use super::*;
+
+        ide!();
+
+        #[parameterized(
+            input = {
+                "set-frame-delay 40",
+                "scale-frame-delay 0.5",
+                "set-frame-delay-at 2 1000",
+                "SET-FRAME-DELAY 20; set-frame-delay-at 0 500",
+            },
+            expected_ops = {
+                vec![Instr::Operation(ImgOp::SetFrameDelay(40))],
+                vec![Instr::Operation(ImgOp::ScaleFrameDelay(0.5))],
+                vec![Instr::Operation(ImgOp::SetFrameDelayAt((2, 1000)))],
+                vec![Instr::Operation(ImgOp::SetFrameDelay(20)), Instr::Operation(ImgOp::SetFrameDelayAt((0, 500)))],
+            }
+        )]
+        fn test_frame_delay_ok(input: &str, expected_ops: Vec<Instr>) {
+            let pairs = SICParser::parse(Rule::main, input)
+                .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
+
+            assert_eq!(parse_image_operations(pairs).unwrap(), expected_ops);
+        }
+
+        #[parameterized(
+            input = {
+                "set-frame-delay",
+                "set-frame-delay -40",
+                "set-frame-delay 0.5",
+                "scale-frame-delay",
+                "set-frame-delay-at 2",
+                "set-frame-delay-at -1 40",
+            }
+        )]
+        fn test_frame_delay_err(input: &str) {
+            let pairs = SICParser::parse(Rule::main, input);
+            assert!(pairs.is_err());
+        }
+    }
+
+    mod channels_test {
+        use super::*;
+        use sic_image_engine::wrapper::channel::{
+            Channel, ChannelOrLuma, ChannelScope, MergeChannelsInputs,
+        };
+
+        ide!();
+
+        #[parameterized(
+            input = {
+                "extract-channel red",
+                "extract-channel A; extract-channel luma",
+                "swap-channels r b",
+                "swap-channels green alpha",
+                "merge-channels 'ao.png' \"roughness.png\" 'metalness.png' keep",
+                "set channel alpha; blur 2; set channel all",
+                "set channel g; invert; del channel",
+            },
+            expected_ops = {
+                vec![Instr::Operation(ImgOp::ExtractChannel(ChannelOrLuma::Channel(Channel::Red)))],
+                vec![Instr::Operation(ImgOp::ExtractChannel(ChannelOrLuma::Channel(Channel::Alpha))), Instr::Operation(ImgOp::ExtractChannel(ChannelOrLuma::Luma))],
+                vec![Instr::Operation(ImgOp::SwapChannels((Channel::Red, Channel::Blue)))],
+                vec![Instr::Operation(ImgOp::SwapChannels((Channel::Green, Channel::Alpha)))],
+                vec![Instr::Operation(ImgOp::MergeChannels(MergeChannelsInputs::new([Some(ImageFromPath::new("ao.png".into())), Some(ImageFromPath::new("roughness.png".into())), Some(ImageFromPath::new("metalness.png".into())), None])))],
+                vec![Instr::EnvAdd(EnvItem::ChannelScope(ChannelScope::Only(Channel::Alpha))), Instr::Operation(ImgOp::Blur(2.0)), Instr::EnvAdd(EnvItem::ChannelScope(ChannelScope::All))],
+                vec![Instr::EnvAdd(EnvItem::ChannelScope(ChannelScope::Only(Channel::Green))), Instr::Operation(ImgOp::Invert), Instr::EnvRemove(ItemName::ChannelScope)],
+            }
+        )]
+        fn test_channels_ok(input: &str, expected_ops: Vec<Instr>) {
+            let pairs = SICParser::parse(Rule::main, input)
+                .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
+
+            assert_eq!(parse_image_operations(pairs).unwrap(), expected_ops);
+        }
+
+        #[parameterized(
+            input = {
+                "extract-channel",
+                "extract-channel rgb",
+                "swap-channels r",
+                "swap-channels r luma",
+                "merge-channels 'r.png' 'g.png' 'b.png'",
+                "merge-channels r.png g.png b.png a.png",
+                "set channel luma",
+            }
+        )]
+        fn test_channels_err(input: &str) {
+            let pairs = SICParser::parse(Rule::main, input);
+            assert!(pairs.is_err());
+        }
+    }
+
+    mod chroma_key_test {
+        use super::*;
+        use sic_image_engine::wrapper::chroma_key::ChromaKeyInputs;
+
+        ide!();
+
+        #[parameterized(
+            input = {
+                "chroma-key rgba(0, 255, 0, 255) 40 20",
+                "chroma-key despill rgba(0, 255, 0, 255) 40.5 0",
+                "CHROMA-KEY DESPILL rgba(255, 255, 255, 255) 0 10; flatten rgba(0, 0, 0, 255)",
+                "color-to-alpha rgba(255, 255, 255, 255)",
+            },
+            expected_ops = {
+                vec![Instr::Operation(ImgOp::ChromaKey(ChromaKeyInputs::new(Rgba([0, 255, 0, 255]), 40.0, 20.0)))],
+                vec![Instr::Operation(ImgOp::ChromaKey(ChromaKeyInputs::new(Rgba([0, 255, 0, 255]), 40.5, 0.0).with_despill(true)))],
+                vec![Instr::Operation(ImgOp::ChromaKey(ChromaKeyInputs::new(Rgba([255, 255, 255, 255]), 0.0, 10.0).with_despill(true))), Instr::Operation(ImgOp::Flatten(Rgba([0, 0, 0, 255])))],
+                vec![Instr::Operation(ImgOp::ColorToAlpha(Rgba([255, 255, 255, 255])))],
+            }
+        )]
+        fn test_chroma_key_ok(input: &str, expected_ops: Vec<Instr>) {
+            let pairs = SICParser::parse(Rule::main, input)
+                .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
+
+            assert_eq!(parse_image_operations(pairs).unwrap(), expected_ops);
+        }
+
+        #[parameterized(
+            input = {
+                "chroma-key rgba(0, 255, 0, 255) 40",
+                "chroma-key rgba(0, 255, 0, 255) 40 20 despill",
+                "chroma-key 0 255 0 40 20",
+                "color-to-alpha",
+                "color-to-alpha rgba(0, 0, 0, 255) 1",
+            }
+        )]
+        fn test_chroma_key_err(input: &str) {
+            let pairs = SICParser::parse(Rule::main, input);
+            assert!(pairs.is_err());
+        }
+    }
+
+    mod pad_test {
+        use super::*;
+        use sic_core::image::Rgba;
+
+        ide!();
+
+        #[parameterized(
+            input = {
+                "pad 1 2 3 4 rgba(0, 0, 0, 255);",
+                "PAD 0 0 0 0 rgba(255, 255, 255, 0)",
+                "pad 10 10 10 10 rgba(1, 2, 3, 4); pad 1 1 1 1 rgba(1, 2, 3, 4)",
+            },
+            expected_ops = {
+                vec![Instr::Operation(ImgOp::Pad(PadInputs::new((1, 2, 3, 4), Rgba([0, 0, 0, 255]))))],
+                vec![Instr::Operation(ImgOp::Pad(PadInputs::new((0, 0, 0, 0), Rgba([255, 255, 255, 0]))))],
+                vec![Instr::Operation(ImgOp::Pad(PadInputs::new((10, 10, 10, 10), Rgba([1, 2, 3, 4])))), Instr::Operation(ImgOp::Pad(PadInputs::new((1, 1, 1, 1), Rgba([1, 2, 3, 4]))))],
+            }
+        )]
+        fn test_pad_ok(input: &str, expected_ops: Vec<Instr>) {
+            let pairs = SICParser::parse(Rule::main, input)
+                .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
+
+            assert_eq!(parse_image_operations(pairs).unwrap(), expected_ops);
+        }
+
+        #[parameterized(
+            input = {
+                "pad 1 2 3 4",
+                "pad 1 2 3 rgba(0, 0, 0, 255)",
+                "pad -1 2 3 4 rgba(0, 0, 0, 255)",
+                "pad 1 2 3 4 5 rgba(0, 0, 0, 255)",
+            }
+        )]
+        fn test_pad_err(input: &str) {
+            let pairs = SICParser::parse(Rule::main, input);
+            assert!(pairs.is_err());
+        }
+
+        #[test]
+        fn test_pad_not_rgba_err() {
+            let pairs = SICParser::parse(Rule::main, "pad 1 2 3 4 size(1.0)").unwrap();
+            assert!(parse_image_operations(pairs).is_err());
+        }
+    }
+
+    mod quantize_test {
+        use super::*;
+        use sic_core::image::Rgba;
+        use sic_image_engine::wrapper::palette::{PaletteSource, QuantizeMode};
+
+        ide!();
+
+        #[parameterized(
+            input = {
+                "quantize nearest \"palette.gpl\";",
+                "QUANTIZE Dither 'C:\\Users\\Some Name\\palette.hex'",
+                "quantize dither rgba(0, 0, 0, 255);",
+                "quantize nearest rgba(0, 0, 0, 255) rgba(255, 255, 255, 0); invert",
+            },
+            expected_ops = {
+                vec![Instr::Operation(ImgOp::Quantize(QuantizeInputs::new(QuantizeMode::Nearest, PaletteSource::Path("palette.gpl".into()))))],
+                vec![Instr::Operation(ImgOp::Quantize(QuantizeInputs::new(QuantizeMode::Dither, PaletteSource::Path("C:\\Users\\Some Name\\palette.hex".into()))))],
+                vec![Instr::Operation(ImgOp::Quantize(QuantizeInputs::new(QuantizeMode::Dither, PaletteSource::Colors(vec![Rgba([0, 0, 0, 255])]))))],
+                vec![Instr::Operation(ImgOp::Quantize(QuantizeInputs::new(QuantizeMode::Nearest, PaletteSource::Colors(vec![Rgba([0, 0, 0, 255]), Rgba([255, 255, 255, 0])])))), Instr::Operation(ImgOp::Invert)],
+            }
+        )]
+        fn test_quantize_ok(input: &str, expected_ops: Vec<Instr>) {
+            let pairs = SICParser::parse(Rule::main, input)
+                .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
+
+            assert_eq!(parse_image_operations(pairs).unwrap(), expected_ops);
+        }
+
+        #[parameterized(
+            input = {
+                "quantize \"palette.gpl\"",
+                "quantize nearest",
+                "quantize closest \"palette.gpl\"",
+                "quantize nearest palette.gpl",
+                "quantize nearest \"palette.gpl\" rgba(0, 0, 0, 255)",
+            }
+        )]
+        fn test_quantize_err(input: &str) {
+            let pairs = SICParser::parse(Rule::main, input);
+            assert!(pairs.is_err());
+        }
+
+        #[test]
+        fn test_quantize_not_rgba_err() {
+            let pairs = SICParser::parse(Rule::main, "quantize nearest size(1.0)").unwrap();
+            assert!(parse_image_operations(pairs).is_err());
+        }
     }
 
-    #[test]
-    fn test_crop_zeros_parse_correct() {
-        let pairs = SICParser::parse(Rule::main, "crop 0 0 0 0;")
-            .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
-        assert_eq!(
-            vec![Instr::Operation(ImgOp::Crop((0, 0, 0, 0)))],
-            parse_image_operations(pairs).unwrap()
-        );
-    }
+    mod extend_canvas_test {
+        use super::*;
+        use sic_core::image::Rgba;
 
-    #[test]
-    #[should_panic]
-    fn test_crop_args_negative_parse_err() {
-        SICParser::parse(Rule::main, "crop -1 -1 -1 -1;")
-            .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
-    }
+        ide!();
 
-    #[test]
-    #[should_panic]
-    fn test_crop_arg_negative_p1_parse_err() {
-        SICParser::parse(Rule::main, "crop -1 0 0 0;")
-            .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
-    }
+        #[parameterized(
+            input = {
+                "extend-canvas 100 50 center rgba(0, 0, 0, 255);",
+                "extend-canvas 1 1 South-West rgba(1, 2, 3, 4)",
+            },
+            expected_ops = {
+                vec![Instr::Operation(ImgOp::ExtendCanvas(ExtendCanvasInputs::new(100, 50, Gravity::Center, Rgba([0, 0, 0, 255]))))],
+                vec![Instr::Operation(ImgOp::ExtendCanvas(ExtendCanvasInputs::new(1, 1, Gravity::SouthWest, Rgba([1, 2, 3, 4]))))],
+            }
+        )]
+        fn test_extend_canvas_ok(input: &str, expected_ops: Vec<Instr>) {
+            let pairs = SICParser::parse(Rule::main, input)
+                .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
 
-    #[test]
-    #[should_panic]
-    fn test_crop_arg_negative_p2_parse_err() {
-        SICParser::parse(Rule::main, "crop 0 -1 0 0;")
-            .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
-    }
+            assert_eq!(parse_image_operations(pairs).unwrap(), expected_ops);
+        }
 
-    #[test]
-    #[should_panic]
-    fn test_crop_arg_negative_p3_parse_err() {
-        SICParser::parse(Rule::main, "crop 0 0 -1 0;")
-            .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
+        #[parameterized(
+            input = {
+                "extend-canvas 100 50 center",
+                "extend-canvas 100 center rgba(0, 0, 0, 255)",
+                "extend-canvas 100 50 middle rgba(0, 0, 0, 255)",
+                "extend-canvas 100 50 rgba(0, 0, 0, 255)",
+            }
+        )]
+        fn test_extend_canvas_err(input: &str) {
+            let pairs = SICParser::parse(Rule::main, input);
+            assert!(pairs.is_err());
+        }
     }
 
-    #[test]
-    #[should_panic]
-    fn test_crop_arg_negative_p4_parse_err() {
-        SICParser::parse(Rule::main, "crop 0 0 0 -1;")
-            .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
-    }
+    mod trim_test {
+        use super::*;
 
-    #[test]
-    fn test_crop_arg_to_big_p4_parse_err() {
-        // 4294967296 == std::u32::MAX + 1
-        let pairs = SICParser::parse(Rule::main, "crop 0 0 0 4294967296")
-            .unwrap_or_else(|_| panic!("Unable to parse image operations script."));
+        ide!();
 
-        assert!(parse_image_operations(pairs).is_err())
-    }
+        #[parameterized(
+            input = {
+                "trim 0;",
+                "trim 255",
+                "TRIM 10; trim 1",
+            },
+            expected_ops = {
+                vec![Instr::Operation(ImgOp::Trim(0))],
+                vec![Instr::Operation(ImgOp::Trim(255))],
+                vec![Instr::Operation(ImgOp::Trim(10)), Instr::Operation(ImgOp::Trim(1))],
+            }
+        )]
+        fn test_trim_ok(input: &str, expected_ops: Vec<Instr>) {
+            let pairs = SICParser::parse(Rule::main, input)
+                .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
 
-    #[test]
-    fn test_crop_arg_just_in_range_p4_parse_ok() {
-        // 4294967296 == std::u32::MAX
-        let pairs = SICParser::parse(Rule::main, "crop 0 0 0 4294967295")
-            .unwrap_or_else(|_| panic!("Unable to parse image operations script."));
+            assert_eq!(parse_image_operations(pairs).unwrap(), expected_ops);
+        }
 
-        assert_eq!(
-            vec![Instr::Operation(ImgOp::Crop((0, 0, 0, u32::MAX,)))],
-            parse_image_operations(pairs).unwrap()
-        );
+        #[parameterized(
+            input = {
+                "trim",
+                "trim -1",
+                "trim 1.5",
+            }
+        )]
+        fn test_trim_err(input: &str) {
+            let pairs = SICParser::parse(Rule::main, input);
+            assert!(pairs.is_err());
+        }
+
+        #[test]
+        fn test_trim_tolerance_out_of_range_err() {
+            let pairs = SICParser::parse(Rule::main, "trim 256").unwrap();
+            assert!(parse_image_operations(pairs).is_err());
+        }
     }
 
     #[test]
@@ -1084,6 +2330,96 @@ mod tests {
             let pairs = SICParser::parse(Rule::main, input);
             assert!(pairs.is_err());
         }
+        fn overlay_blended(path: &str, blend_mode: BlendMode, opacity: f32) -> Vec<Instr> {
+            vec![Instr::Operation(ImgOp::Overlay(
+                OverlayInputs::new(ImageFromPath::new(path.into()), (10, 5))
+                    .with_blending(blend_mode, opacity),
+            ))]
+        }
+
+        #[parameterized(
+            input = {
+                "overlay multiply 0.5 'texture.png' 10 5;",
+                "overlay SOFT-LIGHT 1 'texture.png' 10 5;",
+                "overlay src 1 'texture.png' 10 5;",
+                "overlay src-atop 0.25 'texture.png' 10 5;",
+                "overlay dst-over 0 'texture.png' 10 5;",
+                "overlay overlay 1 'multiply' 10 5;",
+            },
+            expected_ops = {
+                overlay_blended("texture.png", BlendMode::Multiply, 0.5),
+                overlay_blended("texture.png", BlendMode::SoftLight, 1.0),
+                overlay_blended("texture.png", BlendMode::Source, 1.0),
+                overlay_blended("texture.png", BlendMode::SourceAtop, 0.25),
+                overlay_blended("texture.png", BlendMode::DestinationOver, 0.0),
+                overlay_blended("multiply", BlendMode::Overlay, 1.0),
+            }
+        )]
+        fn test_overlay_blended_ok(input: &str, expected_ops: Vec<Instr>) {
+            let pairs = SICParser::parse(Rule::main, input)
+                .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
+
+            assert_eq!(parse_image_operations(pairs).unwrap(), expected_ops);
+        }
+
+        #[parameterized(
+            input = {
+                "overlay multiply 'texture.png' 10 5;",
+                "overlay burn 0.5 'texture.png' 10 5;",
+                "overlay 'texture.png' 10 5 multiply 0.5;",
+            }
+        )]
+        fn test_overlay_blended_err(input: &str) {
+            let pairs = SICParser::parse(Rule::main, input);
+            assert!(pairs.is_err());
+        }
+
+        fn overlay_placed(placement: OverlayPlacement) -> OverlayInputs {
+            OverlayInputs::new(ImageFromPath::new("logo.png".into()), placement)
+        }
+
+        #[parameterized(
+            input = {
+                "overlay 'logo.png' south-east 10 5;",
+                "overlay 'logo.png' North 0 0;",
+                "overlay 'logo.png' tile 20 30 -45;",
+                "overlay 'logo.png' tile 0 0 12.5;",
+                "overlay width 20% 'logo.png' south-east 10 5;",
+                "overlay width 64 'logo.png' 1 2;",
+                "overlay multiply 0.5 width 12.5% 'logo.png' tile 20 30 -45;",
+            },
+            expected_ops = {
+                vec![Instr::Operation(ImgOp::Overlay(overlay_placed(OverlayPlacement::Anchored { gravity: Gravity::SouthEast, margin: (10, 5) })))],
+                vec![Instr::Operation(ImgOp::Overlay(overlay_placed(OverlayPlacement::Anchored { gravity: Gravity::North, margin: (0, 0) })))],
+                vec![Instr::Operation(ImgOp::Overlay(overlay_placed(OverlayPlacement::Tiled { spacing: (20, 30), degrees: -45.0 })))],
+                vec![Instr::Operation(ImgOp::Overlay(overlay_placed(OverlayPlacement::Tiled { spacing: (0, 0), degrees: 12.5 })))],
+                vec![Instr::Operation(ImgOp::Overlay(overlay_placed(OverlayPlacement::Anchored { gravity: Gravity::SouthEast, margin: (10, 5) }).with_width(ResizeValue::Percentage(20.0))))],
+                vec![Instr::Operation(ImgOp::Overlay(overlay_placed(OverlayPlacement::Position(1, 2)).with_width(ResizeValue::Pixels(64))))],
+                vec![Instr::Operation(ImgOp::Overlay(overlay_placed(OverlayPlacement::Tiled { spacing: (20, 30), degrees: -45.0 }).with_blending(BlendMode::Multiply, 0.5).with_width(ResizeValue::Percentage(12.5))))],
+            }
+        )]
+        fn test_overlay_placed_ok(input: &str, expected_ops: Vec<Instr>) {
+            let pairs = SICParser::parse(Rule::main, input)
+                .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
+
+            assert_eq!(parse_image_operations(pairs).unwrap(), expected_ops);
+        }
+
+        #[parameterized(
+            input = {
+                "overlay 'logo.png' south-east 10;",
+                "overlay 'logo.png' middle 10 5;",
+                "overlay 'logo.png' tile 20 30;",
+                "overlay 'logo.png' tile -20 30 0;",
+                "overlay width 'logo.png' 0 0;",
+                "overlay 'logo.png' width 20% 0 0;",
+                "overlay width 20% multiply 0.5 'logo.png' 0 0;",
+            }
+        )]
+        fn test_overlay_placed_err(input: &str) {
+            let pairs = SICParser::parse(Rule::main, input);
+            assert!(pairs.is_err());
+        }
     }
 
     #[test]
@@ -1091,11 +2427,108 @@ mod tests {
         let pairs = SICParser::parse(Rule::main, "resize 99 88;")
             .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
         assert_eq!(
-            vec![Instr::Operation(ImgOp::Resize((99, 88)))],
+            vec![Instr::Operation(ImgOp::Resize((99, 88).into()))],
             parse_image_operations(pairs).unwrap()
         );
     }
 
+    mod resize_test {
+        use super::*;
+        use sic_image_engine::wrapper::resize::ResizeValue;
+
+        ide!();
+
+        #[parameterized(
+            input = {
+                "resize 50% 200;",
+                "resize exact 100 200;",
+                "resize FIT 100 200",
+                "resize cover 12.5% 100",
+                "resize width-only 100",
+                "resize height-only 50%; resize 1 1",
+            },
+            expected_ops = {
+                vec![Instr::Operation(ImgOp::Resize(ResizeInputs::Dimensions(ResizeValue::Percentage(50.0), ResizeValue::Pixels(200))))],
+                vec![Instr::Operation(ImgOp::Resize(ResizeInputs::Exact(ResizeValue::Pixels(100), ResizeValue::Pixels(200))))],
+                vec![Instr::Operation(ImgOp::Resize(ResizeInputs::Fit(ResizeValue::Pixels(100), ResizeValue::Pixels(200))))],
+                vec![Instr::Operation(ImgOp::Resize(ResizeInputs::Cover(ResizeValue::Percentage(12.5), ResizeValue::Pixels(100))))],
+                vec![Instr::Operation(ImgOp::Resize(ResizeInputs::WidthOnly(ResizeValue::Pixels(100))))],
+                vec![Instr::Operation(ImgOp::Resize(ResizeInputs::HeightOnly(ResizeValue::Percentage(50.0)))), Instr::Operation(ImgOp::Resize((1, 1).into()))],
+            }
+        )]
+        fn test_resize_ok(input: &str, expected_ops: Vec<Instr>) {
+            let pairs = SICParser::parse(Rule::main, input)
+                .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
+
+            assert_eq!(parse_image_operations(pairs).unwrap(), expected_ops);
+        }
+
+        #[parameterized(
+            input = {
+                "resize 100",
+                "resize 100 200 300",
+                "resize fit 100",
+                "resize width-only 100 200",
+                "resize stretch 100 200",
+                "resize 50 % 100",
+                "resize -100 100",
+            }
+        )]
+        fn test_resize_err(input: &str) {
+            let pairs = SICParser::parse(Rule::main, input);
+            assert!(pairs.is_err());
+        }
+
+        #[test]
+        fn test_resize_zero_percentage_err() {
+            let pairs = SICParser::parse(Rule::main, "resize 0% 100").unwrap();
+            assert!(parse_image_operations(pairs).is_err());
+        }
+    }
+
+    mod rotate_test {
+        use super::*;
+        use sic_core::image::Rgba;
+
+        ide!();
+
+        #[parameterized(
+            input = {
+                "rotate 45 crop rgba(0, 0, 0, 0);",
+                "rotate 12.5 expand rgba(255, 255, 255, 255);",
+                "rotate -30 EXPAND rgba(1, 2, 3, 4)",
+                "rotate 90 crop rgba(0, 0, 0, 255); rotate90",
+            },
+            expected_ops = {
+                vec![Instr::Operation(ImgOp::Rotate(RotateInputs::new(45.0, RotateCanvas::Crop, Rgba([0, 0, 0, 0]))))],
+                vec![Instr::Operation(ImgOp::Rotate(RotateInputs::new(12.5, RotateCanvas::Expand, Rgba([255, 255, 255, 255]))))],
+                vec![Instr::Operation(ImgOp::Rotate(RotateInputs::new(-30.0, RotateCanvas::Expand, Rgba([1, 2, 3, 4]))))],
+                vec![Instr::Operation(ImgOp::Rotate(RotateInputs::new(90.0, RotateCanvas::Crop, Rgba([0, 0, 0, 255])))), Instr::Operation(ImgOp::Rotate90)],
+            }
+        )]
+        fn test_rotate_ok(input: &str, expected_ops: Vec<Instr>) {
+            let pairs = SICParser::parse(Rule::main, input)
+                .unwrap_or_else(|e| panic!("Unable to parse image operations script: {:?}", e));
+
+            assert_eq!(parse_image_operations(pairs).unwrap(), expected_ops);
+        }
+
+        #[parameterized(
+            input = {
+                "rotate 45",
+                "rotate 45 crop",
+                "rotate 45 rgba(0, 0, 0, 0)",
+                "rotate 45 shrink rgba(0, 0, 0, 0)",
+                "rotate crop rgba(0, 0, 0, 0)",
+                "rotate45 crop rgba(0, 0, 0, 0)",
+            }
+        )]
+        fn test_rotate_err(input: &str) {
+            let pairs = SICParser::parse(Rule::main, input);
+            assert!(pairs.is_err());
+        }
+    }
+
     #[test]
     fn test_rotate90_single_stmt_parse_correct() {
         let pairs = SICParser::parse(Rule::main, "rotate90;")
@@ -1174,7 +2607,7 @@ mod tests {
                 Instr::Operation(ImgOp::Blur(10.0)),
                 Instr::Operation(ImgOp::FlipHorizontal),
                 Instr::Operation(ImgOp::FlipVertical),
-                Instr::Operation(ImgOp::Resize((100, 200)))
+                Instr::Operation(ImgOp::Resize((100, 200).into()))
             ],
             parse_image_operations(pairs).unwrap()
         );
@@ -1191,7 +2624,7 @@ mod tests {
             vec![
                 Instr::Operation(ImgOp::FlipHorizontal),
                 Instr::Operation(ImgOp::FlipVertical),
-                Instr::Operation(ImgOp::Resize((100, 200))),
+                Instr::Operation(ImgOp::Resize((100, 200).into())),
                 Instr::Operation(ImgOp::Blur(10.0))
             ],
             parse_image_operations(pairs).unwrap()
@@ -1209,7 +2642,7 @@ mod tests {
             vec![
                 Instr::Operation(ImgOp::FlipHorizontal),
                 Instr::Operation(ImgOp::FlipVertical),
-                Instr::Operation(ImgOp::Resize((100, 200))),
+                Instr::Operation(ImgOp::Resize((100, 200).into())),
                 Instr::Operation(ImgOp::Blur(10.0))
             ],
             parse_image_operations(pairs).unwrap()
@@ -1227,7 +2660,7 @@ mod tests {
             vec![
                 Instr::Operation(ImgOp::FlipHorizontal),
                 Instr::Operation(ImgOp::FlipVertical),
-                Instr::Operation(ImgOp::Resize((100, 200))),
+                Instr::Operation(ImgOp::Resize((100, 200).into())),
                 Instr::Operation(ImgOp::Blur(10.0))
             ],
             parse_image_operations(pairs).unwrap()
@@ -1245,7 +2678,7 @@ mod tests {
             vec![
                 Instr::Operation(ImgOp::FlipHorizontal),
                 Instr::Operation(ImgOp::FlipVertical),
-                Instr::Operation(ImgOp::Resize((100, 200))),
+                Instr::Operation(ImgOp::Resize((100, 200).into())),
                 Instr::Operation(ImgOp::Blur(10.0))
             ],
             parse_image_operations(pairs).unwrap()
@@ -1263,7 +2696,7 @@ mod tests {
             vec![
                 Instr::Operation(ImgOp::FlipHorizontal),
                 Instr::Operation(ImgOp::FlipVertical),
-                Instr::Operation(ImgOp::Resize((100, 200))),
+                Instr::Operation(ImgOp::Resize((100, 200).into())),
                 Instr::Operation(ImgOp::Blur(10.0))
             ],
             parse_image_operations(pairs).unwrap()
@@ -1281,7 +2714,7 @@ mod tests {
             vec![
                 Instr::Operation(ImgOp::FlipHorizontal),
                 Instr::Operation(ImgOp::FlipVertical),
-                Instr::Operation(ImgOp::Resize((100, 200))),
+                Instr::Operation(ImgOp::Resize((100, 200).into())),
                 Instr::Operation(ImgOp::Blur(10.0))
             ],
             parse_image_operations(pairs).unwrap()
@@ -1366,7 +2799,7 @@ mod tests {
                 Instr::EnvAdd(EnvItem::CustomSamplingFilter(FilterTypeWrap::new(
                     FilterType::Gaussian
                 ))),
-                Instr::Operation(ImgOp::Resize((100, 200)))
+                Instr::Operation(ImgOp::Resize((100, 200).into()))
             ],
             parse_image_operations(pairs).unwrap()
         );
@@ -1417,7 +2850,7 @@ mod tests {
         assert_eq!(
             vec![
                 Instr::EnvAdd(EnvItem::PreserveAspectRatio(true)),
-                Instr::Operation(ImgOp::Resize((100, 200)))
+                Instr::Operation(ImgOp::Resize((100, 200).into()))
             ],
             parse_image_operations(pairs).unwrap()
         );
@@ -1434,7 +2867,7 @@ mod tests {
         assert_eq!(
             vec![
                 Instr::EnvAdd(EnvItem::PreserveAspectRatio(false)),
-                Instr::Operation(ImgOp::Resize((100, 200)))
+                Instr::Operation(ImgOp::Resize((100, 200).into()))
             ],
             parse_image_operations(pairs).unwrap()
         );
@@ -1498,6 +2931,74 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_setopt_dither_algorithm_with_dither() {
+        let pairs = SICParser::parse(
+            Rule::main,
+            "set dither-algorithm Jarvis-Judice-Ninke;\ndither",
+        )
+        .unwrap_or_else(|e| panic!("error: {:?}", e));
+
+        assert_eq!(
+            vec![
+                Instr::EnvAdd(EnvItem::DitherAlgorithm(DitherAlgorithm::JarvisJudiceNinke)),
+                Instr::Operation(ImgOp::Dither)
+            ],
+            parse_image_operations(pairs).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_setopt_dither_algorithm_bayer() {
+        let pairs = SICParser::parse(Rule::main, "set dither-algorithm bayer4x4;")
+            .unwrap_or_else(|e| panic!("error: {:?}", e));
+
+        assert_eq!(
+            vec![Instr::EnvAdd(EnvItem::DitherAlgorithm(
+                DitherAlgorithm::Bayer4x4
+            ))],
+            parse_image_operations(pairs).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_setopt_dither_algorithm_unknown() {
+        let pairs = SICParser::parse(Rule::main, "set dither-algorithm bayer3x3;");
+
+        assert!(pairs.is_err());
+    }
+
+    #[test]
+    fn test_parse_setopt_dither_serpentine() {
+        let pairs = SICParser::parse(
+            Rule::main,
+            "set dither-serpentine true;\nset dither-serpentine false;",
+        )
+        .unwrap_or_else(|e| panic!("error: {:?}", e));
+
+        assert_eq!(
+            vec![
+                Instr::EnvAdd(EnvItem::DitherSerpentine(true)),
+                Instr::EnvAdd(EnvItem::DitherSerpentine(false))
+            ],
+            parse_image_operations(pairs).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_delopt_dither() {
+        let pairs = SICParser::parse(Rule::main, "del dither-algorithm;\ndel dither-serpentine;")
+            .unwrap_or_else(|e| panic!("error: {:?}", e));
+
+        assert_eq!(
+            vec![
+                Instr::EnvRemove(ItemName::DitherAlgorithm),
+                Instr::EnvRemove(ItemName::DitherSerpentine)
+            ],
+            parse_image_operations(pairs).unwrap()
+        );
+    }
+
     mod imageproc_ops_tests {
         use super::*;
         use sic_core::image::Rgba;
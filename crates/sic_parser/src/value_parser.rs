@@ -1,10 +1,35 @@
 use crate::errors::SicParserError;
+use sic_image_engine::wrapper::bilateral::BilateralInputs;
+use sic_image_engine::wrapper::channel::{
+    Channel, ChannelOrLuma, ChannelScope, MergeChannelsInputs,
+};
+use sic_image_engine::wrapper::chroma_key::ChromaKeyInputs;
+use sic_image_engine::wrapper::clahe::ClaheInputs;
+use sic_image_engine::wrapper::dither_algorithm::DitherAlgorithm;
+use sic_image_engine::wrapper::equalize::EqualizeMode;
+use sic_image_engine::wrapper::extend_canvas::ExtendCanvasInputs;
+use sic_image_engine::wrapper::filter::{Divisor, EdgeMode, FilterInputs, Kernel, KernelShape};
+use sic_image_engine::wrapper::frame_range::FrameRange;
+use sic_image_engine::wrapper::gradient::{
+    ColorStop, GradientColorSpace, GradientInputs, GradientShape,
+};
+use sic_image_engine::wrapper::gravity::Gravity;
 use sic_image_engine::wrapper::image_path::ImageFromPath;
+use sic_image_engine::wrapper::levels::LevelsInputs;
+use sic_image_engine::wrapper::morphology::{MorphologyInputs, MorphologyNorm};
+use sic_image_engine::wrapper::nl_means::NlMeansInputs;
 use sic_image_engine::wrapper::{filter_type::FilterTypeWrap, gradient_input::GradientInput};
 use std::path::PathBuf;
 
+use sic_image_engine::wrapper::color_matrix::{ColorMatrixInputs, ColorMatrixPreset};
+use sic_image_engine::wrapper::curves::{CurvePoints, CurvesChannel, CurvesInputs};
 use sic_image_engine::wrapper::draw_text_inner::DrawTextInner;
-use sic_image_engine::wrapper::overlay::OverlayInputs;
+use sic_image_engine::wrapper::overlay::{BlendMode, OverlayInputs, OverlayPlacement};
+use sic_image_engine::wrapper::pad::PadInputs;
+use sic_image_engine::wrapper::palette::{PaletteSource, QuantizeInputs, QuantizeMode};
+use sic_image_engine::wrapper::resize::{ResizeInputs, ResizeMode, ResizeValue};
+use sic_image_engine::wrapper::rotate::{RotateCanvas, RotateInputs};
+use sic_image_engine::wrapper::sprite_sheet::{SplitSpriteSheetInputs, SpriteSheetInputs};
 
 /// The value parser module has a goal to parse image operation inputs.
 
@@ -97,8 +122,38 @@ macro_rules! define_parse_single_input {
 
 define_parse_single_input!(f32, "Unable to map a value to f32. v2");
 define_parse_single_input!(i32, "Unable to map a value to i32. v2");
+define_parse_single_input!(u8, "Unable to map a value to u8. v2");
 define_parse_single_input!(u32, "Unable to map a value to u32. v2");
 define_parse_single_input!(bool, "Unable to map a value to bool. v2");
+define_parse_single_input!(
+    DitherAlgorithm,
+    "Dither algorithm should be one of 'floyd-steinberg', 'atkinson', 'jarvis-judice-ninke', \
+     'stucki', 'sierra', 'bayer2x2', 'bayer4x4' or 'bayer8x8'"
+);
+define_parse_single_input!(
+    GradientColorSpace,
+    "Gradient color space should be one of 'srgb', 'linear' or 'oklab'"
+);
+define_parse_single_input!(
+    EqualizeMode,
+    "Equalize mode should be either 'luma' or 'channels'"
+);
+define_parse_single_input!(
+    FrameRange,
+    "Frame range should be a range of zero-based frame indices, e.g. '3..10', '3..=10', '3..' or '..10'"
+);
+define_parse_single_input!(
+    Channel,
+    "Channel should be one of 'red', 'green', 'blue' or 'alpha' (or 'r', 'g', 'b' or 'a')"
+);
+define_parse_single_input!(
+    ChannelOrLuma,
+    "Channel should be one of 'red', 'green', 'blue', 'alpha' (or 'r', 'g', 'b', 'a') or 'luma'"
+);
+define_parse_single_input!(
+    ChannelScope,
+    "Channel should be one of 'red', 'green', 'blue', 'alpha' (or 'r', 'g', 'b', 'a') or 'all'"
+);
 
 const fn too_many_arguments_err_msg() -> &'static str {
     "Too many arguments found for image operation"
@@ -131,6 +186,33 @@ impl ParseInputsFromIter for (u32, u32, u32, u32) {
     }
 }
 
+// for: crop-to
+impl ParseInputsFromIter for (u32, u32, Gravity) {
+    type Error = SicParserError;
+
+    fn parse<'a, T>(iterable: T) -> Result<Self, Self::Error>
+    where
+        T: IntoIterator,
+        T::Item: Into<Describable<'a>> + std::fmt::Debug,
+        Self: std::marker::Sized,
+    {
+        let mut iter = iterable.into_iter();
+        const ERR_MSG: &str = "Unable to map a value to (u32, u32, Gravity). v2";
+
+        let res: (u32, u32, Gravity) = (
+            parse_next!(iter, u32, ERR_MSG),
+            parse_next!(iter, u32, ERR_MSG),
+            parse_next!(
+                iter,
+                Gravity,
+                "Gravity should be one of 'north-west', 'north', 'north-east', 'west', 'center', 'east', 'south-west', 'south' or 'south-east'"
+            ),
+        );
+
+        return_if_complete!(iter, res)
+    }
+}
+
 // for: filter3x3
 impl ParseInputsFromIter for [f32; 9] {
     type Error = SicParserError;
@@ -163,7 +245,110 @@ impl ParseInputsFromIter for [f32; 9] {
     }
 }
 
-// for: resize
+// for: filter
+impl ParseInputsFromIter for FilterInputs {
+    type Error = SicParserError;
+
+    fn parse<'a, T>(iterable: T) -> Result<Self, Self::Error>
+    where
+        T: IntoIterator,
+        T::Item: Into<Describable<'a>> + std::fmt::Debug,
+        Self: std::marker::Sized,
+    {
+        let mut iter = iterable
+            .into_iter()
+            .map(Into::<Describable>::into)
+            .peekable();
+
+        let shape = parse_next!(
+            iter,
+            KernelShape,
+            "Unable to map the kernel shape to '<width>x<height>' or '<width>x<height>-separable'"
+        );
+
+        // The divisor and bias are optional; the edge mode which follows them is a keyword, so
+        // once it's found, the remaining optional values are absent.
+        let is_edge_mode = |next: Option<&Describable>| {
+            next.is_some_and(|next| next.0.parse::<EdgeMode>().is_ok())
+        };
+
+        let divisor = if is_edge_mode(iter.peek()) {
+            Divisor::Auto
+        } else {
+            parse_next!(
+                iter,
+                Divisor,
+                "Unable to map the divisor to 'auto' or a non-zero number"
+            )
+        };
+        let bias = if is_edge_mode(iter.peek()) {
+            0.0
+        } else {
+            parse_next!(iter, f32, "Unable to map the bias to f32")
+        };
+        let edge_mode = parse_next!(
+            iter,
+            EdgeMode,
+            "Edge mode should be one of 'clamp', 'wrap' or 'mirror'"
+        );
+
+        let mut values = Vec::with_capacity(shape.number_of_values());
+        for _ in 0..shape.number_of_values() {
+            values.push(parse_next!(
+                iter,
+                f32,
+                "Unable to map a kernel value to f32, or the number of kernel values does not match the kernel shape"
+            ));
+        }
+
+        let kernel = Kernel::new(shape, values).map_err(|err| {
+            SicParserError::ValueParsingErrorWithInnerError(
+                "Unable to create a kernel from the given values".to_string(),
+                Box::new(err),
+            )
+        })?;
+
+        return_if_complete!(iter, FilterInputs::new(kernel, divisor, bias, edge_mode))
+    }
+}
+
+// for: color-matrix
+impl ParseInputsFromIter for ColorMatrixInputs {
+    type Error = SicParserError;
+
+    fn parse<'a, T>(iterable: T) -> Result<Self, Self::Error>
+    where
+        T: IntoIterator,
+        T::Item: Into<Describable<'a>> + std::fmt::Debug,
+        Self: std::marker::Sized,
+    {
+        let mut iter = iterable
+            .into_iter()
+            .map(Into::<Describable>::into)
+            .peekable();
+
+        // Either a single preset name, or the 20 values of a 4x5 matrix.
+        let preset = iter
+            .peek()
+            .and_then(|first| first.0.parse::<ColorMatrixPreset>().ok());
+
+        if let Some(preset) = preset {
+            iter.next();
+            return return_if_complete!(iter, preset.matrix());
+        }
+
+        const ERR_MSG: &str = "Unable to map a value to a 4x5 color matrix ([f32; 20])";
+
+        let mut matrix = [0.0; 20];
+        for value in matrix.iter_mut() {
+            *value = parse_next!(iter, f32, ERR_MSG);
+        }
+
+        return_if_complete!(iter, ColorMatrixInputs::new(matrix))
+    }
+}
+
+// for: dither-quant, index-colors-quant
 impl ParseInputsFromIter for (u32, u32) {
     type Error = SicParserError;
 
@@ -185,6 +370,29 @@ impl ParseInputsFromIter for (u32, u32) {
     }
 }
 
+// for: swap-channels
+impl ParseInputsFromIter for (Channel, Channel) {
+    type Error = SicParserError;
+
+    fn parse<'a, T>(iterable: T) -> Result<Self, Self::Error>
+    where
+        T: IntoIterator,
+        T::Item: Into<Describable<'a>> + std::fmt::Debug,
+        Self: std::marker::Sized,
+    {
+        let mut iter = iterable.into_iter();
+        const ERR_MSG: &str =
+            "Channel should be one of 'red', 'green', 'blue' or 'alpha' (or 'r', 'g', 'b' or 'a')";
+
+        let res: (Channel, Channel) = (
+            parse_next!(iter, Channel, ERR_MSG),
+            parse_next!(iter, Channel, ERR_MSG),
+        );
+
+        return_if_complete!(iter, res)
+    }
+}
+
 // for: unsharpen
 impl ParseInputsFromIter for (f32, i32) {
     type Error = SicParserError;
@@ -207,6 +415,28 @@ impl ParseInputsFromIter for (f32, i32) {
     }
 }
 
+// for: canny
+impl ParseInputsFromIter for (f32, f32) {
+    type Error = SicParserError;
+
+    fn parse<'a, T>(iterable: T) -> Result<Self, Self::Error>
+    where
+        T: IntoIterator,
+        T::Item: Into<Describable<'a>> + std::fmt::Debug,
+        Self: std::marker::Sized,
+    {
+        let mut iter = iterable.into_iter();
+        const ERR_MSG: &str = "Unable to map a value to (f32, f32). v2";
+
+        let res: (f32, f32) = (
+            parse_next!(iter, f32, ERR_MSG),
+            parse_next!(iter, f32, ERR_MSG),
+        );
+
+        return_if_complete!(iter, res)
+    }
+}
+
 impl ParseInputsFromIter for String {
     type Error = SicParserError;
 
@@ -275,23 +505,103 @@ impl ParseInputsFromIter for OverlayInputs {
         T::Item: Into<Describable<'a>> + std::fmt::Debug,
         Self: std::marker::Sized,
     {
-        let mut iter = iterable.into_iter();
-        let image_path = parse_to_path_buf(iter.next().map(Into::<Describable>::into))?;
+        let mut iter = iterable
+            .into_iter()
+            .map(Into::<Describable>::into)
+            .peekable();
 
-        let position: (i64, i64) = (
-            parse_next!(
-                iter,
-                i64,
-                "x-axis position value for overlay should be a natural number"
-            ),
-            parse_next!(
-                iter,
-                i64,
-                "y-axis position value for overlay should be a natural number"
+        // The blend mode and opacity are optional; without them, the path is expected right away.
+        let blend_mode = iter
+            .peek()
+            .and_then(|first| first.0.parse::<BlendMode>().ok());
+
+        let blending = match blend_mode {
+            Some(blend_mode) => {
+                iter.next();
+
+                let opacity = parse_next!(
+                    iter,
+                    f32,
+                    "opacity value for overlay should be a number in the range [0, 1]"
+                );
+
+                Some((blend_mode, opacity))
+            }
+            None => None,
+        };
+
+        let width = match iter.peek() {
+            Some(next) if next.0.eq_ignore_ascii_case("width") => {
+                iter.next();
+
+                Some(parse_next!(
+                    iter,
+                    ResizeValue,
+                    "width value for overlay should be a natural number or a percentage"
+                ))
+            }
+            _ => None,
+        };
+
+        let image_path = parse_to_path_buf(iter.next())?;
+
+        let placement = match iter.peek() {
+            Some(next) if next.0.eq_ignore_ascii_case("tile") => {
+                iter.next();
+
+                let spacing_err = "spacing value for tiled overlay should be a natural number";
+
+                OverlayPlacement::Tiled {
+                    spacing: (
+                        parse_next!(iter, u32, spacing_err),
+                        parse_next!(iter, u32, spacing_err),
+                    ),
+                    degrees: parse_next!(
+                        iter,
+                        f32,
+                        "rotation value for tiled overlay should be a number of degrees"
+                    ),
+                }
+            }
+            Some(next) if next.0.parse::<Gravity>().is_ok() => {
+                let gravity = parse_next!(
+                    iter,
+                    Gravity,
+                    "Gravity should be one of 'north-west', 'north', 'north-east', 'west', 'center', 'east', 'south-west', 'south' or 'south-east'"
+                );
+                let margin_err = "margin value for overlay should be a natural number";
+
+                OverlayPlacement::Anchored {
+                    gravity,
+                    margin: (
+                        parse_next!(iter, u32, margin_err),
+                        parse_next!(iter, u32, margin_err),
+                    ),
+                }
+            }
+            _ => OverlayPlacement::Position(
+                parse_next!(
+                    iter,
+                    i64,
+                    "x-axis position value for overlay should be a natural number"
+                ),
+                parse_next!(
+                    iter,
+                    i64,
+                    "y-axis position value for overlay should be a natural number"
+                ),
             ),
-        );
+        };
 
-        let overlay_inputs = OverlayInputs::new(ImageFromPath::new(image_path), position);
+        let overlay_inputs = OverlayInputs::new(ImageFromPath::new(image_path), placement);
+        let overlay_inputs = match blending {
+            Some((blend_mode, opacity)) => overlay_inputs.with_blending(blend_mode, opacity),
+            None => overlay_inputs,
+        };
+        let overlay_inputs = match width {
+            Some(width) => overlay_inputs.with_width(width),
+            None => overlay_inputs,
+        };
 
         return_if_complete!(iter, overlay_inputs)
     }
@@ -331,6 +641,97 @@ fn parse_to_path_buf(value: Option<Describable>) -> Result<PathBuf, SicParserErr
         .map(|v: Describable| PathBuf::from(v.0))
 }
 
+// for: merge-channels
+impl ParseInputsFromIter for MergeChannelsInputs {
+    type Error = SicParserError;
+
+    fn parse<'a, T>(iterable: T) -> Result<Self, Self::Error>
+    where
+        T: IntoIterator,
+        T::Item: Into<Describable<'a>> + std::fmt::Debug,
+        Self: std::marker::Sized,
+    {
+        let mut iter = iterable.into_iter().map(Into::<Describable>::into);
+        let mut sources = [None, None, None, None];
+
+        // Each channel is either taken from the image at a path, or kept as is.
+        for source in sources.iter_mut() {
+            let value = iter.next().ok_or_else(|| {
+                SicParserError::ValueParsingError(
+                    "Expected a path or 'keep' for each of the red, green, blue and alpha channels"
+                        .to_string(),
+                )
+            })?;
+
+            if !value.0.eq_ignore_ascii_case("keep") {
+                *source = Some(ImageFromPath::new(parse_to_path_buf(Some(value))?));
+            }
+        }
+
+        return_if_complete!(iter, MergeChannelsInputs::new(sources))
+    }
+}
+
+// for: quantize
+impl ParseInputsFromIter for QuantizeInputs {
+    type Error = SicParserError;
+
+    fn parse<'a, T>(iterable: T) -> Result<Self, Self::Error>
+    where
+        T: IntoIterator,
+        T::Item: Into<Describable<'a>> + std::fmt::Debug,
+        Self: std::marker::Sized,
+    {
+        use crate::named_value::NamedValue;
+        use sic_core::image::Rgba;
+
+        let starts_with_named_value = |value: &str| {
+            let first = value.split_inclusive(')').next().unwrap_or_default();
+            first.trim().parse::<NamedValue>().is_ok()
+        };
+
+        let mut iter = iterable.into_iter();
+        let mode = parse_next!(
+            iter,
+            QuantizeMode,
+            "Quantize mode should be either 'nearest' or 'dither'"
+        );
+
+        let values = iter.map(Into::into).collect::<Vec<Describable>>();
+
+        // A single value which is not a color is the path to a palette file. Otherwise, each value
+        // holds one or more colors, e.g. 'rgba(0, 0, 0, 255) rgba(255, 255, 255, 255)'.
+        let palette = match values.as_slice() {
+            [] => {
+                return Err(SicParserError::ValueParsingError(
+                    "A palette path or palette colors were expected but none were found."
+                        .to_string(),
+                ));
+            }
+            [value] if !starts_with_named_value(value.0) => {
+                PaletteSource::Path(parse_to_path_buf(Some(value.clone()))?)
+            }
+            values => PaletteSource::Colors(
+                values
+                    .iter()
+                    .flat_map(|value| value.0.split_inclusive(')'))
+                    .map(str::trim)
+                    .filter(|color| !color.is_empty())
+                    .map(|color| {
+                        color
+                            .parse::<NamedValue>()
+                            .and_then(|color| color.extract_rgba())
+                            .map(Rgba)
+                            .map_err(SicParserError::NamedValueParsingError)
+                    })
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+        };
+
+        Ok(QuantizeInputs::new(mode, palette))
+    }
+}
+
 impl ParseInputsFromIter for DrawTextInner {
     type Error = SicParserError;
 
@@ -379,6 +780,104 @@ impl ParseInputsFromIter for DrawTextInner {
     }
 }
 
+// for: gradient
+impl ParseInputsFromIter for GradientInputs {
+    type Error = SicParserError;
+
+    fn parse<'a, T>(iterable: T) -> Result<Self, Self::Error>
+    where
+        T: IntoIterator,
+        T::Item: Into<Describable<'a>> + std::fmt::Debug,
+        Self: std::marker::Sized,
+    {
+        let mut iter = iterable.into_iter().map(Into::<Describable>::into);
+        const ANGLE_ERR_MSG: &str = "Unable to map the gradient angle to f32";
+
+        let shape = match iter
+            .next()
+            .map(|value| value.0.to_ascii_lowercase())
+            .as_deref()
+        {
+            Some("linear") => GradientShape::Linear {
+                angle: parse_next!(iter, f32, ANGLE_ERR_MSG),
+            },
+            Some("radial") => GradientShape::Radial,
+            Some("conic") => GradientShape::Conic {
+                angle: parse_next!(iter, f32, ANGLE_ERR_MSG),
+            },
+            _ => {
+                return Err(SicParserError::ValueParsingError(
+                    "Gradient shape should be one of 'linear', 'radial' or 'conic'".to_string(),
+                ));
+            }
+        };
+
+        // Each value holds one or more color stops, e.g. 'rgba(0, 0, 0, 255) 25% rgba(255, 255,
+        // 255, 255)'.
+        let mut stops = Vec::new();
+        for value in iter {
+            parse_color_stops(value.0, &mut stops)?;
+        }
+
+        if stops.len() < 2 {
+            return Err(SicParserError::ValueParsingError(format!(
+                "A gradient requires at least two color stops, but {} were found",
+                stops.len()
+            )));
+        }
+
+        Ok(GradientInputs::new(shape, stops))
+    }
+}
+
+/// Parses a sequence of colors, each of which may be followed by its position as percentage.
+fn parse_color_stops(text: &str, stops: &mut Vec<ColorStop>) -> Result<(), SicParserError> {
+    use crate::named_value::NamedValue;
+    use sic_core::image::Rgba;
+
+    let mut rest = text.trim_start();
+
+    while !rest.is_empty() {
+        if rest.starts_with(|c: char| c.is_ascii_digit() || c == '-' || c == '.') {
+            let percentage = rest.split_whitespace().next().unwrap_or_default();
+            let position = percentage
+                .strip_suffix('%')
+                .and_then(|position| position.parse::<f32>().ok())
+                .filter(|position| position.is_finite())
+                .ok_or_else(|| {
+                    SicParserError::ValueParsingError(format!(
+                        "Unable to parse color stop position '{}'",
+                        percentage
+                    ))
+                })?;
+
+            let stop = stops
+                .last_mut()
+                .filter(|stop| stop.position().is_none())
+                .ok_or_else(|| {
+                    SicParserError::ValueParsingError(format!(
+                        "Color stop position '{}' should follow a color",
+                        percentage
+                    ))
+                })?;
+            *stop = ColorStop::new(stop.color(), Some(position / 100.0));
+
+            rest = rest[percentage.len()..].trim_start();
+        } else {
+            let end = rest.find(')').map_or(rest.len(), |index| index + 1);
+            let color = rest[..end]
+                .parse::<NamedValue>()
+                .and_then(|color| color.extract_rgba())
+                .map_err(SicParserError::NamedValueParsingError)?;
+
+            stops.push(ColorStop::new(Rgba(color), None));
+            rest = rest[end..].trim_start();
+        }
+    }
+
+    Ok(())
+}
+
 // Horizontal gradient
 impl ParseInputsFromIter for GradientInput {
     type Error = SicParserError;
@@ -413,41 +912,501 @@ impl ParseInputsFromIter for GradientInput {
     }
 }
 
-#[cfg(test)]
-mod tests_parse_from_iter {
-    use super::*;
-    use sic_testing::*;
+// for: levels
+impl ParseInputsFromIter for LevelsInputs {
+    type Error = SicParserError;
 
-    macro_rules! assert_iter_impl {
-        ($lhs_iter:expr, $rhs_iter:expr, $f:expr) => {
-            assert!($lhs_iter.into_iter().zip($rhs_iter.into_iter()).all($f))
-        };
-    }
+    fn parse<'a, T>(iterable: T) -> Result<Self, Self::Error>
+    where
+        T: IntoIterator,
+        T::Item: Into<Describable<'a>> + std::fmt::Debug,
+        Self: std::marker::Sized,
+    {
+        let mut iter = iterable.into_iter();
+        const ERR_MSG: &str = "Unable to map the black or white point to u8 (range 0-255)";
+
+        let input_black = parse_next!(iter, u8, ERR_MSG);
+        let input_white = parse_next!(iter, u8, ERR_MSG);
+        let gamma = parse_next!(iter, f32, "Unable to map the gamma to f32");
+        let output_black = parse_next!(iter, u8, ERR_MSG);
+        let output_white = parse_next!(iter, u8, ERR_MSG);
+
+        let res = LevelsInputs::new(
+            (input_black, input_white),
+            gamma,
+            (output_black, output_white),
+        );
 
-    macro_rules! assert_iter_f32 {
-        ($lhs_iter:expr, $rhs_iter:expr) => {
-            assert_iter_impl!($lhs_iter, $rhs_iter, |(l, r)| (l - r).abs() < f32::EPSILON)
-        };
+        return_if_complete!(iter, res)
     }
+}
 
-    #[test]
-    fn a_f32() {
-        let some: f32 = ParseInputsFromIter::parse(&["-1.03"]).unwrap();
-        sic_testing::approx_eq_f32!(some, -1.03f32)
+// for: curves
+impl ParseInputsFromIter for CurvesInputs {
+    type Error = SicParserError;
+
+    fn parse<'a, T>(iterable: T) -> Result<Self, Self::Error>
+    where
+        T: IntoIterator,
+        T::Item: Into<Describable<'a>> + std::fmt::Debug,
+        Self: std::marker::Sized,
+    {
+        let mut iter = iterable.into_iter();
+
+        let channel = parse_next!(
+            iter,
+            CurvesChannel,
+            "Curves channel should be one of 'rgb', 'red', 'green', 'blue' or 'luminance'"
+        );
+        let points = parse_next!(
+            iter,
+            CurvePoints,
+            "Curve points should be of the form '<input>:<output>,<input>:<output>,...'"
+        );
+
+        return_if_complete!(iter, CurvesInputs::new(channel, points))
     }
+}
 
-    mod tuple_u32_u32_u32_u32 {
-        use super::*;
+// for: dilate, erode, open, close, morph-gradient
+impl ParseInputsFromIter for MorphologyInputs {
+    type Error = SicParserError;
 
-        #[test]
-        fn should_succeed_with() {
-            let some: (u32, u32, u32, u32) =
-                ParseInputsFromIter::parse(&["03579", "0", "1", "1"]).unwrap();
-            assert_eq!(some, (3579u32, 0u32, 1u32, 1u32));
-        }
+    fn parse<'a, T>(iterable: T) -> Result<Self, Self::Error>
+    where
+        T: IntoIterator,
+        T::Item: Into<Describable<'a>> + std::fmt::Debug,
+        Self: std::marker::Sized,
+    {
+        let mut iter = iterable.into_iter();
 
-        #[pm(input = {
-            &["-4", "3", "2", "1"],         // &[a, _b, _c, _d]: a not u32 (neg)
+        let radius = parse_next!(iter, u8, "Unable to map the radius to u8 (range 0-255)");
+        let norm = parse_next!(
+            iter,
+            MorphologyNorm,
+            "Norm should be one of 'l1', 'l2' or 'linf'"
+        );
+
+        return_if_complete!(iter, MorphologyInputs::new(radius, norm))
+    }
+}
+
+// for: bilateral
+impl ParseInputsFromIter for BilateralInputs {
+    type Error = SicParserError;
+
+    fn parse<'a, T>(iterable: T) -> Result<Self, Self::Error>
+    where
+        T: IntoIterator,
+        T::Item: Into<Describable<'a>> + std::fmt::Debug,
+        Self: std::marker::Sized,
+    {
+        let mut iter = iterable.into_iter();
+
+        let radius = parse_next!(iter, u8, "Unable to map the radius to u8 (range 0-255)");
+        let sigma_color = parse_next!(iter, f32, "Unable to map the color sigma to f32");
+        let sigma_space = parse_next!(iter, f32, "Unable to map the spatial sigma to f32");
+
+        return_if_complete!(iter, BilateralInputs::new(radius, sigma_color, sigma_space))
+    }
+}
+
+// for: nl-means
+impl ParseInputsFromIter for NlMeansInputs {
+    type Error = SicParserError;
+
+    fn parse<'a, T>(iterable: T) -> Result<Self, Self::Error>
+    where
+        T: IntoIterator,
+        T::Item: Into<Describable<'a>> + std::fmt::Debug,
+        Self: std::marker::Sized,
+    {
+        let mut iter = iterable.into_iter();
+        const ERR_MSG: &str = "Unable to map the patch or search radius to u32";
+
+        let strength = parse_next!(iter, f32, "Unable to map the strength to f32");
+        let patch_radius = parse_next!(iter, u32, ERR_MSG);
+        let search_radius = parse_next!(iter, u32, ERR_MSG);
+
+        return_if_complete!(
+            iter,
+            NlMeansInputs::new(strength, patch_radius, search_radius)
+        )
+    }
+}
+
+// for: clahe
+impl ParseInputsFromIter for ClaheInputs {
+    type Error = SicParserError;
+
+    fn parse<'a, T>(iterable: T) -> Result<Self, Self::Error>
+    where
+        T: IntoIterator,
+        T::Item: Into<Describable<'a>> + std::fmt::Debug,
+        Self: std::marker::Sized,
+    {
+        let mut iter = iterable.into_iter();
+        const ERR_MSG: &str = "Unable to map the number of tiles to u32";
+
+        let tiles_x = parse_next!(iter, u32, ERR_MSG);
+        let tiles_y = parse_next!(iter, u32, ERR_MSG);
+        let clip_limit = parse_next!(iter, f32, "Unable to map the clip limit to f32");
+
+        return_if_complete!(iter, ClaheInputs::new(tiles_x, tiles_y, clip_limit))
+    }
+}
+
+// for: pad
+impl ParseInputsFromIter for PadInputs {
+    type Error = SicParserError;
+
+    fn parse<'a, T>(iterable: T) -> Result<Self, Self::Error>
+    where
+        T: IntoIterator,
+        T::Item: Into<Describable<'a>> + std::fmt::Debug,
+        Self: std::marker::Sized,
+    {
+        use crate::named_value::NamedValue;
+        use sic_core::image::Rgba;
+
+        let mut iter = iterable.into_iter();
+        const ERR_MSG: &str = "Unable to map the padding to u32";
+
+        let padding = (
+            parse_next!(iter, u32, ERR_MSG),
+            parse_next!(iter, u32, ERR_MSG),
+            parse_next!(iter, u32, ERR_MSG),
+            parse_next!(iter, u32, ERR_MSG),
+        );
+        let fill = parse_next!(iter, NamedValue, "Rgba");
+
+        let res = PadInputs::new(
+            padding,
+            Rgba(
+                fill.extract_rgba()
+                    .map_err(SicParserError::NamedValueParsingError)?,
+            ),
+        );
+
+        return_if_complete!(iter, res)
+    }
+}
+
+// for: color-to-alpha, flatten
+impl ParseInputsFromIter for sic_core::image::Rgba<u8> {
+    type Error = SicParserError;
+
+    fn parse<'a, T>(iterable: T) -> Result<Self, Self::Error>
+    where
+        T: IntoIterator,
+        T::Item: Into<Describable<'a>> + std::fmt::Debug,
+        Self: std::marker::Sized,
+    {
+        use crate::named_value::NamedValue;
+        use sic_core::image::Rgba;
+
+        let mut iter = iterable.into_iter();
+        let color = parse_next!(iter, NamedValue, "Rgba");
+
+        let res = Rgba(
+            color
+                .extract_rgba()
+                .map_err(SicParserError::NamedValueParsingError)?,
+        );
+
+        return_if_complete!(iter, res)
+    }
+}
+
+// for: chroma-key
+impl ParseInputsFromIter for ChromaKeyInputs {
+    type Error = SicParserError;
+
+    fn parse<'a, T>(iterable: T) -> Result<Self, Self::Error>
+    where
+        T: IntoIterator,
+        T::Item: Into<Describable<'a>> + std::fmt::Debug,
+        Self: std::marker::Sized,
+    {
+        use crate::named_value::NamedValue;
+        use sic_core::image::Rgba;
+
+        let mut iter = iterable
+            .into_iter()
+            .map(Into::<Describable>::into)
+            .peekable();
+
+        // Spill suppression is optional; without it, the key color is expected right away.
+        let despill = iter
+            .next_if(|first| first.0.eq_ignore_ascii_case("despill"))
+            .is_some();
+
+        let key = parse_next!(iter, NamedValue, "Rgba");
+        let tolerance = parse_next!(iter, f32, "Unable to parse the tolerance of chroma-key");
+        let softness = parse_next!(iter, f32, "Unable to parse the softness of chroma-key");
+
+        let res = ChromaKeyInputs::new(
+            Rgba(
+                key.extract_rgba()
+                    .map_err(SicParserError::NamedValueParsingError)?,
+            ),
+            tolerance,
+            softness,
+        )
+        .with_despill(despill);
+
+        return_if_complete!(iter, res)
+    }
+}
+
+// for: extend-canvas
+impl ParseInputsFromIter for ExtendCanvasInputs {
+    type Error = SicParserError;
+
+    fn parse<'a, T>(iterable: T) -> Result<Self, Self::Error>
+    where
+        T: IntoIterator,
+        T::Item: Into<Describable<'a>> + std::fmt::Debug,
+        Self: std::marker::Sized,
+    {
+        use crate::named_value::NamedValue;
+        use sic_core::image::Rgba;
+
+        let mut iter = iterable.into_iter();
+        const ERR_MSG: &str = "Unable to map the canvas dimensions to u32";
+
+        let width = parse_next!(iter, u32, ERR_MSG);
+        let height = parse_next!(iter, u32, ERR_MSG);
+        let gravity = parse_next!(
+            iter,
+            Gravity,
+            "Gravity should be one of 'north-west', 'north', 'north-east', 'west', 'center', 'east', 'south-west', 'south' or 'south-east'"
+        );
+        let fill = parse_next!(iter, NamedValue, "Rgba");
+
+        let res = ExtendCanvasInputs::new(
+            width,
+            height,
+            gravity,
+            Rgba(
+                fill.extract_rgba()
+                    .map_err(SicParserError::NamedValueParsingError)?,
+            ),
+        );
+
+        return_if_complete!(iter, res)
+    }
+}
+
+// for: sprite-sheet
+impl ParseInputsFromIter for SpriteSheetInputs {
+    type Error = SicParserError;
+
+    fn parse<'a, T>(iterable: T) -> Result<Self, Self::Error>
+    where
+        T: IntoIterator,
+        T::Item: Into<Describable<'a>> + std::fmt::Debug,
+        Self: std::marker::Sized,
+    {
+        use crate::named_value::NamedValue;
+        use sic_core::image::Rgba;
+
+        let mut iter = iterable
+            .into_iter()
+            .map(Into::<Describable>::into)
+            .peekable();
+
+        // The atlas is optional; without it, the number of columns is expected right away.
+        let atlas = match iter.peek() {
+            Some(next) if next.0.eq_ignore_ascii_case("atlas") => {
+                iter.next();
+
+                Some(parse_to_path_buf(iter.next())?)
+            }
+            _ => None,
+        };
+
+        let columns = parse_next!(
+            iter,
+            u32,
+            "number of columns for sprite-sheet should be a natural number"
+        );
+        let spacing = parse_next!(
+            iter,
+            u32,
+            "spacing value for sprite-sheet should be a natural number"
+        );
+        let background = parse_next!(iter, NamedValue, "Rgba");
+
+        let res = SpriteSheetInputs::new(
+            columns,
+            spacing,
+            Rgba(
+                background
+                    .extract_rgba()
+                    .map_err(SicParserError::NamedValueParsingError)?,
+            ),
+        );
+        let res = match atlas {
+            Some(atlas) => res.with_atlas(atlas),
+            None => res,
+        };
+
+        return_if_complete!(iter, res)
+    }
+}
+
+// for: split-sprite-sheet
+impl ParseInputsFromIter for SplitSpriteSheetInputs {
+    type Error = SicParserError;
+
+    fn parse<'a, T>(iterable: T) -> Result<Self, Self::Error>
+    where
+        T: IntoIterator,
+        T::Item: Into<Describable<'a>> + std::fmt::Debug,
+        Self: std::marker::Sized,
+    {
+        let mut iter = iterable.into_iter();
+        const ERR_MSG: &str = "Unable to map the cell dimensions and spacing to u32";
+
+        let res = SplitSpriteSheetInputs::new(
+            (
+                parse_next!(iter, u32, ERR_MSG),
+                parse_next!(iter, u32, ERR_MSG),
+            ),
+            parse_next!(iter, u32, ERR_MSG),
+        );
+
+        return_if_complete!(iter, res)
+    }
+}
+
+// for: resize
+impl ParseInputsFromIter for ResizeInputs {
+    type Error = SicParserError;
+
+    fn parse<'a, T>(iterable: T) -> Result<Self, Self::Error>
+    where
+        T: IntoIterator,
+        T::Item: Into<Describable<'a>> + std::fmt::Debug,
+        Self: std::marker::Sized,
+    {
+        let mut iter = iterable
+            .into_iter()
+            .map(Into::<Describable>::into)
+            .peekable();
+
+        // The mode is optional; without it, the first value is expected right away.
+        let mode = iter
+            .peek()
+            .and_then(|first| first.0.parse::<ResizeMode>().ok());
+
+        if mode.is_some() {
+            iter.next();
+        }
+
+        let value_err_msg = "Unable to map the resize value to a number of pixels or a percentage";
+
+        let res = match mode {
+            None => ResizeInputs::Dimensions(
+                parse_next!(iter, ResizeValue, value_err_msg),
+                parse_next!(iter, ResizeValue, value_err_msg),
+            ),
+            Some(ResizeMode::Exact) => ResizeInputs::Exact(
+                parse_next!(iter, ResizeValue, value_err_msg),
+                parse_next!(iter, ResizeValue, value_err_msg),
+            ),
+            Some(ResizeMode::Fit) => ResizeInputs::Fit(
+                parse_next!(iter, ResizeValue, value_err_msg),
+                parse_next!(iter, ResizeValue, value_err_msg),
+            ),
+            Some(ResizeMode::Cover) => ResizeInputs::Cover(
+                parse_next!(iter, ResizeValue, value_err_msg),
+                parse_next!(iter, ResizeValue, value_err_msg),
+            ),
+            Some(ResizeMode::WidthOnly) => {
+                ResizeInputs::WidthOnly(parse_next!(iter, ResizeValue, value_err_msg))
+            }
+            Some(ResizeMode::HeightOnly) => {
+                ResizeInputs::HeightOnly(parse_next!(iter, ResizeValue, value_err_msg))
+            }
+        };
+
+        return_if_complete!(iter, res)
+    }
+}
+
+// for: rotate
+impl ParseInputsFromIter for RotateInputs {
+    type Error = SicParserError;
+
+    fn parse<'a, T>(iterable: T) -> Result<Self, Self::Error>
+    where
+        T: IntoIterator,
+        T::Item: Into<Describable<'a>> + std::fmt::Debug,
+        Self: std::marker::Sized,
+    {
+        use crate::named_value::NamedValue;
+        use sic_core::image::Rgba;
+
+        let mut iter = iterable.into_iter();
+        let degrees = parse_next!(iter, f32, "Unable to map the rotation angle to f32");
+        let canvas = parse_next!(
+            iter,
+            RotateCanvas,
+            "Rotate canvas should be either 'crop' or 'expand'"
+        );
+        let background = parse_next!(iter, NamedValue, "Rgba");
+
+        let res = RotateInputs::new(
+            degrees,
+            canvas,
+            Rgba(
+                background
+                    .extract_rgba()
+                    .map_err(SicParserError::NamedValueParsingError)?,
+            ),
+        );
+
+        return_if_complete!(iter, res)
+    }
+}
+
+#[cfg(test)]
+mod tests_parse_from_iter {
+    use super::*;
+    use sic_testing::*;
+
+    macro_rules! assert_iter_impl {
+        ($lhs_iter:expr, $rhs_iter:expr, $f:expr) => {
+            assert!($lhs_iter.into_iter().zip($rhs_iter.into_iter()).all($f))
+        };
+    }
+
+    macro_rules! assert_iter_f32 {
+        ($lhs_iter:expr, $rhs_iter:expr) => {
+            assert_iter_impl!($lhs_iter, $rhs_iter, |(l, r)| (l - r).abs() < f32::EPSILON)
+        };
+    }
+
+    #[test]
+    fn a_f32() {
+        let some: f32 = ParseInputsFromIter::parse(&["-1.03"]).unwrap();
+        sic_testing::approx_eq_f32!(some, -1.03f32)
+    }
+
+    mod tuple_u32_u32_u32_u32 {
+        use super::*;
+
+        #[test]
+        fn should_succeed_with() {
+            let some: (u32, u32, u32, u32) =
+                ParseInputsFromIter::parse(&["03579", "0", "1", "1"]).unwrap();
+            assert_eq!(some, (3579u32, 0u32, 1u32, 1u32));
+        }
+
+        #[pm(input = {
+            &["-4", "3", "2", "1"],         // &[a, _b, _c, _d]: a not u32 (neg)
             &["4", "-3", "2", "1"],         // &[_a, b, _c, _d]: b not u32 (neg)
             &["4", "3", "-2", "1"],         // &[_a, _b, c, _d]: c not u32 (neg)
             &["4", "3", "2", "-1"],         // &[_a, _b, _c, d]: d not u32 (neg)
@@ -457,58 +1416,889 @@ mod tests_parse_from_iter {
             &[],                            // empty
         })]
         fn expected_failures(input: &[&str]) {
-            let result: Result<(u32, u32, u32, u32), SicParserError> =
+            let result: Result<(u32, u32, u32, u32), SicParserError> =
+                ParseInputsFromIter::parse(input);
+            assert!(result.is_err());
+        }
+    }
+
+    mod array_f32x9 {
+        use super::*;
+
+        #[test]
+        fn array_of_f32() {
+            let some: [f32; 9] = ParseInputsFromIter::parse(&[
+                "1", "2", "3", "4", "5.5", "-6.0", "7", "8", "-9.9999",
+            ])
+            .unwrap();
+            const EXPECTED: [f32; 9] = [
+                1f32, 2f32, 3f32, 4f32, 5.5f32, -6.0f32, 7f32, 8f32, -9.9999f32,
+            ];
+
+            assert_iter_f32!(&some, &EXPECTED)
+        }
+
+        #[pm(input = {
+            &["1", "2", "3", "4", "5.5", "-6.0", "7", "8", "lalala"],               // &[..., x]: x not f32 (not a number)
+            &["1", "2", "3", "4", "5.5", "-6.0", "7", "8"],                         // len() == 9 expected
+            &["1", "2", "3", "4", "5.5", "-6.0", "7", "8", "-9.9999", "1"],         // len() == 9 expected
+            &[],                                                                    // empty
+        })]
+        fn expected_failures(input: &[&str]) {
+            let result: Result<[f32; 9], SicParserError> = ParseInputsFromIter::parse(input);
+            assert!(result.is_err())
+        }
+    }
+
+    mod tuple_u32_u32 {
+        use super::*;
+
+        #[test]
+        fn a_tuple_of_u32_u32() {
+            let some: (u32, u32) = ParseInputsFromIter::parse(&["03579", "0"]).unwrap();
+            assert_eq!(some, (3579u32, 0u32))
+        }
+
+        #[pm(input = {
+            &["4", "-0"],   // [_x, y]: y not u32 (neg 0)
+            &["4", "-4"],   // [_x, y]: y not u32 (neg number)
+            &["4", "f"],    // [_x, y]: y not u32 (not a number)
+            &["03579"],     // len() == 2 expected
+            &[],            // empty
+        })]
+        fn expected_failures(input: &[&str]) {
+            let result: Result<(u32, u32), SicParserError> = ParseInputsFromIter::parse(input);
+            assert!(result.is_err());
+        }
+    }
+
+    mod tuple_u32_u32_gravity {
+        use super::*;
+
+        #[test]
+        fn a_tuple_of_u32_u32_gravity() {
+            let some: (u32, u32, Gravity) =
+                ParseInputsFromIter::parse(&["10", "20", "north-east"]).unwrap();
+            assert_eq!(some, (10, 20, Gravity::NorthEast));
+        }
+
+        #[pm(input = {
+            &["-1", "20", "center"],        // [x, _, _]: x not u32
+            &["10", "1.5", "center"],       // [_, y, _]: y not u32
+            &["10", "20", "middle"],        // [_, _, z]: z not a gravity
+            &["10", "20"],                  // len() == 3 expected
+            &["10", "20", "center", "1"],   // len() == 3 expected
+            &[],                            // empty
+        })]
+        fn expected_failures(input: &[&str]) {
+            let result: Result<(u32, u32, Gravity), SicParserError> =
+                ParseInputsFromIter::parse(input);
+            assert!(result.is_err());
+        }
+    }
+
+    mod color_matrix_inputs {
+        use super::*;
+
+        #[test]
+        fn should_succeed_with_preset() {
+            let some: ColorMatrixInputs = ParseInputsFromIter::parse(&["swap-rb"]).unwrap();
+            assert_eq!(some, ColorMatrixPreset::SwapRb.matrix());
+        }
+
+        #[test]
+        fn should_succeed_with_matrix() {
+            let input = [
+                "1", "0", "0", "0", "0", "0", "1", "0", "0", "0", "0", "0", "1", "0", "0", "0",
+                "0", "0", "-1", "1.5",
+            ];
+            let some: ColorMatrixInputs = ParseInputsFromIter::parse(&input).unwrap();
+
+            let mut expected = [0.0; 20];
+            expected[0] = 1.0;
+            expected[6] = 1.0;
+            expected[12] = 1.0;
+            expected[18] = -1.0;
+            expected[19] = 1.5;
+
+            assert_eq!(some, ColorMatrixInputs::new(expected));
+        }
+
+        #[pm(input = {
+            &["sepia", "1"],                    // preset, len() == 1 expected
+            &["sepa"],                          // not a preset, and len() == 20 expected
+            &["1", "0", "0", "0", "0"],         // len() == 20 expected
+            &["1", "0", "0", "0", "0", "0", "1", "0", "0", "0",
+              "0", "0", "1", "0", "0", "0", "0", "0", "1", "a"], // not f32
+            &[],                                // empty
+        })]
+        fn expected_failures(input: &[&str]) {
+            let result: Result<ColorMatrixInputs, SicParserError> =
+                ParseInputsFromIter::parse(input);
+            assert!(result.is_err());
+        }
+    }
+
+    mod filter_inputs {
+        use super::*;
+
+        #[test]
+        fn should_succeed_with_full_kernel() {
+            let some: FilterInputs =
+                ParseInputsFromIter::parse(&["3x1", "auto", "0.5", "wrap", "1", "-2", "1.5"])
+                    .unwrap();
+
+            let kernel = Kernel::new(
+                KernelShape::Full {
+                    width: 3,
+                    height: 1,
+                },
+                vec![1.0, -2.0, 1.5],
+            )
+            .unwrap();
+
+            assert_eq!(
+                some,
+                FilterInputs::new(kernel, Divisor::Auto, 0.5, EdgeMode::Wrap)
+            );
+        }
+
+        #[test]
+        fn should_succeed_with_separable_kernel() {
+            let some: FilterInputs = ParseInputsFromIter::parse(&[
+                "3x1-separable",
+                "4",
+                "0",
+                "mirror",
+                "1",
+                "2",
+                "1",
+                "1",
+            ])
+            .unwrap();
+
+            let kernel = Kernel::new(
+                KernelShape::Separable {
+                    width: 3,
+                    height: 1,
+                },
+                vec![1.0, 2.0, 1.0, 1.0],
+            )
+            .unwrap();
+
+            assert_eq!(
+                some,
+                FilterInputs::new(kernel, Divisor::Value(4.0), 0.0, EdgeMode::Mirror)
+            );
+        }
+
+        #[pm(input = {
+            &["2x1", "auto", "0", "clamp", "1", "1"],            // even kernel size
+            &["3x1", "0", "0", "clamp", "1", "1", "1"],          // divisor is zero
+            &["3x1", "auto", "a", "clamp", "1", "1", "1"],       // bias not f32
+            &["3x1", "auto", "0", "repeat", "1", "1", "1"],      // unknown edge mode
+            &["3x1", "auto", "0", "clamp", "1", "1"],            // too few kernel values
+            &["3x1", "auto", "0", "clamp", "1", "1", "1", "1"],  // too many kernel values
+            &["1x1-separable", "auto", "0", "clamp", "1"],       // too few kernel values
+            &[],                                                 // empty
+        })]
+        fn expected_failures(input: &[&str]) {
+            let result: Result<FilterInputs, SicParserError> = ParseInputsFromIter::parse(input);
+            assert!(result.is_err());
+        }
+    }
+
+    mod levels_inputs {
+        use super::*;
+
+        #[test]
+        fn should_succeed_with() {
+            let some: LevelsInputs =
+                ParseInputsFromIter::parse(&["10", "240", "1.2", "0", "255"]).unwrap();
+            assert_eq!(some, LevelsInputs::new((10, 240), 1.2, (0, 255)));
+        }
+
+        #[pm(input = {
+            &["10", "256", "1.2", "0", "255"],       // [_, w, _, _, _]: w not u8
+            &["10", "240", "a", "0", "255"],         // [_, _, g, _, _]: g not f32
+            &["10", "240", "1.2", "-1", "255"],      // [_, _, _, b, _]: b not u8
+            &["10", "240", "1.2", "0"],              // len() == 5 expected
+            &["10", "240", "1.2", "0", "255", "1"],  // len() == 5 expected
+            &[],                                     // empty
+        })]
+        fn expected_failures(input: &[&str]) {
+            let result: Result<LevelsInputs, SicParserError> = ParseInputsFromIter::parse(input);
+            assert!(result.is_err());
+        }
+    }
+
+    mod curves_inputs {
+        use super::*;
+
+        #[test]
+        fn should_succeed_with() {
+            let some: CurvesInputs =
+                ParseInputsFromIter::parse(&["luminance", "0:10,128:140,255:250"]).unwrap();
+            assert_eq!(
+                some,
+                CurvesInputs::new(
+                    CurvesChannel::Luminance,
+                    CurvePoints::new(vec![(0, 10), (128, 140), (255, 250)]).unwrap()
+                )
+            );
+        }
+
+        #[pm(input = {
+            &["alpha", "0:0,255:255"],            // [c, _]: c not a curves channel
+            &["rgb", "0:0"],                      // [_, p]: p requires two points
+            &["rgb", "0:0,255:256"],              // [_, p]: p not in range
+            &["rgb"],                             // len() == 2 expected
+            &["rgb", "0:0,255:255", "1"],         // len() == 2 expected
+            &[],                                  // empty
+        })]
+        fn expected_failures(input: &[&str]) {
+            let result: Result<CurvesInputs, SicParserError> = ParseInputsFromIter::parse(input);
+            assert!(result.is_err());
+        }
+    }
+
+    mod morphology_inputs {
+        use super::*;
+
+        #[test]
+        fn should_succeed_with() {
+            let some: MorphologyInputs = ParseInputsFromIter::parse(&["3", "LInf"]).unwrap();
+            assert_eq!(some, MorphologyInputs::new(3, MorphologyNorm::LInf));
+        }
+
+        #[pm(input = {
+            &["256", "l1"],         // [r, _]: r not u8
+            &["-1", "l1"],          // [r, _]: r not u8
+            &["1", "l3"],           // [_, n]: n not a norm
+            &["1"],                 // len() == 2 expected
+            &["1", "l1", "1"],      // len() == 2 expected
+            &[],                    // empty
+        })]
+        fn expected_failures(input: &[&str]) {
+            let result: Result<MorphologyInputs, SicParserError> =
+                ParseInputsFromIter::parse(input);
+            assert!(result.is_err());
+        }
+    }
+
+    mod bilateral_inputs {
+        use super::*;
+
+        #[test]
+        fn should_succeed_with() {
+            let some: BilateralInputs = ParseInputsFromIter::parse(&["2", "25", "3.5"]).unwrap();
+            assert_eq!(some, BilateralInputs::new(2, 25.0, 3.5));
+        }
+
+        #[pm(input = {
+            &["256", "25", "3.5"],       // [r, _, _]: r not u8
+            &["2", "a", "3.5"],          // [_, c, _]: c not f32
+            &["2", "25"],                // len() == 3 expected
+            &["2", "25", "3.5", "1"],    // len() == 3 expected
+            &[],                         // empty
+        })]
+        fn expected_failures(input: &[&str]) {
+            let result: Result<BilateralInputs, SicParserError> = ParseInputsFromIter::parse(input);
+            assert!(result.is_err());
+        }
+    }
+
+    mod nl_means_inputs {
+        use super::*;
+
+        #[test]
+        fn should_succeed_with() {
+            let some: NlMeansInputs = ParseInputsFromIter::parse(&["10.5", "1", "5"]).unwrap();
+            assert_eq!(some, NlMeansInputs::new(10.5, 1, 5));
+        }
+
+        #[pm(input = {
+            &["a", "1", "5"],           // [s, _, _]: s not f32
+            &["10", "-1", "5"],         // [_, p, _]: p not u32
+            &["10", "1", "5.5"],        // [_, _, w]: w not u32
+            &["10", "1"],               // len() == 3 expected
+            &["10", "1", "5", "1"],     // len() == 3 expected
+            &[],                        // empty
+        })]
+        fn expected_failures(input: &[&str]) {
+            let result: Result<NlMeansInputs, SicParserError> = ParseInputsFromIter::parse(input);
+            assert!(result.is_err());
+        }
+    }
+
+    mod clahe_inputs {
+        use super::*;
+
+        #[test]
+        fn should_succeed_with() {
+            let some: ClaheInputs = ParseInputsFromIter::parse(&["8", "4", "2.5"]).unwrap();
+            assert_eq!(some, ClaheInputs::new(8, 4, 2.5));
+        }
+
+        #[pm(input = {
+            &["-8", "4", "2.5"],        // [x, _, _]: x not u32
+            &["8", "4.5", "2.5"],       // [_, y, _]: y not u32
+            &["8", "4", "a"],           // [_, _, c]: c not f32
+            &["8", "4"],                // len() == 3 expected
+            &["8", "4", "2.5", "1"],    // len() == 3 expected
+            &[],                        // empty
+        })]
+        fn expected_failures(input: &[&str]) {
+            let result: Result<ClaheInputs, SicParserError> = ParseInputsFromIter::parse(input);
+            assert!(result.is_err());
+        }
+    }
+
+    mod equalize_mode {
+        use super::*;
+
+        #[pm(input = {
+            &["luma"],
+            &["Channels"],
+        }, expected = {
+            EqualizeMode::Luma,
+            EqualizeMode::Channels,
+        })]
+        fn should_succeed_with(input: &[&str], expected: EqualizeMode) {
+            let some: EqualizeMode = ParseInputsFromIter::parse(input).unwrap();
+            assert_eq!(some, expected);
+        }
+
+        #[pm(input = {
+            &["rgb"],              // not a mode
+            &["luma", "luma"],     // len() == 1 expected
+            &[],                   // empty
+        })]
+        fn expected_failures(input: &[&str]) {
+            let result: Result<EqualizeMode, SicParserError> = ParseInputsFromIter::parse(input);
+            assert!(result.is_err());
+        }
+    }
+
+    mod frame_range {
+        use super::*;
+
+        #[pm(input = {
+            &["3..10"],
+            &["..=4"],
+            &["2.."],
+        }, expected = {
+            FrameRange::new(Some(3), Some(10), false),
+            FrameRange::new(None, Some(4), true),
+            FrameRange::new(Some(2), None, false),
+        })]
+        fn should_succeed_with(input: &[&str], expected: FrameRange) {
+            let some: FrameRange = ParseInputsFromIter::parse(input).unwrap();
+            assert_eq!(some, expected);
+        }
+
+        #[pm(input = {
+            &["3"],                // not a range
+            &["3..="],             // inclusive range without end
+            &["3..10", "12"],      // len() == 1 expected
+            &[],                   // empty
+        })]
+        fn expected_failures(input: &[&str]) {
+            let result: Result<FrameRange, SicParserError> = ParseInputsFromIter::parse(input);
+            assert!(result.is_err());
+        }
+    }
+
+    mod channel {
+        use super::*;
+
+        #[pm(input = {
+            &["red"],
+            &["G"],
+            &["Alpha"],
+        }, expected = {
+            Channel::Red,
+            Channel::Green,
+            Channel::Alpha,
+        })]
+        fn should_succeed_with(input: &[&str], expected: Channel) {
+            let some: Channel = ParseInputsFromIter::parse(input).unwrap();
+            assert_eq!(some, expected);
+        }
+
+        #[test]
+        fn channel_or_luma() {
+            let some: ChannelOrLuma = ParseInputsFromIter::parse(&["luma"]).unwrap();
+            assert_eq!(some, ChannelOrLuma::Luma);
+        }
+
+        #[test]
+        fn channel_scope() {
+            let some: ChannelScope = ParseInputsFromIter::parse(&["all"]).unwrap();
+            assert_eq!(some, ChannelScope::All);
+        }
+
+        #[test]
+        fn tuple_channel_channel() {
+            let some: (Channel, Channel) = ParseInputsFromIter::parse(&["r", "b"]).unwrap();
+            assert_eq!(some, (Channel::Red, Channel::Blue));
+        }
+
+        #[pm(input = {
+            &["luma"],             // not a channel
+            &["all"],              // not a channel
+            &["red", "red"],       // len() == 1 expected
+            &[],                   // empty
+        })]
+        fn expected_failures(input: &[&str]) {
+            let result: Result<Channel, SicParserError> = ParseInputsFromIter::parse(input);
+            assert!(result.is_err());
+        }
+    }
+
+    mod merge_channels_inputs {
+        use super::*;
+
+        #[test]
+        fn should_succeed_with() {
+            let some: MergeChannelsInputs =
+                ParseInputsFromIter::parse(&["ao.png", "roughness.png", "metalness.png", "keep"])
+                    .unwrap();
+            assert_eq!(
+                some,
+                MergeChannelsInputs::new([
+                    Some(ImageFromPath::new("ao.png".into())),
+                    Some(ImageFromPath::new("roughness.png".into())),
+                    Some(ImageFromPath::new("metalness.png".into())),
+                    None,
+                ])
+            );
+        }
+
+        #[pm(input = {
+            &["r.png", "g.png", "b.png"],                  // len() == 4 expected
+            &["r.png", "g.png", "b.png", "a.png", "x"],    // len() == 4 expected
+            &[],                                           // empty
+        })]
+        fn expected_failures(input: &[&str]) {
+            let result: Result<MergeChannelsInputs, SicParserError> =
                 ParseInputsFromIter::parse(input);
             assert!(result.is_err());
         }
     }
 
-    mod array_f32x9 {
+    mod gradient_inputs {
         use super::*;
+        use sic_core::image::Rgba;
+
+        const RED: Rgba<u8> = Rgba([255, 0, 0, 255]);
+        const BLUE: Rgba<u8> = Rgba([0, 0, 255, 255]);
+
+        #[pm(input = {
+            &["linear", "90", "rgba(255, 0, 0, 255)", "rgba(0, 0, 255, 255)"],
+            &["Radial", "rgba(255, 0, 0, 255) rgba(0, 0, 255, 255)"],
+            &["conic", "-45.5", "rgba(255, 0, 0, 255) 25%", "rgba(0, 0, 255, 255) 75%"],
+            &["linear", "0", "rgba(255,0,0,255) 0% rgba(255,0,0,255)50% rgba(0,0,255,255)"],
+        }, expected = {
+            GradientInputs::new(GradientShape::Linear { angle: 90.0 }, vec![ColorStop::new(RED, None), ColorStop::new(BLUE, None)]),
+            GradientInputs::new(GradientShape::Radial, vec![ColorStop::new(RED, None), ColorStop::new(BLUE, None)]),
+            GradientInputs::new(GradientShape::Conic { angle: -45.5 }, vec![ColorStop::new(RED, Some(0.25)), ColorStop::new(BLUE, Some(0.75))]),
+            GradientInputs::new(GradientShape::Linear { angle: 0.0 }, vec![ColorStop::new(RED, Some(0.0)), ColorStop::new(RED, Some(0.5)), ColorStop::new(BLUE, None)]),
+        })]
+        fn should_succeed_with(input: &[&str], expected: GradientInputs) {
+            let result: GradientInputs = ParseInputsFromIter::parse(input).unwrap();
+            assert_eq!(result, expected);
+        }
+
+        #[pm(input = {
+            &["linear", "rgba(255, 0, 0, 255)", "rgba(0, 0, 255, 255)"],        // angle expected
+            &["radial", "rgba(255, 0, 0, 255)"],                                // two stops expected
+            &["radial", "50% rgba(255, 0, 0, 255) rgba(0, 0, 255, 255)"],       // color before position expected
+            &["radial", "rgba(255, 0, 0, 255) 10% 20% rgba(0, 0, 255, 255)"],   // single position expected
+            &["radial", "rgba(255, 0, 0, 255) x% rgba(0, 0, 255, 255)"],        // position isn't a number
+            &["radial", "rgba(255, 0, 0, 255) 50 rgba(0, 0, 255, 255)"],        // percentage expected
+            &["elliptic", "rgba(255, 0, 0, 255) rgba(0, 0, 255, 255)"],         // unknown shape
+            &[],                                                                // empty
+        })]
+        fn expected_failures(input: &[&str]) {
+            let result: Result<GradientInputs, SicParserError> = ParseInputsFromIter::parse(input);
+            assert!(result.is_err());
+        }
 
         #[test]
-        fn array_of_f32() {
-            let some: [f32; 9] = ParseInputsFromIter::parse(&[
-                "1", "2", "3", "4", "5.5", "-6.0", "7", "8", "-9.9999",
-            ])
-            .unwrap();
-            const EXPECTED: [f32; 9] = [
-                1f32, 2f32, 3f32, 4f32, 5.5f32, -6.0f32, 7f32, 8f32, -9.9999f32,
-            ];
+        fn color_space() {
+            let result: GradientColorSpace = ParseInputsFromIter::parse(&["OKLab"]).unwrap();
+            assert_eq!(result, GradientColorSpace::Oklab);
 
-            assert_iter_f32!(&some, &EXPECTED)
+            let result: Result<GradientColorSpace, SicParserError> =
+                ParseInputsFromIter::parse(&["hsl"]);
+            assert!(result.is_err());
         }
+    }
+
+    mod dither_algorithm {
+        use super::*;
 
         #[pm(input = {
-            &["1", "2", "3", "4", "5.5", "-6.0", "7", "8", "lalala"],               // &[..., x]: x not f32 (not a number)
-            &["1", "2", "3", "4", "5.5", "-6.0", "7", "8"],                         // len() == 9 expected
-            &["1", "2", "3", "4", "5.5", "-6.0", "7", "8", "-9.9999", "1"],         // len() == 9 expected
-            &[],                                                                    // empty
+            &["floyd-steinberg"],
+            &["Atkinson"],
+            &["jarvis-judice-ninke"],
+            &["stucki"],
+            &["sierra"],
+            &["bayer2x2"],
+            &["bayer4x4"],
+            &["BAYER8X8"],
+        }, expected = {
+            DitherAlgorithm::FloydSteinberg,
+            DitherAlgorithm::Atkinson,
+            DitherAlgorithm::JarvisJudiceNinke,
+            DitherAlgorithm::Stucki,
+            DitherAlgorithm::Sierra,
+            DitherAlgorithm::Bayer2x2,
+            DitherAlgorithm::Bayer4x4,
+            DitherAlgorithm::Bayer8x8,
+        })]
+        fn should_succeed_with(input: &[&str], expected: DitherAlgorithm) {
+            let some: DitherAlgorithm = ParseInputsFromIter::parse(input).unwrap();
+            assert_eq!(some, expected);
+        }
+
+        #[pm(input = {
+            &["bayer16x16"],       // not an algorithm
+            &["floyd", "steinberg"], // len() == 1 expected
+            &[],                   // empty
         })]
         fn expected_failures(input: &[&str]) {
-            let result: Result<[f32; 9], SicParserError> = ParseInputsFromIter::parse(input);
-            assert!(result.is_err())
+            let result: Result<DitherAlgorithm, SicParserError> = ParseInputsFromIter::parse(input);
+            assert!(result.is_err());
         }
     }
 
-    mod tuple_u32_u32 {
+    mod quantize_inputs {
+        use super::*;
+        use sic_core::image::Rgba;
+
+        #[pm(input = {
+            &["nearest", "palette.gpl"],
+            &["Dither", "swatches.png"],
+            &["nearest", "rgba(1, 2, 3, 4)"],
+            &["dither", "rgba(1, 2, 3, 4)", "rgba(5, 6, 7, 8)"],
+            &["dither", "rgba(1, 2, 3, 4) rgba(5, 6, 7, 8)"],
+        }, expected = {
+            QuantizeInputs::new(QuantizeMode::Nearest, PaletteSource::Path("palette.gpl".into())),
+            QuantizeInputs::new(QuantizeMode::Dither, PaletteSource::Path("swatches.png".into())),
+            QuantizeInputs::new(QuantizeMode::Nearest, PaletteSource::Colors(vec![Rgba([1, 2, 3, 4])])),
+            QuantizeInputs::new(QuantizeMode::Dither, PaletteSource::Colors(vec![Rgba([1, 2, 3, 4]), Rgba([5, 6, 7, 8])])),
+            QuantizeInputs::new(QuantizeMode::Dither, PaletteSource::Colors(vec![Rgba([1, 2, 3, 4]), Rgba([5, 6, 7, 8])])),
+        })]
+        fn should_succeed_with(input: &[&str], expected: QuantizeInputs) {
+            let some: QuantizeInputs = ParseInputsFromIter::parse(input).unwrap();
+            assert_eq!(some, expected);
+        }
+
+        #[pm(input = {
+            &["closest", "palette.gpl"],                   // [m, _]: m not a mode
+            &["nearest"],                                  // no palette
+            &["nearest", "palette.gpl", "rgba(1, 2, 3, 4)"], // [_, p, _]: p not a color
+            &["nearest", "rgba(1, 2, 3, 4)", "size(1)"],   // [_, _, c]: c not rgba
+            &[],                                           // empty
+        })]
+        fn expected_failures(input: &[&str]) {
+            let result: Result<QuantizeInputs, SicParserError> = ParseInputsFromIter::parse(input);
+            assert!(result.is_err());
+        }
+    }
+
+    mod overlay_inputs {
+        use super::*;
+        use sic_image_engine::wrapper::image_path::ImageFromPath;
+
+        fn overlay(path: &str, placement: impl Into<OverlayPlacement>) -> OverlayInputs {
+            OverlayInputs::new(ImageFromPath::new(path.into()), placement)
+        }
+
+        const ANCHORED: OverlayPlacement = OverlayPlacement::Anchored {
+            gravity: Gravity::SouthEast,
+            margin: (10, 5),
+        };
+
+        const TILED: OverlayPlacement = OverlayPlacement::Tiled {
+            spacing: (20, 30),
+            degrees: -45.0,
+        };
+
+        #[pm(input = {
+            &["logo.png", "10", "20"],
+            &["multiply", "0.5", "logo.png", "10", "20"],
+            &["Soft-Light", "1", "logo.png", "0", "0"],
+            &["src-over", "0", "logo.png", "0", "0"],
+            &["logo.png", "south-east", "10", "5"],
+            &["logo.png", "tile", "20", "30", "-45"],
+            &["width", "20%", "logo.png", "south-east", "10", "5"],
+            &["screen", "0.5", "width", "64", "logo.png", "tile", "20", "30", "-45"],
+        }, expected = {
+            overlay("logo.png", (10, 20)),
+            overlay("logo.png", (10, 20)).with_blending(BlendMode::Multiply, 0.5),
+            overlay("logo.png", (0, 0)).with_blending(BlendMode::SoftLight, 1.0),
+            overlay("logo.png", (0, 0)).with_blending(BlendMode::Normal, 0.0),
+            overlay("logo.png", ANCHORED),
+            overlay("logo.png", TILED),
+            overlay("logo.png", ANCHORED).with_width(ResizeValue::Percentage(20.0)),
+            overlay("logo.png", TILED).with_blending(BlendMode::Screen, 0.5).with_width(ResizeValue::Pixels(64)),
+        })]
+        fn should_succeed_with(input: &[&str], expected: OverlayInputs) {
+            let some: OverlayInputs = ParseInputsFromIter::parse(input).unwrap();
+            assert_eq!(some, expected);
+        }
+
+        #[pm(input = {
+            &["multiply", "logo.png", "0", "0"],     // missing opacity
+            &["multiply", "0.5", "logo.png", "0"],   // missing y
+            &["logo.png", "0", "0", "multiply"],     // blend mode after the position
+            &["logo.png", "south", "10"],            // missing y margin
+            &["logo.png", "south", "-1", "0"],       // negative margin
+            &["logo.png", "tile", "20", "30"],       // missing rotation
+            &["width", "logo.png", "0", "0"],        // missing width
+            &["logo.png", "width", "20%", "0", "0"], // width after the path
+            &[],                                     // empty
+        })]
+        fn expected_failures(input: &[&str]) {
+            let result: Result<OverlayInputs, SicParserError> = ParseInputsFromIter::parse(input);
+            assert!(result.is_err());
+        }
+    }
+
+    mod rgba {
         use super::*;
+        use sic_core::image::Rgba;
 
         #[test]
-        fn a_tuple_of_u32_u32() {
-            let some: (u32, u32) = ParseInputsFromIter::parse(&["03579", "0"]).unwrap();
-            assert_eq!(some, (3579u32, 0u32))
+        fn should_succeed_with() {
+            let some: Rgba<u8> = ParseInputsFromIter::parse(&["rgba(1, 2, 3, 4)"]).unwrap();
+            assert_eq!(some, Rgba([1, 2, 3, 4]));
         }
 
         #[pm(input = {
-            &["4", "-0"],   // [_x, y]: y not u32 (neg 0)
-            &["4", "-4"],   // [_x, y]: y not u32 (neg number)
-            &["4", "f"],    // [_x, y]: y not u32 (not a number)
-            &["03579"],     // len() == 2 expected
-            &[],            // empty
+            &["rgb(1, 2, 3)"],                           // not rgba
+            &["rgba(1, 2, 3, 4)", "rgba(1, 2, 3, 4)"],   // len() == 1 expected
+            &[],                                         // empty
         })]
         fn expected_failures(input: &[&str]) {
-            let result: Result<(u32, u32), SicParserError> = ParseInputsFromIter::parse(input);
+            let result: Result<Rgba<u8>, SicParserError> = ParseInputsFromIter::parse(input);
+            assert!(result.is_err());
+        }
+    }
+
+    mod chroma_key_inputs {
+        use super::*;
+        use sic_core::image::Rgba;
+
+        #[test]
+        fn should_succeed_with() {
+            let some: ChromaKeyInputs =
+                ParseInputsFromIter::parse(&["rgba(0, 255, 0, 255)", "40", "10.5"]).unwrap();
+            assert_eq!(
+                some,
+                ChromaKeyInputs::new(Rgba([0, 255, 0, 255]), 40.0, 10.5)
+            );
+        }
+
+        #[test]
+        fn should_succeed_with_despill() {
+            let some: ChromaKeyInputs =
+                ParseInputsFromIter::parse(&["despill", "rgba(0, 255, 0, 255)", "40", "0"])
+                    .unwrap();
+            assert_eq!(
+                some,
+                ChromaKeyInputs::new(Rgba([0, 255, 0, 255]), 40.0, 0.0).with_despill(true)
+            );
+        }
+
+        #[pm(input = {
+            &["rgb(0, 255, 0)", "40", "10"],                       // [c, _, _]: c not rgba
+            &["rgba(0, 255, 0, 255)", "a", "10"],                  // [_, x, _]: x not f32
+            &["rgba(0, 255, 0, 255)", "40"],                       // len() == 3 expected
+            &["rgba(0, 255, 0, 255)", "40", "10", "despill"],      // despill comes first
+            &["spill", "rgba(0, 255, 0, 255)", "40", "10"],        // unknown keyword
+            &[],                                                   // empty
+        })]
+        fn expected_failures(input: &[&str]) {
+            let result: Result<ChromaKeyInputs, SicParserError> = ParseInputsFromIter::parse(input);
+            assert!(result.is_err());
+        }
+    }
+
+    mod pad_inputs {
+        use super::*;
+        use sic_core::image::Rgba;
+
+        #[test]
+        fn should_succeed_with() {
+            let some: PadInputs =
+                ParseInputsFromIter::parse(&["1", "2", "3", "4", "rgba(0, 0, 0, 255)"]).unwrap();
+            assert_eq!(some, PadInputs::new((1, 2, 3, 4), Rgba([0, 0, 0, 255])));
+        }
+
+        #[pm(input = {
+            &["-1", "2", "3", "4", "rgba(0, 0, 0, 255)"],      // [x, _, _, _, _]: x not u32
+            &["1", "2", "3", "4", "rgb(0, 0, 0)"],             // [_, _, _, _, c]: c not rgba
+            &["1", "2", "3", "rgba(0, 0, 0, 255)"],            // len() == 5 expected
+            &["1", "2", "3", "4", "rgba(0, 0, 0, 255)", "5"],  // len() == 5 expected
+            &[],                                               // empty
+        })]
+        fn expected_failures(input: &[&str]) {
+            let result: Result<PadInputs, SicParserError> = ParseInputsFromIter::parse(input);
+            assert!(result.is_err());
+        }
+    }
+
+    mod extend_canvas_inputs {
+        use super::*;
+        use sic_core::image::Rgba;
+
+        #[test]
+        fn should_succeed_with() {
+            let some: ExtendCanvasInputs =
+                ParseInputsFromIter::parse(&["10", "20", "south", "rgba(1, 2, 3, 4)"]).unwrap();
+            assert_eq!(
+                some,
+                ExtendCanvasInputs::new(10, 20, Gravity::South, Rgba([1, 2, 3, 4]))
+            );
+        }
+
+        #[pm(input = {
+            &["a", "20", "south", "rgba(1, 2, 3, 4)"],           // [x, _, _, _]: x not u32
+            &["10", "20", "below", "rgba(1, 2, 3, 4)"],          // [_, _, g, _]: g not a gravity
+            &["10", "20", "south", "1"],                         // [_, _, _, c]: c not rgba
+            &["10", "20", "south"],                              // len() == 4 expected
+            &["10", "20", "south", "rgba(1, 2, 3, 4)", "1"],     // len() == 4 expected
+            &[],                                                 // empty
+        })]
+        fn expected_failures(input: &[&str]) {
+            let result: Result<ExtendCanvasInputs, SicParserError> =
+                ParseInputsFromIter::parse(input);
+            assert!(result.is_err());
+        }
+    }
+
+    mod sprite_sheet_inputs {
+        use super::*;
+        use sic_core::image::Rgba;
+
+        #[test]
+        fn should_succeed_with() {
+            let some: SpriteSheetInputs =
+                ParseInputsFromIter::parse(&["4", "2", "rgba(0, 0, 0, 0)"]).unwrap();
+            assert_eq!(some, SpriteSheetInputs::new(4, 2, Rgba([0, 0, 0, 0])));
+        }
+
+        #[test]
+        fn should_succeed_with_atlas() {
+            let some: SpriteSheetInputs =
+                ParseInputsFromIter::parse(&["atlas", "sheet.json", "4", "2", "rgba(0, 0, 0, 0)"])
+                    .unwrap();
+            assert_eq!(
+                some,
+                SpriteSheetInputs::new(4, 2, Rgba([0, 0, 0, 0])).with_atlas("sheet.json".into())
+            );
+        }
+
+        #[pm(input = {
+            &["-1", "2", "rgba(0, 0, 0, 0)"],                    // [x, _, _]: x not u32
+            &["4", "2", "rgb(0, 0, 0)"],                         // [_, _, c]: c not rgba
+            &["4", "rgba(0, 0, 0, 0)"],                          // len() == 3 expected
+            &["4", "2", "rgba(0, 0, 0, 0)", "1"],                // len() == 3 expected
+            &["atlas", "4", "2", "rgba(0, 0, 0, 0)"],            // atlas without path
+            &[],                                                 // empty
+        })]
+        fn expected_failures(input: &[&str]) {
+            let result: Result<SpriteSheetInputs, SicParserError> =
+                ParseInputsFromIter::parse(input);
+            assert!(result.is_err());
+        }
+    }
+
+    mod split_sprite_sheet_inputs {
+        use super::*;
+
+        #[test]
+        fn should_succeed_with() {
+            let some: SplitSpriteSheetInputs =
+                ParseInputsFromIter::parse(&["32", "16", "1"]).unwrap();
+            assert_eq!(some, SplitSpriteSheetInputs::new((32, 16), 1));
+        }
+
+        #[pm(input = {
+            &["32", "-16", "1"],        // [_, x, _]: x not u32
+            &["32", "16"],              // len() == 3 expected
+            &["32", "16", "1", "1"],    // len() == 3 expected
+            &[],                        // empty
+        })]
+        fn expected_failures(input: &[&str]) {
+            let result: Result<SplitSpriteSheetInputs, SicParserError> =
+                ParseInputsFromIter::parse(input);
+            assert!(result.is_err());
+        }
+    }
+
+    mod resize_inputs {
+        use super::*;
+
+        #[pm(input = {
+            (&["100", "200"], ResizeInputs::from((100, 200))),
+            (&["50%", "12.5%"], ResizeInputs::Dimensions(ResizeValue::Percentage(50.0), ResizeValue::Percentage(12.5))),
+            (&["exact", "100", "50%"], ResizeInputs::Exact(ResizeValue::Pixels(100), ResizeValue::Percentage(50.0))),
+            (&["fit", "100", "200"], ResizeInputs::Fit(ResizeValue::Pixels(100), ResizeValue::Pixels(200))),
+            (&["COVER", "100", "200"], ResizeInputs::Cover(ResizeValue::Pixels(100), ResizeValue::Pixels(200))),
+            (&["width-only", "100"], ResizeInputs::WidthOnly(ResizeValue::Pixels(100))),
+            (&["height-only", "25%"], ResizeInputs::HeightOnly(ResizeValue::Percentage(25.0))),
+        })]
+        fn should_succeed_with(input: (&[&str], ResizeInputs)) {
+            let some: ResizeInputs = ParseInputsFromIter::parse(input.0).unwrap();
+            assert_eq!(some, input.1);
+        }
+
+        #[pm(input = {
+            &["100"],                       // len() == 2 expected
+            &["100", "200", "300"],         // len() == 2 expected
+            &["-100", "200"],               // [x, _]: x not a resize value
+            &["100", "0%"],                 // [_, y]: y not a positive percentage
+            &["stretch", "100", "200"],     // unknown mode
+            &["fit", "100"],                // mode fit takes two values
+            &["width-only", "100", "200"],  // mode width-only takes one value
+            &["height-only"],               // mode height-only takes one value
+            &[],                            // empty
+        })]
+        fn expected_failures(input: &[&str]) {
+            let result: Result<ResizeInputs, SicParserError> = ParseInputsFromIter::parse(input);
+            assert!(result.is_err());
+        }
+    }
+
+    mod rotate_inputs {
+        use super::*;
+        use sic_core::image::Rgba;
+
+        #[test]
+        fn should_succeed_with() {
+            let some: RotateInputs =
+                ParseInputsFromIter::parse(&["-12.5", "expand", "rgba(0, 0, 0, 0)"]).unwrap();
+            assert_eq!(
+                some,
+                RotateInputs::new(-12.5, RotateCanvas::Expand, Rgba([0, 0, 0, 0]))
+            );
+        }
+
+        #[pm(input = {
+            &["a", "crop", "rgba(0, 0, 0, 0)"],             // [x, _, _]: x not f32
+            &["45", "shrink", "rgba(0, 0, 0, 0)"],          // [_, y, _]: y not a canvas option
+            &["45", "crop", "rgb(0, 0, 0)"],                // [_, _, z]: z not rgba
+            &["45", "crop"],                                // len() == 3 expected
+            &["45", "crop", "rgba(0, 0, 0, 0)", "1"],       // len() == 3 expected
+            &[],                                            // empty
+        })]
+        fn expected_failures(input: &[&str]) {
+            let result: Result<RotateInputs, SicParserError> = ParseInputsFromIter::parse(input);
             assert!(result.is_err());
         }
     }
@@ -534,4 +2324,26 @@ mod tests_parse_from_iter {
             assert!(result.is_err());
         }
     }
+
+    mod tuple_f32_f32 {
+        use super::*;
+
+        #[test]
+        fn a_tuple_of_f32_f32() {
+            let some: (f32, f32) = ParseInputsFromIter::parse(&["50", "-1.5"]).unwrap();
+            assert_eq!(some, (50f32, -1.5f32))
+        }
+
+        #[pm(input = {
+            &["-f", "-1"],      // [x, _y]: x not f32
+            &["-1.0", "f"],     // [_x, y]: y not f32
+            &["4"],             // len() == 2 expected
+            &["4", "4", "4"],   // len() == 2 expected
+            &[],                // empty
+        })]
+        fn expected_failures(input: &[&str]) {
+            let result: Result<(f32, f32), SicParserError> = ParseInputsFromIter::parse(input);
+            assert!(result.is_err());
+        }
+    }
 }
@@ -35,6 +35,11 @@ define_arg_consts!(arg_names, {
     // config for glob/batch mode
     ARG_GLOB_NO_SKIP_UNSUPPORTED_EXTENSIONS,
 
+    // assemble an animation from multiple input images
+    ARG_ASSEMBLE,
+    ARG_ASSEMBLE_GLOB,
+    ARG_ASSEMBLE_FRAME_DELAY,
+
     // set specific configurations for decoding
     ARG_SELECT_FRAME,
 
@@ -44,6 +49,7 @@ define_arg_consts!(arg_names, {
     ARG_JPEG_ENCODING_QUALITY,
     ARG_PNM_ENCODING_ASCII,
     ARG_GIF_REPEAT,
+    ARG_OUTPUT_FRAMES,
 
     // provide image operations using image script
     ARG_APPLY_OPERATIONS,
@@ -70,8 +76,89 @@ fn wrap_with(app: Command) -> Command {
             .long(OperationId::Threshold.as_str())
             .action(ArgAction::Count),
     )
+    .arg(
+        Arg::new(OperationId::Sobel.as_str())
+            .help("Operation: compute the gradient magnitudes of the input image using the Sobel operator, resulting in a gray edge map")
+            .long(OperationId::Sobel.as_str())
+            .action(ArgAction::Count),
+    )
+    .arg(
+        Arg::new(OperationId::Scharr.as_str())
+            .help("Operation: compute the gradient magnitudes of the input image using the Scharr operator, resulting in a gray edge map")
+            .long(OperationId::Scharr.as_str())
+            .action(ArgAction::Count),
+    )
+    .arg(
+        Arg::new(OperationId::Laplacian.as_str())
+            .help("Operation: apply a 3x3 Laplacian filter to the input image, resulting in a gray edge map")
+            .long(OperationId::Laplacian.as_str())
+            .action(ArgAction::Count),
+    )
+    .arg(
+        Arg::new(OperationId::Canny.as_str())
+            .help("Operation: detect edges using the Canny edge detector, with a low and high hysteresis threshold (the largest sensible threshold is about 1140), resulting in a black and white edge map")
+            .long(OperationId::Canny.as_str())
+            .action(ArgAction::Append)
+            .value_names(["low threshold", "high threshold"])
+            .num_args(2),
+    )
+    .arg(
+        Arg::new(OperationId::Erode.as_str())
+            .help("Operation: replace each pixel by the minimum of its neighbourhood; shrinks bright regions. The neighbourhood (structuring element) consists of the pixels within the given radius, measured by the norm: 'l1' (diamond), 'l2' (disk) or 'linf' (square)")
+            .long(OperationId::Erode.as_str())
+            .action(ArgAction::Append)
+            .value_names(["radius", "norm"])
+            .num_args(2),
+    )
+    .arg(
+        Arg::new(OperationId::Dilate.as_str())
+            .help("Operation: replace each pixel by the maximum of its neighbourhood; grows bright regions. The neighbourhood (structuring element) consists of the pixels within the given radius, measured by the norm: 'l1' (diamond), 'l2' (disk) or 'linf' (square)")
+            .long(OperationId::Dilate.as_str())
+            .action(ArgAction::Append)
+            .value_names(["radius", "norm"])
+            .num_args(2),
+    )
+    .arg(
+        Arg::new(OperationId::Open.as_str())
+            .help("Operation: erode and then dilate; removes bright details smaller than the structuring element, such as noise in a mask. The neighbourhood (structuring element) consists of the pixels within the given radius, measured by the norm: 'l1' (diamond), 'l2' (disk) or 'linf' (square)")
+            .long(OperationId::Open.as_str())
+            .action(ArgAction::Append)
+            .value_names(["radius", "norm"])
+            .num_args(2),
+    )
+    .arg(
+        Arg::new(OperationId::Close.as_str())
+            .help("Operation: dilate and then erode; fills dark details smaller than the structuring element, such as holes in a mask. The neighbourhood (structuring element) consists of the pixels within the given radius, measured by the norm: 'l1' (diamond), 'l2' (disk) or 'linf' (square)")
+            .long(OperationId::Close.as_str())
+            .action(ArgAction::Append)
+            .value_names(["radius", "norm"])
+            .num_args(2),
+    )
+    .arg(
+        Arg::new(OperationId::MorphGradient.as_str())
+            .help("Operation: subtract the eroded from the dilated image; outlines regions. The neighbourhood (structuring element) consists of the pixels within the given radius, measured by the norm: 'l1' (diamond), 'l2' (disk) or 'linf' (square)")
+            .long(OperationId::MorphGradient.as_str())
+            .action(ArgAction::Append)
+            .value_names(["radius", "norm"])
+            .num_args(2),
+    )
+    .arg(
+        Arg::new(OperationId::Median.as_str())
+            .help("Operation: replace each channel of each pixel by the median within a square window of the given radius, removing salt-and-pepper noise while preserving edges")
+            .long(OperationId::Median.as_str())
+            .action(ArgAction::Append)
+            .value_name("radius")
+            .num_args(1),
+    )
+    .arg(
+        Arg::new(OperationId::Bilateral.as_str())
+            .help("Operation: smooth the input image while preserving edges, by averaging each window of the given radius weighted by both color distance and spatial distance. The color sigma is on a scale of 0 to 255, the spatial sigma is in pixels")
+            .long(OperationId::Bilateral.as_str())
+            .action(ArgAction::Append)
+            .value_names(["radius", "color sigma", "spatial sigma"])
+            .num_args(3),
+    )
 }
-
 pub fn create_app(version: &'static str, about: &'static str, help_ops: &'static str) -> Command {
     wrap_with(Command::new("imagineer")
         .version(version)
@@ -91,12 +178,12 @@ pub fn create_app(version: &'static str, about: &'static str, help_ops: &'static
             .long("license")
             .help("Displays the license of this piece of software (`imagineer`).")
             .action(ArgAction::SetTrue)
-            .conflicts_with_all([ARG_DEP_LICENSES, ARG_INPUT, ARG_OUTPUT, ARG_INPUT_GLOB, ARG_OUTPUT_GLOB]))
+            .conflicts_with_all([ARG_DEP_LICENSES, ARG_INPUT, ARG_OUTPUT, ARG_INPUT_GLOB, ARG_OUTPUT_GLOB, ARG_ASSEMBLE, ARG_ASSEMBLE_GLOB]))
         .arg(Arg::new(ARG_DEP_LICENSES)
             .long("dep-licenses")
             .help("Displays the licenses of the dependencies on which this software relies.")
             .action(ArgAction::SetTrue)
-            .conflicts_with_all([ARG_LICENSE, ARG_INPUT, ARG_OUTPUT, ARG_INPUT_GLOB, ARG_OUTPUT_GLOB]))
+            .conflicts_with_all([ARG_LICENSE, ARG_INPUT, ARG_OUTPUT, ARG_INPUT_GLOB, ARG_OUTPUT_GLOB, ARG_ASSEMBLE, ARG_ASSEMBLE_GLOB]))
 
         // io(input):
         .arg(Arg::new(ARG_INPUT)
@@ -139,10 +226,39 @@ pub fn create_app(version: &'static str, about: &'static str, help_ops: &'static
         .arg(Arg::new(ARG_GLOB_NO_SKIP_UNSUPPORTED_EXTENSIONS)
             .long("no-skip-unsupported-extensions")
             .help("Files which don't have a known extension will not be skipped in glob mode")
-            .long_help("Only has an effect when combined with --glob-input")
+            .long_help("Only has an effect when combined with --glob-input or --assemble-glob")
             .action(ArgAction::SetTrue)
         )
 
+        // assemble an animation from multiple input images
+        .arg(Arg::new(ARG_ASSEMBLE)
+            .long("assemble")
+            .value_name("INPUT_PATHS")
+            .action(ArgAction::Append)
+            .num_args(1..)
+            .help("Assemble the given input images, in the given order, into the frames of an animated image. Use with --output.")
+            .long_help("Assemble the given input images, in the given order, into the frames of an animated image. Use with --output. \
+                Each frame is shown for --assemble-frame-delay milliseconds, and the animation is repeated as set by --gif-repeat. \
+                Images which do not have the dimensions of the first image are resized to fit within its canvas and centered. \
+                For animated input images, the frame selected with --select-frame (by default the first frame) is used.")
+            .conflicts_with_all([ARG_LICENSE, ARG_DEP_LICENSES, ARG_INPUT, ARG_INPUT_GLOB, ARG_OUTPUT_GLOB, ARG_ASSEMBLE_GLOB])
+        )
+
+        .arg(Arg::new(ARG_ASSEMBLE_GLOB)
+            .long("assemble-glob")
+            .value_name("GLOB_INPUT_PATTERN")
+            .action(ArgAction::Set)
+            .help("Like --assemble, but takes the images matching the given glob pattern, sorted by path in natural order (e.g. 'frame_2.png' before 'frame_10.png').")
+            .conflicts_with_all([ARG_LICENSE, ARG_DEP_LICENSES, ARG_INPUT, ARG_INPUT_GLOB, ARG_OUTPUT_GLOB, ARG_ASSEMBLE])
+        )
+
+        .arg(Arg::new(ARG_ASSEMBLE_FRAME_DELAY)
+            .long("assemble-frame-delay")
+            .value_name("MILLISECONDS")
+            .action(ArgAction::Set)
+            .help("Delay in milliseconds of each frame of an animation assembled with --assemble or --assemble-glob. Defaults to 100.")
+        )
+
         // config(in):
         .arg(Arg::new(ARG_SELECT_FRAME)
             .long("select-frame")
@@ -182,11 +298,18 @@ pub fn create_app(version: &'static str, about: &'static str, help_ops: &'static
 
         .arg(Arg::new(ARG_GIF_REPEAT)
             .long("gif-repeat")
-            .help("Repeat the frames of a (to be) gif encoded image `n` times, `infinite` times or `never`.")
+            .help("Repeat the frames of a (to be) gif, apng or webp encoded animated image `n` times, `infinite` times or `never`.")
             .value_name("REPETITIONS")
             .action(ArgAction::Set)
         )
 
+        .arg(Arg::new(ARG_OUTPUT_FRAMES)
+            .long("output-frames")
+            .action(ArgAction::SetTrue)
+            .help("Write each frame of an animated image to a separate file, named after the output path with the zero-based frame index appended (e.g. 'out_0.png', 'out_1.png', ...). Indices are zero-padded to the same width, so the files sort in frame order.")
+            .requires(ARG_OUTPUT)
+        )
+
         // image-operations(script):
         .arg(Arg::new(ARG_APPLY_OPERATIONS)
             .long("apply-operations")
@@ -219,6 +342,10 @@ pub fn create_app(version: &'static str, about: &'static str, help_ops: &'static
             .value_name("fp")
             .num_args(1)
             .allow_hyphen_values(true))
+        .arg(Arg::new(OperationId::Boomerang.as_str())
+            .help("Operation: append the frames of an animated image in reverse order, so it plays forwards and then backwards; the first and last frame aren't repeated")
+            .long(OperationId::Boomerang.as_str())
+            .action(ArgAction::Count))
         .arg(Arg::new(OperationId::Brighten.as_str())
             .help("Operation: increase or decrease the brightness of the input image")
             .long(OperationId::Brighten.as_str())
@@ -226,6 +353,35 @@ pub fn create_app(version: &'static str, about: &'static str, help_ops: &'static
             .value_name("int")
             .num_args(1)
             .allow_hyphen_values(true))
+        .arg(Arg::new(OperationId::ChromaKey.as_str())
+            .help("Operation: make pixels near a key color transparent, e.g. to remove a green screen or a white backdrop")
+            .long_help("Pixels of which the color lies within the tolerance of the key color become fully transparent. Beyond the \
+                tolerance, pixels fade to their original opacity over the softness. Both are distances in RGB space, on a scale of \
+                0 to 255 per channel. If 'despill' is given as first argument, the tint of the key color is also removed from the \
+                remaining pixels, e.g. '--chroma-key despill \"rgba(0, 255, 0, 255)\" 60 20'.")
+            .long(OperationId::ChromaKey.as_str())
+            .action(ArgAction::Append)
+            .value_names(["[despill]", "rgba(r,g,b,a)", "tolerance", "softness"])
+            .num_args(3..=4))
+        .arg(Arg::new(OperationId::Clahe.as_str())
+            .help("Operation: enhance local contrast using contrast limited adaptive histogram equalization (CLAHE), which equalizes the luma of each tile of a grid separately. The clip limit (a multiple of the average histogram bin height, e.g. 2) limits the contrast enhancement")
+            .long(OperationId::Clahe.as_str())
+            .action(ArgAction::Append)
+            .value_names(["tiles x", "tiles y", "clip limit"])
+            .num_args(3))
+        .arg(Arg::new(OperationId::ColorMatrix.as_str())
+            .help("Operation: apply a 4x5 color matrix (20 values, in row-major order) to each RGBA pixel, or use one of the presets 'sepia', 'swap-rb', 'desaturate', 'protanopia' or 'luminance-to-alpha'")
+            .long(OperationId::ColorMatrix.as_str())
+            .action(ArgAction::Append)
+            .value_name("preset or matrix")
+            .num_args(1..=20)
+            .allow_negative_numbers(true))
+        .arg(Arg::new(OperationId::ColorToAlpha.as_str())
+            .help("Operation: remove a color from the input image, making each pixel as transparent as possible while its appearance over the color is preserved")
+            .long(OperationId::ColorToAlpha.as_str())
+            .action(ArgAction::Append)
+            .value_name("rgba(r,g,b,a)")
+            .num_args(1))
         .arg(Arg::new(OperationId::Contrast.as_str())
             .help("Operation: increase or decrease the contrast of the input image")
             .long(OperationId::Contrast.as_str())
@@ -239,6 +395,21 @@ pub fn create_app(version: &'static str, about: &'static str, help_ops: &'static
             .action(ArgAction::Append)
             .value_names(["lx", "ly", "rx", "ry"])
             .num_args(4))
+        .arg(Arg::new(OperationId::CropTo.as_str())
+            .help("Operation: crop the input image to width by height pixels, anchored by gravity; the selection is clamped to the image bounds")
+            .long_help("The gravity determines where the selection is anchored, and is one of 'north-west', 'north', 'north-east', \
+                'west', 'center', 'east', 'south-west', 'south' or 'south-east'. If the image is smaller than the selection, \
+                the selection is clamped to the dimensions of the image.")
+            .long(OperationId::CropTo.as_str())
+            .action(ArgAction::Append)
+            .value_names(["width", "height", "gravity"])
+            .num_args(3))
+        .arg(Arg::new(OperationId::Curves.as_str())
+            .help("Operation: remap the tones of the given channel (rgb, red, green, blue or luminance) through a smooth curve interpolated between the given input:output control points, e.g. '0:0,64:50,255:255'")
+            .long(OperationId::Curves.as_str())
+            .action(ArgAction::Append)
+            .value_names(["channel", "points"])
+            .num_args(2))
         .arg(Arg::new(OperationId::Diff.as_str())
             .help("Operation: show ")
             .long(OperationId::Diff.as_str())
@@ -256,17 +427,68 @@ pub fn create_app(version: &'static str, about: &'static str, help_ops: &'static
             .action(ArgAction::Append)
             .value_names(["colors", "sample factor"])
             .num_args(2))
+        .arg(Arg::new(OperationId::DropFrames.as_str())
+            .help("Operation: remove every n-th frame of an animated image, e.g. '2' removes every second frame")
+            .long(OperationId::DropFrames.as_str())
+            .action(ArgAction::Append)
+            .value_name("n")
+            .num_args(1))
+        .arg(Arg::new(OperationId::DuplicateFrame.as_str())
+            .help("Operation: insert the given number of copies of the frame at the given zero-based index of an animated image, directly after that frame")
+            .long(OperationId::DuplicateFrame.as_str())
+            .action(ArgAction::Append)
+            .value_names(["index", "copies"])
+            .num_args(2))
         .arg(Arg::new(OperationId::IndexColors.as_str())
-            .help("Operation: map each pixel to the index of its nearest black or white palette entry (BiLevel); the output holds the palette index per pixel")
+            .help("Operation: map each pixel to the index of its nearest black or white palette entry (BiLevel); PNG and GIF outputs are written as palette based images")
             .long(OperationId::IndexColors.as_str())
             .action(ArgAction::Count))
         .arg(Arg::new(OperationId::IndexColorsQuant.as_str())
-            .help("Operation: train a color palette on the image's own pixels (NeuQuant) and map each pixel to the index of its nearest palette entry; the output holds the palette index per pixel")
-            .long_help("Takes the number of palette colors (>= 64) and a sample factor in the range [1, 30] (1 = best quality and slowest, 10 = good compromise)")
+            .help("Operation: train a color palette on the image's own pixels (NeuQuant) and map each pixel to the index of its nearest palette entry; PNG and GIF outputs are written as palette based images")
+            .long_help("Takes the number of palette colors (in the range [64, 256]) and a sample factor in the range [1, 30] (1 = best quality and slowest, 10 = good compromise)")
             .long(OperationId::IndexColorsQuant.as_str())
             .action(ArgAction::Append)
             .value_names(["colors", "sample factor"])
             .num_args(2))
+        .arg(Arg::new(OperationId::Equalize.as_str())
+            .help("Operation: spread the values of the input image over the full range using histogram equalization, either of the luma ('luma') or of each color channel independently ('channels')")
+            .long(OperationId::Equalize.as_str())
+            .action(ArgAction::Append)
+            .value_name("mode")
+            .num_args(1))
+        .arg(Arg::new(OperationId::Exposure.as_str())
+            .help("Operation: adjust the exposure of the input image by the given number of stops; positive values brighten, negative values darken")
+            .long(OperationId::Exposure.as_str())
+            .action(ArgAction::Append)
+            .value_name("stops")
+            .num_args(1)
+            .allow_hyphen_values(true))
+        .arg(Arg::new(OperationId::ExtendCanvas.as_str())
+            .help("Operation: place the input image on a canvas of width by height pixels, anchored by gravity, and fill the uncovered canvas with a color")
+            .long_help("The gravity determines where the image is placed on the canvas, and is one of 'north-west', 'north', \
+                'north-east', 'west', 'center', 'east', 'south-west', 'south' or 'south-east'. If the canvas is smaller than the \
+                image, the parts of the image which fall outside the canvas are cut off. Can be used to letterbox images.")
+            .long(OperationId::ExtendCanvas.as_str())
+            .action(ArgAction::Append)
+            .value_names(["width", "height", "gravity", "rgba(r,g,b,a)"])
+            .num_args(4))
+        .arg(Arg::new(OperationId::ExtractAlpha.as_str())
+            .help("Operation: replace the input image by a grayscale image of its alpha channel; images without alpha channel result in a white image")
+            .long(OperationId::ExtractAlpha.as_str())
+            .action(ArgAction::Count))
+        .arg(Arg::new(OperationId::ExtractChannel.as_str())
+            .help("Operation: replace the input image by a grayscale image of one of its channels ('red', 'green', 'blue' or 'alpha', or 'r', 'g', 'b' or 'a'), or of its luma ('luma')")
+            .long(OperationId::ExtractChannel.as_str())
+            .action(ArgAction::Append)
+            .value_name("channel")
+            .num_args(1))
+        .arg(Arg::new(OperationId::Filter.as_str())
+            .help("Operation: convolve the input image with a kernel of any odd size, e.g. '--filter 3x3 auto 0 clamp 1 1 1 1 1 1 1 1 1'. The shape is '<width>x<height>' (followed by width * height kernel values) or '<width>x<height>-separable' (followed by a row vector of width values and a column vector of height values). The optional divisor is either 'auto' (the sum of the kernel values, the default) or a number, the optional bias (0 by default) is added after dividing, and the edge mode is one of 'clamp', 'wrap' or 'mirror'")
+            .long(OperationId::Filter.as_str())
+            .action(ArgAction::Append)
+            .value_names(["shape", "[divisor]", "[bias]", "edge mode", "values"])
+            .num_args(3..)
+            .allow_negative_numbers(true))
         .arg(Arg::new(OperationId::Filter3x3.as_str())
             .help("Operation: apply a 3x3 convolution filter to the input image (matrix arguments should be given left-to-right, top-to-bottom)")
             .long(OperationId::Filter3x3.as_str())
@@ -274,6 +496,15 @@ pub fn create_app(version: &'static str, about: &'static str, help_ops: &'static
             .value_names(["fp", "fp", "fp", "fp", "fp", "fp", "fp", "fp", "fp"])
             .num_args(9)
             .allow_hyphen_values(true))
+        .arg(Arg::new(OperationId::Flatten.as_str())
+            .help("Operation: composite the input image over a background color")
+            .long_help("If the background color is fully opaque, the alpha channel is removed afterwards. Use this operation \
+                before writing images with transparency to formats without alpha channel support, such as JPEG, where the \
+                alpha channel would otherwise be dropped, e.g. '--flatten \"rgba(255, 255, 255, 255)\"'.")
+            .long(OperationId::Flatten.as_str())
+            .action(ArgAction::Append)
+            .value_name("rgba(r,g,b,a)")
+            .num_args(1))
         .arg(Arg::new(OperationId::FlipHorizontal.as_str())
             .help("Operation: flip the input image horizontally")
             .long(OperationId::FlipHorizontal.as_str())
@@ -282,6 +513,35 @@ pub fn create_app(version: &'static str, about: &'static str, help_ops: &'static
             .help("Operation: flip the input image vertically")
             .long(OperationId::FlipVertical.as_str())
             .action(ArgAction::Count))
+        .arg(Arg::new(OperationId::Frames.as_str())
+            .help("Operation: keep the frames of an animated image within the given range of zero-based frame indices, and remove all other frames")
+            .long_help("The range is written like a Rust range: '3..10' keeps the frames 3 up to (but excluding) frame 10, \
+                '3..=10' also keeps frame 10, '3..' keeps the frames from frame 3 onwards, and '..10' keeps the first 10 frames. \
+                Static images are left unchanged.")
+            .long(OperationId::Frames.as_str())
+            .action(ArgAction::Append)
+            .value_name("range")
+            .num_args(1))
+        .arg(Arg::new(OperationId::Gamma.as_str())
+            .help("Operation: apply gamma correction to the input image; values above 1 brighten the midtones, values below 1 darken them")
+            .long(OperationId::Gamma.as_str())
+            .action(ArgAction::Append)
+            .value_name("gamma")
+            .num_args(1))
+        .arg(Arg::new(OperationId::Gradient.as_str())
+            .help("Operation: draw a linear, radial or conic gradient with two or more color stops over the input image, e.g. '--gradient linear 90 \"rgba(255,0,0,255) rgba(0,0,255,255)\"'")
+            .long_help("The shape is either 'linear', followed by an angle in degrees (0 runs from bottom to top, 90 from left to \
+                right), 'radial', which runs from the center to the corners of the image, or 'conic', followed by the angle in \
+                degrees at which it starts (0 is at the top), which runs clockwise around the center of the image. \
+                The color stops are given as a single argument, e.g. 'rgba(255,0,0,255) rgba(255,255,255,255) 25% \
+                rgba(0,0,255,255)'. Each color may be followed by its position on the gradient as percentage; colors without \
+                a position are spread evenly. The colors are interpolated in the color space given by the \
+                'gradient-color-space' modifier.")
+            .long(OperationId::Gradient.as_str())
+            .action(ArgAction::Append)
+            .value_names(["shape", "angle", "color stops"])
+            .num_args(2..=3)
+            .allow_negative_numbers(true))
         .arg(Arg::new(OperationId::Grayscale.as_str())
             .help("Operation: discard the chrominance signal from the input image, so it becomes achromatic")
             .long_help("Note that (depending on the provided settings flags), the processed image may still be stored in a format which encodes its chrominance")
@@ -308,18 +568,101 @@ pub fn create_app(version: &'static str, about: &'static str, help_ops: &'static
             .help("Operation: invert the each pixel of the input image ")
             .long(OperationId::Invert.as_str())
             .action(ArgAction::Count))
+        .arg(Arg::new(OperationId::Levels.as_str())
+            .help("Operation: remap the input black and white points to the output black and white points, applying a gamma to the midtones")
+            .long(OperationId::Levels.as_str())
+            .action(ArgAction::Append)
+            .value_names(["input black", "input white", "gamma", "output black", "output white"])
+            .num_args(5))
+        .arg(Arg::new(OperationId::MergeChannels.as_str())
+            .help("Operation: replace the red, green, blue and alpha channels of the input image by the luma of the images loaded from the four provided paths")
+            .long_help("Instead of a path, 'keep' may be given for a channel, in which case the channel keeps its values. The \
+                images must have the same dimensions as the input image. An alpha channel is added if the input image has none \
+                and an image is given for the alpha channel. E.g. '--merge-channels ao.png roughness.png metalness.png keep' \
+                packs three grayscale maps into the color channels of a single image.")
+            .long(OperationId::MergeChannels.as_str())
+            .action(ArgAction::Append)
+            .value_names(["red", "green", "blue", "alpha"])
+            .num_args(4))
+        .arg(Arg::new(OperationId::NlMeans.as_str())
+            .help("Operation: denoise the input image using non-local means, which averages pixels whose surrounding patches are similar, preserving edges and textures. Takes a strength (on a scale of 0 to 255), a patch radius and a search radius; small radii (e.g. 1 and 5) are recommended, since the cost grows quickly with both")
+            .long(OperationId::NlMeans.as_str())
+            .action(ArgAction::Append)
+            .value_names(["strength", "patch radius", "search radius"])
+            .num_args(3))
         .arg(Arg::new(OperationId::Overlay.as_str())
             .help("Operation: overlay an image loaded from the provided path argument, over the input image (at a certain position)")
+            .long_help("An optional blend mode and opacity (in the range [0, 1]) may be given as first arguments. The blend mode is \
+                either one of the Porter-Duff operators 'normal' (or 'src-over'), 'clear', 'src', 'dst', 'dst-over', 'src-in', \
+                'dst-in', 'src-out', 'dst-out', 'src-atop', 'dst-atop' and 'xor', or one of the modes 'multiply', 'screen', \
+                'overlay', 'darken', 'lighten', 'soft-light', 'hard-light' and 'difference', which mix the colors of the overlay \
+                image with the colors of the input image. Only the pixels covered by the overlay image are changed. Without a \
+                blend mode, the overlay image is placed over the input image at full opacity. \
+                The overlay image may be scaled by giving 'width' followed by a width before the path, either as number of pixels \
+                (e.g. '100') or as percentage of the width of the input image (e.g. '20%'); its aspect ratio is preserved. \
+                The overlay image is placed with its top-left corner at the x and y position. Alternatively, a gravity ('north-west', \
+                'north', 'north-east', 'west', 'center', 'east', 'south-west', 'south' or 'south-east') followed by a horizontal \
+                and vertical margin anchors the overlay image to a side, corner or the center of the input image, e.g. \
+                '--overlay logo.png south-east 10 10'. Finally, 'tile' followed by a horizontal and vertical spacing and a rotation \
+                in degrees, repeats the overlay image over the complete input image, e.g. '--overlay screen 0.3 logo.png tile 40 40 -30'. \
+                The 'sampling-filter' modifier determines how pixels are interpolated when scaling and rotating.")
             .long(OperationId::Overlay.as_str())
-            .value_names(["overlay image path", "x", "y"])
+            .value_names([
+                "[blend mode]", "[opacity]", "[width]", "[size]", "overlay image path", "x | gravity | tile", "y | margin x | spacing x",
+                "[margin y | spacing y]", "[degrees]",
+            ])
             .action(ArgAction::Append)
-            .num_args(3))
+            .num_args(3..=9)
+            .allow_negative_numbers(true))
+        .arg(Arg::new(OperationId::Pad.as_str())
+            .help("Operation: grow the canvas of the input image by adding the given number of pixels to each side, filled with a color")
+            .long(OperationId::Pad.as_str())
+            .action(ArgAction::Append)
+            .value_names(["top", "right", "bottom", "left", "rgba(r,g,b,a)"])
+            .num_args(5))
+        .arg(Arg::new(OperationId::PremultiplyAlpha.as_str())
+            .help("Operation: multiply the color channels of each pixel of the input image by its alpha value")
+            .long(OperationId::PremultiplyAlpha.as_str())
+            .action(ArgAction::Count))
+        .arg(Arg::new(OperationId::Quantize.as_str())
+            .help("Operation: map the colors of the input image to the colors of a palette")
+            .long_help("The mode is either 'nearest', which maps each pixel to the nearest palette color, or 'dither', which \
+                distributes the quantization error using the 'dither-algorithm' and 'dither-serpentine' modifiers. The palette is \
+                either the path to a palette file, or one or more colors (e.g. 'rgba(0,0,0,255) rgba(255,255,255,255)'). \
+                Supported palette files are GIMP palettes ('.gpl'), lists of hex colors with one 'rrggbb' or 'rrggbbaa' color per \
+                line ('.hex' or '.txt'), and images, of which each distinct color becomes a palette color (e.g. a swatch strip).")
+            .long(OperationId::Quantize.as_str())
+            .action(ArgAction::Append)
+            .value_names(["mode", "palette"])
+            .num_args(2))
         .arg(Arg::new(OperationId::Resize.as_str())
             .help("Operation: resize the input image to x by y pixels")
+            .long_help("The x and y values are either a number of pixels (e.g. '100') or a percentage of the input dimension \
+                (e.g. '50%'). An optional mode may be given as first argument: 'exact' resizes to exactly x by y pixels, 'fit' \
+                resizes to the largest size within x by y pixels which preserves the aspect ratio and 'cover' resizes to the smallest \
+                size which covers x by y pixels while preserving the aspect ratio, after which the center is cropped to x by y pixels. \
+                The 'width-only' and 'height-only' modes take a single value, and compute the other dimension from the aspect ratio. \
+                Without a mode, the image is resized exactly, unless the 'preserve-aspect-ratio' modifier is set, in which case it \
+                behaves like 'fit'.")
             .long(OperationId::Resize.as_str())
             .action(ArgAction::Append)
-            .value_names(["x", "y"])
-            .num_args(2))
+            .value_names(["[mode]", "x", "y"])
+            .num_args(2..=3))
+        .arg(Arg::new(OperationId::ReverseFrames.as_str())
+            .help("Operation: reverse the order of the frames of an animated image, so it plays backwards")
+            .long(OperationId::ReverseFrames.as_str())
+            .action(ArgAction::Count))
+        .arg(Arg::new(OperationId::Rotate.as_str())
+            .help("Operation: rotate the input image clockwise by an arbitrary angle (in degrees)")
+            .long_help("The canvas argument is either 'crop', which keeps the dimensions of the input image, or 'expand', which grows \
+                the output image so the complete rotated image fits. Uncovered pixels are filled with the background color. \
+                Pixels are interpolated based on the 'sampling-filter' modifier: 'nearest' uses nearest neighbour interpolation, \
+                'triangle' uses bilinear interpolation and all other filters use bicubic interpolation.")
+            .long(OperationId::Rotate.as_str())
+            .action(ArgAction::Append)
+            .value_names(["degrees", "crop|expand", "rgba(r,g,b,a)"])
+            .num_args(3)
+            .allow_hyphen_values(true))
         .arg(Arg::new(OperationId::Rotate90.as_str())
             .help("Operation: rotate the input image by 90 degrees")
             .long(OperationId::Rotate90.as_str())
@@ -332,6 +675,85 @@ pub fn create_app(version: &'static str, about: &'static str, help_ops: &'static
             .help("Operation: rotate the input image by 270 degrees")
             .long(OperationId::Rotate270.as_str())
             .action(ArgAction::Count))
+        .arg(Arg::new(OperationId::Saturate.as_str())
+            .help("Operation: scale the saturation of the input image by the given factor; 0 produces a grayscale image, 1 leaves the image unchanged")
+            .long(OperationId::Saturate.as_str())
+            .action(ArgAction::Append)
+            .value_name("factor")
+            .num_args(1)
+            .allow_hyphen_values(true))
+        .arg(Arg::new(OperationId::ScaleAlpha.as_str())
+            .help("Operation: multiply the alpha channel of the input image by the given non-negative factor; an alpha channel is added if the input image has none")
+            .long(OperationId::ScaleAlpha.as_str())
+            .action(ArgAction::Append)
+            .value_name("factor")
+            .num_args(1))
+        .arg(Arg::new(OperationId::ScaleFrameDelay.as_str())
+            .help("Operation: multiply the delay of each frame of an animated image by the given positive factor; a factor below 1 speeds the animation up and a factor above 1 slows it down")
+            .long_help("The delays are rounded to whole milliseconds. GIF images store delays in units of 10 milliseconds, so \
+                GIF outputs round the delays once more to the nearest multiple of 10 milliseconds. Static images are left unchanged.")
+            .long(OperationId::ScaleFrameDelay.as_str())
+            .action(ArgAction::Append)
+            .value_name("factor")
+            .num_args(1))
+        .arg(Arg::new(OperationId::SetAlpha.as_str())
+            .help("Operation: replace the alpha channel of the input image by a mask loaded from the provided path; white mask pixels are opaque and black or transparent mask pixels are transparent")
+            .long_help("The mask must have the same dimensions as the input image. The alpha value of each pixel becomes the \
+                luma of the matching mask pixel, multiplied by the alpha value of that mask pixel. An alpha channel is added if \
+                the input image has none.")
+            .long(OperationId::SetAlpha.as_str())
+            .action(ArgAction::Append)
+            .value_name("path to mask")
+            .num_args(1))
+        .arg(Arg::new(OperationId::SetFrameDelay.as_str())
+            .help("Operation: set the delay of each frame of an animated image to the given number of milliseconds")
+            .long_help("GIF outputs round the delay to the nearest multiple of 10 milliseconds. Static images are left unchanged.")
+            .long(OperationId::SetFrameDelay.as_str())
+            .action(ArgAction::Append)
+            .value_name("milliseconds")
+            .num_args(1))
+        .arg(Arg::new(OperationId::SetFrameDelayAt.as_str())
+            .help("Operation: set the delay of the frame at the given zero-based index of an animated image to the given number of milliseconds")
+            .long_help("GIF outputs round the delay to the nearest multiple of 10 milliseconds. Static images are left unchanged.")
+            .long(OperationId::SetFrameDelayAt.as_str())
+            .action(ArgAction::Append)
+            .value_names(["index", "milliseconds"])
+            .num_args(2))
+        .arg(Arg::new(OperationId::SplitSpriteSheet.as_str())
+            .help("Operation: split a sprite sheet into an animated image with a frame for each cell of the given width and height, from left to right and top to bottom, where cells are separated by the given spacing")
+            .long_help("Cells which don't fit entirely within the sprite sheet are skipped. Each frame is shown for 100 milliseconds, \
+                which can be changed with --set-frame-delay. Combine with --output-frames to write each cell to a separate file.")
+            .long(OperationId::SplitSpriteSheet.as_str())
+            .action(ArgAction::Append)
+            .value_names(["cell width", "cell height", "spacing"])
+            .num_args(3))
+        .arg(Arg::new(OperationId::SpriteSheet.as_str())
+            .help("Operation: pack the frames of an animated image into a sprite sheet: a grid with the given number of columns, with the given spacing between the cells and filled with a background color")
+            .long_help("Frames are placed from left to right and top to bottom, and each cell is as large as the largest frame. \
+                If 'atlas' and a path are given as first arguments, a JSON atlas which describes the rectangle and delay of each \
+                frame is written to the path, e.g. '--sprite-sheet atlas sheet.json 8 2 \"rgba(0, 0, 0, 0)\"'. In batch mode, the \
+                atlas of each image is written next to its output image, with the file stem of the output image prepended to \
+                the file name of the path. To pack multiple images, combine with --assemble. Static images are left unchanged.")
+            .long(OperationId::SpriteSheet.as_str())
+            .action(ArgAction::Append)
+            .value_names(["[atlas path]", "columns", "spacing", "rgba(r,g,b,a)"])
+            .num_args(3..=5))
+        .arg(Arg::new(OperationId::SwapChannels.as_str())
+            .help("Operation: swap two channels of the input image, e.g. '--swap-channels r b' turns BGR into RGB; an alpha channel is added if one of the channels is 'alpha' and the input image has none")
+            .long(OperationId::SwapChannels.as_str())
+            .action(ArgAction::Append)
+            .value_names(["channel", "channel"])
+            .num_args(2))
+        .arg(Arg::new(OperationId::Trim.as_str())
+            .help("Operation: remove borders which match the color of the top-left pixel, where each channel may differ at most by the given tolerance")
+            .long(OperationId::Trim.as_str())
+            .action(ArgAction::Append)
+            .value_name("tolerance")
+            .num_args(1))
+        .arg(Arg::new(OperationId::UnpremultiplyAlpha.as_str())
+            .help("Operation: divide the color channels of each pixel of the input image by its alpha value; the inverse of premultiply-alpha")
+            .long(OperationId::UnpremultiplyAlpha.as_str())
+            .action(ArgAction::Count))
         .arg(Arg::new(OperationId::Unsharpen.as_str())
             .help("Operation: sharpen an image by combining an unsharp (blurred) mask of the input image with the (original) input image, sharpening for pixels where the difference is bigger than the provided threshold")
             .long(OperationId::Unsharpen.as_str())
@@ -349,8 +771,44 @@ pub fn create_app(version: &'static str, about: &'static str, help_ops: &'static
             .num_args(2))
 
         // image-operations(cli-arguments/modifiers):
+        .arg(Arg::new(OperationId::Channel.as_str())
+            .help("Operation modifier for all image operations: apply subsequent image operations to a single channel only, as if it were a grayscale image, or to all channels ('all')")
+            .long(OperationId::Channel.as_str())
+            .action(ArgAction::Append)
+            .value_name("channel")
+            .num_args(1)
+            .value_parser(["all", "red", "green", "blue", "alpha", "r", "g", "b", "a"])
+        )
+
+        .arg(Arg::new(OperationId::DitherAlgorithm.as_str())
+            .help("Operation modifier for 'dither', 'dither-quant' and 'quantize': distribute the quantization error using a specific dithering algorithm")
+            .long(OperationId::DitherAlgorithm.as_str())
+            .action(ArgAction::Append)
+            .value_name("algorithm")
+            .num_args(1)
+            .value_parser(["floyd-steinberg", "atkinson", "jarvis-judice-ninke", "stucki", "sierra", "bayer2x2", "bayer4x4", "bayer8x8"])
+        )
+
+        .arg(Arg::new(OperationId::DitherSerpentine.as_str())
+            .help("Operation modifier for 'dither', 'dither-quant' and 'quantize': scan every other row from right to left while diffusing the quantization error")
+            .long(OperationId::DitherSerpentine.as_str())
+            .action(ArgAction::Append)
+            .value_name("bool")
+            .num_args(1)
+            .value_parser(["true", "false"])
+        )
+
+        .arg(Arg::new(OperationId::GradientColorSpace.as_str())
+            .help("Operation modifier for 'gradient': interpolate the colors of a gradient in the sRGB (default), linear light or OKLab color space")
+            .long(OperationId::GradientColorSpace.as_str())
+            .action(ArgAction::Append)
+            .value_name("color space")
+            .num_args(1)
+            .value_parser(["srgb", "linear", "oklab"])
+        )
+
         .arg(Arg::new(OperationId::PreserveAspectRatio.as_str())
-            .help("Operation modifier for 'resize': preserve the aspect ratio of the original input image")
+            .help("Operation modifier for 'resize' (without a mode): preserve the aspect ratio of the original input image")
             .long(OperationId::PreserveAspectRatio.as_str())
             .action(ArgAction::Append)
             .value_name("bool")
@@ -359,7 +817,7 @@ pub fn create_app(version: &'static str, about: &'static str, help_ops: &'static
         )
 
         .arg(Arg::new(OperationId::SamplingFilter.as_str())
-            .help("Operation modifier for 'resize': resize the image using a specific sampling-filter")
+            .help("Operation modifier for 'resize' and 'rotate': resize or rotate the image using a specific sampling-filter")
             .long(OperationId::SamplingFilter.as_str())
             .action(ArgAction::Append)
             .value_name("sampling filter")
@@ -399,6 +857,14 @@ pub fn build_app_config(matches: &ArgMatches) -> anyhow::Result<Config<'_>> {
         builder = builder.select_frame(Some(index));
     }
 
+    // config(in)/assemble-frame-delay:
+    if let Some(value) = matches.get_one::<String>(ARG_ASSEMBLE_FRAME_DELAY) {
+        let delay = u32::from_str(value).map_err(|_| {
+            anyhow!("Provided argument for --assemble-frame-delay is not a valid number of milliseconds.")
+        })?;
+        builder = builder.assemble_frame_delay(delay);
+    }
+
     // config(out)/disable-automatic-color-type-adjustment:
     if matches.get_flag(ARG_DISABLE_AUTOMATIC_COLOR_TYPE_ADJUSTMENT) {
         builder = builder.disable_automatic_color_type_adjustment(true);
@@ -429,6 +895,11 @@ pub fn build_app_config(matches: &ArgMatches) -> anyhow::Result<Config<'_>> {
         builder = builder.gif_repeat(repeat);
     }
 
+    // config(out)/output-frames:
+    if matches.get_flag(ARG_OUTPUT_FRAMES) {
+        builder = builder.output_frames(true);
+    }
+
     // image-operations:
     //
     // Image operations are a bit more involved.
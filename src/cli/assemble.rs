@@ -0,0 +1,184 @@
+use sic_core::image::imageops::FilterType;
+use sic_core::image::{Delay, DynamicImage, Frame, GenericImageView, RgbaImage, imageops};
+use sic_core::{AnimatedImage, SicImage};
+use std::cmp::Ordering;
+use std::path::Path;
+
+/// Compares two paths in natural order, i.e. runs of digits are compared by their numeric value,
+/// so `frame_2.png` sorts before `frame_10.png`.
+pub fn natural_order(lhs: &Path, rhs: &Path) -> Ordering {
+    let lhs = lhs.to_string_lossy();
+    let rhs = rhs.to_string_lossy();
+
+    let mut lhs = lhs.chars().peekable();
+    let mut rhs = rhs.chars().peekable();
+
+    loop {
+        match (lhs.peek().copied(), rhs.peek().copied()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(l), Some(r)) if l.is_ascii_digit() && r.is_ascii_digit() => {
+                let l = take_digits(&mut lhs);
+                let r = take_digits(&mut rhs);
+
+                let ordering = compare_digits(&l, &r);
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            (Some(l), Some(r)) => {
+                if l != r {
+                    return l.cmp(&r);
+                }
+
+                lhs.next();
+                rhs.next();
+            }
+        }
+    }
+}
+
+fn take_digits(chars: &mut std::iter::Peekable<std::str::Chars>) -> String {
+    let mut digits = String::new();
+
+    while let Some(c) = chars.next_if(char::is_ascii_digit) {
+        digits.push(c);
+    }
+
+    digits
+}
+
+// Compares by numeric value first; on equal values, fewer leading zeros sort first.
+fn compare_digits(lhs: &str, rhs: &str) -> Ordering {
+    let l = lhs.trim_start_matches('0');
+    let r = rhs.trim_start_matches('0');
+
+    l.len()
+        .cmp(&r.len())
+        .then_with(|| l.cmp(r))
+        .then_with(|| lhs.len().cmp(&rhs.len()))
+}
+
+/// Assembles still images into an animated image, in the given order, where each frame is shown
+/// for `delay_ms` milliseconds.
+///
+/// The canvas of the animation has the dimensions of the first image. Images with different
+/// dimensions are resized to fit within the canvas (preserving their aspect ratio), and
+/// centered on a transparent background.
+pub fn assemble_animation(
+    images: impl IntoIterator<Item = DynamicImage>,
+    delay_ms: u32,
+) -> Option<SicImage> {
+    let mut images = images.into_iter().peekable();
+    let (width, height) = images.peek()?.dimensions();
+    let delay = Delay::from_numer_denom_ms(delay_ms, 1);
+
+    let frames = images
+        .map(|image| Frame::from_parts(fit_to_canvas(image, width, height), 0, 0, delay))
+        .collect::<Vec<_>>();
+
+    Some(SicImage::Animated(AnimatedImage::from_frames(frames)))
+}
+
+fn fit_to_canvas(image: DynamicImage, width: u32, height: u32) -> RgbaImage {
+    if image.dimensions() == (width, height) {
+        return image.into_rgba8();
+    }
+
+    let fitted = image
+        .resize(width, height, FilterType::Lanczos3)
+        .into_rgba8();
+
+    let mut canvas = RgbaImage::new(width, height);
+    let x = (width - fitted.width()) / 2;
+    let y = (height - fitted.height()) / 2;
+    imageops::overlay(&mut canvas, &fitted, i64::from(x), i64::from(y));
+
+    canvas
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sic_core::image::Rgba;
+    use std::path::PathBuf;
+
+    #[test]
+    fn natural_order_sorts_numbers_by_value() {
+        let mut paths = ["frame_10.png", "frame_2.png", "frame_1.png", "frame_02.png"]
+            .iter()
+            .map(PathBuf::from)
+            .collect::<Vec<_>>();
+
+        paths.sort_by(|l, r| natural_order(l, r));
+
+        let expected = ["frame_1.png", "frame_2.png", "frame_02.png", "frame_10.png"]
+            .iter()
+            .map(PathBuf::from)
+            .collect::<Vec<_>>();
+
+        assert_eq!(paths, expected);
+    }
+
+    #[test]
+    fn natural_order_sorts_text_lexicographically() {
+        assert_eq!(
+            natural_order(Path::new("a/b.png"), Path::new("a/c.png")),
+            Ordering::Less
+        );
+        assert_eq!(
+            natural_order(Path::new("a.png"), Path::new("a.png")),
+            Ordering::Equal
+        );
+        assert_eq!(
+            natural_order(Path::new("ab"), Path::new("a")),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn assemble_without_images() {
+        assert!(assemble_animation(Vec::new(), 100).is_none());
+    }
+
+    #[test]
+    fn assemble_frames_in_order_with_delay() {
+        let images = (0..3u8)
+            .map(|i| DynamicImage::ImageRgba8(RgbaImage::from_pixel(2, 2, Rgba([i, 0, 0, 255]))))
+            .collect::<Vec<_>>();
+
+        let Some(SicImage::Animated(animated)) = assemble_animation(images, 40) else {
+            panic!("expected an animated image");
+        };
+
+        let frames = animated.frames();
+        assert_eq!(frames.len(), 3);
+
+        for (i, frame) in frames.iter().enumerate() {
+            assert_eq!(frame.delay().numer_denom_ms(), (40, 1));
+            assert_eq!(frame.buffer().get_pixel(0, 0), &Rgba([i as u8, 0, 0, 255]));
+        }
+    }
+
+    #[test]
+    fn assemble_fits_mismatched_frames_to_canvas() {
+        let images = vec![
+            DynamicImage::ImageRgba8(RgbaImage::from_pixel(4, 4, Rgba([255, 0, 0, 255]))),
+            DynamicImage::ImageRgba8(RgbaImage::from_pixel(8, 4, Rgba([0, 255, 0, 255]))),
+        ];
+
+        let Some(SicImage::Animated(animated)) = assemble_animation(images, 100) else {
+            panic!("expected an animated image");
+        };
+
+        let second = animated.frames()[1].buffer();
+        assert_eq!(second.dimensions(), (4, 4));
+
+        // fitted to 4x2 and centered vertically
+        assert_eq!(second.get_pixel(0, 0), &Rgba([0, 0, 0, 0]));
+        assert_eq!(second.get_pixel(0, 1)[1], 255);
+        assert_eq!(second.get_pixel(3, 2)[1], 255);
+        assert_eq!(second.get_pixel(3, 3), &Rgba([0, 0, 0, 0]));
+    }
+}
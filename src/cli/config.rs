@@ -1,6 +1,8 @@
 use crate::cli::app::arg_names::{
-    ARG_GLOB_NO_SKIP_UNSUPPORTED_EXTENSIONS, ARG_INPUT, ARG_INPUT_GLOB, ARG_OUTPUT, ARG_OUTPUT_GLOB,
+    ARG_ASSEMBLE, ARG_ASSEMBLE_GLOB, ARG_GLOB_NO_SKIP_UNSUPPORTED_EXTENSIONS, ARG_INPUT,
+    ARG_INPUT_GLOB, ARG_OUTPUT, ARG_OUTPUT_GLOB,
 };
+use crate::cli::assemble::natural_order;
 use crate::cli::glob_base_dir::glob_builder_base;
 use anyhow::{Context, bail};
 use clap::ArgMatches;
@@ -45,6 +47,10 @@ pub enum InputOutputMode {
         inputs: CommonDir,
         output_root_folder: PathBuf,
     },
+    Assemble {
+        inputs: Vec<PathBuf>,
+        output: PathVariant,
+    },
 }
 
 impl InputOutputMode {
@@ -84,6 +90,35 @@ impl InputOutputMode {
                     output_root_folder: { output.into() },
                 })
             }
+            InputOutputModeType::Assemble => {
+                let inputs = if let Some(paths) = matches.get_many::<String>(ARG_ASSEMBLE) {
+                    paths.map(PathBuf::from).collect::<Vec<_>>()
+                } else {
+                    let pattern = matches
+                        .get_one::<String>(ARG_ASSEMBLE_GLOB)
+                        .with_context(|| "Assemble mode requires input paths or a glob pattern")?;
+                    let inputs = Self::create_glob_walker(pattern)?;
+
+                    let mut paths = Self::lookup_paths(
+                        inputs,
+                        !matches.get_flag(ARG_GLOB_NO_SKIP_UNSUPPORTED_EXTENSIONS),
+                    )?;
+                    paths.sort_by(|lhs, rhs| natural_order(lhs, rhs));
+                    paths
+                };
+
+                if inputs.is_empty() {
+                    bail!("Assemble mode requires at least one input image");
+                }
+
+                Ok(InputOutputMode::Assemble {
+                    inputs,
+                    output: match matches.get_one::<String>(ARG_OUTPUT) {
+                        Some(p) => PathVariant::Path(p.into()),
+                        None => PathVariant::StdStream,
+                    },
+                })
+            }
         }
     }
 
@@ -141,11 +176,14 @@ fn filter_unsupported_paths(paths: Vec<PathBuf>) -> Vec<PathBuf> {
 pub enum InputOutputModeType {
     Simple,
     Batch,
+    Assemble,
 }
 
 impl InputOutputModeType {
     pub fn from_arg_matches(matches: &ArgMatches) -> InputOutputModeType {
-        if matches.contains_id(ARG_INPUT_GLOB) {
+        if matches.contains_id(ARG_ASSEMBLE) || matches.contains_id(ARG_ASSEMBLE_GLOB) {
+            InputOutputModeType::Assemble
+        } else if matches.contains_id(ARG_INPUT_GLOB) {
             InputOutputModeType::Batch
         } else {
             InputOutputModeType::Simple
@@ -165,12 +203,18 @@ pub struct Config<'a> {
 
     pub selected_frame: Option<FrameIndex>,
 
+    /// Delay in milliseconds of each frame of an animation assembled from multiple input images.
+    pub assemble_frame_delay: u32,
+
     /// Disable color type adjustments on save.
     pub disable_automatic_color_type_adjustment: bool,
 
     /// Format to which an image will be converted (enforced).
     pub forced_output_format: Option<&'a str>,
 
+    /// Write each frame of an animated image to a separate output file.
+    pub output_frames: bool,
+
     /// Encoding settings for specific output formats.
     pub encoding_settings: FormatEncodingSettings,
 
@@ -195,12 +239,18 @@ impl Default for Config<'_> {
             // By default no frame is selected
             selected_frame: None,
 
+            // Defaults to showing each assembled frame for 100 milliseconds.
+            assemble_frame_delay: 100,
+
             // Defaults to using automatic color type adjustment where appropriate.
             disable_automatic_color_type_adjustment: false,
 
             // Defaults to not forcing a specific image output format.
             forced_output_format: None,
 
+            // Defaults to writing animated images to a single output file.
+            output_frames: false,
+
             // Default format encoding settings.
             encoding_settings: FormatEncodingSettings {
                 // Default JPEG quality is set to 80.
@@ -252,6 +302,12 @@ impl<'a> ConfigBuilder<'a> {
         self
     }
 
+    // config(in)
+    pub fn assemble_frame_delay(mut self, delay: u32) -> ConfigBuilder<'a> {
+        self.settings.assemble_frame_delay = delay;
+        self
+    }
+
     // config(out)
     pub fn forced_output_format(mut self, format: &'a str) -> ConfigBuilder<'a> {
         self.settings.forced_output_format = Some(format);
@@ -264,6 +320,12 @@ impl<'a> ConfigBuilder<'a> {
         self
     }
 
+    // config(out)
+    pub fn output_frames(mut self, toggle: bool) -> ConfigBuilder<'a> {
+        self.settings.output_frames = toggle;
+        self
+    }
+
     // config(out)
     pub fn jpeg_quality(mut self, quality: u8) -> ConfigBuilder<'a> {
         self.settings.encoding_settings.jpeg_quality = quality;
@@ -1,4 +1,5 @@
 pub mod app;
+pub mod assemble;
 pub mod config;
 pub mod glob_base_dir;
 pub mod license;
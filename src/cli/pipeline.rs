@@ -1,15 +1,17 @@
 use std::borrow::BorrowMut;
 use std::fs::File;
 use std::io::{self, Read, Seek, SeekFrom, Stdout, Write};
+use std::path::{Path, PathBuf};
 
+use crate::cli::assemble::assemble_animation;
 use crate::cli::config::{Config, InputOutputMode, InputOutputModeType, PathVariant};
 use crate::cli::license::LicenseTexts;
 use crate::cli::license::PrintTextFor;
 use anyhow::{Context, anyhow, bail};
-use sic_core::image;
+use sic_core::{SicImage, image};
 use sic_image_engine::engine::ImageEngine;
 use sic_io::decode;
-use sic_io::decode::SicImageDecoder;
+use sic_io::decode::{FrameIndex, SicImageDecoder};
 use sic_io::encode::SicImageEncoder;
 use sic_io::encode::dynamic::{DynamicEncoder, IntoImageEncoder};
 use sic_io::encode_settings::EncodeSettings;
@@ -55,9 +57,46 @@ pub fn run_with_devices<'c>(
 
             Ok(())
         }
+        InputOutputMode::Assemble { inputs, output } => {
+            if output.is_std_stream() {
+                warn_default_std_output_format();
+            }
+
+            let image = assemble(&inputs, config)?;
+
+            process_and_encode(
+                image,
+                |ext: Option<&str>| create_writer(&output, ext),
+                config,
+                &output,
+            )
+            .with_context(|| format!("With output: {}", output.describe_input()))
+        }
     }
 }
 
+/// Decode each input image as a still image, and assemble them into the frames of an animated image.
+fn assemble(inputs: &[PathBuf], config: &Config) -> anyhow::Result<SicImage> {
+    let decoder = SicImageDecoder::new(config.selected_frame.or(Some(FrameIndex::First)));
+
+    let images = inputs
+        .iter()
+        .map(|path| {
+            decode_still(&decoder, path).with_context(|| format!("With input: {}", path.display()))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    assemble_animation(images, config.assemble_frame_delay)
+        .ok_or_else(|| anyhow!("Unable to assemble an animated image without input images"))
+}
+
+fn decode_still(decoder: &SicImageDecoder, path: &Path) -> anyhow::Result<image::DynamicImage> {
+    let mut reader = decode::file_reader(path)?;
+    let image = decoder.decode(&mut reader)?;
+
+    Ok(image::DynamicImage::try_from(image)?)
+}
+
 fn warn_default_std_output_format() {
     eprintln!(
         "warn: The default output format when using stdout output (the current output mode) is \
@@ -83,10 +122,23 @@ where
     let decoder = SicImageDecoder::new(config.selected_frame);
     let img = decoder.decode(&mut reader)?;
 
+    process_and_encode(img, supply_writer, config, output_path_variant)
+}
+
+fn process_and_encode<W, WS>(
+    img: SicImage,
+    supply_writer: W,
+    config: &Config,
+    output_path_variant: &PathVariant,
+) -> anyhow::Result<()>
+where
+    W: Fn(Option<&str>) -> anyhow::Result<WS>,
+    WS: Write + Seek,
+{
     // Apply image operations
     let image_engine = ImageEngine::new(img);
-    let buffer = image_engine
-        .ignite(&config.image_operations_program)
+    let (buffer, atlases) = image_engine
+        .ignite_with_atlases(&config.image_operations_program)
         .with_context(|| "Unable to apply image operations.")?;
 
     // FIXME: decide whether in simple mode, extension should also change by default,
@@ -97,8 +149,51 @@ where
         None
     };
 
-    // Create a writer and the encoder
-    let writer = supply_writer(format)?;
+    match (buffer, output_path_variant) {
+        (SicImage::Animated(animated), PathVariant::Path(path)) if config.output_frames => {
+            let frames = animated.collect_frames();
+            let count = frames.len();
+
+            for (index, frame) in frames.into_iter().enumerate() {
+                let frame_path = PathVariant::Path(frame_output_path(path, index, count));
+                let image = SicImage::Static(image::DynamicImage::ImageRgba8(frame.into_buffer()));
+
+                encode(
+                    image,
+                    create_writer(&frame_path, format)?,
+                    config,
+                    &frame_path,
+                )
+                .with_context(|| format!("With frame output: {}", frame_path.describe_input()))?;
+            }
+        }
+        (_, PathVariant::StdStream) if config.output_frames => {
+            bail!("Unable to write frames to separate files: an output path is required")
+        }
+        (buffer, _) => encode(buffer, supply_writer(format)?, config, output_path_variant)?,
+    }
+
+    for atlas in atlases {
+        let path = atlas_output_path(atlas.path(), config, output_path_variant);
+
+        std::fs::write(&path, atlas.to_json()).with_context(|| {
+            format!(
+                "Unable to write sprite sheet atlas to path '{}'",
+                path.display()
+            )
+        })?;
+    }
+
+    Ok(())
+}
+
+fn encode<WS: Write + Seek>(
+    buffer: SicImage,
+    writer: WS,
+    config: &Config,
+    output_path_variant: &PathVariant,
+) -> anyhow::Result<()> {
+    // Create the encoder
     let encode_settings = create_encode_settings(config)?;
     let dynamic_encoder =
         create_dynamic_encoder(writer, config, &encode_settings, output_path_variant)?;
@@ -122,6 +217,36 @@ where
         .with_context(|| "Unable to write image")
 }
 
+/// The output path of a single frame, which is the output path with the zero-based frame index
+/// appended to its file stem, zero-padded so the paths sort in frame order, e.g. `out_07.png`.
+fn frame_output_path(path: &Path, index: usize, count: usize) -> PathBuf {
+    let width = count.saturating_sub(1).to_string().len();
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+
+    let mut file_name = format!("{stem}_{index:0width$}");
+    if let Some(ext) = path.extension() {
+        file_name.push('.');
+        file_name.push_str(&ext.to_string_lossy());
+    }
+
+    path.with_file_name(file_name)
+}
+
+/// The path to which a sprite sheet atlas is written. In batch mode, where each input would
+/// otherwise overwrite the same atlas, the atlas is written next to the output image instead, with
+/// the file stem of the output image prepended, e.g. `out/walk_sheet.json`.
+fn atlas_output_path(atlas: &Path, config: &Config, output_path_variant: &PathVariant) -> PathBuf {
+    match output_path_variant {
+        PathVariant::Path(output) if config.mode == InputOutputModeType::Batch => {
+            let stem = output.file_stem().unwrap_or_default().to_string_lossy();
+            let name = atlas.file_name().unwrap_or_default().to_string_lossy();
+
+            output.with_file_name(format!("{stem}_{name}"))
+        }
+        _ => atlas.to_path_buf(),
+    }
+}
+
 /// Create a reader which will be used to load the image.
 /// The reader can be a file or the stdin.
 /// If no file path is provided, the stdin will be assumed.
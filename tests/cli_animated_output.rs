@@ -0,0 +1,142 @@
+#![deny(clippy::all)]
+
+#[macro_use]
+pub mod common;
+
+use common::{SicTestCommandBuilder, setup_input_path, setup_output_path};
+use sic_core::image::codecs::gif::GifDecoder;
+use sic_core::image::codecs::png::PngDecoder;
+use sic_core::image::codecs::webp::WebPDecoder;
+use sic_core::image::{AnimationDecoder, Frame};
+use std::io::{BufReader, Cursor};
+
+fn input_frames(input: &str) -> Vec<Frame> {
+    let file = std::fs::File::open(setup_input_path(input)).unwrap();
+
+    GifDecoder::new(BufReader::new(file))
+        .unwrap()
+        .into_frames()
+        .collect_frames()
+        .unwrap()
+}
+
+fn apng_frames(bytes: &[u8]) -> Vec<Frame> {
+    PngDecoder::new(Cursor::new(bytes))
+        .unwrap()
+        .apng()
+        .unwrap()
+        .into_frames()
+        .collect_frames()
+        .unwrap()
+}
+
+fn webp_frames(bytes: &[u8]) -> Vec<Frame> {
+    WebPDecoder::new(Cursor::new(bytes))
+        .unwrap()
+        .into_frames()
+        .collect_frames()
+        .unwrap()
+}
+
+fn convert(input: &str, output: &str, args: &[&str]) -> Vec<u8> {
+    let mut process = SicTestCommandBuilder::new()
+        .input_from_resources(input)
+        .output_in_target(output)
+        .with_args(args.iter().copied())
+        .spawn_child();
+
+    assert!(process.wait().unwrap().success());
+
+    std::fs::read(setup_output_path(output)).unwrap()
+}
+
+fn delays(frames: &[Frame]) -> Vec<(u32, u32)> {
+    frames
+        .iter()
+        .map(|frame| frame.delay().numer_denom_ms())
+        .collect()
+}
+
+// The number of times the animation is played, from the 'acTL' chunk
+fn apng_num_plays(bytes: &[u8]) -> u32 {
+    let position = bytes
+        .windows(4)
+        .position(|window| window == b"acTL")
+        .unwrap();
+
+    let num_plays = &bytes[position + 8..position + 12];
+    u32::from_be_bytes(num_plays.try_into().unwrap())
+}
+
+#[test]
+fn gif_to_apng_keeps_frames_and_delays() {
+    let bytes = convert("loop.gif", "animated_output/loop.png", &[]);
+
+    let expected = input_frames("loop.gif");
+    let frames = apng_frames(&bytes);
+
+    assert_eq!(frames.len(), expected.len());
+    assert_eq!(delays(&frames), delays(&expected));
+    assert_eq!(frames[1].buffer(), expected[1].buffer());
+}
+
+#[test]
+fn gif_to_webp_keeps_frames_and_delays() {
+    let bytes = convert("loop.gif", "animated_output/loop.webp", &[]);
+
+    let expected = input_frames("loop.gif");
+    let frames = webp_frames(&bytes);
+
+    assert_eq!(frames.len(), expected.len());
+    assert_eq!(delays(&frames), delays(&expected));
+    assert_eq!(frames[1].buffer(), expected[1].buffer());
+}
+
+#[test]
+fn apng_to_webp() {
+    let input = std::fs::read(setup_input_path("apng_sample.png")).unwrap();
+    let bytes = convert("apng_sample.png", "animated_output/apng_sample.webp", &[]);
+
+    let expected = apng_frames(&input);
+    let frames = webp_frames(&bytes);
+
+    assert!(frames.len() > 1);
+    assert_eq!(frames.len(), expected.len());
+}
+
+#[test]
+fn apng_repeat() {
+    let infinite = convert("loop.gif", "animated_output/repeat_infinite.png", &[]);
+    assert_eq!(apng_num_plays(&infinite), 0);
+
+    let never = convert(
+        "loop.gif",
+        "animated_output/repeat_never.png",
+        &["--gif-repeat", "never"],
+    );
+    assert_eq!(apng_num_plays(&never), 1);
+
+    let finite = convert(
+        "loop.gif",
+        "animated_output/repeat_finite.png",
+        &["--gif-repeat", "2"],
+    );
+    assert_eq!(apng_num_plays(&finite), 3);
+}
+
+#[test]
+fn select_frame_still_writes_static_png() {
+    let bytes = convert(
+        "loop.gif",
+        "animated_output/select_frame.png",
+        &["--select-frame", "1"],
+    );
+
+    assert!(
+        PngDecoder::new(Cursor::new(&bytes))
+            .unwrap()
+            .is_apng()
+            .map(|is_apng| !is_apng)
+            .unwrap()
+    );
+}
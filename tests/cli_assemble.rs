@@ -0,0 +1,152 @@
+#![deny(clippy::all)]
+
+#[macro_use]
+pub mod common;
+
+use common::{SicTestCommandBuilder, setup_input_path, setup_output_path};
+use sic_core::image::codecs::gif::GifDecoder;
+use sic_core::image::{AnimationDecoder, Frame, Rgba};
+use std::path::Path;
+
+fn output_frames(output: &str) -> Vec<Frame> {
+    let file = std::fs::File::open(setup_output_path(output)).unwrap();
+
+    GifDecoder::new(std::io::BufReader::new(file))
+        .unwrap()
+        .into_frames()
+        .collect_frames()
+        .unwrap()
+}
+
+fn resource(name: &str) -> String {
+    setup_input_path(name).to_string_lossy().into_owned()
+}
+
+#[test]
+fn assemble_in_given_order() {
+    const OUT: &str = "assemble/given_order.gif";
+
+    let mut process = SicTestCommandBuilder::new()
+        .with_args([
+            "--assemble".to_string(),
+            resource("2x2_bbbb.png"),
+            resource("2x2_wwaw.png"),
+            resource("2x2_bbbb.png"),
+        ])
+        .output_in_target(OUT)
+        .with_args(["--assemble-frame-delay", "50"])
+        .spawn_child();
+
+    assert!(process.wait().unwrap().success());
+
+    let frames = output_frames(OUT);
+    assert_eq!(frames.len(), 3);
+
+    for frame in &frames {
+        assert_eq!(frame.buffer().dimensions(), (2, 2));
+        assert_eq!(frame.delay().numer_denom_ms(), (50, 1));
+    }
+
+    assert_eq!(frames[0].buffer().get_pixel(0, 0), &Rgba([0, 0, 0, 255]));
+    assert_eq!(
+        frames[1].buffer().get_pixel(0, 0),
+        &Rgba([255, 255, 255, 255])
+    );
+    assert_eq!(frames[2].buffer().get_pixel(0, 0), &Rgba([0, 0, 0, 255]));
+}
+
+#[test]
+fn assemble_from_glob() {
+    const OUT: &str = "assemble/from_glob.gif";
+
+    let pattern = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("resources")
+        .join("2x2_*.png");
+
+    let mut process = SicTestCommandBuilder::new()
+        .with_args([
+            "--assemble-glob".to_string(),
+            pattern.to_string_lossy().into_owned(),
+        ])
+        .output_in_target(OUT)
+        .spawn_child();
+
+    assert!(process.wait().unwrap().success());
+
+    let frames = output_frames(OUT);
+    assert_eq!(frames.len(), 4);
+
+    for frame in &frames {
+        assert_eq!(frame.delay().numer_denom_ms(), (100, 1));
+    }
+}
+
+#[test]
+fn assemble_resizes_mismatched_frames_to_first() {
+    const OUT: &str = "assemble/mismatched.gif";
+
+    let mut process = SicTestCommandBuilder::new()
+        .with_args([
+            "--assemble".to_string(),
+            resource("2x2_bbbb.png"),
+            resource("rainbow_8x6.bmp"),
+            resource("3x2_wbaaba.png"),
+        ])
+        .output_in_target(OUT)
+        .spawn_child();
+
+    assert!(process.wait().unwrap().success());
+
+    let frames = output_frames(OUT);
+    assert_eq!(frames.len(), 3);
+
+    for frame in &frames {
+        assert_eq!(frame.buffer().dimensions(), (2, 2));
+    }
+}
+
+#[test]
+fn assemble_then_apply_operations() {
+    const OUT: &str = "assemble/with_operations.gif";
+
+    let mut process = SicTestCommandBuilder::new()
+        .with_args([
+            "--assemble".to_string(),
+            resource("2x2_bbbb.png"),
+            resource("2x2_bbbb.png"),
+        ])
+        .output_in_target(OUT)
+        .with_args(["--invert", "--reverse-frames"])
+        .spawn_child();
+
+    assert!(process.wait().unwrap().success());
+
+    let frames = output_frames(OUT);
+    assert_eq!(frames.len(), 2);
+    assert_eq!(
+        frames[0].buffer().get_pixel(1, 1),
+        &Rgba([255, 255, 255, 255])
+    );
+}
+
+#[test]
+fn assemble_conflicts_with_input() {
+    let mut process = SicTestCommandBuilder::new()
+        .input_from_resources("2x2_bbbb.png")
+        .with_args(["--assemble".to_string(), resource("2x2_bbbb.png")])
+        .output_in_target("assemble/conflict.gif")
+        .spawn_child();
+
+    assert!(!process.wait().unwrap().success());
+}
+
+#[test]
+fn assemble_invalid_frame_delay() {
+    let mut process = SicTestCommandBuilder::new()
+        .with_args(["--assemble".to_string(), resource("2x2_bbbb.png")])
+        .output_in_target("assemble/invalid_delay.gif")
+        .with_args(["--assemble-frame-delay", "-1"])
+        .spawn_child();
+
+    assert!(!process.wait().unwrap().success());
+}
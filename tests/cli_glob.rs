@@ -41,6 +41,32 @@ fn modify_format() {
     assert!(count_files_with_ext_in_folder(check_path, "jpg") >= 12)
 }
 
+#[test]
+fn sprite_sheet_atlas_per_input() {
+    const OUT: &str = "globtest/sprite_sheet_atlas/";
+
+    let mut process = SicTestCommandBuilder::new()
+        .glob_input_from_resources("loop*.gif")
+        .glob_output_in_target(OUT)
+        .with_args([
+            "--sprite-sheet",
+            "atlas",
+            "sheet.json",
+            "4",
+            "0",
+            "rgba(0, 0, 0, 0)",
+        ])
+        .spawn_child();
+
+    let exit_status = process.wait().unwrap();
+    assert!(exit_status.success());
+
+    let check_path = setup_output_path(OUT);
+    assert!(check_path.join("loop_sheet.json").is_file());
+    assert!(check_path.join("loop-diff_sheet.json").is_file());
+    assert!(!Path::new("sheet.json").exists());
+}
+
 fn count_files_with_ext_in_folder<P: AsRef<Path>, S: AsRef<OsStr>>(path: P, ext: S) -> usize {
     std::fs::read_dir(path)
         .unwrap()
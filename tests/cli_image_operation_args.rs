@@ -208,16 +208,16 @@ mod diff {
 }
 
 #[cfg(test)]
-mod filter3x3 {
+mod dither {
     use super::*;
     use crate::common::*;
 
     #[test]
-    fn filter3x3() {
+    fn dither_with_algorithm() {
         let mut process = command(
             DEFAULT_IN,
-            "cio_f3x3_1.png",
-            "--filter3x3 1 2 3 4 5 -6 -7 -8 -9.555",
+            "cio_dither_atkinson.png",
+            "--dither-algorithm atkinson --dither-serpentine true --dither",
         );
         let result = process.wait();
         assert!(result.is_ok());
@@ -225,11 +225,11 @@ mod filter3x3 {
     }
 
     #[test]
-    fn filter3x3_emboss() {
+    fn dither_quant_with_ordered_algorithm() {
         let mut process = command(
             DEFAULT_IN,
-            "cio_f3x3_emboss.png",
-            "--filter3x3 -1 -1 0 -1 1 1 0 1 1",
+            "cio_dither_quant_bayer4x4.png",
+            "--dither-algorithm bayer4x4 --dither-quant 64 10",
         );
         let result = process.wait();
         assert!(result.is_ok());
@@ -237,11 +237,11 @@ mod filter3x3 {
     }
 
     #[test]
-    fn filter3x3_not() {
+    fn dither_unknown_algorithm() {
         let mut process = command(
             DEFAULT_IN,
-            "cio_f3x3_2.png",
-            "--filter3x3 1 2 3 4 p -6 -7 -8 -9.555",
+            "cio_dither_err.png",
+            "--dither-algorithm bayer3x3 --dither",
         );
         let result = process.wait();
         assert!(result.is_ok());
@@ -250,123 +250,338 @@ mod filter3x3 {
 }
 
 #[cfg(test)]
-mod fliph {
+mod quantize {
     use super::*;
     use crate::common::*;
 
     #[test]
-    fn fliph() {
-        let mut process = command(DEFAULT_IN, "cio_fliph.png", "--flip-horizontal");
+    fn quantize_palette_file() {
+        let mut process = command(
+            DEFAULT_IN,
+            "cio_quantize_gpl.png",
+            &[
+                "--quantize",
+                "dither",
+                setup_input_path("palette_4.gpl").to_str().unwrap(),
+            ]
+            .join(" "),
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn quantize_writes_palette_based_png() {
+        let mut process = command(
+            DEFAULT_IN,
+            "cio_quantize_indexed.png",
+            &[
+                "--quantize",
+                "nearest",
+                setup_input_path("palette_4.hex").to_str().unwrap(),
+            ]
+            .join(" "),
+        );
         let result = process.wait();
         assert!(result.is_ok());
         assert!(result.unwrap().success());
+
+        let bytes = std::fs::read(setup_output_path("cio_quantize_indexed.png")).unwrap();
+        assert!(bytes.windows(4).any(|chunk| chunk == b"PLTE"));
+    }
+
+    #[test]
+    fn quantize_missing_palette_file() {
+        let mut process = command(
+            DEFAULT_IN,
+            "cio_quantize_err.png",
+            "--quantize nearest palette_does_not_exist.gpl",
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert_not!(result.unwrap().success());
     }
 }
 
 #[cfg(test)]
-mod flipv {
+mod edges {
     use super::*;
     use crate::common::*;
 
     #[test]
-    fn flipv() {
-        let mut process = command(DEFAULT_IN, "cio_flipv.png", "--flip-vertical");
+    fn sobel() {
+        let mut process = command(DEFAULT_IN, "cio_edges_sobel.png", "--sobel");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn scharr() {
+        let mut process = command(DEFAULT_IN, "cio_edges_scharr.png", "--scharr");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn laplacian() {
+        let mut process = command(DEFAULT_IN, "cio_edges_laplacian.png", "--laplacian");
         let result = process.wait();
         assert!(result.is_ok());
         assert!(result.unwrap().success());
     }
+
+    #[test]
+    fn canny() {
+        let mut process = command(DEFAULT_IN, "cio_edges_canny1.png", "--canny 50 100");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn canny_low_exceeds_high() {
+        let mut process = command(DEFAULT_IN, "cio_edges_canny2.png", "--canny 100 50");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert_not!(result.unwrap().success());
+    }
 }
 
 #[cfg(test)]
-mod grayscale {
+mod histogram_equalization {
     use super::*;
     use crate::common::*;
 
     #[test]
-    fn grayscale() {
-        let mut process = command(DEFAULT_IN, "cio_gs.png", "--grayscale");
+    fn equalize() {
+        let mut process = command(DEFAULT_IN, "cio_equalize.png", "--equalize luma");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn clahe() {
+        let mut process = command(DEFAULT_IN, "cio_clahe.png", "--clahe 8 8 2");
         let result = process.wait();
         assert!(result.is_ok());
         assert!(result.unwrap().success());
     }
+
+    #[test]
+    fn clahe_zero_tiles() {
+        let mut process = command(DEFAULT_IN, "cio_clahe_err.png", "--clahe 0 8 2");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert_not!(result.unwrap().success());
+    }
 }
 
 #[cfg(test)]
-mod hue_rotate {
+mod denoise {
     use super::*;
     use crate::common::*;
 
     #[test]
-    fn hue_rotate() {
-        let mut process = command(DEFAULT_IN, "cio_hr.png", "--hue-rotate -90");
+    fn median() {
+        let mut process = command(DEFAULT_IN, "cio_denoise_median.png", "--median 2");
         let result = process.wait();
         assert!(result.is_ok());
         assert!(result.unwrap().success());
     }
 
     #[test]
-    fn hue_rotate_not() {
-        let mut process = command(DEFAULT_IN, "cio_hr2.png", "--hue-rotate -p");
+    fn bilateral() {
+        let mut process = command(
+            DEFAULT_IN,
+            "cio_denoise_bilateral.png",
+            "--bilateral 3 25 3",
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn bilateral_invalid_sigma() {
+        let mut process = command(
+            DEFAULT_IN,
+            "cio_denoise_bilateral_err.png",
+            "--bilateral 3 0 3",
+        );
         let result = process.wait();
         assert!(result.is_ok());
         assert_not!(result.unwrap().success());
     }
+
+    #[test]
+    fn nl_means() {
+        let mut process = command(DEFAULT_IN, "cio_denoise_nl_means.png", "--nl-means 10 1 2");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
 }
 
 #[cfg(test)]
-mod invert {
+mod morphology {
     use super::*;
     use crate::common::*;
 
     #[test]
-    fn invert() {
-        let mut process = command(DEFAULT_IN, "cio_invert.png", "--invert");
+    fn open_thresholded_mask() {
+        let mut process = command(
+            DEFAULT_IN,
+            "cio_morphology_open.png",
+            "--threshold --open 1 linf",
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn erode_dilate_close() {
+        let mut process = command(
+            DEFAULT_IN,
+            "cio_morphology_erode_dilate_close.png",
+            "--erode 1 l1 --dilate 2 l2 --close 1 linf",
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn morph_gradient() {
+        let mut process = command(
+            DEFAULT_IN,
+            "cio_morphology_gradient.png",
+            "--morph-gradient 1 l1",
+        );
         let result = process.wait();
         assert!(result.is_ok());
         assert!(result.unwrap().success());
     }
+
+    #[test]
+    fn unknown_norm() {
+        let mut process = command(DEFAULT_IN, "cio_morphology_err.png", "--erode 1 l3");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert_not!(result.unwrap().success());
+    }
 }
 
 #[cfg(test)]
-mod overlay {
+mod filter {
     use super::*;
     use crate::common::*;
 
     #[test]
-    fn overlay() {
+    fn filter() {
         let mut process = command(
             DEFAULT_IN,
-            "cio_overlay.png",
-            &[
-                "--overlay",
-                setup_input_path("3x2_wbaaba.png").to_str().unwrap(),
-                "0",
-                "0",
-            ]
-            .join(" "),
+            "cio_filter1.png",
+            "--filter 3x3 1 0.5 mirror -2 -1 0 -1 1 1 0 1 2 --invert",
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn filter_separable() {
+        let mut process = command(
+            DEFAULT_IN,
+            "cio_filter2.png",
+            "--filter 5x1-separable auto 0 wrap 1 1 1 1 1 1",
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn filter_default_divisor_and_bias() {
+        let mut process = command(
+            DEFAULT_IN,
+            "cio_filter5.png",
+            "--filter 3x3 clamp 1 1 1 1 1 1 1 1 1",
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn filter_not() {
+        let mut process = command(
+            DEFAULT_IN,
+            "cio_filter3.png",
+            "--filter 3x3 auto 0 clamp 1 1 1",
         );
         let result = process.wait();
         assert!(result.is_ok());
+        assert_not!(result.unwrap().success());
+    }
+
+    #[test]
+    fn filter_followed_by_output() {
+        let output = setup_output_path("cio_filter4.png");
+        let _ = std::fs::remove_file(&output);
+
+        let mut process = SicTestCommandBuilder::new()
+            .input_from_resources(DEFAULT_IN)
+            .with_args("--filter 3x3 auto 0 clamp 1 1 1 1 1 1 1 1 1 -o".split(' '))
+            .with_args([&output])
+            .spawn_child();
+        let result = process.wait();
+        assert!(result.is_ok());
         assert!(result.unwrap().success());
+        assert!(output.exists());
     }
 }
 
 #[cfg(test)]
-mod resize {
+mod filter3x3 {
     use super::*;
     use crate::common::*;
 
     #[test]
-    fn resize() {
-        let mut process = command(DEFAULT_IN, "cio_resize1.png", "--resize 10 10");
+    fn filter3x3() {
+        let mut process = command(
+            DEFAULT_IN,
+            "cio_f3x3_1.png",
+            "--filter3x3 1 2 3 4 5 -6 -7 -8 -9.555",
+        );
         let result = process.wait();
         assert!(result.is_ok());
         assert!(result.unwrap().success());
     }
 
     #[test]
-    fn resize_not() {
-        let mut process = command(DEFAULT_IN, "cio_resize2.png", "--resize 10 p");
+    fn filter3x3_emboss() {
+        let mut process = command(
+            DEFAULT_IN,
+            "cio_f3x3_emboss.png",
+            "--filter3x3 -1 -1 0 -1 1 1 0 1 1",
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn filter3x3_not() {
+        let mut process = command(
+            DEFAULT_IN,
+            "cio_f3x3_2.png",
+            "--filter3x3 1 2 3 4 p -6 -7 -8 -9.555",
+        );
         let result = process.wait();
         assert!(result.is_ok());
         assert_not!(result.unwrap().success());
@@ -374,13 +589,13 @@ mod resize {
 }
 
 #[cfg(test)]
-mod rotate90 {
+mod fliph {
     use super::*;
     use crate::common::*;
 
     #[test]
-    fn rotate90() {
-        let mut process = command(DEFAULT_IN, "cio_rot90.png", "--rotate90");
+    fn fliph() {
+        let mut process = command(DEFAULT_IN, "cio_fliph.png", "--flip-horizontal");
         let result = process.wait();
         assert!(result.is_ok());
         assert!(result.unwrap().success());
@@ -388,13 +603,13 @@ mod rotate90 {
 }
 
 #[cfg(test)]
-mod rotate180 {
+mod flipv {
     use super::*;
     use crate::common::*;
 
     #[test]
-    fn rotate180() {
-        let mut process = command(DEFAULT_IN, "cio_rot180.png", "--rotate180");
+    fn flipv() {
+        let mut process = command(DEFAULT_IN, "cio_flipv.png", "--flip-vertical");
         let result = process.wait();
         assert!(result.is_ok());
         assert!(result.unwrap().success());
@@ -402,13 +617,13 @@ mod rotate180 {
 }
 
 #[cfg(test)]
-mod rotate270 {
+mod grayscale {
     use super::*;
     use crate::common::*;
 
     #[test]
-    fn rotate270() {
-        let mut process = command(DEFAULT_IN, "cio_rot270.png", "--rotate270");
+    fn grayscale() {
+        let mut process = command(DEFAULT_IN, "cio_gs.png", "--grayscale");
         let result = process.wait();
         assert!(result.is_ok());
         assert!(result.unwrap().success());
@@ -416,21 +631,1139 @@ mod rotate270 {
 }
 
 #[cfg(test)]
-mod unsharpen {
+mod hue_rotate {
     use super::*;
     use crate::common::*;
 
     #[test]
-    fn unsharpen() {
-        let mut process = command(DEFAULT_IN, "cio_resize1.png", "--unsharpen 1.5 1");
+    fn hue_rotate() {
+        let mut process = command(DEFAULT_IN, "cio_hr.png", "--hue-rotate -90");
         let result = process.wait();
         assert!(result.is_ok());
         assert!(result.unwrap().success());
     }
 
     #[test]
-    fn unsharpen_not() {
-        let mut process = command(DEFAULT_IN, "cio_resize2.png", "--unsharpen 1.5 1.0");
+    fn hue_rotate_not() {
+        let mut process = command(DEFAULT_IN, "cio_hr2.png", "--hue-rotate -p");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert_not!(result.unwrap().success());
+    }
+}
+
+#[cfg(test)]
+mod invert {
+    use super::*;
+    use crate::common::*;
+
+    #[test]
+    fn invert() {
+        let mut process = command(DEFAULT_IN, "cio_invert.png", "--invert");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+}
+
+#[cfg(test)]
+mod overlay {
+    use super::*;
+    use crate::common::*;
+
+    #[test]
+    fn overlay() {
+        let mut process = command(
+            DEFAULT_IN,
+            "cio_overlay.png",
+            &[
+                "--overlay",
+                setup_input_path("3x2_wbaaba.png").to_str().unwrap(),
+                "0",
+                "0",
+            ]
+            .join(" "),
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn overlay_blended() {
+        let mut process = command(
+            DEFAULT_IN,
+            "cio_overlay_blended.png",
+            &[
+                "--overlay",
+                "soft-light",
+                "0.5",
+                setup_input_path("3x2_wbaaba.png").to_str().unwrap(),
+                "1",
+                "1",
+            ]
+            .join(" "),
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn overlay_anchored_and_scaled() {
+        let mut process = command(
+            DEFAULT_IN,
+            "cio_overlay_anchored_and_scaled.png",
+            &[
+                "--overlay",
+                "width",
+                "20%",
+                setup_input_path("3x2_wbaaba.png").to_str().unwrap(),
+                "south-east",
+                "10",
+                "10",
+                "--invert",
+            ]
+            .join(" "),
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn overlay_tiled() {
+        let mut process = command(
+            DEFAULT_IN,
+            "cio_overlay_tiled.png",
+            &[
+                "--overlay",
+                "screen",
+                "0.3",
+                setup_input_path("3x2_wbaaba.png").to_str().unwrap(),
+                "tile",
+                "20",
+                "20",
+                "-30",
+                "--invert",
+            ]
+            .join(" "),
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+}
+
+#[cfg(test)]
+mod crop_to {
+    use super::*;
+    use crate::common::*;
+
+    #[test]
+    fn crop_to() {
+        let mut process = command(DEFAULT_IN, "cio_crop_to1.png", "--crop-to 1000 1 center");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn crop_to_not() {
+        let mut process = command(DEFAULT_IN, "cio_crop_to2.png", "--crop-to 1 1 middle");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert_not!(result.unwrap().success());
+    }
+}
+
+#[cfg(test)]
+mod pad {
+    use super::*;
+    use crate::common::*;
+
+    #[test]
+    fn pad() {
+        let mut process = command(DEFAULT_IN, "cio_pad1.png", "--pad 1 2 3 4 rgba(0,0,0,255)");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn pad_not() {
+        let mut process = command(DEFAULT_IN, "cio_pad2.png", "--pad 1 2 3 rgba(0,0,0,255)");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert_not!(result.unwrap().success());
+    }
+}
+
+#[cfg(test)]
+mod extend_canvas {
+    use super::*;
+    use crate::common::*;
+
+    #[test]
+    fn extend_canvas() {
+        let mut process = command(
+            DEFAULT_IN,
+            "cio_extend_canvas1.png",
+            "--extend-canvas 10 5 south-east rgba(0,0,0,255)",
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn extend_canvas_not() {
+        let mut process = command(
+            DEFAULT_IN,
+            "cio_extend_canvas2.png",
+            "--extend-canvas 10 5 rgba(0,0,0,255)",
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert_not!(result.unwrap().success());
+    }
+}
+
+#[cfg(test)]
+mod resize {
+    use super::*;
+    use crate::common::*;
+
+    #[test]
+    fn resize() {
+        let mut process = command(DEFAULT_IN, "cio_resize1.png", "--resize 10 10");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn resize_percentage() {
+        let mut process = command(DEFAULT_IN, "cio_resize3.png", "--resize 50% 10");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn resize_cover() {
+        let mut process = command(DEFAULT_IN, "cio_resize4.png", "--resize cover 10 5");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn resize_width_only() {
+        let mut process = command(
+            DEFAULT_IN,
+            "cio_resize5.png",
+            "--resize width-only 10 --flip-vertical",
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn resize_not() {
+        let mut process = command(DEFAULT_IN, "cio_resize2.png", "--resize 10 p");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert_not!(result.unwrap().success());
+    }
+
+    #[test]
+    fn resize_mode_not() {
+        let mut process = command(DEFAULT_IN, "cio_resize6.png", "--resize fit 10");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert_not!(result.unwrap().success());
+    }
+
+    #[test]
+    fn resize_surplus_value_not() {
+        let mut process = command(DEFAULT_IN, "cio_resize7.png", "--resize width-only 4 100");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert_not!(result.unwrap().success());
+    }
+}
+
+#[cfg(test)]
+mod trim {
+    use super::*;
+    use crate::common::*;
+
+    #[test]
+    fn trim() {
+        let mut process = command(DEFAULT_IN, "cio_trim1.png", "--trim 16");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn trim_not() {
+        let mut process = command(DEFAULT_IN, "cio_trim2.png", "--trim 256");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert_not!(result.unwrap().success());
+    }
+}
+
+#[cfg(test)]
+mod rotate {
+    use super::*;
+    use crate::common::*;
+
+    #[test]
+    fn rotate_crop() {
+        let mut process = command(DEFAULT_IN, "cio_rot1.png", "--rotate 30 crop rgba(0,0,0,0)");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn rotate_expand() {
+        let mut process = command(
+            DEFAULT_IN,
+            "cio_rot2.png",
+            "--rotate -30 expand rgba(255,255,255,255)",
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn rotate_not() {
+        let mut process = command(
+            DEFAULT_IN,
+            "cio_rot3.png",
+            "--rotate 30 shrink rgba(0,0,0,0)",
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert_not!(result.unwrap().success());
+    }
+}
+
+#[cfg(test)]
+mod rotate90 {
+    use super::*;
+    use crate::common::*;
+
+    #[test]
+    fn rotate90() {
+        let mut process = command(DEFAULT_IN, "cio_rot90.png", "--rotate90");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+}
+
+#[cfg(test)]
+mod rotate180 {
+    use super::*;
+    use crate::common::*;
+
+    #[test]
+    fn rotate180() {
+        let mut process = command(DEFAULT_IN, "cio_rot180.png", "--rotate180");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+}
+
+#[cfg(test)]
+mod rotate270 {
+    use super::*;
+    use crate::common::*;
+
+    #[test]
+    fn rotate270() {
+        let mut process = command(DEFAULT_IN, "cio_rot270.png", "--rotate270");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+}
+
+#[cfg(test)]
+mod unsharpen {
+    use super::*;
+    use crate::common::*;
+
+    #[test]
+    fn unsharpen() {
+        let mut process = command(DEFAULT_IN, "cio_resize1.png", "--unsharpen 1.5 1");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn unsharpen_not() {
+        let mut process = command(DEFAULT_IN, "cio_resize2.png", "--unsharpen 1.5 1.0");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert_not!(result.unwrap().success());
+    }
+}
+
+#[cfg(test)]
+mod color_matrix {
+    use super::*;
+    use crate::common::*;
+
+    #[test]
+    fn color_matrix_preset() {
+        let mut process = command(
+            DEFAULT_IN,
+            "cio_color_matrix1.png",
+            "--color-matrix sepia --invert",
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn color_matrix_values() {
+        let mut process = command(
+            DEFAULT_IN,
+            "cio_color_matrix2.png",
+            "--color-matrix 0 0 1 0 0 0 1 0 0 0 1 0 0 0 0 0 0 0 -1 1",
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn color_matrix_not() {
+        let mut process = command(
+            DEFAULT_IN,
+            "cio_color_matrix3.png",
+            "--color-matrix 1 0 0 0 0",
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert_not!(result.unwrap().success());
+    }
+
+    #[test]
+    fn color_matrix_followed_by_output() {
+        let output = setup_output_path("cio_color_matrix4.png");
+        let _ = std::fs::remove_file(&output);
+
+        let mut process = SicTestCommandBuilder::new()
+            .input_from_resources(DEFAULT_IN)
+            .with_args(["--color-matrix", "sepia", "-o"])
+            .with_args([&output])
+            .spawn_child();
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+        assert!(output.exists());
+    }
+
+    #[test]
+    fn color_matrix_followed_by_operation_and_output() {
+        let output = setup_output_path("cio_color_matrix5.png");
+        let _ = std::fs::remove_file(&output);
+
+        let mut process = SicTestCommandBuilder::new()
+            .input_from_resources(DEFAULT_IN)
+            .with_args(["--color-matrix", "sepia", "--resize", "1", "1", "-o"])
+            .with_args([&output])
+            .spawn_child();
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+        assert!(output.exists());
+    }
+}
+
+#[cfg(test)]
+mod saturate {
+    use super::*;
+    use crate::common::*;
+
+    #[test]
+    fn saturate() {
+        let mut process = command(DEFAULT_IN, "cio_saturate1.png", "--saturate 1.5");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn saturate_negative() {
+        let mut process = command(DEFAULT_IN, "cio_saturate2.png", "--saturate -1");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn saturate_not() {
+        let mut process = command(DEFAULT_IN, "cio_saturate3.png", "--saturate a");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert_not!(result.unwrap().success());
+    }
+}
+
+#[cfg(test)]
+mod gamma {
+    use super::*;
+    use crate::common::*;
+
+    #[test]
+    fn gamma() {
+        let mut process = command(DEFAULT_IN, "cio_gamma1.png", "--gamma 2.2");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn gamma_not_positive() {
+        let mut process = command(DEFAULT_IN, "cio_gamma2.png", "--gamma 0");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert_not!(result.unwrap().success());
+    }
+}
+
+#[cfg(test)]
+mod exposure {
+    use super::*;
+    use crate::common::*;
+
+    #[test]
+    fn exposure() {
+        let mut process = command(DEFAULT_IN, "cio_exposure1.png", "--exposure 1");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn exposure_negative() {
+        let mut process = command(DEFAULT_IN, "cio_exposure2.png", "--exposure -1.5");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn exposure_not() {
+        let mut process = command(DEFAULT_IN, "cio_exposure3.png", "--exposure");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert_not!(result.unwrap().success());
+    }
+}
+
+#[cfg(test)]
+mod levels {
+    use super::*;
+    use crate::common::*;
+
+    #[test]
+    fn levels() {
+        let mut process = command(DEFAULT_IN, "cio_levels1.png", "--levels 10 240 1.2 0 255");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn levels_invalid_input_range() {
+        let mut process = command(DEFAULT_IN, "cio_levels2.png", "--levels 240 10 1.0 0 255");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert_not!(result.unwrap().success());
+    }
+}
+
+#[cfg(test)]
+mod curves {
+    use super::*;
+    use crate::common::*;
+
+    #[test]
+    fn curves() {
+        let mut process = command(
+            DEFAULT_IN,
+            "cio_curves1.png",
+            "--curves rgb 0:0,64:50,255:255",
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn curves_luminance() {
+        let mut process = command(
+            DEFAULT_IN,
+            "cio_curves2.png",
+            "--curves luminance 0:20,255:235",
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn curves_not() {
+        let mut process = command(DEFAULT_IN, "cio_curves3.png", "--curves rgb 0:0");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert_not!(result.unwrap().success());
+    }
+}
+
+#[cfg(test)]
+mod alpha {
+    use super::*;
+    use crate::common::*;
+
+    #[test]
+    fn set_alpha_scale_alpha_and_flatten() {
+        let mut process = command(
+            DEFAULT_IN,
+            "cio_alpha1.jpg",
+            &[
+                "--set-alpha",
+                setup_input_path(DEFAULT_IN).to_str().unwrap(),
+                "--scale-alpha 0.5",
+                "--flatten rgba(255,255,255,255)",
+            ]
+            .join(" "),
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn premultiply_unpremultiply_and_extract_alpha() {
+        let mut process = command(
+            DEFAULT_IN,
+            "cio_alpha2.png",
+            "--premultiply-alpha --unpremultiply-alpha --extract-alpha",
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn scale_alpha_not() {
+        let mut process = command(DEFAULT_IN, "cio_alpha3.png", "--scale-alpha -1");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert_not!(result.unwrap().success());
+    }
+
+    #[test]
+    fn set_alpha_not() {
+        let mut process = command(
+            DEFAULT_IN,
+            "cio_alpha4.png",
+            &[
+                "--set-alpha",
+                setup_input_path("3x2_wbaaba.png").to_str().unwrap(),
+            ]
+            .join(" "),
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert_not!(result.unwrap().success());
+    }
+}
+
+#[cfg(test)]
+mod chroma_key {
+    use super::*;
+    use crate::common::*;
+
+    #[test]
+    fn chroma_key() {
+        let mut process = command(
+            DEFAULT_IN,
+            "cio_chroma_key1.png",
+            "--chroma-key rgba(0,255,0,255) 60 20",
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn chroma_key_despill_and_color_to_alpha() {
+        let mut process = command(
+            DEFAULT_IN,
+            "cio_chroma_key2.png",
+            "--chroma-key despill rgba(0,255,0,255) 60 20 --color-to-alpha rgba(255,255,255,255)",
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn chroma_key_not() {
+        let mut process = command(
+            DEFAULT_IN,
+            "cio_chroma_key3.png",
+            "--chroma-key rgba(0,255,0,255) -1 20",
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert_not!(result.unwrap().success());
+    }
+}
+
+#[cfg(test)]
+mod gradient {
+    use super::*;
+    use crate::common::*;
+
+    #[test]
+    fn gradient_linear() {
+        let mut process = SicTestCommandBuilder::new()
+            .input_from_resources(DEFAULT_IN)
+            .output_in_target("cio_gradient1.png")
+            .with_args([
+                "--gradient",
+                "linear",
+                "-45",
+                "rgba(255,0,0,255) rgba(255,255,0,255) 30% rgba(0,0,255,255)",
+            ])
+            .spawn_child();
+
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn gradient_radial_and_conic_with_color_space() {
+        let mut process = SicTestCommandBuilder::new()
+            .input_from_resources(DEFAULT_IN)
+            .output_in_target("cio_gradient2.png")
+            .with_args([
+                "--gradient-color-space",
+                "oklab",
+                "--gradient",
+                "radial",
+                "rgba(0,0,0,0) 50% rgba(0,0,0,200)",
+                "--gradient",
+                "conic",
+                "90",
+                "rgba(255,0,0,128) rgba(0,255,0,128) rgba(255,0,0,128)",
+            ])
+            .spawn_child();
+
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn gradient_single_stop() {
+        let mut process = command(
+            DEFAULT_IN,
+            "cio_gradient3.png",
+            "--gradient radial rgba(255,0,0,255)",
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert_not!(result.unwrap().success());
+    }
+}
+
+#[cfg(test)]
+mod frame_delay {
+    use super::*;
+    use crate::common::*;
+    use sic_core::image::AnimationDecoder;
+    use sic_core::image::codecs::gif::GifDecoder;
+
+    fn output_delays(output: &str) -> Vec<u32> {
+        let file = std::fs::File::open(setup_output_path(output)).unwrap();
+
+        GifDecoder::new(std::io::BufReader::new(file))
+            .unwrap()
+            .into_frames()
+            .collect_frames()
+            .unwrap()
+            .iter()
+            .map(|frame| frame.delay().numer_denom_ms())
+            .map(|(numerator, denominator)| numerator / denominator)
+            .collect()
+    }
+
+    #[test]
+    fn set_frame_delay() {
+        let mut process = command(
+            "loop.gif",
+            "cio_set_frame_delay.gif",
+            "--set-frame-delay 50 --set-frame-delay-at 0 1000",
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+
+        let delays = output_delays("cio_set_frame_delay.gif");
+        assert!(delays.len() > 1);
+        assert_eq!(delays[0], 1000);
+        assert!(delays[1..].iter().all(|&delay| delay == 50));
+    }
+
+    #[test]
+    fn scale_frame_delay() {
+        let mut process = command(
+            "loop.gif",
+            "cio_scale_frame_delay.gif",
+            "--set-frame-delay 100 --scale-frame-delay 0.5 --flip-horizontal",
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+
+        let delays = output_delays("cio_scale_frame_delay.gif");
+        assert!(delays.iter().all(|&delay| delay == 50));
+    }
+
+    #[test]
+    fn scale_frame_delay_not_positive() {
+        let mut process = command(
+            "loop.gif",
+            "cio_scale_frame_delay_err.gif",
+            "--scale-frame-delay 0",
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert_not!(result.unwrap().success());
+    }
+
+    #[test]
+    fn set_frame_delay_at_out_of_range() {
+        let mut process = command(
+            "loop.gif",
+            "cio_set_frame_delay_at_err.gif",
+            "--set-frame-delay-at 1000 50",
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert_not!(result.unwrap().success());
+    }
+}
+
+#[cfg(test)]
+mod frame_editing {
+    use super::*;
+    use crate::common::*;
+    use sic_core::image::AnimationDecoder;
+    use sic_core::image::codecs::gif::GifDecoder;
+
+    fn frame_count(path: std::path::PathBuf) -> usize {
+        let file = std::fs::File::open(path).unwrap();
+
+        GifDecoder::new(std::io::BufReader::new(file))
+            .unwrap()
+            .into_frames()
+            .count()
+    }
+
+    #[test]
+    fn frames_and_boomerang() {
+        let mut process = command(
+            "loop.gif",
+            "cio_frames_boomerang.gif",
+            "--frames 1..4 --boomerang",
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+
+        assert_eq!(
+            frame_count(setup_output_path("cio_frames_boomerang.gif")),
+            4
+        );
+    }
+
+    #[test]
+    fn reverse_drop_and_duplicate_frames() {
+        let mut process = command(
+            "loop.gif",
+            "cio_reverse_drop_duplicate.gif",
+            "--reverse-frames --drop-frames 2 --duplicate-frame 0 3",
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+
+        let frames = frame_count(setup_input_path("loop.gif"));
+        assert_eq!(
+            frame_count(setup_output_path("cio_reverse_drop_duplicate.gif")),
+            frames - frames / 2 + 3
+        );
+    }
+
+    #[test]
+    fn frames_empty_range() {
+        let mut process = command("loop.gif", "cio_frames_err.gif", "--frames 1000..");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert_not!(result.unwrap().success());
+    }
+
+    #[test]
+    fn frames_script() {
+        let mut process = SicTestCommandBuilder::new()
+            .input_from_resources("loop.gif")
+            .output_in_target("cio_frames_script.gif")
+            .with_args(["--apply-operations", "frames ..=1; reverse-frames"])
+            .spawn_child();
+
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+
+        assert_eq!(frame_count(setup_output_path("cio_frames_script.gif")), 2);
+    }
+}
+
+#[cfg(test)]
+mod sprite_sheet {
+    use super::*;
+    use crate::common::*;
+    use sic_core::image::codecs::gif::GifDecoder;
+    use sic_core::image::{AnimationDecoder, GenericImageView};
+
+    fn gif_frames(path: std::path::PathBuf) -> Vec<sic_core::image::Frame> {
+        let file = std::fs::File::open(path).unwrap();
+
+        GifDecoder::new(std::io::BufReader::new(file))
+            .unwrap()
+            .into_frames()
+            .collect_frames()
+            .unwrap()
+    }
+
+    #[test]
+    fn sprite_sheet_round_trip() {
+        let frames = gif_frames(setup_input_path("loop.gif"));
+        let (width, height) = frames[0].buffer().dimensions();
+        let columns = 2;
+        let rows = frames.len().div_ceil(2) as u32;
+
+        let mut process = SicTestCommandBuilder::new()
+            .input_from_resources("loop.gif")
+            .output_in_target("cio_sprite_sheet.png")
+            .with_args(["--sprite-sheet", "2", "1", "rgba(0, 0, 0, 0)"])
+            .spawn_child();
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+
+        let sheet = sic_core::image::open(setup_output_path("cio_sprite_sheet.png")).unwrap();
+        assert_eq!(
+            sheet.dimensions(),
+            (columns * width + 1, rows * height + rows - 1)
+        );
+
+        let split_args = format!("--split-sprite-sheet {width} {height} 1");
+        let mut process = command(
+            "../target/cio_sprite_sheet.png",
+            "cio_sprite_sheet_split.gif",
+            &split_args,
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+
+        let split = gif_frames(setup_output_path("cio_sprite_sheet_split.gif"));
+        assert_eq!(split.len(), (columns * rows) as usize);
+        assert_eq!(split[0].buffer().dimensions(), (width, height));
+    }
+
+    #[test]
+    fn sprite_sheet_atlas() {
+        let atlas = setup_output_path("cio_sprite_sheet_atlas.json");
+        let _ = std::fs::remove_file(&atlas);
+
+        let mut process = SicTestCommandBuilder::new()
+            .input_from_resources("loop.gif")
+            .output_in_target("cio_sprite_sheet_atlas.png")
+            .with_args([
+                "--sprite-sheet".to_string(),
+                "atlas".to_string(),
+                atlas.to_string_lossy().into_owned(),
+                "4".to_string(),
+                "0".to_string(),
+                "rgba(0, 0, 0, 0)".to_string(),
+            ])
+            .spawn_child();
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+
+        let contents = std::fs::read_to_string(atlas).unwrap();
+        assert!(contents.contains(r#""index": 0, "x": 0, "y": 0"#));
+    }
+
+    #[test]
+    fn sprite_sheet_from_assembled_images() {
+        let mut process = SicTestCommandBuilder::new()
+            .with_args([
+                "--assemble".to_string(),
+                setup_input_path("2x2_bbbb.png")
+                    .to_string_lossy()
+                    .into_owned(),
+                setup_input_path("2x2_wwaw.png")
+                    .to_string_lossy()
+                    .into_owned(),
+                setup_input_path("2x2_baab.png")
+                    .to_string_lossy()
+                    .into_owned(),
+            ])
+            .output_in_target("cio_sprite_sheet_assembled.png")
+            .with_args(["--sprite-sheet", "3", "0", "rgba(0, 0, 0, 0)"])
+            .spawn_child();
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+
+        let sheet =
+            sic_core::image::open(setup_output_path("cio_sprite_sheet_assembled.png")).unwrap();
+        assert_eq!(sheet.dimensions(), (6, 2));
+    }
+
+    #[test]
+    fn split_sprite_sheet_to_separate_files() {
+        let mut process = command(
+            "3x3_babababab.png",
+            "cio_sprite_sheet_cells/cell.png",
+            "--split-sprite-sheet 1 1 0 --output-frames",
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+
+        for index in 0..9 {
+            let cell = setup_output_path(&format!("cio_sprite_sheet_cells/cell_{index}.png"));
+            let cell = sic_core::image::open(cell).unwrap();
+            assert_eq!(cell.dimensions(), (1, 1));
+        }
+    }
+
+    #[test]
+    fn split_sprite_sheet_cell_too_large() {
+        let mut process = command(
+            "3x3_babababab.png",
+            "cio_sprite_sheet_err.gif",
+            "--split-sprite-sheet 4 1 0",
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert_not!(result.unwrap().success());
+    }
+}
+
+#[cfg(test)]
+mod channels {
+    use super::*;
+    use crate::common::*;
+
+    #[test]
+    fn extract_channel() {
+        let mut process = command(DEFAULT_IN, "cio_channels1.png", "--extract-channel green");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn swap_channels() {
+        let mut process = command(DEFAULT_IN, "cio_channels2.png", "--swap-channels r a");
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn merge_channels() {
+        let mut process = command(
+            "2x3_wrabaa.png",
+            "cio_channels3.png",
+            &[
+                "--merge-channels",
+                setup_input_path("2x3_rrgrbb.bmp").to_str().unwrap(),
+                "keep",
+                "keep",
+                setup_input_path("2x3_yrgyyb.bmp").to_str().unwrap(),
+            ]
+            .join(" "),
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn merge_channels_dimensions_mismatch() {
+        let mut process = command(
+            "2x3_wrabaa.png",
+            "cio_channels4.png",
+            &[
+                "--merge-channels",
+                "keep",
+                setup_input_path("3x2_rgbrrb.bmp").to_str().unwrap(),
+                "keep",
+                "keep",
+            ]
+            .join(" "),
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert_not!(result.unwrap().success());
+    }
+
+    #[test]
+    fn channel_modifier() {
+        let mut process = command(
+            DEFAULT_IN,
+            "cio_channels5.png",
+            "--channel blue --invert --blur 1 --channel all --brighten 10",
+        );
+        let result = process.wait();
+        assert!(result.is_ok());
+        assert!(result.unwrap().success());
+    }
+
+    #[test]
+    fn channel_modifier_resize() {
+        let mut process = command(
+            DEFAULT_IN,
+            "cio_channels6.png",
+            "--channel red --resize 4 3",
+        );
         let result = process.wait();
         assert!(result.is_ok());
         assert_not!(result.unwrap().success());
@@ -98,3 +98,17 @@ fn only_o() {
     // expect a non zero exit status
     assert_not!(result.unwrap().success());
 }
+
+#[test]
+fn output_frames_to_stdout_is_rejected() {
+    use imagineer::cli::config::{ConfigBuilder, InputOutputMode, PathVariant};
+    use imagineer::cli::pipeline::run_with_devices;
+
+    let config = ConfigBuilder::new().output_frames(true).build();
+    let mode = InputOutputMode::Single {
+        input: PathVariant::Path(setup_input_path("loop.gif")),
+        output: PathVariant::StdStream,
+    };
+
+    assert!(run_with_devices(mode, &config).is_err());
+}